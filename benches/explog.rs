@@ -0,0 +1,15 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use twofloat::TwoFloat;
+
+fn explog_benchmark(c: &mut Criterion) {
+    let x = TwoFloat::new_add(1.2345678901234567, 3.1e-17);
+    let small = TwoFloat::new_add(0.012345678901234567, 3.1e-19);
+
+    c.bench_function("exp", |b| b.iter(|| black_box(x).exp()));
+    c.bench_function("exp2", |b| b.iter(|| black_box(x).exp2()));
+    c.bench_function("exp_m1", |b| b.iter(|| black_box(small).exp_m1()));
+    c.bench_function("ln", |b| b.iter(|| black_box(x).ln()));
+}
+
+criterion_group!(benches, explog_benchmark);
+criterion_main!(benches);
@@ -1,60 +1,545 @@
-use core::ops::{
-    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+#![macro_use]
+
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+use crate::{
+    status::{self, FpFlags},
+    TwoFloat,
 };
 
-use crate::TwoFloat;
+// The basic operation used to determine special results and status flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operation {
+    pub(crate) fn apply<F>(self, a: F, b: F) -> F
+    where
+        F: Add<Output = F> + Sub<Output = F> + Mul<Output = F> + Div<Output = F>,
+    {
+        match self {
+            Self::Add => a + b,
+            Self::Sub => a - b,
+            Self::Mul => a * b,
+            Self::Div => a / b,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+        }
+    }
+}
+
+// Generates the special value handling and the kernels of double-word
+// arithmetic for the type `$dw` with `hi` and `lo` words of the base type
+// `$f`. Divisors with magnitude above `$div_scale_limit` are scaled down
+// before division, and should be set to the largest power of two with a
+// normal reciprocal. `$underflow_scale` should be set to the reciprocal of
+// the square root of the smallest normal value of the base type. The
+// generated items refer to `Operation`, `status` and `FpFlags`, which must be
+// in scope where the macro is invoked.
+macro_rules! double_word_arithmetic {
+    ($dw:ident, $f:ident, $div_scale_limit:expr, $underflow_scale:expr) => {
+        // Returns the IEEE result of the equivalent operation on the high
+        // words `a` and `b`, for a result whose high word `hi` is zero or not
+        // finite, together with the exceptions raised. A zero result where the
+        // high words do not cancel exactly is always +0, as for exact
+        // cancellation in the base type.
+        pub(crate) fn special_value(op: Operation, a: $f, b: $f, hi: $f) -> ($f, FpFlags) {
+            let value = op.apply(a, b);
+            let finite_args = a.is_finite() && b.is_finite();
+            if value.is_nan() {
+                let flags = if a.is_nan() || b.is_nan() {
+                    FpFlags::empty()
+                } else {
+                    FpFlags::INVALID
+                };
+                ($f::NAN, flags)
+            } else if hi == 0.0 && value != 0.0 {
+                (0.0, FpFlags::empty())
+            } else if value.is_infinite() && finite_args {
+                let flags = if op == Operation::Div && b == 0.0 {
+                    FpFlags::DIVISION_BY_ZERO
+                } else {
+                    FpFlags::OVERFLOW
+                };
+                (value, flags)
+            } else {
+                let underflow = match op {
+                    Operation::Mul => a != 0.0 && b != 0.0,
+                    Operation::Div => a != 0.0,
+                    _ => false,
+                };
+                let flags = if value == 0.0 && underflow && finite_args {
+                    FpFlags::UNDERFLOW
+                } else {
+                    FpFlags::empty()
+                };
+                (value, flags)
+            }
+        }
+
+        // Classifies a result with high word `hi` for any type made of words
+        // of the base type whose `MIN_POSITIVE` has high word `min_positive`.
+        // Returns the IEEE result of the operation on the high words `a` and
+        // `b` if it replaces a non-finite or zero result, with the exceptions
+        // raised. A nonzero result with a high word of at most `min_positive`
+        // only underflows if `tiny_lost_precision` returns true.
+        #[inline]
+        pub(crate) fn classify_words(
+            hi: $f,
+            min_positive: $f,
+            op: Operation,
+            a: $f,
+            b: $f,
+            tiny_lost_precision: impl FnOnce() -> bool,
+        ) -> (Option<$f>, FpFlags) {
+            let abs_hi = hi.abs();
+            if abs_hi > min_positive && abs_hi <= $f::MAX {
+                (None, FpFlags::empty())
+            } else if hi.is_finite() && hi != 0.0 {
+                let flags = if tiny_lost_precision() {
+                    FpFlags::UNDERFLOW
+                } else {
+                    FpFlags::empty()
+                };
+                (None, flags)
+            } else {
+                let (value, flags) = special_value(op, a, b, hi);
+                (Some(value), flags)
+            }
+        }
+
+        // Classifies a result as for `check_special`, returning the
+        // exceptions instead of raising them. A nonzero result below
+        // `MIN_POSITIVE` only underflows if `lost_precision` returns true.
+        #[inline]
+        fn special_flags(
+            result: $dw,
+            op: Operation,
+            a: $f,
+            b: $f,
+            lost_precision: impl FnOnce(&$dw) -> bool,
+        ) -> ($dw, FpFlags) {
+            let (value, flags) = classify_words(result.hi, $dw::MIN_POSITIVE.hi, op, a, b, || {
+                result.abs() < $dw::MIN_POSITIVE && lost_precision(&result)
+            });
+            match value {
+                None => (result, flags),
+                Some(value) if value.is_nan() => ($dw::NAN, flags),
+                Some(value) => ($dw { hi: value, lo: 0.0 }, flags),
+            }
+        }
+
+        // Replaces a non-finite or zero result with the IEEE result of the
+        // equivalent operation on the high words `a` and `b`, so that
+        // infinities, NaN and signed zeros propagate in the same way as for
+        // the base type. Any exception is raised in the current status flags.
+        // Tiny sums and differences are exact multiples of the smallest
+        // subnormal value of the base type, so they never underflow.
+        #[inline]
+        fn check_special(result: $dw, op: Operation, a: $f, b: $f) -> $dw {
+            let (result, flags) = special_flags(result, op, a, b, |_| false);
+            status::raise(flags, op.name());
+            result
+        }
+
+        // Checks a product or quotient as for `check_special`, where `scaled`
+        // repeats the operation with an operand multiplied by
+        // `UNDERFLOW_SCALE`. A tiny result only underflows if it lost
+        // precision to the limited exponent range, as detected by
+        // `lost_precision`.
+        #[inline]
+        fn check_scaled(
+            result: $dw,
+            op: Operation,
+            a: $f,
+            b: $f,
+            scaled: impl FnOnce() -> $dw,
+        ) -> $dw {
+            let (result, flags) = special_flags(result, op, a, b, |r| lost_precision(r, scaled()));
+            status::raise(flags, op.name());
+            result
+        }
+
+        // Products and quotients with a tiny result are repeated with an
+        // operand multiplied by this power of two, which keeps the operands
+        // and the result of the repeated operation in the normal range.
+        pub(crate) const UNDERFLOW_SCALE: $f = $underflow_scale;
+
+        // Returns whether a tiny result differs from `scaled`, the result of
+        // the same operation with an operand multiplied by `UNDERFLOW_SCALE`,
+        // which is what the result would be with an unbounded exponent range.
+        #[inline]
+        fn lost_precision(result: &$dw, scaled: $dw) -> bool {
+            scaled.hi != result.hi * UNDERFLOW_SCALE || scaled.lo != result.lo * UNDERFLOW_SCALE
+        }
+
+        #[inline]
+        fn scale_up(x: &$dw) -> $dw {
+            $dw {
+                hi: x.hi * UNDERFLOW_SCALE,
+                lo: x.lo * UNDERFLOW_SCALE,
+            }
+        }
+
+        // Divisors above this limit have a subnormal reciprocal, so are scaled
+        // down before division to avoid losing precision.
+        const DIV_SCALE_LIMIT: $f = $div_scale_limit;
+
+        pub(crate) fn fast_two_sum(a: $f, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 1
+            let s = a + b;
+            let z = s - a;
+            $dw { hi: s, lo: b - z }
+        }
+
+        // The kernels below implement the arithmetic algorithms without
+        // handling special values, so that they can be combined without
+        // raising status flags for intermediate results.
+
+        #[inline]
+        pub(crate) fn two_sum(a: $f, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 2
+            let s = a + b;
+            let aa = s - b;
+            let bb = s - aa;
+            let da = a - aa;
+            let db = b - bb;
+            $dw { hi: s, lo: da + db }
+        }
+
+        #[inline]
+        fn two_diff(a: $f, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 2 modified for negative
+            // right-hand side
+            let s = a - b;
+            let aa = s + b;
+            let bb = s - aa;
+            let da = a - aa;
+            let db = b + bb;
+            $dw { hi: s, lo: da - db }
+        }
+
+        #[inline]
+        pub(crate) fn two_prod(a: $f, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 3
+            let p = a * b;
+            $dw {
+                hi: p,
+                lo: a.mul_add(b, -p),
+            }
+        }
+
+        #[inline]
+        fn add_word(a: &$dw, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 4
+            let (sh, sl) = two_sum(a.hi, b).into();
+            let v = a.lo + sl;
+            fast_two_sum(sh, v)
+        }
+
+        #[inline]
+        fn sub_word(a: &$dw, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 4 modified for negative
+            // right-hand side
+            let (sh, sl) = two_diff(a.hi, b).into();
+            let v = a.lo + sl;
+            fast_two_sum(sh, v)
+        }
+
+        #[inline]
+        fn word_sub(a: $f, b: &$dw) -> $dw {
+            // Joldes et al. (2017) Algorithm 4 modified for negative
+            // left-hand side
+            let (sh, sl) = two_diff(a, b.hi).into();
+            let v = sl - b.lo;
+            fast_two_sum(sh, v)
+        }
+
+        #[inline]
+        fn add(a: &$dw, b: &$dw) -> $dw {
+            // Joldes et al. (2017) Algorithm 6
+            let (sh, sl) = two_sum(a.hi, b.hi).into();
+            let (th, tl) = two_sum(a.lo, b.lo).into();
+            let c = sl + th;
+            let (vh, vl) = fast_two_sum(sh, c).into();
+            let w = tl + vl;
+            fast_two_sum(vh, w)
+        }
+
+        #[inline]
+        fn sub(a: &$dw, b: &$dw) -> $dw {
+            // Joldes et al. (2017) Algorithm 6 modified for negative
+            // right-hand side
+            let (sh, sl) = two_diff(a.hi, b.hi).into();
+            let (th, tl) = two_diff(a.lo, b.lo).into();
+            let c = sl + th;
+            let (vh, vl) = fast_two_sum(sh, c).into();
+            let w = tl + vl;
+            fast_two_sum(vh, w)
+        }
+
+        #[inline]
+        fn mul_word(a: &$dw, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 9
+            let (ch, cl1) = two_prod(a.hi, b).into();
+            let cl3 = a.lo.mul_add(b, cl1);
+            fast_two_sum(ch, cl3)
+        }
+
+        #[inline]
+        fn mul(a: &$dw, b: &$dw) -> $dw {
+            // Joldes et al. (2017) Algorithm 12
+            let (ch, cl1) = two_prod(a.hi, b.hi).into();
+            let tl0 = a.lo * b.lo;
+            let tl1 = a.hi.mul_add(b.lo, tl0);
+            let cl2 = a.lo.mul_add(b.hi, tl1);
+            let cl3 = cl1 + cl2;
+            fast_two_sum(ch, cl3)
+        }
+
+        #[inline]
+        fn div_word(a: &$dw, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 15
+            let th = a.hi / b;
+            let (ph, pl) = two_prod(th, b).into();
+            let dh = a.hi - ph;
+            let dt = dh - pl;
+            let d = dt + a.lo;
+            let tl = d / b;
+            fast_two_sum(th, tl)
+        }
+
+        // Approximates the reciprocal of `b` for Joldes et al. (2017)
+        // Algorithm 18. The residual 1 - b.hi th is computed exactly with an
+        // fma as in the paper, since rounding the product b.hi th, which is
+        // within one unit in the last place of 1, loses all the bits of the
+        // residual and leaves a relative error of about u instead of 9.8u^2.
+        #[inline]
+        fn recip_approx(b: &$dw) -> $dw {
+            let th = b.hi.recip();
+            let rh = (-b.hi).mul_add(th, 1.0);
+            let rl = -(b.lo * th);
+            let (eh, el) = fast_two_sum(rh, rl).into();
+            let e = $dw { hi: eh, lo: el };
+            let d = mul_word(&e, th);
+            add_word(&d, th)
+        }
+
+        #[inline]
+        fn word_div(a: $f, b: &$dw) -> $dw {
+            // Joldes et al. (2017) Algorithm 18 modified for the left-hand side
+            // having a zero value in the low word
+            if b.hi.abs() > DIV_SCALE_LIMIT && b.hi.is_finite() {
+                return word_div(a * 0.25, &mul_word(b, 0.25));
+            }
+            mul_word(&recip_approx(b), a)
+        }
+
+        #[inline]
+        fn div(a: &$dw, b: &$dw) -> $dw {
+            // Joldes et al. (2017) Algorithm 18
+            if b.hi.abs() > DIV_SCALE_LIMIT && b.hi.is_finite() {
+                return div(&mul_word(a, 0.25), &mul_word(b, 0.25));
+            }
+            mul(a, &recip_approx(b))
+        }
+
+        #[inline]
+        fn div_words(a: $f, b: $f) -> $dw {
+            // Joldes et al. (2017) Algorithm 15 modified for the left-hand
+            // side having a zero value in the low word
+            let th = a / b;
+            let (ph, pl) = two_prod(th, b).into();
+            let dh = a - ph;
+            let d = dh - pl;
+            let tl = d / b;
+            fast_two_sum(th, tl)
+        }
+
+        // The kernels below repeat the products and quotients with the
+        // smaller factor or the dividend multiplied by `UNDERFLOW_SCALE`.
+        // The smaller factor of a tiny product is at most the square root of
+        // the smallest normal value, and the dividend of a tiny quotient is
+        // small, so the scaled operands do not overflow.
+
+        fn two_prod_scaled(a: $f, b: $f) -> $dw {
+            if a.abs() < b.abs() {
+                two_prod(a * UNDERFLOW_SCALE, b)
+            } else {
+                two_prod(a, b * UNDERFLOW_SCALE)
+            }
+        }
+
+        fn mul_word_scaled(a: &$dw, b: $f) -> $dw {
+            if a.hi.abs() < b.abs() {
+                mul_word(&scale_up(a), b)
+            } else {
+                mul_word(a, b * UNDERFLOW_SCALE)
+            }
+        }
+
+        fn mul_scaled(a: &$dw, b: &$dw) -> $dw {
+            if a.hi.abs() < b.hi.abs() {
+                mul(&scale_up(a), b)
+            } else {
+                mul(a, &scale_up(b))
+            }
+        }
+
+        fn div_words_scaled(a: $f, b: $f) -> $dw {
+            div_words(a * UNDERFLOW_SCALE, b)
+        }
+
+        fn div_word_scaled(a: &$dw, b: $f) -> $dw {
+            div_word(&scale_up(a), b)
+        }
 
-pub(crate) fn fast_two_sum(a: f64, b: f64) -> TwoFloat {
-    // Joldes et al. (2017) Algorithm 1
-    let s = a + b;
-    let z = s - a;
-    TwoFloat { hi: s, lo: b - z }
+        fn word_div_scaled(a: $f, b: &$dw) -> $dw {
+            word_div(a * UNDERFLOW_SCALE, b)
+        }
+
+        fn div_scaled(a: &$dw, b: &$dw) -> $dw {
+            div(&scale_up(a), b)
+        }
+    };
+}
+
+double_word_arithmetic!(TwoFloat, f64, 4.49423283715579e307, 6.703903964971299e153);
+
+// Returns the result of an arithmetic operation on two `TwoFloat` values with
+// the exceptions it raises, without raising them in the status flags.
+pub(crate) fn flagged_op(op: Operation, a: &TwoFloat, b: &TwoFloat) -> (TwoFloat, FpFlags) {
+    match op {
+        Operation::Add => special_flags(add(a, b), op, a.hi, b.hi, |_| false),
+        Operation::Sub => special_flags(sub(a, b), op, a.hi, b.hi, |_| false),
+        Operation::Mul => special_flags(mul(a, b), op, a.hi, b.hi, |r| {
+            lost_precision(r, mul_scaled(a, b))
+        }),
+        Operation::Div => special_flags(div(a, b), op, a.hi, b.hi, |r| {
+            lost_precision(r, div_scaled(a, b))
+        }),
+    }
+}
+
+// The square of the unit roundoff u = 2^-53 of f64.
+const U2: f64 = 1.232595164407831e-32;
+
+// Bounds on the relative error of the `TwoFloat` operators in the absence of
+// underflow, from Joldes et al. (2017): 2u^2 for Algorithms 4 and 9, 3u^2 for
+// Algorithm 15, 3u^2 + 13u^3 for Algorithm 6, 4u^2 for Algorithm 12 and
+// 9.8u^2 for Algorithm 18, rounded up. Used by `TwoFloatInterval` and
+// `TrackedTwoFloat`.
+pub(crate) const ADD_WORD_ERROR_BOUND: f64 = 2.0 * U2;
+pub(crate) const MUL_WORD_ERROR_BOUND: f64 = 2.0 * U2;
+pub(crate) const DIV_WORD_ERROR_BOUND: f64 = 3.0 * U2;
+pub(crate) const ADD_ERROR_BOUND: f64 = 3.25 * U2;
+pub(crate) const MUL_ERROR_BOUND: f64 = 4.0 * U2;
+pub(crate) const DIV_ERROR_BOUND: f64 = 10.0 * U2;
+
+// The low word of the reciprocal used by Algorithm 18 has an absolute error of
+// at most 2^-1072 when it is subnormal, so the division by `b` has an
+// additional relative error of at most this factor times |b|, which is only
+// significant for divisors above about 2^960.
+pub(crate) const RECIP_UNDERFLOW_ERROR: f64 = 8e-323; // 2^-1070
+
+// Gives a zero remainder the sign of the dividend, as for f64.
+#[inline]
+fn rem_sign(result: TwoFloat, lhs: f64) -> TwoFloat {
+    if result.hi == 0.0 {
+        TwoFloat::from(0.0f64.copysign(lhs))
+    } else {
+        result
+    }
+}
+
+// Approximates the sum of the terms by non-overlapping words, using VecSum
+// followed by VecSumErrBranch (Fabiano et al. (2019) Algorithms 3 and 4) on
+// the terms sorted in order of decreasing magnitude. VecSum is exact, but
+// VecSumErrBranch stops once the last word is filled and discards the
+// remaining error terms, so the result is truncated rather than exact. The
+// words are then rounded to nearest in turn, so that the representation of a
+// value is unique. Used by the wider `ThreeFloat` and `QuadFloat` types.
+pub(crate) fn renormalize(terms: &mut [f64], words: &mut [f64]) {
+    terms.sort_unstable_by(|a, b| b.abs().partial_cmp(&a.abs()).unwrap_or(Ordering::Equal));
+    for i in (0..terms.len() - 1).rev() {
+        let (s, e) = two_sum(terms[i], terms[i + 1]).into();
+        terms[i] = s;
+        terms[i + 1] = e;
+    }
+
+    let n = words.len();
+    words.iter_mut().for_each(|w| *w = 0.0);
+    let mut j = 0;
+    let mut eps = terms[0];
+    for &e in &terms[1..] {
+        let (s, err) = fast_two_sum(eps, e).into();
+        words[j] = s;
+        if err == 0.0 {
+            eps = s;
+        } else if j == n - 1 {
+            eps = 0.0;
+            break;
+        } else {
+            j += 1;
+            eps = err;
+        }
+    }
+    if eps != 0.0 {
+        words[j] = eps;
+    }
+
+    let mut x = words[0];
+    for i in 1..n {
+        let (s, e) = two_sum(x, words[i]).into();
+        words[i - 1] = s;
+        x = e;
+    }
+    words[n - 1] = x;
 }
 
 impl TwoFloat {
     /// Creates a new `TwoFloat` by adding two `f64` values using Algorithm 2
     /// from Joldes et al. (2017).
     pub fn new_add(a: f64, b: f64) -> Self {
-        let s = a + b;
-        let aa = s - b;
-        let bb = s - aa;
-        let da = a - aa;
-        let db = b - bb;
-        Self { hi: s, lo: da + db }
+        check_special(two_sum(a, b), Operation::Add, a, b)
     }
 
     /// Creates a new `TwoFloat` by subtracting two `f64` values using
     /// Algorithm 2 from Joldes et al. (2017) modified for negative right-hand
     /// side.
     pub fn new_sub(a: f64, b: f64) -> Self {
-        let s = a - b;
-        let aa = s + b;
-        let bb = s - aa;
-        let da = a - aa;
-        let db = b + bb;
-        Self { hi: s, lo: da - db }
+        check_special(two_diff(a, b), Operation::Sub, a, b)
     }
 
     /// Creates a new `TwoFloat` by multiplying two `f64` values using
     /// Algorithm 3 from Joldes et al. (2017).
     pub fn new_mul(a: f64, b: f64) -> Self {
-        let p = a * b;
-        Self {
-            hi: p,
-            lo: a.mul_add(b, -p),
-        }
+        check_scaled(two_prod(a, b), Operation::Mul, a, b, || {
+            two_prod_scaled(a, b)
+        })
     }
 
     /// Creates a new `TwoFloat` by dividing two `f64` values using Algorithm
     /// 15 from Joldes et al. (2017) modified for the left-hand-side having a
     /// zero value in the low word.
     pub fn new_div(a: f64, b: f64) -> Self {
-        let th = a / b;
-        let (ph, pl) = Self::new_mul(th, b).into();
-        let dh = a - ph;
-        let d = dh - pl;
-        let tl = d / b;
-        fast_two_sum(th, tl)
+        check_scaled(div_words(a, b), Operation::Div, a, b, || {
+            div_words_scaled(a, b)
+        })
     }
 }
 
@@ -71,138 +556,116 @@ binary_ops! {
     /// Implements addition of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4.
     fn Add::add<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_add(self.hi, *rhs).into();
-        let v = self.lo + sl;
-        fast_two_sum(sh, v)
+        check_special(add_word(self, *rhs), Operation::Add, self.hi, *rhs)
     }
 
     /// Implements addition of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4.
     fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_add(rhs.hi, *self).into();
-        let v = rhs.lo + sl;
-        fast_two_sum(sh, v)
+        check_special(add_word(rhs, *self), Operation::Add, *self, rhs.hi)
     }
 
     /// Implements addition of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 6.
     fn Add::add<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_add(self.hi, rhs.hi).into();
-        let (th, tl) = TwoFloat::new_add(self.lo, rhs.lo).into();
-        let c = sl + th;
-        let (vh, vl) = fast_two_sum(sh, c).into();
-        let w = tl + vl;
-        fast_two_sum(vh, w)
+        check_special(add(self, rhs), Operation::Add, self.hi, rhs.hi)
     }
 
     /// Implements subtraction of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4 modified for negative right-hand side.
     fn Sub::sub<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_sub(self.hi, *rhs).into();
-        let v = self.lo + sl;
-        fast_two_sum(sh, v)
+        check_special(sub_word(self, *rhs), Operation::Sub, self.hi, *rhs)
     }
 
     /// Implements subtraction of `f64` and `TwoFloat` using Joldes et al.
     /// (2017) Algorithm 4 modified for negative left-hand side.
     fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_sub(*self, rhs.hi).into();
-        let v = sl - rhs.lo;
-        fast_two_sum(sh, v)
+        check_special(word_sub(*self, rhs), Operation::Sub, *self, rhs.hi)
     }
 
     /// Implements subtraction of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 6 modified for a negative right-hand side.
     fn Sub::sub<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let (sh, sl) = TwoFloat::new_sub(self.hi, rhs.hi).into();
-        let (th, tl) = TwoFloat::new_sub(self.lo, rhs.lo).into();
-        let c = sl + th;
-        let (vh, vl) = fast_two_sum(sh, c).into();
-        let w = tl + vl;
-        fast_two_sum(vh, w)
+        check_special(sub(self, rhs), Operation::Sub, self.hi, rhs.hi)
     }
 
     /// Implements multiplication of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 9.
     fn Mul::mul<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let (ch, cl1) = TwoFloat::new_mul(self.hi, *rhs).into();
-        let cl3 = self.lo.mul_add(*rhs, cl1);
-        fast_two_sum(ch, cl3)
+        check_scaled(mul_word(self, *rhs), Operation::Mul, self.hi, *rhs, || {
+            mul_word_scaled(self, *rhs)
+        })
     }
 
     /// Implements multiplication of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 9.
     fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let (ch, cl1) = TwoFloat::new_mul(rhs.hi, *self).into();
-        let cl3 = rhs.lo.mul_add(*self, cl1);
-        fast_two_sum(ch, cl3)
+        check_scaled(mul_word(rhs, *self), Operation::Mul, *self, rhs.hi, || {
+            mul_word_scaled(rhs, *self)
+        })
     }
 
     /// Implements multiplication of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 12.
     fn Mul::mul<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let (ch, cl1) = TwoFloat::new_mul(self.hi, rhs.hi).into();
-        let tl0 = self.lo * rhs.lo;
-        let tl1 = self.hi.mul_add(rhs.lo, tl0);
-        let cl2 = self.lo.mul_add(rhs.hi, tl1);
-        let cl3 = cl1 + cl2;
-        fast_two_sum(ch, cl3)
+        check_scaled(mul(self, rhs), Operation::Mul, self.hi, rhs.hi, || {
+            mul_scaled(self, rhs)
+        })
     }
 
     /// Implements division of `TwoFloat` and `f64` using Joldes et al. (2017)
     /// Algorithm 15
     fn Div::div<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let th = self.hi / rhs;
-        let (ph, pl) = TwoFloat::new_mul(th, *rhs).into();
-        let dh = self.hi - ph;
-        let dt = dh - pl;
-        let d = dt + self.lo;
-        let tl = d / rhs;
-        fast_two_sum(th, tl)
+        check_scaled(div_word(self, *rhs), Operation::Div, self.hi, *rhs, || {
+            div_word_scaled(self, *rhs)
+        })
     }
 
     /// Implements division of `f64` and `TwoFloat` using Joldes et al. (2017)
     /// Algorithm 18 modified for the left-hand side having a zero value in
     /// the low word.
     fn Div::div<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let th = rhs.hi.recip();
-        let rh = 1.0 - rhs.hi * th;
-        let rl = -(rhs.lo * th);
-        let (eh, el) = fast_two_sum(rh, rl).into();
-        let e = TwoFloat { hi: eh, lo: el };
-        let d = e * th;
-        let m = d + th;
-        let (ch, cl1) = TwoFloat::new_mul(m.hi, *self).into();
-        let cl3 = m.lo.mul_add(*self, cl1);
-        fast_two_sum(ch, cl3)
+        check_scaled(word_div(*self, rhs), Operation::Div, *self, rhs.hi, || {
+            word_div_scaled(*self, rhs)
+        })
     }
 
     /// Implements division of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 18.
     fn Div::div<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let th = rhs.hi.recip();
-        let rh = 1.0 - rhs.hi * th;
-        let rl = -(rhs.lo * th);
-        let (eh, el) = fast_two_sum(rh, rl).into();
-        let e = TwoFloat { hi: eh, lo: el };
-        let d = e * th;
-        let m = d + th;
-        self * m
+        check_scaled(div(self, rhs), Operation::Div, self.hi, rhs.hi, || {
+            div_scaled(self, rhs)
+        })
     }
 
     fn Rem::rem<'a, 'b>(self: &'a TwoFloat, rhs: &'b f64) -> TwoFloat {
-        let quotient = (self / rhs).trunc();
-        self - quotient * rhs
+        status::function("rem", &[*self, TwoFloat::from(*rhs)], || {
+            if rhs.is_infinite() && self.is_finite() {
+                return *self;
+            }
+            let quotient = (self / rhs).trunc();
+            rem_sign(self - quotient * rhs, self.hi)
+        })
     }
 
     fn Rem::rem<'a, 'b>(self: &'a f64, rhs: &'b TwoFloat) -> TwoFloat {
-        let quotient = (self / rhs).trunc();
-        self - quotient * rhs
+        status::function("rem", &[TwoFloat::from(*self), *rhs], || {
+            if rhs.is_infinite() && self.is_finite() {
+                return TwoFloat::from(*self);
+            }
+            let quotient = (self / rhs).trunc();
+            rem_sign(self - quotient * rhs, *self)
+        })
     }
 
     fn Rem::rem<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoFloat) -> TwoFloat {
-        let quotient = (self / rhs).trunc();
-        self - quotient * rhs
+        status::function("rem", &[*self, *rhs], || {
+            if rhs.is_infinite() && self.is_finite() {
+                return *self;
+            }
+            let quotient = (self / rhs).trunc();
+            rem_sign(self - quotient * rhs, self.hi)
+        })
     }
 }
 
@@ -212,93 +675,65 @@ assign_ops! {
     /// Implements addition of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4.
     fn AddAssign::add_assign<'a>(self: &mut TwoFloat, rhs: &'a f64) {
-        let (sh, sl) = TwoFloat::new_add(self.hi, *rhs).into();
-        let v = self.lo + sl;
-        *self = fast_two_sum(sh, v);
+        *self = check_special(add_word(self, *rhs), Operation::Add, self.hi, *rhs);
     }
 
     /// Implements addition of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 6.
     fn AddAssign::add_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let (sh, sl) = TwoFloat::new_add(self.hi, rhs.hi).into();
-        let (th, tl) = TwoFloat::new_add(self.lo, rhs.lo).into();
-        let c = sl + th;
-        let (vh, vl) = fast_two_sum(sh, c).into();
-        let w = tl + vl;
-        *self = fast_two_sum(vh, w)
+        *self = check_special(add(self, rhs), Operation::Add, self.hi, rhs.hi);
     }
 
     /// Implements subtraction of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 4 modified for negative right-hand side.
     fn SubAssign::sub_assign<'a>(self: &mut TwoFloat, rhs: &'a f64) {
-        let (sh, sl) = TwoFloat::new_sub(self.hi, *rhs).into();
-        let v = self.lo + sl;
-        *self = fast_two_sum(sh, v);
+        *self = check_special(sub_word(self, *rhs), Operation::Sub, self.hi, *rhs);
     }
 
     /// Implements subtraction of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 6 modified for a negative right-hand side.
     fn SubAssign::sub_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let (sh, sl) = TwoFloat::new_sub(self.hi, rhs.hi).into();
-        let (th, tl) = TwoFloat::new_sub(self.lo, rhs.lo).into();
-        let c = sl + th;
-        let (vh, vl) = fast_two_sum(sh, c).into();
-        let w = tl + vl;
-        *self = fast_two_sum(vh, w)
+        *self = check_special(sub(self, rhs), Operation::Sub, self.hi, rhs.hi);
     }
 
     /// Implements multiplication of `TwoFloat` and `f64` using Joldes et al.
     /// (2017) Algorithm 9.
     fn MulAssign::mul_assign<'a>(self: &mut TwoFloat, rhs: &'a f64) {
-        let (ch, cl1) = TwoFloat::new_mul(self.hi, *rhs).into();
-        let cl3 = self.lo.mul_add(*rhs, cl1);
-        *self = fast_two_sum(ch, cl3);
+        *self = check_scaled(mul_word(self, *rhs), Operation::Mul, self.hi, *rhs, || {
+            mul_word_scaled(self, *rhs)
+        });
     }
 
     /// Implements multiplication of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 12.
     fn MulAssign::mul_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let (ch, cl1) = TwoFloat::new_mul(self.hi, rhs.hi).into();
-        let tl0 = self.lo * rhs.lo;
-        let tl1 = self.hi.mul_add(rhs.lo, tl0);
-        let cl2 = self.lo.mul_add(rhs.hi, tl1);
-        let cl3 = cl1 + cl2;
-        *self = fast_two_sum(ch, cl3)
+        *self = check_scaled(mul(self, rhs), Operation::Mul, self.hi, rhs.hi, || {
+            mul_scaled(self, rhs)
+        });
     }
 
     /// Implements division of `TwoFloat` and `f64` using Joldes et al. (2017)
     /// Algorithm 15
     fn DivAssign::div_assign<'a>(self: &mut TwoFloat, rhs: &'a f64) {
-        let th = self.hi / rhs;
-        let (ph, pl) = TwoFloat::new_mul(th, *rhs).into();
-        let dh = self.hi - ph;
-        let dt = dh - pl;
-        let d = dt + self.lo;
-        let tl = d / rhs;
-        *self = fast_two_sum(th, tl)
+        *self = check_scaled(div_word(self, *rhs), Operation::Div, self.hi, *rhs, || {
+            div_word_scaled(self, *rhs)
+        });
     }
 
     /// Implements division of two `TwoFloat` values using Joldes et al.
     /// (2017) Algorithm 18.
     fn DivAssign::div_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let th = rhs.hi.recip();
-        let rh = 1.0 - rhs.hi * th;
-        let rl = -(rhs.lo * th);
-        let (eh, el) = fast_two_sum(rh, rl).into();
-        let e = TwoFloat { hi: eh, lo: el };
-        let d = e * th;
-        let m = d + th;
-        *self *= m;
+        *self = check_scaled(div(self, rhs), Operation::Div, self.hi, rhs.hi, || {
+            div_scaled(self, rhs)
+        });
     }
 
     fn RemAssign::rem_assign<'b>(self: &mut TwoFloat, rhs: &'b f64) {
-        let quotient = (*self / rhs).trunc();
-        *self -= quotient * rhs;
+        *self = *self % rhs;
     }
 
     fn RemAssign::rem_assign<'a>(self: &mut TwoFloat, rhs: &'a TwoFloat) {
-        let quotient = (*self / rhs).trunc();
-        *self -= quotient * rhs;
+        *self = *self % rhs;
     }
 }
 
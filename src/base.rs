@@ -7,41 +7,184 @@ fn exponent(x: f64) -> u32 {
     ((x.to_bits() >> 52) & 0x7ff) as u32
 }
 
-/// Checks if two `f64` values do not overlap, with the first value being the
-/// more significant.
-///
-/// # Examples
-///
-/// ```
-/// # use twofloat::no_overlap;
-/// let a = no_overlap(1.0, -1e-200);
-/// let b = no_overlap(1e-200, 1.0);
-/// let c = no_overlap(1.0, 0.25);
-///
-/// assert!(a);
-/// assert!(!b);
-/// assert!(!c);
-pub fn no_overlap(a: f64, b: f64) -> bool {
-    match (a.classify(), b.classify()) {
-        (FpCategory::Normal, FpCategory::Normal) => {
-            exponent(a) >= exponent(b) + f64::MANTISSA_DIGITS
+#[inline]
+fn exponent_f32(x: f32) -> u32 {
+    (x.to_bits() >> 23) & 0xff
+}
+
+pub(crate) fn mul_pow2(mut x: f64, mut y: i32) -> f64 {
+    loop {
+        if y < -1022 {
+            // Multiplying by 2^-969 is exact unless the product is below
+            // 2^-1075, when the final result rounds to zero anyway, so that a
+            // subnormal result is only rounded once
+            x *= f64::from_bits(54 << 52);
+            y += 969;
+        } else if y < 1024 {
+            return x * f64::from_bits(((y + 1023) as u64) << 52);
+        } else {
+            x *= f64::from_bits(0x7fe << 52);
+            y -= 1023;
+        }
+    }
+}
+
+// Multiplies both words of `x` by 2^k. A subnormal result is rounded once
+// from the sum of the words.
+pub(crate) fn scale(x: TwoFloat, k: i32) -> TwoFloat {
+    let hi = mul_pow2(x.hi, k);
+    if hi.is_infinite() {
+        TwoFloat::from(hi)
+    } else if hi.abs() < f64::MIN_POSITIVE && x.lo != 0.0 {
+        // The low word only changes the rounding of the high word if the high
+        // word lies exactly halfway between two subnormal values
+        let error = x.hi - mul_pow2(hi, -k);
+        let half = mul_pow2(f64::from_bits(1), -k) * 0.5;
+        if error.abs() == half && error.is_sign_positive() == x.lo.is_sign_positive() {
+            TwoFloat::from(hi + f64::from_bits(1).copysign(error))
+        } else {
+            TwoFloat::from(hi)
+        }
+    } else {
+        TwoFloat {
+            hi,
+            lo: mul_pow2(x.lo, k),
+        }
+    }
+}
+
+// Returns the power of two 2^e such that 2^e <= |x| < 2^(e+1), for finite
+// non-zero values.
+pub(crate) fn binade(x: TwoFloat) -> i32 {
+    let hi_bits = x.hi.to_bits() & !(1 << 63);
+    let hi_exp = exponent(x.hi) as i32;
+    let (e, is_pow2) = if hi_exp == 0 {
+        let mantissa = hi_bits & ((1 << 52) - 1);
+        (
+            63 - mantissa.leading_zeros() as i32 - 1074,
+            mantissa.is_power_of_two(),
+        )
+    } else {
+        (hi_exp - 1023, hi_bits & ((1 << 52) - 1) == 0)
+    };
+
+    if is_pow2 && x.lo != 0.0 && x.lo.is_sign_positive() != x.hi.is_sign_positive() {
+        e - 1
+    } else {
+        e
+    }
+}
+
+impl TwoFloat {
+    fn is_pow2(&self) -> bool {
+        self.lo == 0.0 && {
+            let bits = self.hi.to_bits() & !(1 << 63);
+            if exponent(self.hi) == 0 {
+                bits.is_power_of_two()
+            } else {
+                bits & ((1 << 52) - 1) == 0
+            }
         }
-        (FpCategory::Normal, FpCategory::Subnormal) => {
-            let a_exponent = exponent(a);
-            if a_exponent >= f64::MANTISSA_DIGITS {
-                true
+    }
+
+    // Exponent of the unit in the last place for values in the binade 2^e.
+    fn ulp_exponent(e: i32) -> i32 {
+        (e + 1 - Self::MANTISSA_DIGITS as i32).max(-1074)
+    }
+
+    // Smallest multiple of 2^k greater than a positive finite value. The
+    // high word is always a multiple of 2^k, so only the low word needs to
+    // be rounded.
+    fn next_up_positive(self) -> Self {
+        let k = Self::ulp_exponent(binade(self));
+        let scaled = mul_pow2(self.lo, -k);
+        let m = if scaled.abs() < 1.0 {
+            if self.lo < 0.0 {
+                -1.0
             } else {
-                let b_mantissa = b.to_bits() & ((1 << 52) - 1);
-                a_exponent >= 65 - b_mantissa.leading_zeros()
+                0.0
             }
+        } else {
+            scaled.floor()
+        };
+        let result = Self::new_add(self.hi, mul_pow2(m + 1.0, k));
+        if result.hi.is_infinite() {
+            Self::INFINITY
+        } else {
+            result
         }
-        (FpCategory::Normal, FpCategory::Zero) => true,
-        (FpCategory::Subnormal, FpCategory::Zero) => true,
-        (FpCategory::Zero, FpCategory::Zero) => true,
-        _ => false,
+    }
+
+    // Largest multiple of 2^k less than a positive finite value.
+    fn next_down_positive(self) -> Self {
+        let e = binade(self);
+        let k = Self::ulp_exponent(if self.is_pow2() { e - 1 } else { e });
+        let scaled = mul_pow2(self.lo, -k);
+        let m = if scaled.abs() < 1.0 {
+            if self.lo > 0.0 {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            scaled.ceil()
+        };
+        Self::new_add(self.hi, mul_pow2(m - 1.0, k))
     }
 }
 
+// Generates an overlap check for the base type `$f`, with `$exponent`
+// returning the biased exponent and `$mantissa_mask` selecting the stored
+// mantissa bits.
+macro_rules! no_overlap_fn {
+    ($(#[$meta:meta])* $vis:vis fn $name:ident($f:ident, $exponent:ident, $mantissa_mask:expr)) => {
+        $(#[$meta])*
+        $vis fn $name(a: $f, b: $f) -> bool {
+            match (a.classify(), b.classify()) {
+                (FpCategory::Normal, FpCategory::Normal) => {
+                    $exponent(a) >= $exponent(b) + $f::MANTISSA_DIGITS
+                }
+                (FpCategory::Normal, FpCategory::Subnormal) => {
+                    let a_exponent = $exponent(a);
+                    if a_exponent >= $f::MANTISSA_DIGITS {
+                        true
+                    } else {
+                        let b_mantissa = b.to_bits() & $mantissa_mask;
+                        let bits = 8 * core::mem::size_of::<$f>() as u32;
+                        a_exponent + b_mantissa.leading_zeros() > bits
+                    }
+                }
+                (FpCategory::Normal, FpCategory::Zero) => true,
+                (FpCategory::Subnormal, FpCategory::Zero) => true,
+                (FpCategory::Zero, FpCategory::Zero) => true,
+                _ => false,
+            }
+        }
+    };
+}
+
+no_overlap_fn! {
+    /// Checks if two `f64` values do not overlap, with the first value being the
+    /// more significant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::no_overlap;
+    /// let a = no_overlap(1.0, -1e-200);
+    /// let b = no_overlap(1e-200, 1.0);
+    /// let c = no_overlap(1.0, 0.25);
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    /// assert!(!c);
+    pub fn no_overlap(f64, exponent, (1 << 52) - 1)
+}
+
+no_overlap_fn! {
+    pub(crate) fn no_overlap_f32(f32, exponent_f32, (1 << 23) - 1)
+}
+
 impl TwoFloat {
     /// Returns the high word of `self`.
     ///
@@ -83,6 +226,99 @@ impl TwoFloat {
         self.hi.is_finite() && self.lo.is_finite() && no_overlap(self.hi, self.lo)
     }
 
+    /// Returns `true` if `self` is `NAN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.0) / 0.0;
+    /// let b = TwoFloat::INFINITY;
+    ///
+    /// assert!(a.is_nan());
+    /// assert!(!b.is_nan());
+    pub fn is_nan(&self) -> bool {
+        self.hi.is_nan() || self.lo.is_nan()
+    }
+
+    /// Returns `true` if `self` is positive or negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.0) / 0.0;
+    /// let b = TwoFloat::MAX * 2.0;
+    /// let c = TwoFloat::MAX;
+    ///
+    /// assert!(a.is_infinite());
+    /// assert!(b.is_infinite());
+    /// assert!(!c.is_infinite());
+    pub fn is_infinite(&self) -> bool {
+        self.hi.is_infinite() && !self.lo.is_nan()
+    }
+
+    /// Returns `true` if `self` is neither infinite nor `NAN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_add(1.0, 1.0e-300);
+    /// let b = TwoFloat::NEG_INFINITY;
+    ///
+    /// assert!(a.is_finite());
+    /// assert!(!b.is_finite());
+    pub fn is_finite(&self) -> bool {
+        self.hi.is_finite() && self.lo.is_finite()
+    }
+
+    /// Returns `true` if `self` is neither zero, infinite, subnormal nor
+    /// `NAN`.
+    ///
+    /// A `TwoFloat` is considered subnormal if its magnitude is less than
+    /// `MIN_POSITIVE`, where the full `MANTISSA_DIGITS` bits of precision
+    /// are no longer available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.0e-200);
+    /// let b = TwoFloat::from(1.0e-300);
+    ///
+    /// assert!(a.is_normal());
+    /// assert!(!b.is_normal());
+    pub fn is_normal(&self) -> bool {
+        self.classify() == FpCategory::Normal
+    }
+
+    /// Returns the floating point category of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::FpCategory;
+    /// # use twofloat::TwoFloat;
+    /// assert_eq!(TwoFloat::from(-0.0).classify(), FpCategory::Zero);
+    /// assert_eq!(TwoFloat::from(1.0e-300).classify(), FpCategory::Subnormal);
+    /// assert_eq!(TwoFloat::MAX.classify(), FpCategory::Normal);
+    /// assert_eq!(TwoFloat::INFINITY.classify(), FpCategory::Infinite);
+    /// assert_eq!(TwoFloat::NAN.classify(), FpCategory::Nan);
+    pub fn classify(&self) -> FpCategory {
+        if self.is_nan() {
+            FpCategory::Nan
+        } else if self.hi.is_infinite() {
+            FpCategory::Infinite
+        } else if self.hi == 0.0 {
+            FpCategory::Zero
+        } else if self.abs() < Self::MIN_POSITIVE {
+            FpCategory::Subnormal
+        } else {
+            FpCategory::Normal
+        }
+    }
+
     /// Returns the minimum of two numbers. If one of the arguments is `NAN`,
     /// the other is returned.
     ///
@@ -95,9 +331,9 @@ impl TwoFloat {
     ///
     /// assert_eq!(a.min(b), b);
     pub fn min(self, other: Self) -> Self {
-        if !self.is_valid() {
+        if self.is_nan() {
             other
-        } else if !other.is_valid() || self <= other {
+        } else if other.is_nan() || self <= other {
             self
         } else {
             other
@@ -116,24 +352,159 @@ impl TwoFloat {
     ///
     /// assert_eq!(a.max(b), a);
     pub fn max(self, other: Self) -> Self {
-        if !self.is_valid() {
+        if self.is_nan() {
             other
-        } else if !other.is_valid() || self >= other {
+        } else if other.is_nan() || self >= other {
             self
         } else {
             other
         }
     }
 
+    /// Returns the unit in the last place of `self`, the spacing between
+    /// `MANTISSA_DIGITS`-bit values at the magnitude of `self`.
+    ///
+    /// This is the resolution of the `TwoFloat` format at a given
+    /// magnitude, which is finer than `self.hi().ulp()` by a factor of
+    /// 2<sup>53</sup>. Values with a magnitude below `MIN_POSITIVE` have
+    /// reduced precision and share the ulp of the smallest subnormal `f64`.
+    /// Returns `NAN` for non-finite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.0).ulp();
+    /// let b = TwoFloat::new_add(1024.0, 1e-20).ulp();
+    ///
+    /// assert_eq!(a, TwoFloat::EPSILON);
+    /// assert_eq!(b, TwoFloat::EPSILON * 1024.0);
+    pub fn ulp(self) -> Self {
+        if !self.hi.is_finite() || !self.lo.is_finite() {
+            Self::NAN
+        } else if self.hi == 0.0 {
+            Self::from(f64::from_bits(1))
+        } else {
+            Self::from(mul_pow2(1.0, Self::ulp_exponent(binade(self))))
+        }
+    }
+
+    /// Returns the least number greater than `self` which is a multiple of
+    /// the `ulp` at its magnitude.
+    ///
+    /// The values visited by repeated calls are the `TwoFloat` values with
+    /// at most `MANTISSA_DIGITS` significant bits. Returns `INFINITY` when
+    /// called on `MAX`, and `MIN` when called on `NEG_INFINITY`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.0);
+    /// let b = a.next_up();
+    ///
+    /// assert_eq!(b - a, TwoFloat::EPSILON);
+    /// assert_eq!(b.next_down(), a);
+    /// assert_eq!(TwoFloat::MAX.next_up(), TwoFloat::INFINITY);
+    pub fn next_up(self) -> Self {
+        if self.hi.is_nan() || self.lo.is_nan() {
+            Self::NAN
+        } else if self.hi.is_infinite() {
+            if self.hi > 0.0 {
+                self
+            } else {
+                Self::MIN
+            }
+        } else if self.hi == 0.0 {
+            Self::from(f64::from_bits(1))
+        } else if self.hi > 0.0 {
+            self.next_up_positive()
+        } else {
+            -(-self).next_down_positive()
+        }
+    }
+
+    /// Returns the greatest number less than `self` which is a multiple of
+    /// the `ulp` at its magnitude.
+    ///
+    /// This is the inverse of `next_up`. Returns `NEG_INFINITY` when called
+    /// on `MIN`, and `MAX` when called on `INFINITY`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(1.0);
+    /// let b = a.next_down();
+    ///
+    /// assert_eq!(a - b, TwoFloat::EPSILON / 2.0);
+    /// assert_eq!(b.next_up(), a);
+    pub fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+
     /// Represents an error value equivalent to `f64::NAN`.
     pub const NAN: Self = Self {
         hi: f64::NAN,
         lo: f64::NAN,
     };
+
+    /// Positive infinity.
+    pub const INFINITY: Self = Self {
+        hi: f64::INFINITY,
+        lo: 0.0,
+    };
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Self {
+        hi: f64::NEG_INFINITY,
+        lo: 0.0,
+    };
+
+    /// The value zero.
+    pub const ZERO: Self = Self { hi: 0.0, lo: 0.0 };
+
+    /// The value one.
+    pub const ONE: Self = Self { hi: 1.0, lo: 0.0 };
+
+    /// Number of significant binary digits, treating a `TwoFloat` as having
+    /// the combined precision of its two `f64` components.
+    pub const MANTISSA_DIGITS: u32 = 2 * f64::MANTISSA_DIGITS;
+
+    /// Machine epsilon value for `TwoFloat`, the difference between `1.0`
+    /// and the next larger value returned by `next_up`: 2<sup>-105</sup>.
+    pub const EPSILON: Self = Self {
+        hi: 2.465190328815662e-32,
+        lo: 0.0,
+    };
+
+    /// Largest finite `TwoFloat` value, `2^1024 - 2^970 - 2^918`.
+    pub const MAX: Self = Self {
+        hi: f64::MAX,
+        lo: 9.979201547673597e291,
+    };
+
+    /// Smallest finite `TwoFloat` value, equal to `-MAX`.
+    pub const MIN: Self = Self {
+        hi: f64::MIN,
+        lo: -9.979201547673597e291,
+    };
+
+    /// Smallest positive value retaining the full `MANTISSA_DIGITS` bits of
+    /// precision: 2<sup>-969</sup>.
+    pub const MIN_POSITIVE: Self = Self {
+        hi: 2.004168360008973e-292,
+        lo: 0.0,
+    };
 }
 
 impl fmt::Display for TwoFloat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return fmt::Display::fmt(&f64::NAN, f);
+        } else if self.hi.is_infinite() {
+            return fmt::Display::fmt(&self.hi, f);
+        }
         let sign_char = if self.lo().is_sign_positive() {
             '+'
         } else {
@@ -163,6 +534,11 @@ impl fmt::Display for TwoFloat {
 
 impl fmt::LowerExp for TwoFloat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return fmt::LowerExp::fmt(&f64::NAN, f);
+        } else if self.hi.is_infinite() {
+            return fmt::LowerExp::fmt(&self.hi, f);
+        }
         let sign_char = if self.lo().is_sign_positive() {
             '+'
         } else {
@@ -200,6 +576,11 @@ impl fmt::LowerExp for TwoFloat {
 
 impl fmt::UpperExp for TwoFloat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return fmt::UpperExp::fmt(&f64::NAN, f);
+        } else if self.hi.is_infinite() {
+            return fmt::UpperExp::fmt(&self.hi, f);
+        }
         let sign_char = if self.lo().is_sign_positive() {
             '+'
         } else {
@@ -271,7 +652,7 @@ impl PartialOrd<TwoFloat> for f64 {
 
 #[cfg(test)]
 mod tests {
-    use super::{no_overlap, TwoFloat};
+    use super::{no_overlap, no_overlap_f32, TwoFloat};
 
     #[test]
     fn no_overlap_test() {
@@ -300,6 +681,23 @@ mod tests {
         assert!(no_overlap(0.0, 0.0));
     }
 
+    #[test]
+    fn no_overlap_f32_test() {
+        assert!(!no_overlap_f32(1.0, (-23f32).exp2()));
+        assert!(no_overlap_f32(1.0, (-24f32).exp2()));
+        assert!(no_overlap_f32(-1.0, -(-24f32).exp2()));
+        assert!(no_overlap_f32(1.0, 0.0));
+
+        assert!(!no_overlap_f32((-103f32).exp2(), (-126f32).exp2()));
+        assert!(no_overlap_f32((-103f32).exp2(), (-127f32).exp2()));
+        assert!(!no_overlap_f32((-104f32).exp2(), (-127f32).exp2()));
+        assert!(no_overlap_f32((-104f32).exp2(), (-128f32).exp2()));
+
+        assert!(!no_overlap_f32(f32::INFINITY, 1.0));
+        assert!(!no_overlap_f32(0.0, 1.0));
+        assert!(no_overlap_f32(0.0, 0.0));
+    }
+
     #[test]
     fn display_test() {
         let value = TwoFloat { hi: 1.0, lo: 0.3 };
@@ -0,0 +1,508 @@
+#[cfg(feature = "math_funcs")]
+use core::num::FpCategory;
+
+use crate::{
+    arithmetic::{flagged_op, Operation},
+    status::FpFlags,
+    TwoFloat, TwoFloatError,
+};
+
+// Checks that all arguments to an operation are finite.
+fn check_args(op: &'static str, args: &[TwoFloat]) -> Result<(), TwoFloatError> {
+    if args.iter().all(TwoFloat::is_finite) {
+        Ok(())
+    } else {
+        Err(TwoFloatError::DomainError { op })
+    }
+}
+
+// Classifies the result of an operation on finite arguments. If `nonzero` is
+// set, the exact result is known to be non-zero, so a zero result indicates
+// underflow.
+#[cfg(feature = "math_funcs")]
+fn check_result(
+    op: &'static str,
+    result: TwoFloat,
+    nonzero: bool,
+) -> Result<TwoFloat, TwoFloatError> {
+    match result.classify() {
+        FpCategory::Nan => Err(TwoFloatError::DomainError { op }),
+        FpCategory::Infinite => Err(TwoFloatError::Overflow),
+        FpCategory::Subnormal => Err(TwoFloatError::Underflow),
+        FpCategory::Zero if nonzero => Err(TwoFloatError::Underflow),
+        _ => Ok(result),
+    }
+}
+
+// Classifies the result of an arithmetic operation on finite arguments by the
+// exceptions it raises, so that tiny results only underflow if they lost
+// precision.
+fn check_op(op: Operation, a: TwoFloat, b: TwoFloat) -> Result<TwoFloat, TwoFloatError> {
+    let (result, flags) = flagged_op(op, &a, &b);
+    if flags.contains(FpFlags::INVALID) {
+        Err(TwoFloatError::DomainError { op: op.name() })
+    } else if flags.contains(FpFlags::DIVISION_BY_ZERO) {
+        Err(TwoFloatError::DivisionByZero)
+    } else if flags.contains(FpFlags::OVERFLOW) {
+        Err(TwoFloatError::Overflow)
+    } else if flags.contains(FpFlags::UNDERFLOW) {
+        Err(TwoFloatError::Underflow)
+    } else {
+        Ok(result)
+    }
+}
+
+impl TwoFloat {
+    /// Checked addition. Returns an error if either argument is not finite,
+    /// or if the result overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(1.0).checked_add(TwoFloat::from(2.0));
+    /// let b = TwoFloat::MAX.checked_add(TwoFloat::MAX);
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(3.0)));
+    /// assert_eq!(b, Err(TwoFloatError::Overflow));
+    pub fn checked_add(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check_args("add", &[self, rhs])?;
+        check_op(Operation::Add, self, rhs)
+    }
+
+    /// Checked subtraction. Returns an error if either argument is not
+    /// finite, or if the result overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(1.0).checked_sub(TwoFloat::from(2.0));
+    /// let b = TwoFloat::INFINITY.checked_sub(TwoFloat::from(2.0));
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(-1.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DomainError { op: "sub" }));
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check_args("sub", &[self, rhs])?;
+        check_op(Operation::Sub, self, rhs)
+    }
+
+    /// Checked multiplication. Returns an error if either argument is not
+    /// finite, or if the result overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(1.5).checked_mul(TwoFloat::from(2.0));
+    /// let b = TwoFloat::from(1e-200).checked_mul(TwoFloat::from(1e-200));
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(3.0)));
+    /// assert_eq!(b, Err(TwoFloatError::Underflow));
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check_args("mul", &[self, rhs])?;
+        check_op(Operation::Mul, self, rhs)
+    }
+
+    /// Checked division. Returns an error if either argument is not finite,
+    /// if `rhs` is zero, or if the result overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(3.0).checked_div(TwoFloat::from(2.0));
+    /// let b = TwoFloat::from(3.0).checked_div(TwoFloat::from(0.0));
+    /// let c = TwoFloat::from(0.0).checked_div(TwoFloat::from(0.0));
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(1.5)));
+    /// assert_eq!(b, Err(TwoFloatError::DivisionByZero));
+    /// assert_eq!(c, Err(TwoFloatError::DomainError { op: "div" }));
+    pub fn checked_div(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check_args("div", &[self, rhs])?;
+        if rhs == 0.0 {
+            if self == 0.0 {
+                Err(TwoFloatError::DomainError { op: "div" })
+            } else {
+                Err(TwoFloatError::DivisionByZero)
+            }
+        } else {
+            check_op(Operation::Div, self, rhs)
+        }
+    }
+
+    /// Checked remainder. Returns an error if either argument is not finite
+    /// or if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(7.0).checked_rem(TwoFloat::from(4.0));
+    /// let b = TwoFloat::from(7.0).checked_rem(TwoFloat::from(0.0));
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(3.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DomainError { op: "rem" }));
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, TwoFloatError> {
+        check_args("rem", &[self, rhs])?;
+        if rhs == 0.0 {
+            Err(TwoFloatError::DomainError { op: "rem" })
+        } else {
+            // The remainder is exact, so a tiny result does not underflow
+            let result = self % rhs;
+            if result.is_nan() {
+                Err(TwoFloatError::DomainError { op: "rem" })
+            } else {
+                Ok(result)
+            }
+        }
+    }
+
+    /// Checked reciprocal. Returns an error if the argument is zero or not
+    /// finite, or if the result overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(4.0).checked_recip();
+    /// let b = TwoFloat::from(0.0).checked_recip();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(0.25)));
+    /// assert_eq!(b, Err(TwoFloatError::DivisionByZero));
+    pub fn checked_recip(self) -> Result<Self, TwoFloatError> {
+        Self::from(1.0).checked_div(self)
+    }
+}
+
+#[cfg(feature = "math_funcs")]
+impl TwoFloat {
+    /// Checked square root. Returns an error if the argument is negative or
+    /// not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(4.0).checked_sqrt();
+    /// let b = TwoFloat::from(-4.0).checked_sqrt();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(2.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DomainError { op: "sqrt" }));
+    pub fn checked_sqrt(self) -> Result<Self, TwoFloatError> {
+        check_args("sqrt", &[self])?;
+        if self < 0.0 {
+            Err(TwoFloatError::DomainError { op: "sqrt" })
+        } else {
+            check_result("sqrt", self.sqrt(), self != 0.0)
+        }
+    }
+
+    /// Checked reciprocal square root. Returns an error if the argument is
+    /// negative, zero or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(4.0).checked_rsqrt();
+    /// let b = TwoFloat::from(0.0).checked_rsqrt();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(0.5)));
+    /// assert_eq!(b, Err(TwoFloatError::DivisionByZero));
+    pub fn checked_rsqrt(self) -> Result<Self, TwoFloatError> {
+        check_args("rsqrt", &[self])?;
+        if self == 0.0 {
+            Err(TwoFloatError::DivisionByZero)
+        } else if self < 0.0 {
+            Err(TwoFloatError::DomainError { op: "rsqrt" })
+        } else {
+            check_result("rsqrt", self.rsqrt(), true)
+        }
+    }
+
+    /// Checked integer power. Returns an error if the argument is not
+    /// finite, if zero is raised to a negative power, or if the result
+    /// overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(3.0).checked_powi(3);
+    /// let b = TwoFloat::from(1e100).checked_powi(4);
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(27.0)));
+    /// assert_eq!(b, Err(TwoFloatError::Overflow));
+    pub fn checked_powi(self, n: i32) -> Result<Self, TwoFloatError> {
+        check_args("powi", &[self])?;
+        if n == 0 {
+            Ok(Self::from(1.0))
+        } else if self == 0.0 {
+            if n < 0 {
+                Err(TwoFloatError::DivisionByZero)
+            } else {
+                Ok(Self::from(0.0))
+            }
+        } else {
+            check_result("powi", self.powi(n), true)
+        }
+    }
+
+    /// Checked floating point power. Returns an error if either argument is
+    /// not finite, if a negative number is raised to a non-integer power, if
+    /// zero is raised to a negative power, or if the result overflows or
+    /// underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(4.0).checked_powf(TwoFloat::from(0.5));
+    /// let b = TwoFloat::from(-4.0).checked_powf(TwoFloat::from(0.5));
+    ///
+    /// assert!((a.unwrap() - 2.0).abs() < 1e-12);
+    /// assert_eq!(b, Err(TwoFloatError::DomainError { op: "powf" }));
+    pub fn checked_powf(self, y: Self) -> Result<Self, TwoFloatError> {
+        check_args("powf", &[self, y])?;
+        if y == 0.0 {
+            Ok(Self::from(1.0))
+        } else if self == 0.0 {
+            if y < 0.0 {
+                Err(TwoFloatError::DivisionByZero)
+            } else {
+                Ok(Self::from(0.0))
+            }
+        } else if self < 0.0 && y.fract() != 0.0 {
+            Err(TwoFloatError::DomainError { op: "powf" })
+        } else {
+            check_result("powf", self.powf(y), true)
+        }
+    }
+
+    /// Checked exponential function. Returns an error if the argument is not
+    /// finite, or if the result overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(0.0).checked_exp();
+    /// let b = TwoFloat::from(-800.0).checked_exp();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(1.0)));
+    /// assert_eq!(b, Err(TwoFloatError::Underflow));
+    pub fn checked_exp(self) -> Result<Self, TwoFloatError> {
+        check_args("exp", &[self])?;
+        check_result("exp", self.exp(), true)
+    }
+
+    /// Checked base 2 exponential function. Returns an error if the argument
+    /// is not finite, or if the result overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(3.0).checked_exp2();
+    /// let b = TwoFloat::from(1024.0).checked_exp2();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(8.0)));
+    /// assert_eq!(b, Err(TwoFloatError::Overflow));
+    pub fn checked_exp2(self) -> Result<Self, TwoFloatError> {
+        check_args("exp2", &[self])?;
+        check_result("exp2", self.exp2(), true)
+    }
+
+    /// Checked version of `exp_m1`. Returns an error if the argument is not
+    /// finite, or if the result overflows or underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(-800.0).checked_exp_m1();
+    /// let b = TwoFloat::from(800.0).checked_exp_m1();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(-1.0)));
+    /// assert_eq!(b, Err(TwoFloatError::Overflow));
+    pub fn checked_exp_m1(self) -> Result<Self, TwoFloatError> {
+        check_args("exp_m1", &[self])?;
+        check_result("exp_m1", self.exp_m1(), self != 0.0)
+    }
+
+    /// Checked natural logarithm. Returns an error if the argument is zero,
+    /// negative or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(1.0).checked_ln();
+    /// let b = TwoFloat::from(0.0).checked_ln();
+    /// let c = TwoFloat::from(-1.0).checked_ln();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(0.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DivisionByZero));
+    /// assert_eq!(c, Err(TwoFloatError::DomainError { op: "ln" }));
+    pub fn checked_ln(self) -> Result<Self, TwoFloatError> {
+        self.checked_log_impl("ln", Self::ln)
+    }
+
+    /// Checked base 2 logarithm. Returns an error if the argument is zero,
+    /// negative or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(8.0).checked_log2();
+    /// let b = TwoFloat::from(-8.0).checked_log2();
+    ///
+    /// assert!((a.unwrap() - 3.0).abs() < 1e-12);
+    /// assert_eq!(b, Err(TwoFloatError::DomainError { op: "log2" }));
+    pub fn checked_log2(self) -> Result<Self, TwoFloatError> {
+        self.checked_log_impl("log2", Self::log2)
+    }
+
+    /// Checked base 10 logarithm. Returns an error if the argument is zero,
+    /// negative or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(100.0).checked_log10();
+    /// let b = TwoFloat::from(0.0).checked_log10();
+    ///
+    /// assert!((a.unwrap() - 2.0).abs() < 1e-12);
+    /// assert_eq!(b, Err(TwoFloatError::DivisionByZero));
+    pub fn checked_log10(self) -> Result<Self, TwoFloatError> {
+        self.checked_log_impl("log10", Self::log10)
+    }
+
+    /// Checked version of `ln_1p`. Returns an error if the argument is less
+    /// than or equal to -1, or is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(0.0).checked_ln_1p();
+    /// let b = TwoFloat::from(-1.0).checked_ln_1p();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(0.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DivisionByZero));
+    pub fn checked_ln_1p(self) -> Result<Self, TwoFloatError> {
+        check_args("ln_1p", &[self])?;
+        if self == -1.0 {
+            Err(TwoFloatError::DivisionByZero)
+        } else if self < -1.0 {
+            Err(TwoFloatError::DomainError { op: "ln_1p" })
+        } else {
+            check_result("ln_1p", self.ln_1p(), self != 0.0)
+        }
+    }
+
+    fn checked_log_impl(
+        self,
+        op: &'static str,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Result<Self, TwoFloatError> {
+        check_args(op, &[self])?;
+        if self == 0.0 {
+            Err(TwoFloatError::DivisionByZero)
+        } else if self < 0.0 {
+            Err(TwoFloatError::DomainError { op })
+        } else {
+            check_result(op, f(self), false)
+        }
+    }
+
+    /// Checked arcsine. Returns an error if the argument is outside the
+    /// range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(0.0).checked_asin();
+    /// let b = TwoFloat::from(1.5).checked_asin();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(0.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DomainError { op: "asin" }));
+    pub fn checked_asin(self) -> Result<Self, TwoFloatError> {
+        check_args("asin", &[self])?;
+        if self.abs() > 1.0 {
+            Err(TwoFloatError::DomainError { op: "asin" })
+        } else {
+            check_result("asin", self.asin(), self != 0.0)
+        }
+    }
+
+    /// Checked arccosine. Returns an error if the argument is outside the
+    /// range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(1.0).checked_acos();
+    /// let b = TwoFloat::from(-1.5).checked_acos();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(0.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DomainError { op: "acos" }));
+    pub fn checked_acos(self) -> Result<Self, TwoFloatError> {
+        check_args("acos", &[self])?;
+        if self.abs() > 1.0 {
+            Err(TwoFloatError::DomainError { op: "acos" })
+        } else {
+            check_result("acos", self.acos(), false)
+        }
+    }
+
+    /// Checked inverse hyperbolic cosine. Returns an error if the argument
+    /// is less than 1 or is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(1.0).checked_acosh();
+    /// let b = TwoFloat::from(0.5).checked_acosh();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(0.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DomainError { op: "acosh" }));
+    pub fn checked_acosh(self) -> Result<Self, TwoFloatError> {
+        check_args("acosh", &[self])?;
+        if self < 1.0 {
+            Err(TwoFloatError::DomainError { op: "acosh" })
+        } else {
+            check_result("acosh", self.acosh(), false)
+        }
+    }
+
+    /// Checked inverse hyperbolic tangent. Returns an error if the argument
+    /// is outside the range (-1, 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatError};
+    /// let a = TwoFloat::from(0.0).checked_atanh();
+    /// let b = TwoFloat::from(1.0).checked_atanh();
+    ///
+    /// assert_eq!(a, Ok(TwoFloat::from(0.0)));
+    /// assert_eq!(b, Err(TwoFloatError::DivisionByZero));
+    pub fn checked_atanh(self) -> Result<Self, TwoFloatError> {
+        check_args("atanh", &[self])?;
+        let abs_val = self.abs();
+        if abs_val == 1.0 {
+            Err(TwoFloatError::DivisionByZero)
+        } else if abs_val > 1.0 {
+            Err(TwoFloatError::DomainError { op: "atanh" })
+        } else {
+            check_result("atanh", self.atanh(), self != 0.0)
+        }
+    }
+}
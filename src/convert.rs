@@ -61,6 +61,70 @@ impl TryFrom<[f64; 2]> for TwoFloat {
     }
 }
 
+impl TwoFloat {
+    /// Creates a new `TwoFloat` holding the exact sum of two `f64` values,
+    /// which may be given in any order of magnitude. The result is rounded
+    /// to nearest in the high word, as for `new_add`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from_sum(1.0e-20, 1.0);
+    /// let b = TwoFloat::from_sum(1.0, 0.25);
+    ///
+    /// assert_eq!((a.hi(), a.lo()), (1.0, 1.0e-20));
+    /// assert_eq!((b.hi(), b.lo()), (1.25, 0.0));
+    pub fn from_sum(a: f64, b: f64) -> Self {
+        Self::new_add(a, b)
+    }
+
+    /// Creates a new `TwoFloat` from a pair of `f64` values which represent
+    /// the value `hi + lo` but may overlap, such as `(1.0, 0.25)`. Pairs
+    /// which are already valid are returned unchanged, other finite pairs
+    /// are renormalized so that the result is valid and has the same value.
+    ///
+    /// Non-finite pairs return the same result as adding the two values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::normalize(1.0, 0.25);
+    /// let b = TwoFloat::normalize(1.0, -1.0e-200);
+    ///
+    /// assert!(a.is_valid() && b.is_valid());
+    /// assert_eq!(a, TwoFloat::from(1.25));
+    /// assert_eq!((b.hi(), b.lo()), (1.0, -1.0e-200));
+    pub fn normalize(hi: f64, lo: f64) -> Self {
+        if hi.is_finite() && lo.is_finite() && no_overlap(hi, lo) {
+            Self { hi, lo }
+        } else {
+            Self::from_sum(hi, lo)
+        }
+    }
+
+    /// Creates a new `TwoFloat` from its high and low words without checking
+    /// that they are valid.
+    ///
+    /// This function is safe, but if the words overlap the results of
+    /// operations on the returned value are unspecified. Use `normalize` or
+    /// `TryFrom<(f64, f64)>` when the words are not known to satisfy
+    /// `no_overlap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// const A: TwoFloat = TwoFloat::from_parts_unchecked(1.0, 1.0e-20);
+    ///
+    /// assert!(A.is_valid());
+    /// assert_eq!((A.hi(), A.lo()), (1.0, 1.0e-20));
+    pub const fn from_parts_unchecked(hi: f64, lo: f64) -> Self {
+        Self { hi, lo }
+    }
+}
+
 macro_rules! float_convert {
     ($type:tt) => {
         impl From<$type> for TwoFloat {
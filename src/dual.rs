@@ -0,0 +1,342 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::TwoFloat;
+
+#[cfg(feature = "math_funcs")]
+mod functions;
+
+/// Represents a `TwoFloat` value together with its partial derivatives with
+/// respect to `N` independent variables, for forward-mode automatic
+/// differentiation.
+///
+/// Arithmetic operators are provided for combinations of `DualN`, `TwoFloat`
+/// and `f64` values, where `TwoFloat` and `f64` values are treated as
+/// constants. If the `math_funcs` feature is enabled, the functions provided
+/// by `TwoFloat` are also available, with derivatives computed by the chain
+/// rule at the same precision as the value.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{DualN, TwoFloat};
+/// let x = DualN::<2>::variable(TwoFloat::from(3.0), 0);
+/// let y = DualN::<2>::variable(TwoFloat::from(4.0), 1);
+/// let z = x * x * y + 1.0;
+///
+/// assert_eq!(z.value, 37.0);
+/// assert_eq!(z.derivatives, [TwoFloat::from(24.0), TwoFloat::from(9.0)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DualN<const N: usize> {
+    /// The value.
+    pub value: TwoFloat,
+    /// The partial derivatives of the value.
+    pub derivatives: [TwoFloat; N],
+}
+
+/// Represents a `TwoFloat` value together with its derivative with respect to
+/// a single variable.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{Dual, TwoFloat};
+/// let x = Dual::variable(TwoFloat::from(2.0), 0);
+/// let y = 1.0 / x;
+///
+/// assert_eq!(y.value, 0.5);
+/// assert_eq!(y.derivatives[0], -0.25);
+/// ```
+pub type Dual = DualN<1>;
+
+impl<const N: usize> DualN<N> {
+    /// Creates a new `DualN` from its value and partial derivatives.
+    pub const fn new(value: TwoFloat, derivatives: [TwoFloat; N]) -> Self {
+        Self { value, derivatives }
+    }
+
+    /// Creates a constant, with all partial derivatives zero.
+    pub const fn constant(value: TwoFloat) -> Self {
+        Self {
+            value,
+            derivatives: [TwoFloat::ZERO; N],
+        }
+    }
+
+    /// Creates the independent variable with the given index, whose partial
+    /// derivative is one with respect to itself and zero with respect to the
+    /// other variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    pub fn variable(value: TwoFloat, index: usize) -> Self {
+        let mut result = Self::constant(value);
+        result.derivatives[index] = TwoFloat::ONE;
+        result
+    }
+
+    /// Returns `true` if the value or any of the partial derivatives is
+    /// `NAN`.
+    pub fn is_nan(&self) -> bool {
+        self.value.is_nan() || self.derivatives.iter().any(TwoFloat::is_nan)
+    }
+
+    /// Returns `true` if the value and all the partial derivatives are
+    /// finite.
+    pub fn is_finite(&self) -> bool {
+        self.value.is_finite() && self.derivatives.iter().all(TwoFloat::is_finite)
+    }
+
+    /// Returns the absolute value, whose derivatives at zero are taken from
+    /// the side given by the sign of the zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Dual, TwoFloat};
+    /// let a = Dual::variable(TwoFloat::from(-2.0), 0).abs();
+    ///
+    /// assert_eq!(a.value, 2.0);
+    /// assert_eq!(a.derivatives[0], -1.0);
+    pub fn abs(self) -> Self {
+        if self.value.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Returns the reciprocal of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Dual, TwoFloat};
+    /// let a = Dual::variable(TwoFloat::from(4.0), 0).recip();
+    ///
+    /// assert_eq!(a.value, 0.25);
+    /// assert_eq!(a.derivatives[0], -0.0625);
+    pub fn recip(self) -> Self {
+        1.0 / self
+    }
+
+    // Returns the result of a function with the given value, whose derivative
+    // at `self` is `derivative`.
+    fn chain(&self, value: TwoFloat, derivative: TwoFloat) -> Self {
+        let mut result = Self::constant(value);
+        for (r, d) in result.derivatives.iter_mut().zip(self.derivatives.iter()) {
+            *r = d * derivative;
+        }
+        result
+    }
+
+    // Returns the result of a function with the given value, whose derivative
+    // at `self` is `1 / divisor`.
+    fn chain_div(&self, value: TwoFloat, divisor: TwoFloat) -> Self {
+        let mut result = Self::constant(value);
+        for (r, d) in result.derivatives.iter_mut().zip(self.derivatives.iter()) {
+            *r = d / divisor;
+        }
+        result
+    }
+
+    // Applies `f` to each pair of partial derivatives of `self` and `other`.
+    fn zip_with(
+        &self,
+        other: &Self,
+        value: TwoFloat,
+        f: impl Fn(TwoFloat, TwoFloat) -> TwoFloat,
+    ) -> Self {
+        let mut result = Self::constant(value);
+        for (r, (a, b)) in result
+            .derivatives
+            .iter_mut()
+            .zip(self.derivatives.iter().zip(other.derivatives.iter()))
+        {
+            *r = f(*a, *b);
+        }
+        result
+    }
+}
+
+impl<const N: usize> Default for DualN<N> {
+    fn default() -> Self {
+        Self::constant(TwoFloat::ZERO)
+    }
+}
+
+impl<const N: usize> From<TwoFloat> for DualN<N> {
+    fn from(value: TwoFloat) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<const N: usize> From<f64> for DualN<N> {
+    fn from(value: f64) -> Self {
+        Self::constant(TwoFloat::from(value))
+    }
+}
+
+unary_ops! {
+    fn Neg::neg<const N: usize>(self: &DualN<N>) -> DualN<N> {
+        self.chain(-self.value, TwoFloat::from(-1.0))
+    }
+}
+
+binary_ops! {
+    /// Implements addition of two `DualN` values.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b DualN<N>) -> DualN<N> {
+        self.zip_with(rhs, self.value + rhs.value, |a, b| a + b)
+    }
+
+    /// Implements addition of `DualN` and a constant `TwoFloat`.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b TwoFloat) -> DualN<N> {
+        DualN::new(self.value + rhs, self.derivatives)
+    }
+
+    /// Implements addition of a constant `TwoFloat` and `DualN`.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a TwoFloat, rhs: &'b DualN<N>) -> DualN<N> {
+        DualN::new(self + rhs.value, rhs.derivatives)
+    }
+
+    /// Implements addition of `DualN` and a constant `f64`.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b f64) -> DualN<N> {
+        DualN::new(self.value + rhs, self.derivatives)
+    }
+
+    /// Implements addition of a constant `f64` and `DualN`.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a f64, rhs: &'b DualN<N>) -> DualN<N> {
+        DualN::new(self + rhs.value, rhs.derivatives)
+    }
+
+    /// Implements subtraction of two `DualN` values.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b DualN<N>) -> DualN<N> {
+        self.zip_with(rhs, self.value - rhs.value, |a, b| a - b)
+    }
+
+    /// Implements subtraction of a constant `TwoFloat` from `DualN`.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b TwoFloat) -> DualN<N> {
+        DualN::new(self.value - rhs, self.derivatives)
+    }
+
+    /// Implements subtraction of `DualN` from a constant `TwoFloat`.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a TwoFloat, rhs: &'b DualN<N>) -> DualN<N> {
+        rhs.chain(self - rhs.value, TwoFloat::from(-1.0))
+    }
+
+    /// Implements subtraction of a constant `f64` from `DualN`.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b f64) -> DualN<N> {
+        DualN::new(self.value - rhs, self.derivatives)
+    }
+
+    /// Implements subtraction of `DualN` from a constant `f64`.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a f64, rhs: &'b DualN<N>) -> DualN<N> {
+        rhs.chain(self - rhs.value, TwoFloat::from(-1.0))
+    }
+
+    /// Implements multiplication of two `DualN` values using the product
+    /// rule.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b DualN<N>) -> DualN<N> {
+        self.zip_with(rhs, self.value * rhs.value, |a, b| a * rhs.value + self.value * b)
+    }
+
+    /// Implements multiplication of `DualN` and a constant `TwoFloat`.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b TwoFloat) -> DualN<N> {
+        self.chain(self.value * rhs, *rhs)
+    }
+
+    /// Implements multiplication of a constant `TwoFloat` and `DualN`.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a TwoFloat, rhs: &'b DualN<N>) -> DualN<N> {
+        rhs * self
+    }
+
+    /// Implements multiplication of `DualN` and a constant `f64`.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b f64) -> DualN<N> {
+        self.chain(self.value * rhs, TwoFloat::from(*rhs))
+    }
+
+    /// Implements multiplication of a constant `f64` and `DualN`.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a f64, rhs: &'b DualN<N>) -> DualN<N> {
+        rhs * self
+    }
+
+    /// Implements division of two `DualN` values using the quotient rule.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b DualN<N>) -> DualN<N> {
+        let q = self.value / rhs.value;
+        self.zip_with(rhs, q, |a, b| (a - q * b) / rhs.value)
+    }
+
+    /// Implements division of `DualN` by a constant `TwoFloat`.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b TwoFloat) -> DualN<N> {
+        self.chain_div(self.value / rhs, *rhs)
+    }
+
+    /// Implements division of a constant `TwoFloat` by `DualN`.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a TwoFloat, rhs: &'b DualN<N>) -> DualN<N> {
+        let q = self / rhs.value;
+        rhs.chain(q, -(q / rhs.value))
+    }
+
+    /// Implements division of `DualN` by a constant `f64`.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a DualN<N>, rhs: &'b f64) -> DualN<N> {
+        self.chain_div(self.value / rhs, TwoFloat::from(*rhs))
+    }
+
+    /// Implements division of a constant `f64` by `DualN`.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a f64, rhs: &'b DualN<N>) -> DualN<N> {
+        TwoFloat::from(*self) / rhs
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a DualN<N>) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a TwoFloat) {
+        self.value += rhs;
+    }
+
+    fn AddAssign::add_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a f64) {
+        self.value += rhs;
+    }
+
+    fn SubAssign::sub_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a DualN<N>) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a TwoFloat) {
+        self.value -= rhs;
+    }
+
+    fn SubAssign::sub_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a f64) {
+        self.value -= rhs;
+    }
+
+    fn MulAssign::mul_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a DualN<N>) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a TwoFloat) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a f64) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a DualN<N>) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a TwoFloat) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a; const N: usize>(self: &mut DualN<N>, rhs: &'a f64) {
+        *self = *self / rhs;
+    }
+}
@@ -0,0 +1,295 @@
+use super::DualN;
+use crate::{
+    consts::{LN_10, LN_2},
+    TwoFloat,
+};
+
+impl<const N: usize> DualN<N> {
+    /// Returns `e^x`, where `x` is the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Dual, TwoFloat};
+    /// let a = Dual::variable(TwoFloat::from(2.0), 0).exp();
+    ///
+    /// assert_eq!(a.derivatives[0], a.value);
+    pub fn exp(self) -> Self {
+        let value = self.value.exp();
+        self.chain(value, value)
+    }
+
+    /// Returns `e^x - 1`, where `x` is the value.
+    pub fn exp_m1(self) -> Self {
+        let value = self.value.exp_m1();
+        self.chain(value, value + 1.0)
+    }
+
+    /// Returns `2^x`, where `x` is the value.
+    pub fn exp2(self) -> Self {
+        let value = self.value.exp2();
+        self.chain(value, value * LN_2)
+    }
+
+    /// Returns the natural logarithm of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Dual, TwoFloat};
+    /// let a = Dual::variable(TwoFloat::from(4.0), 0).ln();
+    ///
+    /// assert_eq!(a.derivatives[0], 0.25);
+    pub fn ln(self) -> Self {
+        self.chain_div(self.value.ln(), self.value)
+    }
+
+    /// Returns the natural logarithm of `1 + x`, where `x` is the value.
+    pub fn ln_1p(self) -> Self {
+        self.chain_div(self.value.ln_1p(), self.value + 1.0)
+    }
+
+    /// Returns the logarithm of the value with respect to an arbitrary base.
+    pub fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    /// Returns the base 2 logarithm of the value.
+    pub fn log2(self) -> Self {
+        self.chain_div(self.value.log2(), self.value * LN_2)
+    }
+
+    /// Returns the base 10 logarithm of the value.
+    pub fn log10(self) -> Self {
+        self.chain_div(self.value.log10(), self.value * LN_10)
+    }
+
+    /// Returns the square root of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Dual, TwoFloat};
+    /// let a = Dual::variable(TwoFloat::from(4.0), 0).sqrt();
+    ///
+    /// assert_eq!(a.value, 2.0);
+    /// assert_eq!(a.derivatives[0], 0.25);
+    pub fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        self.chain_div(value, value * 2.0)
+    }
+
+    /// Returns the reciprocal of the square root of the value.
+    pub fn rsqrt(self) -> Self {
+        let value = self.value.rsqrt();
+        self.chain(value, -(value / self.value) * 0.5)
+    }
+
+    /// Returns the cube root of the value.
+    pub fn cbrt(self) -> Self {
+        let value = self.value.cbrt();
+        self.chain_div(value, value * value * 3.0)
+    }
+
+    /// Returns the length of the hypotenuse of a right-angle triangle with
+    /// sides `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{DualN, TwoFloat};
+    /// let x = DualN::<2>::variable(TwoFloat::from(3.0), 0);
+    /// let y = DualN::<2>::variable(TwoFloat::from(4.0), 1);
+    /// let h = x.hypot(y);
+    ///
+    /// assert_eq!(h.value, 5.0);
+    /// assert!((h.derivatives[0] - TwoFloat::from(3.0) / 5.0).abs() < 1e-30);
+    /// assert!((h.derivatives[1] - TwoFloat::from(4.0) / 5.0).abs() < 1e-30);
+    pub fn hypot(self, other: Self) -> Self {
+        let value = self.value.hypot(other.value);
+        let (a, b) = (self.value / value, other.value / value);
+        self.zip_with(&other, value, |da, db| a * da + b * db)
+    }
+
+    /// Raises the value to an integer power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Dual, TwoFloat};
+    /// let a = Dual::variable(TwoFloat::from(2.0), 0).powi(3);
+    ///
+    /// assert_eq!(a.value, 8.0);
+    /// assert_eq!(a.derivatives[0], 12.0);
+    pub fn powi(self, n: i32) -> Self {
+        let value = self.value.powi(n);
+        let derivative = if n == 0 {
+            TwoFloat::ZERO
+        } else if let Some(m) = n.checked_sub(1) {
+            self.value.powi(m) * f64::from(n)
+        } else {
+            value / self.value * f64::from(n)
+        };
+        self.chain(value, derivative)
+    }
+
+    /// Raises the value to a power, where both may depend on the variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{DualN, TwoFloat};
+    /// let x = DualN::<2>::variable(TwoFloat::from(2.0), 0);
+    /// let y = DualN::<2>::variable(TwoFloat::from(3.0), 1);
+    /// let z = x.powf(y);
+    ///
+    /// assert!((z.derivatives[0] - 12.0).abs() < 1e-9);
+    /// assert!((z.derivatives[1] - 8.0 * TwoFloat::from(2.0).ln()).abs() < 1e-9);
+    pub fn powf(self, y: Self) -> Self {
+        let value = self.value.powf(y.value);
+        let base_derivative = if self.value == 0.0 {
+            self.value.powf(y.value - 1.0) * y.value
+        } else {
+            value * y.value / self.value
+        };
+        // Only take the logarithm if the exponent is not constant, so that
+        // negative values may be raised to constant integer powers
+        let log_derivative = if y.derivatives.iter().any(|d| *d != 0.0) {
+            value * self.value.ln()
+        } else {
+            TwoFloat::ZERO
+        };
+        self.zip_with(&y, value, |dx, dy| {
+            base_derivative * dx + log_derivative * dy
+        })
+    }
+
+    /// Computes the sine of the value (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Dual, TwoFloat};
+    /// let a = Dual::variable(TwoFloat::from(0.5), 0).sin();
+    ///
+    /// assert_eq!(a.derivatives[0], TwoFloat::from(0.5).cos());
+    pub fn sin(self) -> Self {
+        let (s, c) = self.value.sin_cos();
+        self.chain(s, c)
+    }
+
+    /// Computes the cosine of the value (in radians).
+    pub fn cos(self) -> Self {
+        let (s, c) = self.value.sin_cos();
+        self.chain(c, -s)
+    }
+
+    /// Simultaneously computes the sine and cosine of the value.
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = self.value.sin_cos();
+        (self.chain(s, c), self.chain(c, -s))
+    }
+
+    /// Computes the tangent of the value (in radians).
+    pub fn tan(self) -> Self {
+        let value = self.value.tan();
+        self.chain(value, value * value + 1.0)
+    }
+
+    /// Computes the arcsine of the value.
+    pub fn asin(self) -> Self {
+        let root = ((1.0 - self.value) * (1.0 + self.value)).sqrt();
+        self.chain_div(self.value.asin(), root)
+    }
+
+    /// Computes the arccosine of the value.
+    pub fn acos(self) -> Self {
+        let root = ((1.0 - self.value) * (1.0 + self.value)).sqrt();
+        self.chain_div(self.value.acos(), -root)
+    }
+
+    /// Computes the arctangent of the value.
+    pub fn atan(self) -> Self {
+        self.chain_div(self.value.atan(), self.value * self.value + 1.0)
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other`
+    /// (`x`) in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{DualN, TwoFloat};
+    /// let y = DualN::<2>::variable(TwoFloat::from(1.0), 0);
+    /// let x = DualN::<2>::variable(TwoFloat::from(1.0), 1);
+    /// let a = y.atan2(x);
+    ///
+    /// assert!((a.derivatives[0] - 0.5).abs() < 1e-30);
+    /// assert!((a.derivatives[1] + 0.5).abs() < 1e-30);
+    pub fn atan2(self, other: Self) -> Self {
+        let value = self.value.atan2(other.value);
+        // Scaled by the radius to avoid overflow in the squared radius
+        let r = self.value.hypot(other.value);
+        let (x, y) = (other.value / r, self.value / r);
+        self.zip_with(&other, value, |dy, dx| (x * dy - y * dx) / r)
+    }
+
+    /// Converts degrees to radians.
+    pub fn to_radians(self) -> Self {
+        let mut result = Self::constant(self.value.to_radians());
+        for (r, d) in result.derivatives.iter_mut().zip(self.derivatives.iter()) {
+            *r = d.to_radians();
+        }
+        result
+    }
+
+    /// Converts radians to degrees.
+    pub fn to_degrees(self) -> Self {
+        let mut result = Self::constant(self.value.to_degrees());
+        for (r, d) in result.derivatives.iter_mut().zip(self.derivatives.iter()) {
+            *r = d.to_degrees();
+        }
+        result
+    }
+
+    /// Hyperbolic sine function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Dual, TwoFloat};
+    /// let a = Dual::variable(TwoFloat::from(1.0), 0).sinh();
+    ///
+    /// assert_eq!(a.derivatives[0], TwoFloat::from(1.0).cosh());
+    pub fn sinh(self) -> Self {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+
+    /// Hyperbolic cosine function.
+    pub fn cosh(self) -> Self {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+
+    /// Hyperbolic tangent function.
+    pub fn tanh(self) -> Self {
+        let c = self.value.cosh();
+        self.chain_div(self.value.tanh(), c * c)
+    }
+
+    /// Inverse hyperbolic sine function.
+    pub fn asinh(self) -> Self {
+        self.chain_div(self.value.asinh(), self.value.hypot(TwoFloat::ONE))
+    }
+
+    /// Inverse hyperbolic cosine function.
+    pub fn acosh(self) -> Self {
+        let root = (self.value - 1.0).sqrt() * (self.value + 1.0).sqrt();
+        self.chain_div(self.value.acosh(), root)
+    }
+
+    /// Inverse hyperbolic tangent function.
+    pub fn atanh(self) -> Self {
+        let divisor = (1.0 - self.value) * (1.0 + self.value);
+        self.chain_div(self.value.atanh(), divisor)
+    }
+}
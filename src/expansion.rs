@@ -0,0 +1,395 @@
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{
+    arithmetic::{self, fast_two_sum, two_prod, two_sum},
+    TwoFloat,
+};
+
+/// Represents a value exactly as the sum of a growable list of
+/// non-overlapping `f64` components, using the expansion arithmetic of
+/// Shewchuk (1997).
+///
+/// The components are held in order of increasing magnitude with zero
+/// components removed, so that zero is represented by an empty list and the
+/// largest component has the sign of the value. Addition, subtraction and
+/// multiplication of `Expansion`, `TwoFloat` and `f64` values are exact, at
+/// the cost of the number of components growing with each operation, which
+/// can be reduced by calling `compress()`. The results are only exact if no
+/// intermediate product underflows, and are not meaningful if any operation
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{Expansion, TwoFloat};
+/// let mut a = Expansion::from(1.0e100);
+/// a += 1.0;
+/// a -= 1.0e100;
+///
+/// assert_eq!(a.components(), [1.0]);
+/// assert_eq!(TwoFloat::from(&a * &a), 1.0);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Expansion {
+    components: Vec<f64>,
+}
+
+impl Expansion {
+    /// Creates a new `Expansion` with the value zero.
+    pub const fn new() -> Self {
+        Self {
+            components: Vec::new(),
+        }
+    }
+
+    /// Returns the non-zero components in order of increasing magnitude.
+    pub fn components(&self) -> &[f64] {
+        &self.components
+    }
+
+    /// Returns `true` if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Returns the exact product of the value and an `f64`, using the
+    /// scale-expansion algorithm of Shewchuk (1997).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Expansion, TwoFloat};
+    /// let d = 2f64.powi(-70);
+    /// let a = Expansion::from(TwoFloat::new_add(1.0, d)).scale(3.0);
+    ///
+    /// assert_eq!(a.components(), [3.0 * d, 3.0]);
+    pub fn scale(&self, b: f64) -> Self {
+        let mut iter = self.components.iter();
+        let first = match iter.next() {
+            Some(x) => *x,
+            None => return Self::new(),
+        };
+
+        let mut result = Self {
+            components: Vec::with_capacity(2 * self.components.len()),
+        };
+        let (mut q, e) = two_prod(first, b).into();
+        result.push(e);
+        for x in iter {
+            let (p, pe) = two_prod(*x, b).into();
+            let (s, e) = two_sum(q, pe).into();
+            result.push(e);
+            let (s, e) = fast_two_sum(p, s).into();
+            result.push(e);
+            q = s;
+        }
+        result.push(q);
+        result
+    }
+
+    /// Reduces the number of components without changing the value, using
+    /// the compress algorithm of Shewchuk (1997). Afterwards, the largest
+    /// component approximates the value to within one unit in the last
+    /// place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{Expansion, TwoFloat};
+    /// let third = Expansion::from(TwoFloat::from(1.0) / 3.0);
+    /// let mut a = &third * &third * &third;
+    /// let (value, len) = (TwoFloat::from(&a), a.components().len());
+    ///
+    /// a.compress();
+    /// assert!(a.components().len() < len);
+    /// assert_eq!(TwoFloat::from(&a), value);
+    pub fn compress(&mut self) {
+        let e = &self.components;
+        let m = e.len();
+        if m == 0 {
+            return;
+        }
+
+        // Sweep downwards from the largest component, then upwards from the
+        // smallest of the resulting components
+        let mut g = vec![0.0; m];
+        let mut bottom = m - 1;
+        let mut q = e[m - 1];
+        for x in e[..m - 1].iter().rev() {
+            let (s, t) = fast_two_sum(q, *x).into();
+            if t != 0.0 {
+                g[bottom] = s;
+                bottom -= 1;
+                q = t;
+            } else {
+                q = s;
+            }
+        }
+        g[bottom] = q;
+
+        let mut result = Self {
+            components: Vec::with_capacity(m - bottom),
+        };
+        let mut q = g[bottom];
+        for x in g[bottom + 1..].iter() {
+            let (s, t) = fast_two_sum(*x, q).into();
+            result.push(t);
+            q = s;
+        }
+        result.push(q);
+        *self = result;
+    }
+
+    // Returns the sum of the components, which has the same sign as the
+    // exact value.
+    fn estimate(&self) -> f64 {
+        self.components.iter().sum()
+    }
+
+    // Adds an `f64` value in place using the grow-expansion algorithm of
+    // Shewchuk (1997), eliminating zero components.
+    fn grow(&mut self, b: f64) {
+        if b == 0.0 {
+            return;
+        }
+        let mut q = b;
+        let mut n = 0;
+        for i in 0..self.components.len() {
+            let (s, e) = two_sum(q, self.components[i]).into();
+            q = s;
+            if e != 0.0 {
+                self.components[n] = e;
+                n += 1;
+            }
+        }
+        self.components.truncate(n);
+        self.push(q);
+    }
+
+    fn push(&mut self, x: f64) {
+        if x != 0.0 {
+            self.components.push(x);
+        }
+    }
+}
+
+impl From<f64> for Expansion {
+    fn from(value: f64) -> Self {
+        let mut result = Self::new();
+        result.push(value);
+        result
+    }
+}
+
+impl From<TwoFloat> for Expansion {
+    fn from(value: TwoFloat) -> Self {
+        let mut result = Self::from(value.lo);
+        result.grow(value.hi);
+        result
+    }
+}
+
+impl<'a> From<&'a TwoFloat> for Expansion {
+    fn from(value: &'a TwoFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl<'a> From<&'a Expansion> for TwoFloat {
+    /// Rounds the value to a `TwoFloat`, summing the components exactly
+    /// before rounding them into two words as for the conversions from
+    /// `ThreeFloat` and `QuadFloat`.
+    fn from(value: &'a Expansion) -> Self {
+        let estimate = value.estimate();
+        if !estimate.is_finite() || value.components.len() < 2 {
+            return Self::from(estimate);
+        }
+
+        let mut terms: Vec<f64> = value.components.iter().rev().copied().collect();
+        let mut words = [0.0; 2];
+        arithmetic::renormalize(&mut terms, &mut words);
+        Self {
+            hi: words[0],
+            lo: words[1],
+        }
+    }
+}
+
+impl From<Expansion> for TwoFloat {
+    fn from(value: Expansion) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<'a> From<&'a Expansion> for f64 {
+    /// Rounds the value to `f64` by way of its `TwoFloat` rounding.
+    fn from(value: &'a Expansion) -> Self {
+        TwoFloat::from(value).hi
+    }
+}
+
+impl From<Expansion> for f64 {
+    fn from(value: Expansion) -> Self {
+        Self::from(&value)
+    }
+}
+
+// Returns the exact sum of two expansions by growing the longer expansion by
+// each component of the shorter.
+fn add(a: &Expansion, b: &Expansion) -> Expansion {
+    let (a, b) = if a.components.len() < b.components.len() {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    let mut result = a.clone();
+    for x in b.components.iter() {
+        result.grow(*x);
+    }
+    result
+}
+
+// Returns the exact product of two expansions as the sum of the expansions
+// scaled by each component of the shorter.
+fn mul(a: &Expansion, b: &Expansion) -> Expansion {
+    let (a, b) = if a.components.len() < b.components.len() {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    b.components
+        .iter()
+        .fold(Expansion::new(), |sum, x| add(&sum, &a.scale(*x)))
+}
+
+unary_ops! {
+    fn Neg::neg(self: &Expansion) -> Expansion {
+        Expansion {
+            components: self.components.iter().map(|x| -x).collect(),
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements exact addition of two `Expansion` values.
+    fn Add::add<'a, 'b>(self: &'a Expansion, rhs: &'b Expansion) -> Expansion {
+        add(self, rhs)
+    }
+
+    /// Implements exact addition of `Expansion` and `TwoFloat`.
+    fn Add::add<'a, 'b>(self: &'a Expansion, rhs: &'b TwoFloat) -> Expansion {
+        let mut result = self.clone();
+        result.grow(rhs.lo);
+        result.grow(rhs.hi);
+        result
+    }
+
+    /// Implements exact addition of `TwoFloat` and `Expansion`.
+    fn Add::add<'a, 'b>(self: &'a TwoFloat, rhs: &'b Expansion) -> Expansion {
+        rhs + self
+    }
+
+    /// Implements exact addition of `Expansion` and `f64`.
+    fn Add::add<'a, 'b>(self: &'a Expansion, rhs: &'b f64) -> Expansion {
+        let mut result = self.clone();
+        result.grow(*rhs);
+        result
+    }
+
+    /// Implements exact addition of `f64` and `Expansion`.
+    fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b Expansion) -> Expansion {
+        rhs + self
+    }
+
+    /// Implements exact subtraction of two `Expansion` values.
+    fn Sub::sub<'a, 'b>(self: &'a Expansion, rhs: &'b Expansion) -> Expansion {
+        add(self, &-rhs)
+    }
+
+    /// Implements exact subtraction of `TwoFloat` from `Expansion`.
+    fn Sub::sub<'a, 'b>(self: &'a Expansion, rhs: &'b TwoFloat) -> Expansion {
+        self + -rhs
+    }
+
+    /// Implements exact subtraction of `Expansion` from `TwoFloat`.
+    fn Sub::sub<'a, 'b>(self: &'a TwoFloat, rhs: &'b Expansion) -> Expansion {
+        -rhs + self
+    }
+
+    /// Implements exact subtraction of `f64` from `Expansion`.
+    fn Sub::sub<'a, 'b>(self: &'a Expansion, rhs: &'b f64) -> Expansion {
+        self + -rhs
+    }
+
+    /// Implements exact subtraction of `Expansion` from `f64`.
+    fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b Expansion) -> Expansion {
+        -rhs + self
+    }
+
+    /// Implements exact multiplication of two `Expansion` values.
+    fn Mul::mul<'a, 'b>(self: &'a Expansion, rhs: &'b Expansion) -> Expansion {
+        mul(self, rhs)
+    }
+
+    /// Implements exact multiplication of `Expansion` and `TwoFloat`.
+    fn Mul::mul<'a, 'b>(self: &'a Expansion, rhs: &'b TwoFloat) -> Expansion {
+        add(&self.scale(rhs.lo), &self.scale(rhs.hi))
+    }
+
+    /// Implements exact multiplication of `TwoFloat` and `Expansion`.
+    fn Mul::mul<'a, 'b>(self: &'a TwoFloat, rhs: &'b Expansion) -> Expansion {
+        rhs * self
+    }
+
+    /// Implements exact multiplication of `Expansion` and `f64`.
+    fn Mul::mul<'a, 'b>(self: &'a Expansion, rhs: &'b f64) -> Expansion {
+        self.scale(*rhs)
+    }
+
+    /// Implements exact multiplication of `f64` and `Expansion`.
+    fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b Expansion) -> Expansion {
+        rhs.scale(*self)
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut Expansion, rhs: &'a Expansion) {
+        *self = add(self, rhs);
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut Expansion, rhs: &'a TwoFloat) {
+        self.grow(rhs.lo);
+        self.grow(rhs.hi);
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut Expansion, rhs: &'a f64) {
+        self.grow(*rhs);
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut Expansion, rhs: &'a Expansion) {
+        *self = add(self, &-rhs);
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut Expansion, rhs: &'a TwoFloat) {
+        self.grow(-rhs.lo);
+        self.grow(-rhs.hi);
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut Expansion, rhs: &'a f64) {
+        self.grow(-rhs);
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut Expansion, rhs: &'a Expansion) {
+        *self = mul(self, rhs);
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut Expansion, rhs: &'a TwoFloat) {
+        *self = &*self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut Expansion, rhs: &'a f64) {
+        *self = self.scale(*rhs);
+    }
+}
@@ -4,6 +4,8 @@ pub mod sign;
 #[cfg(feature = "math_funcs")]
 mod function_utils;
 
+#[cfg(feature = "math_funcs")]
+pub mod bessel;
 #[cfg(feature = "math_funcs")]
 pub mod explog;
 #[cfg(feature = "math_funcs")]
@@ -11,4 +13,6 @@ pub mod hyperbolic;
 #[cfg(feature = "math_funcs")]
 pub mod power;
 #[cfg(feature = "math_funcs")]
+pub mod special;
+#[cfg(feature = "math_funcs")]
 pub mod trigonometry;
@@ -1,4 +1,17 @@
-use crate::{consts::LN_2, TwoFloat};
+use crate::{
+    arithmetic::two_prod,
+    base::{binade, scale},
+    consts::{LN_2, LOG10_2, LOG10_E},
+    status::{self, FpFlags},
+    ThreeFloat, TwoFloat,
+};
+
+// ln(2) to triple-word precision
+const LN_2_THREE: ThreeFloat = ThreeFloat {
+    hi: 0.6931471805599453,
+    mid: 2.3190468138462996e-17,
+    lo: 5.707708438416212e-34,
+};
 
 // 1/ln(2)
 const FRAC_1_LN_2: TwoFloat = TwoFloat {
@@ -6,180 +19,991 @@ const FRAC_1_LN_2: TwoFloat = TwoFloat {
     lo: 2.0355273740931033e-17,
 };
 
-// ln(10)
-const LN_10: TwoFloat = TwoFloat {
-    hi: 2.302585092994046,
-    lo: -2.1707562233822494e-16,
+// limits
+const EXP_M1_SMALL_LIMIT: f64 = 0.0054152123481245725; // ln(2)/128
+const LN_1P_SMALL_LIMIT: f64 = 0.0055248618784530384; // 1/181
+
+// ln(2^-1075), below which the exponential rounds to zero
+const EXP_LOWER_LIMIT: TwoFloat = TwoFloat {
+    hi: -745.1332191019412,
+    lo: 1.4483164125345337e-14,
 };
 
-// ln(3/2)
-const LN_FRAC_3_2: TwoFloat = TwoFloat {
-    hi: 0.4054651081081644,
-    lo: -2.8811380259626426e-18,
+// ln(MAX), above which the exponential overflows
+const EXP_UPPER_LIMIT: TwoFloat = TwoFloat {
+    hi: 709.782712893384,
+    lo: 2.369152822255485e-14,
 };
 
-// limits
-const EXP_UPPER_LIMIT: f64 = 709.782712893384;
-const EXP_LOWER_LIMIT: f64 = -745.1332191019412;
+// Bounds on the relative error of `exp` outside the subnormal range, and on
+// the error of `ln` relative to the larger of one and the magnitude of the
+// result. Used to widen the bounds of `TwoFloatInterval`.
+pub(crate) const EXP_ERROR_BOUND: f64 = 7.888609052210118e-31; // 2^-100
+pub(crate) const LN_ERROR_BOUND: f64 = 7.888609052210118e-31; // 2^-100
 
-// Coefficients for polynomial approximation of x*(exp(x)+1)/(exp(x)-1)
-const EXP_COEFFS: [TwoFloat; 6] = [
+// ln(2)/64 split into three parts, where the first has 36 significant bits so
+// that its product with the reduction multiple n is exact
+const LN_2_64_HI: f64 = 0.010830424696223417;
+const LN_2_64_MID: f64 = 2.572804622327669e-14;
+const LN_2_64_LO: f64 = -1.5746795524851787e-30;
+const FRAC_64_LN_2: f64 = 92.33248261689366;
+
+// Coefficients for polynomial approximation of (exp(r)-1-r)/r^2 on
+// [-ln(2)/128, ln(2)/128]. The terms of degree five and higher are below 2^-37
+// and only need the precision of f64.
+const EXP_M1_COEFFS: [TwoFloat; 5] = [
+    TwoFloat { hi: 0.5, lo: 0.0 },
     TwoFloat {
         hi: 0.16666666666666666,
-        lo: 8.301559840894034e-18,
+        lo: 9.251858537891686e-18,
+    },
+    TwoFloat {
+        hi: 0.041666666666666664,
+        lo: 2.312964634581469e-18,
+    },
+    TwoFloat {
+        hi: 0.008333333333333333,
+        lo: 1.1594435919663843e-19,
+    },
+    TwoFloat {
+        hi: 0.001388888888888889,
+        lo: -5.29807622564395e-20,
+    },
+];
+
+const EXP_M1_TAIL_COEFFS: [f64; 4] = [
+    0.00019841269841266206,
+    2.4801587301584273e-05,
+    2.7557335753416944e-06,
+    2.7557332998511216e-07,
+];
+
+// 2^(j/64) for j = 0, 1, ..., 63
+const EXP2_TABLE: [TwoFloat; 64] = [
+    TwoFloat { hi: 1.0, lo: 0.0 },
+    TwoFloat {
+        hi: 1.0108892860517005,
+        lo: -1.5234778603368577e-17,
+    },
+    TwoFloat {
+        hi: 1.0218971486541166,
+        lo: 5.109225028973444e-17,
+    },
+    TwoFloat {
+        hi: 1.0330248790212284,
+        lo: 7.600838874027088e-18,
+    },
+    TwoFloat {
+        hi: 1.0442737824274138,
+        lo: 8.551889705537965e-17,
+    },
+    TwoFloat {
+        hi: 1.0556451783605572,
+        lo: 1.759325738772092e-18,
+    },
+    TwoFloat {
+        hi: 1.0671404006768237,
+        lo: -7.899853966841582e-17,
+    },
+    TwoFloat {
+        hi: 1.0787607977571199,
+        lo: -6.656660436056593e-17,
+    },
+    TwoFloat {
+        hi: 1.0905077326652577,
+        lo: -3.046782079812471e-17,
+    },
+    TwoFloat {
+        hi: 1.102382583307841,
+        lo: 5.2660368715706944e-17,
+    },
+    TwoFloat {
+        hi: 1.1143867425958924,
+        lo: 1.0410278456845571e-16,
+    },
+    TwoFloat {
+        hi: 1.1265216186082418,
+        lo: 5.165856758795457e-17,
+    },
+    TwoFloat {
+        hi: 1.1387886347566916,
+        lo: 8.912812676025408e-17,
+    },
+    TwoFloat {
+        hi: 1.1511892299529827,
+        lo: 3.250710218863827e-17,
+    },
+    TwoFloat {
+        hi: 1.1637248587775775,
+        lo: 3.8292048369240935e-17,
+    },
+    TwoFloat {
+        hi: 1.1763969916502812,
+        lo: 5.554203254218079e-17,
+    },
+    TwoFloat {
+        hi: 1.189207115002721,
+        lo: 3.982015231465646e-17,
+    },
+    TwoFloat {
+        hi: 1.202156731452703,
+        lo: 6.644981499252301e-17,
+    },
+    TwoFloat {
+        hi: 1.215247359980469,
+        lo: -7.712630692681488e-17,
+    },
+    TwoFloat {
+        hi: 1.22848053610687,
+        lo: -1.89878163130253e-17,
+    },
+    TwoFloat {
+        hi: 1.241857812073484,
+        lo: 4.658027591836937e-17,
+    },
+    TwoFloat {
+        hi: 1.255380757024691,
+        lo: -6.7113898212968784e-18,
+    },
+    TwoFloat {
+        hi: 1.2690509571917332,
+        lo: 2.667932131342186e-18,
+    },
+    TwoFloat {
+        hi: 1.2828700160787783,
+        lo: 1.713594918243561e-17,
+    },
+    TwoFloat {
+        hi: 1.2968395546510096,
+        lo: 2.5382502794888315e-17,
+    },
+    TwoFloat {
+        hi: 1.3109612115247644,
+        lo: -7.181536135519454e-17,
+    },
+    TwoFloat {
+        hi: 1.3252366431597413,
+        lo: -2.8587312100388614e-17,
+    },
+    TwoFloat {
+        hi: 1.339667524053303,
+        lo: 8.927282594831732e-17,
+    },
+    TwoFloat {
+        hi: 1.3542555469368927,
+        lo: 7.70094837980299e-17,
+    },
+    TwoFloat {
+        hi: 1.3690024229745905,
+        lo: 9.593797919118849e-17,
+    },
+    TwoFloat {
+        hi: 1.383909881963832,
+        lo: -6.770511658794786e-17,
+    },
+    TwoFloat {
+        hi: 1.3989796725383112,
+        lo: -9.614213209051323e-17,
+    },
+    TwoFloat {
+        hi: 1.4142135623730951,
+        lo: -9.667293313452913e-17,
+    },
+    TwoFloat {
+        hi: 1.42961333839197,
+        lo: -1.2031642489053655e-17,
+    },
+    TwoFloat {
+        hi: 1.4451808069770467,
+        lo: -3.0237581349939873e-17,
+    },
+    TwoFloat {
+        hi: 1.460917794180647,
+        lo: -5.600377186075216e-17,
+    },
+    TwoFloat {
+        hi: 1.4768261459394993,
+        lo: -3.483994556892796e-17,
     },
     TwoFloat {
-        hi: -0.0027777777777776512,
-        lo: 1.1664268064351513e-19,
+        hi: 1.4929077282912648,
+        lo: 1.4192920154284036e-17,
     },
     TwoFloat {
-        hi: 6.613756613123634e-05,
-        lo: 3.613966532258593e-21,
+        hi: 1.5091644275934228,
+        lo: -1.016455327754295e-16,
     },
     TwoFloat {
-        hi: -1.6534390027595268e-06,
-        lo: 2.6408090483313454e-23,
+        hi: 1.5255981507445384,
+        lo: -1.1024941712342561e-16,
     },
     TwoFloat {
-        hi: 4.175167193059256e-08,
-        lo: -2.949837910669653e-24,
+        hi: 1.5422108254079407,
+        lo: 7.949834809697621e-17,
     },
     TwoFloat {
-        hi: -1.0456596683715461e-09,
-        lo: -9.375356618962057e-26,
+        hi: 1.559004400237837,
+        lo: 3.7812070533575275e-17,
+    },
+    TwoFloat {
+        hi: 1.5759808451078865,
+        lo: -1.0136916471278304e-17,
+    },
+    TwoFloat {
+        hi: 1.593142151342267,
+        lo: -1.0094406542311964e-16,
+    },
+    TwoFloat {
+        hi: 1.6104903319492543,
+        lo: 2.4707192569797888e-17,
+    },
+    TwoFloat {
+        hi: 1.6280274218573478,
+        lo: -6.712955084707084e-17,
+    },
+    TwoFloat {
+        hi: 1.645755478153965,
+        lo: -1.0125679913674773e-16,
+    },
+    TwoFloat {
+        hi: 1.6636765803267364,
+        lo: 5.8909926967131e-17,
+    },
+    TwoFloat {
+        hi: 1.681792830507429,
+        lo: 8.199010020581497e-17,
+    },
+    TwoFloat {
+        hi: 1.7001063537185235,
+        lo: -8.0237193703977e-18,
+    },
+    TwoFloat {
+        hi: 1.718619298122478,
+        lo: -1.851380418263111e-17,
+    },
+    TwoFloat {
+        hi: 1.7373338352737062,
+        lo: 3.164389299292957e-17,
+    },
+    TwoFloat {
+        hi: 1.7562521603732995,
+        lo: 2.960140695448873e-17,
+    },
+    TwoFloat {
+        hi: 1.7753764925265212,
+        lo: 6.429731796556572e-17,
+    },
+    TwoFloat {
+        hi: 1.7947090750031072,
+        lo: 1.8227458427912087e-17,
+    },
+    TwoFloat {
+        hi: 1.8142521755003989,
+        lo: -9.969531538920349e-17,
+    },
+    TwoFloat {
+        hi: 1.8340080864093424,
+        lo: 3.283107224245627e-17,
+    },
+    TwoFloat {
+        hi: 1.8539791250833855,
+        lo: 9.761887490727594e-17,
+    },
+    TwoFloat {
+        hi: 1.8741676341103,
+        lo: -6.122763413004143e-17,
+    },
+    TwoFloat {
+        hi: 1.8945759815869656,
+        lo: 3.4034035352165297e-17,
+    },
+    TwoFloat {
+        hi: 1.9152065613971474,
+        lo: -1.0619946056195963e-16,
+    },
+    TwoFloat {
+        hi: 1.9360617934922943,
+        lo: 1.0332385960676326e-16,
+    },
+    TwoFloat {
+        hi: 1.9571441241754002,
+        lo: 8.960767791036668e-17,
+    },
+    TwoFloat {
+        hi: 1.978456026387951,
+        lo: 4.0388753109278167e-17,
     },
 ];
 
-const EXP_M1_COEFFS: [TwoFloat; 12] = [
+// Coefficients for polynomial approximation of (ln(1+t)-t)/t^2 on
+// [-1/181, 1/181]
+const LN_1P_COEFFS: [TwoFloat; 12] = [
     TwoFloat {
-        hi: 0.5,
-        lo: 2.4147853280441852e-17,
+        hi: -0.5,
+        lo: 2.8270496490123654e-32,
     },
     TwoFloat {
-        hi: 0.1666666666666666,
-        lo: -1.0126242119486759e-17,
+        hi: 0.3333333333333333,
+        lo: 1.8503717077085917e-17,
     },
     TwoFloat {
-        hi: 0.04166666666666422,
-        lo: -3.1228374568246144e-18,
+        hi: -0.25,
+        lo: -6.666118077916589e-26,
     },
     TwoFloat {
-        hi: 0.00833333333333542,
-        lo: 5.220576901966033e-19,
+        hi: 0.2,
+        lo: -1.1102230184034422e-17,
     },
     TwoFloat {
-        hi: 0.0013888888889601945,
-        lo: -6.710675996026077e-20,
+        hi: -0.16666666666666666,
+        lo: -9.226388700143303e-18,
     },
     TwoFloat {
-        hi: 0.00019841269843008256,
-        lo: 7.744211626999651e-22,
+        hi: 0.14285714285714285,
+        lo: 7.906392596880812e-18,
     },
     TwoFloat {
-        hi: 2.4801586443947164e-05,
-        lo: 1.2547780925434967e-21,
+        hi: -0.12500000000000355,
+        lo: -6.224225480044863e-18,
     },
     TwoFloat {
-        hi: 2.755731053267803e-06,
-        lo: -1.0646797628167905e-23,
+        hi: 0.11111111111111444,
+        lo: -2.823728278683459e-18,
     },
     TwoFloat {
-        hi: 2.755775448074009e-07,
-        lo: -8.322155420550663e-24,
+        hi: -0.09999999977521876,
+        lo: 1.8215312596023786e-18,
     },
     TwoFloat {
-        hi: 2.505957492948909e-08,
-        lo: -5.793840740452254e-25,
+        hi: 0.09090909069929497,
+        lo: -3.982438195061071e-18,
     },
     TwoFloat {
-        hi: 2.0819091856857293e-09,
-        lo: -3.914736871893147e-26,
+        hi: -0.08333987679774314,
+        lo: -5.456275132324632e-18,
     },
     TwoFloat {
-        hi: 1.412762092583865e-10,
-        lo: 2.8362941933894766e-27,
+        hi: 0.07692918415909714,
+        lo: -6.866775520227276e-18,
     },
 ];
 
-// Coefficients for polynomial approximation of 2^x on [-0.5, 0.5]
-const EXP2_COEFFS: [TwoFloat; 14] = [
+// ln(1/c) for c = i/128, where 1/c is rounded to `f64`, for i from 90 to 182
+const LN_RECIP_TABLE: [TwoFloat; 93] = [
+    TwoFloat {
+        hi: 0.35222059358935215,
+        lo: -1.1623903064849822e-17,
+    },
+    TwoFloat {
+        hi: 0.3411707574027672,
+        lo: 3.1846151250956206e-18,
+    },
+    TwoFloat {
+        hi: 0.3302416868705768,
+        lo: 1.6927253978145054e-17,
+    },
+    TwoFloat {
+        hi: 0.3194307707663613,
+        lo: 2.5640385520940108e-17,
+    },
+    TwoFloat {
+        hi: 0.30873548164961323,
+        lo: 1.5025836482434425e-17,
+    },
+    TwoFloat {
+        hi: 0.2981533723190763,
+        lo: 1.575278736910067e-17,
+    },
+    TwoFloat {
+        hi: 0.28768207245178085,
+        lo: 2.6071606164425637e-17,
+    },
+    TwoFloat {
+        hi: 0.27731928541623435,
+        lo: -2.652724229158001e-17,
+    },
+    TwoFloat {
+        hi: 0.26706278524904514,
+        lo: 2.3896107240262357e-17,
+    },
+    TwoFloat {
+        hi: 0.2569104137850273,
+        lo: -9.92419178127068e-19,
+    },
+    TwoFloat {
+        hi: 0.2468600779315258,
+        lo: 6.678539813576451e-18,
+    },
+    TwoFloat {
+        hi: 0.23690974707835774,
+        lo: -1.3644270985951448e-17,
+    },
+    TwoFloat {
+        hi: 0.22705745063534608,
+        lo: -4.326372045075968e-18,
+    },
+    TwoFloat {
+        hi: 0.2173012756899813,
+        lo: -1.8526017065773163e-18,
+    },
+    TwoFloat {
+        hi: 0.20763936477824455,
+        lo: 1.2053243216686127e-17,
+    },
+    TwoFloat {
+        hi: 0.19806991376209387,
+        lo: 1.0681737386368664e-17,
+    },
+    TwoFloat {
+        hi: 0.18859116980754997,
+        lo: 9.915070540571144e-18,
+    },
+    TwoFloat {
+        hi: 0.17920142945771092,
+        lo: -2.111400074974391e-18,
+    },
+    TwoFloat {
+        hi: 0.16989903679539742,
+        lo: -4.868008764439086e-19,
+    },
+    TwoFloat {
+        hi: 0.16068238169047352,
+        lo: -3.650183553047839e-18,
+    },
+    TwoFloat {
+        hi: 0.15154989812720088,
+        lo: 1.2105853272368787e-17,
+    },
+    TwoFloat {
+        hi: 0.142500062607283,
+        lo: 9.155570001519129e-18,
+    },
     TwoFloat {
-        hi: 0.6931471805599453,
-        lo: 2.3190643482818175e-17,
+        hi: 0.13353139262452257,
+        lo: -3.664457663660086e-18,
     },
     TwoFloat {
-        hi: 0.24022650695910072,
-        lo: -9.493874028535377e-18,
+        hi: 0.12464244520727659,
+        lo: -5.8089126789409715e-18,
     },
     TwoFloat {
-        hi: 0.05550410866482158,
-        lo: -3.192006150784052e-18,
+        hi: 0.11583181552512165,
+        lo: 4.3384843698080944e-18,
     },
     TwoFloat {
-        hi: 0.009618129107628477,
-        lo: 2.7893564000728505e-19,
+        hi: 0.10709813555636712,
+        lo: -3.4717745161358675e-18,
     },
     TwoFloat {
-        hi: 0.0013333558146428454,
-        lo: 6.081607270943552e-20,
+        hi: 0.09844007281325251,
+        lo: -4.439009633675136e-18,
     },
     TwoFloat {
-        hi: 0.00015403530393381622,
-        lo: 1.481577195325289e-21,
+        hi: 0.08985632912186114,
+        lo: 2.84207093558465e-18,
     },
     TwoFloat {
-        hi: 1.5252733804038298e-05,
-        lo: -5.2457376024944265e-22,
+        hi: 0.0813456394539524,
+        lo: 1.6076294039775555e-18,
     },
     TwoFloat {
-        hi: 1.3215486790126266e-06,
-        lo: 5.182514493196138e-23,
+        hi: 0.07290677080808773,
+        lo: 5.836204074304871e-18,
     },
     TwoFloat {
-        hi: 1.0178086030302029e-07,
-        lo: -9.783640823461531e-25,
+        hi: 0.06453852113757116,
+        lo: -6.470486661692933e-18,
     },
     TwoFloat {
-        hi: 7.054911635035306e-09,
-        lo: -1.8596402662109374e-25,
+        hi: 0.05623971832287611,
+        lo: -3.2835149805605617e-18,
     },
     TwoFloat {
-        hi: 4.445527244701503e-10,
-        lo: 2.2736481131821772e-26,
+        hi: 0.04800921918636066,
+        lo: -2.030356617224395e-18,
     },
     TwoFloat {
-        hi: 2.567837336021261e-11,
-        lo: -5.1428171225883105e-28,
+        hi: 0.03984590854719978,
+        lo: -1.3948242043384064e-18,
     },
     TwoFloat {
-        hi: 1.3720884847209076e-12,
-        lo: 8.060868050272103e-29,
+        hi: 0.03174869831458027,
+        lo: 3.0382263084680854e-18,
     },
     TwoFloat {
-        hi: 6.793159072191384e-14,
-        lo: 5.621628913936963e-30,
+        hi: 0.023716526617316065,
+        lo: -1.5774243488668216e-18,
+    },
+    TwoFloat {
+        hi: 0.015748356968139112,
+        lo: 1.0021578630528958e-18,
+    },
+    TwoFloat {
+        hi: 0.007843177461025879,
+        lo: 2.764708154124903e-19,
+    },
+    TwoFloat { hi: 0.0, lo: 0.0 },
+    TwoFloat {
+        hi: -0.007782140442054963,
+        lo: 1.2819179123343749e-20,
+    },
+    TwoFloat {
+        hi: -0.015504186535965199,
+        lo: 3.2783210228924137e-19,
+    },
+    TwoFloat {
+        hi: -0.023167059281534418,
+        lo: 3.095927552179262e-19,
+    },
+    TwoFloat {
+        hi: -0.03077165866675366,
+        lo: -1.0431732029005972e-18,
+    },
+    TwoFloat {
+        hi: -0.03831886430213666,
+        lo: 2.3579961573512846e-18,
+    },
+    TwoFloat {
+        hi: -0.04580953603129422,
+        lo: -1.6823639049745016e-19,
+    },
+    TwoFloat {
+        hi: -0.05324451451881224,
+        lo: -1.803871134979952e-18,
+    },
+    TwoFloat {
+        hi: -0.060624621816434854,
+        lo: -2.6424025938726934e-18,
+    },
+    TwoFloat {
+        hi: -0.06795066190850778,
+        lo: -3.9239563038692484e-18,
+    },
+    TwoFloat {
+        hi: -0.07522342123758752,
+        lo: 4.195880720316434e-18,
+    },
+    TwoFloat {
+        hi: -0.08244366921107454,
+        lo: 4.707903082046854e-18,
+    },
+    TwoFloat {
+        hi: -0.08961215868968717,
+        lo: 1.9573659817110993e-18,
+    },
+    TwoFloat {
+        hi: -0.09672962645855114,
+        lo: 4.0291867005826106e-18,
+    },
+    TwoFloat {
+        hi: -0.10379679368164355,
+        lo: 3.195893222617445e-18,
+    },
+    TwoFloat {
+        hi: -0.11081436634029011,
+        lo: -2.0511100808140527e-18,
+    },
+    TwoFloat {
+        hi: -0.11778303565638351,
+        lo: 1.1971685747593662e-18,
+    },
+    TwoFloat {
+        hi: -0.12470347850095725,
+        lo: 4.6522609636496624e-18,
+    },
+    TwoFloat {
+        hi: -0.13157635778871932,
+        lo: -1.112300087972959e-17,
+    },
+    TwoFloat {
+        hi: -0.1384023228591192,
+        lo: 1.3766819196398948e-17,
+    },
+    TwoFloat {
+        hi: -0.14518200984449783,
+        lo: -8.242418783022477e-18,
+    },
+    TwoFloat {
+        hi: -0.151916042025842,
+        lo: -4.1233095848339465e-19,
+    },
+    TwoFloat {
+        hi: -0.15860503017663852,
+        lo: -2.583386492298558e-18,
+    },
+    TwoFloat {
+        hi: -0.16524957289530717,
+        lo: 9.227573884334224e-18,
+    },
+    TwoFloat {
+        hi: -0.17185025692665928,
+        lo: 6.022453821011369e-18,
+    },
+    TwoFloat {
+        hi: -0.17840765747281825,
+        lo: -1.2720936612962572e-17,
+    },
+    TwoFloat {
+        hi: -0.18492233849401193,
+        lo: 7.384679440503435e-18,
+    },
+    TwoFloat {
+        hi: -0.19139485299962947,
+        lo: 1.126213516780448e-17,
+    },
+    TwoFloat {
+        hi: -0.19782574332991992,
+        lo: 7.995487338741543e-18,
+    },
+    TwoFloat {
+        hi: -0.20421554142869083,
+        lo: -7.9379985298027e-18,
+    },
+    TwoFloat {
+        hi: -0.21056476910734964,
+        lo: -1.136310596906137e-17,
+    },
+    TwoFloat {
+        hi: -0.2168739383006143,
+        lo: -6.285749669211092e-18,
+    },
+    TwoFloat {
+        hi: -0.2231435513142097,
+        lo: 9.091270597324798e-18,
+    },
+    TwoFloat {
+        hi: -0.2293741010648459,
+        lo: 5.684839459813236e-18,
+    },
+    TwoFloat {
+        hi: -0.23556607131276697,
+        lo: 2.394337149518734e-18,
+    },
+    TwoFloat {
+        hi: -0.24171993688714513,
+        lo: -1.323779871210866e-17,
+    },
+    TwoFloat {
+        hi: -0.2478361639045812,
+        lo: -8.384472133019162e-18,
+    },
+    TwoFloat {
+        hi: -0.25391520998096345,
+        lo: 7.180735656435798e-18,
+    },
+    TwoFloat {
+        hi: -0.259957524436926,
+        lo: -2.4167516341742964e-17,
+    },
+    TwoFloat {
+        hi: -0.2659635484971379,
+        lo: -1.35209848201012e-19,
+    },
+    TwoFloat {
+        hi: -0.2719337154836418,
+        lo: -7.833196376974436e-19,
+    },
+    TwoFloat {
+        hi: -0.2778684510034563,
+        lo: -2.2502748630777633e-17,
+    },
+    TwoFloat {
+        hi: -0.2837681731306446,
+        lo: 6.448868003452105e-18,
+    },
+    TwoFloat {
+        hi: -0.2896332925830427,
+        lo: -2.0535953219858177e-17,
+    },
+    TwoFloat {
+        hi: -0.2954642128938359,
+        lo: 7.768320796245443e-18,
+    },
+    TwoFloat {
+        hi: -0.30126133057816185,
+        lo: 1.5120043309967385e-17,
+    },
+    TwoFloat {
+        hi: -0.3070250352949119,
+        lo: -1.5578716077124932e-18,
+    },
+    TwoFloat {
+        hi: -0.3127557100038969,
+        lo: 1.3650721793001109e-17,
+    },
+    TwoFloat {
+        hi: -0.3184537311185346,
+        lo: 6.407962483026777e-19,
+    },
+    TwoFloat {
+        hi: -0.324119468654212,
+        lo: 4.488767429940198e-18,
+    },
+    TwoFloat {
+        hi: -0.32975328637246804,
+        lo: 2.5633554999431966e-17,
+    },
+    TwoFloat {
+        hi: -0.3353555419211378,
+        lo: 1.3746739934976202e-17,
+    },
+    TwoFloat {
+        hi: -0.3409265869705932,
+        lo: 2.069678002794501e-17,
+    },
+    TwoFloat {
+        hi: -0.3464667673462086,
+        lo: 3.591951952851805e-18,
+    },
+    TwoFloat {
+        hi: -0.3519764231571781,
+        lo: -2.0005853013367377e-17,
+    },
+];
+
+// Third words of `LN_RECIP_TABLE`, for the triple-word logarithm
+const LN_RECIP_TABLE_LO: [f64; 93] = [
+    -4.177361593281059e-34,
+    1.5310027605611622e-34,
+    5.90581254077382e-34,
+    -3.4335836190079215e-34,
+    -8.225184367584692e-34,
+    1.331684170036286e-33,
+    4.699413794904933e-34,
+    8.732927663607953e-34,
+    -1.2521867558882536e-33,
+    1.1267352599497779e-35,
+    -1.3427761332238647e-34,
+    5.319950863383398e-34,
+    9.03248084774598e-35,
+    -5.216073205396462e-35,
+    6.934295861642487e-34,
+    -1.8066628338218584e-34,
+    1.88269992340476e-34,
+    1.5796177269331044e-34,
+    -2.761518034439742e-35,
+    1.959251196939972e-34,
+    -6.727529718955586e-34,
+    -4.953098808326774e-34,
+    1.9543611395855355e-34,
+    3.5076021423626465e-34,
+    -1.966016315219788e-34,
+    2.7266357918358635e-34,
+    1.0275939170581138e-34,
+    -1.4718324501461808e-34,
+    7.169681969098387e-35,
+    -2.579074627380538e-34,
+    -2.5573177581653744e-34,
+    1.6099675490717502e-34,
+    -5.021471364917395e-35,
+    -4.0182765106095705e-35,
+    5.938726465918062e-35,
+    6.717706344838898e-36,
+    -1.3230954218251744e-35,
+    1.4373060040999001e-36,
+    0.0,
+    -6.191991814581058e-37,
+    1.5904679466898835e-35,
+    3.0465075204369026e-36,
+    7.246134058454665e-35,
+    -8.592090817647135e-35,
+    -6.196645617731986e-36,
+    -1.3337963480178658e-34,
+    5.569417864413656e-36,
+    -1.3724378866154364e-34,
+    3.0838795165233116e-35,
+    -7.244509443495301e-35,
+    -1.5106958354724012e-34,
+    -1.529759233547028e-34,
+    -1.9262304827007777e-35,
+    1.0298039462731527e-34,
+    -1.607407373808177e-35,
+    2.4375471137303675e-34,
+    5.565016550131821e-34,
+    -4.054737339285517e-34,
+    6.131085144129313e-34,
+    1.880217963180494e-35,
+    -1.523522753756252e-34,
+    -6.366230455990136e-34,
+    1.0382896674242222e-34,
+    -3.7500194417664297e-34,
+    -6.413966935107311e-34,
+    2.0000642613414285e-34,
+    -9.252985807890424e-36,
+    2.153273832060369e-34,
+    7.271860404173096e-34,
+    1.4010267490618668e-34,
+    -6.293766580876689e-34,
+    -1.4736997314734489e-34,
+    -3.214814747616349e-35,
+    4.645857990053716e-34,
+    -1.3547058510250993e-34,
+    4.056734964982325e-34,
+    -1.5246099306101538e-33,
+    9.554134020816971e-36,
+    -1.6898476119360942e-36,
+    5.418690063270529e-34,
+    -2.3862125134580813e-34,
+    4.729408818817877e-34,
+    4.90899760752614e-34,
+    1.1155850437478416e-33,
+    1.929927354683526e-36,
+    -2.9332138265415314e-34,
+    -1.2294050028499488e-35,
+    -2.2172563909886757e-34,
+    1.5139135506350073e-33,
+    6.20874970533104e-35,
+    -9.885070031697271e-34,
+    -2.3606455580743697e-34,
+    1.1230733877242838e-33,
+];
+
+// Coefficients for polynomial approximation of (ln(1+t)-t+t^2/2)/t^3 on
+// [-1/181, 1/181], used by the triple-word logarithm
+const LN_1P_CUBIC_COEFFS: [TwoFloat; 13] = [
+    TwoFloat {
+        hi: 0.3333333333333333,
+        lo: 1.850371707708594e-17,
+    },
+    TwoFloat {
+        hi: -0.25,
+        lo: 1.6045705614397882e-31,
+    },
+    TwoFloat {
+        hi: 0.2,
+        lo: -1.1102230246251717e-17,
+    },
+    TwoFloat {
+        hi: -0.16666666666666666,
+        lo: -9.25185868573137e-18,
+    },
+    TwoFloat {
+        hi: 0.14285714285714285,
+        lo: 7.930164600138597e-18,
+    },
+    TwoFloat {
+        hi: -0.125,
+        lo: 3.857621956677398e-20,
+    },
+    TwoFloat {
+        hi: 0.1111111111111111,
+        lo: 6.131598641928328e-18,
+    },
+    TwoFloat {
+        hi: -0.10000000000000434,
+        lo: 2.427262351931444e-18,
+    },
+    TwoFloat {
+        hi: 0.09090909090909499,
+        lo: -4.4790266072802e-18,
+    },
+    TwoFloat {
+        hi: -0.08333333309674663,
+        lo: -3.686607290678126e-18,
+    },
+    TwoFloat {
+        hi: 0.07692307670040699,
+        lo: -2.7294413937856504e-18,
+    },
+    TwoFloat {
+        hi: -0.0714347719726047,
+        lo: 4.1176873106756145e-18,
+    },
+    TwoFloat {
+        hi: 0.0666725024748991,
+        lo: -1.6623700264844363e-18,
     },
 ];
 
-fn mul_pow2(mut x: f64, mut y: i32) -> f64 {
-    loop {
-        if y < -1074 {
-            x *= f64::from_bits(1u64);
-            y += 1074;
-        } else if y < -1022 {
-            return x * f64::from_bits(1u64 << (y + 1074));
-        } else if y < 1024 {
-            return x * f64::from_bits(((y + 1023) as u64) << 52);
-        } else {
-            x *= f64::from_bits(0x7fe << 52);
-            y -= 1023;
-        }
+// Returns exp(r)-1 for |r| <= ln(2)/128
+fn exp_m1_small(r: TwoFloat) -> TwoFloat {
+    let rh = r.hi;
+    let tail = polynomial!(rh, EXP_M1_TAIL_COEFFS);
+    let q = EXP_M1_COEFFS
+        .iter()
+        .rev()
+        .fold(TwoFloat::from(tail), |a, c| r * a + c);
+    r + r * r * q
+}
+
+// Reduces x to n and r = x - n ln(2)/64, where n is the nearest integer to
+// 64x/ln(2). The first product is exact and the difference is exact by
+// Sterbenz's lemma.
+fn exp_reduce(x: TwoFloat) -> (f64, TwoFloat) {
+    let n = (x.hi * FRAC_64_LN_2).round();
+    let r = TwoFloat::new_add(x.hi - n * LN_2_64_HI, x.lo)
+        - (two_prod(n, LN_2_64_MID) + n * LN_2_64_LO);
+    (n, r)
+}
+
+// Splits 2^(n/64) e^r into 2^m, t = 2^(j/64) and t (e^r - 1), where
+// n = 64m + j. The parts are left unscaled so that results near the overflow
+// threshold are only rounded once.
+fn exp_parts(n: f64, r: TwoFloat) -> (i32, TwoFloat, TwoFloat) {
+    let m = (n / 64.0).floor();
+    let t = EXP2_TABLE[(n - 64.0 * m) as usize];
+    (m as i32, t, t * exp_m1_small(r))
+}
+
+// Returns ln(1+t) for |t| <= 1/181
+fn ln_1p_small(t: TwoFloat) -> TwoFloat {
+    t + t * t * polynomial!(t, LN_1P_COEFFS)
+}
+
+// Reduces a finite positive value x = 2^k m, where m lies in [1/√2, √2), to
+// k, the index i of the nearest multiple c = i/128 of 1/128, and the split
+// products of m with the rounded 1/c, whose sum minus one is t = m/c - 1.
+fn ln_reduce(x: TwoFloat) -> (i32, usize, TwoFloat, TwoFloat) {
+    let mut k = binade(x);
+    let mut m = scale(x, -k);
+    if m.hi > core::f64::consts::SQRT_2 {
+        m = scale(m, -1);
+        k += 1;
     }
+
+    let i = (m.hi * 128.0).round();
+    let r = 128.0 / i;
+    (k, i as usize, two_prod(m.hi, r), two_prod(m.lo, r))
+}
+
+// Splits the logarithm of a finite positive value x = 2^k m, where m lies in
+// [1/√2, √2), into k and ln(m). The logarithm of m is the sum of ln(1/c) for
+// the nearest multiple c of 1/128 from the table, and ln(1+t) for
+// t = m/c - 1, which is computed exactly from the rounded 1/c.
+fn ln_parts(x: TwoFloat) -> (f64, TwoFloat) {
+    let (k, i, p, q) = ln_reduce(x);
+    let t = TwoFloat::new_add(p.hi - 1.0, p.lo) + q;
+    let ln_m = ln_1p_small(t) - LN_RECIP_TABLE[i - 90];
+    (f64::from(k), ln_m)
+}
+
+// Returns the natural logarithm of a finite positive value in triple-word
+// arithmetic, using the same reduction as `ln_parts`, with an error below
+// 2^-115 relative to the larger of one and the magnitude of the result.
+pub(crate) fn ln_three(x: TwoFloat) -> ThreeFloat {
+    let (k, i, p, q) = ln_reduce(x);
+    let t = ThreeFloat::from(TwoFloat::new_add(p.hi - 1.0, p.lo)) + ThreeFloat::from(q);
+
+    // ln(1+t) = t - t^2/2 + t^3 R(t), where the last term only needs the
+    // precision of `TwoFloat`
+    let t2 = TwoFloat::from(t);
+    let cubic = t2 * t2 * t2 * polynomial!(t2, LN_1P_CUBIC_COEFFS);
+    let ln_1p = t - t * t * 0.5 + ThreeFloat::from(cubic);
+
+    let ln_recip = ThreeFloat {
+        hi: LN_RECIP_TABLE[i - 90].hi,
+        mid: LN_RECIP_TABLE[i - 90].lo,
+        lo: LN_RECIP_TABLE_LO[i - 90],
+    };
+    LN_2_THREE * f64::from(k) - ln_recip + ln_1p
 }
 
 impl TwoFloat {
     /// Returns `e^(self)`, (the exponential function).
     ///
+    /// The result is computed from a table of powers of two and a polynomial,
+    /// with a relative error below 2^-104 outside the subnormal range.
+    ///
     /// # Examples
     ///
     /// ```
@@ -188,55 +1012,31 @@ impl TwoFloat {
     /// let b = a.exp();
     /// let e2 = twofloat::consts::E * twofloat::consts::E;
     ///
-    /// assert!((b - e2).abs() / e2 < 1e-16);
+    /// assert!((b - e2).abs() / e2 < 1e-30);
     pub fn exp(self) -> Self {
-        if self.hi <= EXP_LOWER_LIMIT {
-            Self::from(0.0)
-        } else if self.hi >= EXP_UPPER_LIMIT {
-            Self {
-                hi: f64::INFINITY,
-                lo: 0.0,
-            }
-        } else if self.hi == 0.0 {
-            Self::from(1.0)
-        } else {
-            // reduce value to range |r| <= ln(2)/2
-            // where self = k*ln(2) + r
-
-            let k = ((FRAC_1_LN_2 * self).hi + 0.5).trunc();
-            let r = self - LN_2 * k;
-
-            // Now approximate the function
-            //
-            // R(r^2) = r*(exp(r)+1)/(exp(r)-1) = 2 + P1*r^2 + P2*r^4 + ...
-            //
-            // using a polynomial obtained by the Remez algorithm on the
-            // interval [0, ln(2)/2], then:
-            //
-            // exp(r) = 1 + 2*r/(R-r) = 1 + r + (r*R1) / (2-R1)
-            //
-            // where R1 = r - (P1*r^2 + P2*r^4 + ...)
-
-            let rr = r * r;
-            let r1 = r - rr * polynomial!(rr, EXP_COEFFS);
-
-            let exp_r = 1.0 - ((r * r1) / (r1 - 2.0) - r);
-
-            // then scale back
-
-            if k == 0.0 {
-                exp_r
-            } else {
-                Self {
-                    hi: mul_pow2(exp_r.hi, k as i32),
-                    lo: mul_pow2(exp_r.lo, k as i32),
+        status::function("exp", &[self], || {
+            if self.is_nan() {
+                Self::NAN
+            } else if self < EXP_LOWER_LIMIT {
+                if self.is_finite() {
+                    status::raise(FpFlags::UNDERFLOW, "exp");
                 }
+                Self::from(0.0)
+            } else if self > EXP_UPPER_LIMIT {
+                Self::INFINITY
+            } else if self.hi == 0.0 {
+                Self::from(1.0)
+            } else {
+                let (n, r) = exp_reduce(self);
+                let (m, t, tp) = exp_parts(n, r);
+                scale(t + tp, m)
             }
-        }
+        })
     }
 
     /// Returns `e^(self) - 1` in a way that provides additional accuracy
-    /// when the value is close to zero.
+    /// when the value is close to zero. The relative error of the result is
+    /// below 2^-100.
     ///
     /// # Examples
     ///
@@ -244,19 +1044,27 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(0.05);
     /// let b = a.exp_m1();
-    /// let c = 0.05f64.exp_m1();
+    /// let c = TwoFloat::new_add(0.05127109637602404, 2.2068111176530193e-18);
     ///
-    /// assert!((b - c).abs() < 1e-16);
+    /// assert!(((b - c) / c).abs() < 1e-30);
     pub fn exp_m1(self) -> Self {
-        if self < -LN_2 || self > LN_FRAC_3_2 {
-            self.exp() - 1.0
-        } else {
-            let r = polynomial!(self, 1.0, EXP_M1_COEFFS);
-            self * r
-        }
+        status::function("exp_m1", &[self], || {
+            if self.hi.abs() <= EXP_M1_SMALL_LIMIT {
+                exp_m1_small(self)
+            } else if self.hi.abs() < 1.0 {
+                // e^x - 1 = (2^m t - 1) + 2^m t (e^r - 1), where n is
+                // non-zero so the subtraction does not cancel
+                let (n, r) = exp_reduce(self);
+                let (m, t, tp) = exp_parts(n, r);
+                (scale(t, m) - 1.0) + scale(tp, m)
+            } else {
+                self.exp() - 1.0
+            }
+        })
     }
 
-    /// Returns `2^(self)`.
+    /// Returns `2^(self)`. The result is exact for integers, and otherwise has
+    /// a relative error below 2^-104 outside the subnormal range.
     ///
     /// # Examples
     ///
@@ -265,77 +1073,93 @@ impl TwoFloat {
     /// let a = TwoFloat::from(0.5).exp2();
     /// let b = TwoFloat::from(2).sqrt();
     ///
-    /// assert!((a - b).abs() < 1e-15);
+    /// assert!((a - b).abs() < 1e-30);
+    /// assert_eq!(TwoFloat::from(-1074.0).exp2(), f64::from_bits(1));
     pub fn exp2(self) -> Self {
-        if self < -1074.0 {
-            Self::from(0.0)
-        } else if self >= 1023.0 {
-            Self {
-                hi: f64::INFINITY,
-                lo: f64::INFINITY,
-            }
-        } else {
-            let k = self.hi.round();
-            let r = self - k;
-            let r1 = polynomial!(r, 1.0, EXP2_COEFFS);
-            if k == 0.0 {
-                r1
-            } else {
-                Self {
-                    hi: mul_pow2(r1.hi, k as i32),
-                    lo: mul_pow2(r1.lo, k as i32),
+        status::function("exp2", &[self], || {
+            if self.is_nan() {
+                Self::NAN
+            } else if self < -1074.0 {
+                if self.is_finite() {
+                    status::raise(FpFlags::UNDERFLOW, "exp2");
                 }
+                Self::from(0.0)
+            } else if self >= 1024.0 {
+                Self::INFINITY
+            } else {
+                // The difference is exact, as the bits of n/64 below the
+                // least significant bit of self.hi are zero
+                let n = (self.hi * 64.0).round();
+                let r = TwoFloat::new_add(self.hi - n / 64.0, self.lo) * LN_2;
+                let (m, t, tp) = exp_parts(n, r);
+                scale(t + tp, m)
             }
-        }
+        })
     }
 
     /// Returns the natural logarithm of the value.
     ///
-    /// Uses Newton–Raphson iteration which depends on the `exp` function, so
-    /// may not be fully accurate to the full precision of a `TwoFloat`.
+    /// The result is computed from a table of logarithms and a polynomial,
+    /// with a relative error below 2^-103.
     ///
     /// # Example
     ///
     /// ```
     /// let a = twofloat::consts::E.ln();
-    /// assert!((a - 1.0).abs() < 1e-11);
+    /// assert!((a - 1.0).abs() < 1e-30);
     pub fn ln(self) -> Self {
-        if self == 1.0 {
-            Self::from(0.0)
-        } else if self <= 0.0 {
-            Self::NAN
-        } else {
-            let mut x = Self::from(self.hi.ln());
-            x += self * (-x).exp() - 1.0;
-            x + self * (-x).exp() - 1.0
-        }
+        status::function("ln", &[self], || {
+            if self == 1.0 {
+                Self::from(0.0)
+            } else if self == 0.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "ln");
+                Self::NEG_INFINITY
+            } else if self < 0.0 || self.is_nan() {
+                Self::NAN
+            } else if self.hi.is_infinite() {
+                self
+            } else {
+                let (k, ln_m) = ln_parts(self);
+                LN_2 * k + ln_m
+            }
+        })
     }
 
-    /// Returns the natural logarithm of `1 + self`.
+    /// Returns the natural logarithm of `1 + self`, in a way that provides
+    /// additional accuracy when the value is close to zero.
     ///
-    /// Uses Newton–Raphson iteration which depends on the `expm1` function,
-    /// so may not be fully accurate to the full precision of a `TwoFloat`.
+    /// The relative error of the result is below 2^-103.
     ///
     /// # Example
     ///
     /// ```
     /// # use twofloat::TwoFloat;
-    /// let a = TwoFloat::from(0.1);
+    /// let a = TwoFloat::from(1e-20);
     /// let b = a.ln_1p();
-    /// let c = 0.1f64.ln_1p();
-    /// assert!((b - c).abs() < 1e-10);
+    /// let c = TwoFloat::new_add(1e-20, -5e-41);
+    /// assert!(((b - c) / c).abs() < 1e-30);
     pub fn ln_1p(self) -> Self {
-        if self == 0.0 {
-            Self::from(0.0)
-        } else if self <= -1.0 {
-            Self::NAN
-        } else {
-            let mut x = Self::from(self.hi.ln_1p());
-            let mut e = x.exp_m1();
-            x -= (e - self) / (e + 1.0);
-            e = x.exp_m1();
-            x - (e - self) / (e + 1.0)
-        }
+        status::function("ln_1p", &[self], || {
+            if self == 0.0 {
+                Self::from(0.0)
+            } else if self == -1.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "ln_1p");
+                Self::NEG_INFINITY
+            } else if self < -1.0 || self.is_nan() {
+                Self::NAN
+            } else if self.hi.is_infinite() {
+                self
+            } else if self.hi.abs() <= LN_1P_SMALL_LIMIT {
+                ln_1p_small(self)
+            } else {
+                // ln(1+x) = ln(y) + ln(1 + e/y), where y = 1+x is rounded with
+                // error e
+                let y = 1.0 + self;
+                let e = (1.0 - y) + self;
+                let (k, ln_m) = ln_parts(y);
+                LN_2 * k + ln_m + e / y
+            }
+        })
     }
 
     /// Returns the logarithm of the number with respect to an arbitrary base.
@@ -351,37 +1175,42 @@ impl TwoFloat {
     ///
     /// assert!((c - 4.0).abs() < 1e-12);
     pub fn log(self, base: Self) -> Self {
-        self.ln() / base.ln()
+        status::function("log", &[self, base], || self.ln() / base.ln())
     }
 
-    /// Returns the base 2 logarithm of the number.
-    ///
-    /// Uses Newton–Raphson iteration which depends on the `exp2` function,
-    /// so may not be fully accurate to the full precision of a `TwoFloat`.
+    /// Returns the base 2 logarithm of the number. The result is exact for
+    /// powers of two, and otherwise has a relative error below 2^-103.
     ///
     /// # Examples
     ///
     /// ```
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(64.0).log2();
+    /// let b = TwoFloat::from(3.0).log2();
+    /// let c = TwoFloat::new_add(1.584962500721156, 1.0579781240112554e-16);
     ///
-    /// assert!((a - 6.0).abs() < 1e-12, "{}", a);
+    /// assert_eq!(a, 6.0);
+    /// assert!(((b - c) / c).abs() < 1e-30);
     pub fn log2(self) -> Self {
-        if self == 1.0 {
-            Self::from(1.0)
-        } else if self <= 0.0 {
-            Self::NAN
-        } else {
-            let mut x = Self::from(self.hi.log2());
-            x += (self * (-x).exp2() - 1.0) * FRAC_1_LN_2;
-            x + (self * (-x).exp2() - 1.0) * FRAC_1_LN_2
-        }
+        status::function("log2", &[self], || {
+            if self == 1.0 {
+                Self::from(0.0)
+            } else if self == 0.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "log2");
+                Self::NEG_INFINITY
+            } else if self < 0.0 || self.is_nan() {
+                Self::NAN
+            } else if self.hi.is_infinite() {
+                self
+            } else {
+                let (k, ln_m) = ln_parts(self);
+                ln_m * FRAC_1_LN_2 + k
+            }
+        })
     }
 
-    /// Returns the base 10 logarithm of the number.
-    ///
-    /// This is a convenience method that computes `self.ln() / LN_10`, no
-    /// additional accuracy is provided.
+    /// Returns the base 10 logarithm of the number, with a relative error
+    /// below 2^-103.
     ///
     /// # Examples
     ///
@@ -389,9 +1218,23 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(100.0).log10();
     ///
-    /// assert!((a - 2.0).abs() < 1e-12);
+    /// assert!((a - 2.0).abs() < 1e-30);
     pub fn log10(self) -> Self {
-        self.ln() / LN_10
+        status::function("log10", &[self], || {
+            if self == 1.0 {
+                Self::from(0.0)
+            } else if self == 0.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "log10");
+                Self::NEG_INFINITY
+            } else if self < 0.0 || self.is_nan() {
+                Self::NAN
+            } else if self.hi.is_infinite() {
+                self
+            } else {
+                let (k, ln_m) = ln_parts(self);
+                LOG10_2 * k + ln_m * LOG10_E
+            }
+        })
     }
 }
 
@@ -1,5 +1,7 @@
 #![macro_use]
 
+use crate::arithmetic::{two_prod, two_sum};
+
 macro_rules! polynomial {
     ($x:ident, $poly:expr) => {
         {
@@ -12,3 +14,52 @@ macro_rules! polynomial {
         $x * polynomial!($x, $($coeffs),+) + $coeff
     );
 }
+
+// Exact sum of `f64` values, held as a non-overlapping expansion in order of
+// increasing magnitude using the grow-expansion algorithm of Shewchuk (1997).
+pub(crate) struct ExactSum {
+    terms: [f64; 40],
+    len: usize,
+}
+
+impl ExactSum {
+    pub(crate) fn new() -> Self {
+        Self {
+            terms: [0.0; 40],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn add(&mut self, x: f64) {
+        if x == 0.0 {
+            return;
+        }
+        let mut q = x;
+        let mut n = 0;
+        for i in 0..self.len {
+            let (s, e) = two_sum(q, self.terms[i]).into();
+            q = s;
+            if e != 0.0 {
+                self.terms[n] = e;
+                n += 1;
+            }
+        }
+        if q != 0.0 {
+            self.terms[n] = q;
+            n += 1;
+        }
+        self.len = n;
+    }
+
+    pub(crate) fn add_product(&mut self, a: f64, b: f64) {
+        let (p, e) = two_prod(a, b).into();
+        self.add(p);
+        self.add(e);
+    }
+
+    // Returns an approximation of the sum which has the same sign as the
+    // exact sum.
+    pub(crate) fn value(&self) -> f64 {
+        self.terms[..self.len].iter().sum()
+    }
+}
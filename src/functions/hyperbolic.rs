@@ -1,10 +1,29 @@
-use crate::TwoFloat;
+use crate::{
+    consts::LN_2,
+    status::{self, FpFlags},
+    TwoFloat,
+};
+
+// Beyond this magnitude e^-x is below 2^-110 relative to e^x, so it does not
+// contribute to cosh, sinh and tanh
+const EXP_DOMINANT_LIMIT: f64 = 40.0;
+
+// Beyond this magnitude x^2 + 1 and x^2 - 1 round to x^2, so the inverse
+// functions are computed as ln(2x) to avoid the overflow of x^2
+const INVERSE_LARGE_LIMIT: f64 = 1152921504606846976.0; // 2^60
+
+// Returns e^x / 2 for x >= 0, splitting the exponential so that the result
+// does not overflow before it is halved
+fn half_exp(x: TwoFloat) -> TwoFloat {
+    let e = (x / 2.0).exp();
+    (e / 2.0) * e
+}
 
 impl TwoFloat {
     /// Hyperbolic cosine function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// exponential function.
+    /// The result has a relative error below 2^-100, and only overflows when
+    /// it is out of range.
     ///
     /// # Examples
     ///
@@ -12,17 +31,28 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(2.0);
     /// let b = a.cosh();
-    /// let c = 2.0f64.cosh();
+    /// let c = TwoFloat::new_add(3.7621956910836314, 7.146584908813439e-17);
     ///
-    /// assert!((b - c).abs() < 1e-10);
+    /// assert!(((b - c) / c).abs() < 1e-30);
     pub fn cosh(self) -> Self {
-        self.exp() / 2.0 + (-self).exp() / 2.0
+        status::function("cosh", &[self], || {
+            let x = self.abs();
+            if x.is_nan() {
+                Self::NAN
+            } else if x.hi < EXP_DOMINANT_LIMIT {
+                let e = x.exp();
+                (e + e.recip()) / 2.0
+            } else {
+                half_exp(x)
+            }
+        })
     }
 
     /// Hyperbolic sine function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// exponential function.
+    /// The result is computed from `exp_m1` for moderate arguments, which
+    /// avoids the cancellation near zero, and has a relative error below
+    /// 2^-100.
     ///
     /// # Examples
     ///
@@ -30,17 +60,30 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(2.0);
     /// let b = a.sinh();
-    /// let c = 2.0f64.sinh();
+    /// let c = TwoFloat::new_add(3.6268604078470186, 1.9291196578353674e-16);
     ///
-    /// assert!((b - c).abs() < 1e-10);
+    /// assert!(((b - c) / c).abs() < 1e-30);
+    /// assert_eq!(TwoFloat::from(1e-20).sinh().hi(), 1e-20);
     pub fn sinh(self) -> Self {
-        self.exp() / 2.0 - (-self).exp() / 2.0
+        status::function("sinh", &[self], || {
+            let x = self.abs();
+            let value = if x.is_nan() {
+                Self::NAN
+            } else if x.hi < EXP_DOMINANT_LIMIT {
+                // sinh(x) = (E + E / (E + 1)) / 2, where E = e^x - 1
+                let e = x.exp_m1();
+                (e + e / (e + 1.0)) / 2.0
+            } else {
+                half_exp(x)
+            };
+            value.copysign(self)
+        })
     }
 
     /// Hyperbolic tangent function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// exponential function.
+    /// The result is computed from `exp_m1`, which avoids the cancellation
+    /// near zero, and has a relative error below 2^-100.
     ///
     /// # Examples
     ///
@@ -48,19 +91,30 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(2.0);
     /// let b = a.tanh();
-    /// let c = 2.0f64.tanh();
+    /// let c = TwoFloat::new_add(0.9640275800758169, -1.9413550547557176e-17);
     ///
-    /// assert!((b - c).abs() < 1e-10);
+    /// assert!(((b - c) / c).abs() < 1e-30);
     pub fn tanh(self) -> Self {
-        let e_plus = self.exp();
-        let e_minus = (-self).exp();
-        (e_plus - e_minus) / (e_plus + e_minus)
+        status::function("tanh", &[self], || {
+            let x = self.abs();
+            let value = if x.is_nan() {
+                Self::NAN
+            } else if x.hi < EXP_DOMINANT_LIMIT {
+                // tanh(x) = E / (E + 2), where E = e^(2x) - 1
+                let e = (2.0 * x).exp_m1();
+                e / (e + 2.0)
+            } else {
+                Self::ONE
+            };
+            value.copysign(self)
+        })
     }
 
     /// Inverse hyperbolic cosine function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// `sqrt` and `ln` functions.
+    /// Arguments close to 1 are computed from `ln_1p`, which avoids the
+    /// cancellation near the zero of the function, and the result has a
+    /// relative error below 2^-100.
     ///
     /// # Examples
     ///
@@ -68,17 +122,30 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(2.0);
     /// let b = a.acosh();
-    /// let c = 2.0f64.acosh();
+    /// let c = TwoFloat::new_add(1.3169578969248168, -8.682250844852022e-17);
     ///
-    /// assert!((b - c).abs() < 1e-10);
+    /// assert!(((b - c) / c).abs() < 1e-30);
     pub fn acosh(self) -> Self {
-        (self + (self * self - 1.0).sqrt()).ln()
+        status::function("acosh", &[self], || {
+            if self.is_nan() || self < 1.0 {
+                Self::NAN
+            } else if self.hi < 2.0 {
+                // acosh(1 + t) = ln(1 + t + sqrt(2t + t^2))
+                let t = self - 1.0;
+                (t + (t * (t + 2.0)).sqrt()).ln_1p()
+            } else if self.hi < INVERSE_LARGE_LIMIT {
+                (self + (self * self - 1.0).sqrt()).ln()
+            } else {
+                self.ln() + LN_2
+            }
+        })
     }
 
     /// Inverse hyperbolic sine function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// `sqrt` and `ln` functions.
+    /// Arguments of magnitude less than 1 are computed from `ln_1p`, which
+    /// avoids the cancellation near zero, and the result has a relative error
+    /// below 2^-100.
     ///
     /// # Examples
     ///
@@ -86,17 +153,32 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(2.0);
     /// let b = a.asinh();
-    /// let c = 2.0f64.asinh();
+    /// let c = TwoFloat::new_add(1.4436354751788103, 4.124885142212745e-17);
     ///
-    /// assert!((b - c).abs() < 1e-10);
+    /// assert!(((b - c) / c).abs() < 1e-30);
+    /// assert_eq!(TwoFloat::from(1e-20).asinh().hi(), 1e-20);
     pub fn asinh(self) -> Self {
-        (self + (self * self + 1.0).sqrt()).ln()
+        status::function("asinh", &[self], || {
+            let x = self.abs();
+            let value = if x.is_nan() || x.is_infinite() {
+                x
+            } else if x.hi < 1.0 {
+                // asinh(x) = ln(1 + x + x^2 / (1 + sqrt(1 + x^2)))
+                let xx = x * x;
+                (x + xx / (1.0 + (xx + 1.0).sqrt())).ln_1p()
+            } else if x.hi < INVERSE_LARGE_LIMIT {
+                (x + (x * x + 1.0).sqrt()).ln()
+            } else {
+                x.ln() + LN_2
+            };
+            value.copysign(self)
+        })
     }
 
     /// Inverse hyperbolic tangent function.
     ///
-    /// This is a convenience method that computes the value by calling the
-    /// `ln` function.
+    /// The result is computed from `ln_1p`, which avoids the cancellation near
+    /// zero, and has a relative error below 2^-100.
     ///
     /// # Examples
     ///
@@ -104,10 +186,22 @@ impl TwoFloat {
     /// # use twofloat::TwoFloat;
     /// let a = TwoFloat::from(0.5);
     /// let b = a.atanh();
-    /// let c = 0.5f64.atanh();
+    /// let c = TwoFloat::new_add(0.5493061443340549, -4.535648617500765e-17);
     ///
-    /// assert!((b - c).abs() < 1e-10);
+    /// assert!(((b - c) / c).abs() < 1e-30);
     pub fn atanh(self) -> Self {
-        ((1.0 + self) / (1.0 - self)).ln() / 2.0
+        status::function("atanh", &[self], || {
+            let x = self.abs();
+            let value = if x.is_nan() || x > 1.0 {
+                Self::NAN
+            } else if x == 1.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "atanh");
+                Self::INFINITY
+            } else {
+                // atanh(x) = ln(1 + 2x / (1 - x)) / 2
+                (2.0 * x / (1.0 - x)).ln_1p() / 2.0
+            };
+            value.copysign(self)
+        })
     }
 }
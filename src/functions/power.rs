@@ -1,4 +1,247 @@
-use crate::TwoFloat;
+use crate::{
+    arithmetic::{fast_two_sum, two_prod, two_sum},
+    base::{binade, mul_pow2, scale},
+    functions::{explog::ln_three, function_utils::ExactSum},
+    status::{self, FpFlags},
+    ThreeFloat, TwoFloat,
+};
+
+// Approximations closer than this to the midpoint between two
+// representable values, in units of the last place, are rounded using an
+// exact comparison against the midpoint.
+const MIDPOINT_TOLERANCE: f64 = 1e-9;
+
+// Bound on the relative error of the correctly rounded `sqrt`, which is half
+// a unit in the last place of a 106-bit significand. Used to widen the bounds
+// of `TwoFloatInterval`.
+pub(crate) const SQRT_ERROR_BOUND: f64 = 1.232595164407831e-32; // 2^-106
+
+// Integer powers with exponents up to this magnitude are computed by binary
+// powering, which is exact for representable results. The error of binary
+// powering grows with the exponent, so larger exponents use the exponential
+// of the logarithm.
+const POWI_LIMIT: f64 = 4294967296.0; // 2^32
+
+// Scales a positive finite value by an even power of two 2^-s to give a
+// value in the range [1, 4), returning the scaled value and s.
+fn scale_root(x: TwoFloat) -> (TwoFloat, i32) {
+    let e = binade(x);
+    let s = e - e.rem_euclid(2);
+    let scaled = TwoFloat {
+        hi: mul_pow2(x.hi, -s),
+        lo: mul_pow2(x.lo, -s),
+    };
+    (scaled, s)
+}
+
+// Approximates the square root of a value in [1, 4) with a relative error of
+// at most 25/8 * 2^-106, using Algorithm 8 (SQRTDWtoDW) of Lefèvre et al.
+// (2023).
+fn approx_sqrt(x: TwoFloat) -> TwoFloat {
+    let sh = x.hi.sqrt();
+    let rho = (-sh).mul_add(sh, x.hi) + x.lo;
+    fast_two_sum(sh, rho / (2.0 * sh))
+}
+
+// Returns the exact terms of (a + b + c)^2, where c is zero or a power of
+// two small enough for the products involving it to be exact.
+fn square_terms(a: f64, b: f64, c: f64) -> [f64; 9] {
+    let (aa, aa_err) = two_prod(a, a).into();
+    let (bb, bb_err) = two_prod(b, b).into();
+    let (ab, ab_err) = two_prod(2.0 * a, b).into();
+    [
+        aa,
+        aa_err,
+        bb,
+        bb_err,
+        ab,
+        ab_err,
+        c * c,
+        2.0 * a * c,
+        2.0 * b * c,
+    ]
+}
+
+// Returns an approximation of x - (a + b + c)^2 with the correct sign.
+fn sqrt_residual(x: TwoFloat, a: f64, b: f64, c: f64) -> f64 {
+    let mut sum = ExactSum::new();
+    sum.add(x.hi);
+    sum.add(x.lo);
+    for term in square_terms(a, b, c).iter() {
+        sum.add(-term);
+    }
+    sum.value()
+}
+
+// Returns an approximation of 1 - x * (a + b + c)^2 with the correct sign.
+fn rsqrt_residual(x: TwoFloat, a: f64, b: f64, c: f64) -> f64 {
+    let mut sum = ExactSum::new();
+    sum.add(1.0);
+    for term in square_terms(a, b, c).iter() {
+        sum.add_product(-term, x.hi);
+        sum.add_product(-term, x.lo);
+    }
+    sum.value()
+}
+
+// Rounds the approximation y + d of a root to the nearest multiple of 2^k,
+// where y.hi is a multiple of 2^k, d is a small correction and the root lies
+// in a single binade with unit in the last place 2^k. If the approximation
+// is too close to a midpoint m = a + b + c to decide the rounding,
+// `above_midpoint(a, b, c)` must return whether the root is greater than m.
+fn round_root(
+    y: TwoFloat,
+    d: f64,
+    k: i32,
+    above_midpoint: impl FnOnce(f64, f64, f64) -> bool,
+) -> TwoFloat {
+    let (th, tl) = two_sum(y.lo, d).into();
+    let w = mul_pow2(th, -k);
+    let mut n = w.round();
+    let mut r = (w - n) + mul_pow2(tl, -k);
+    if r > 0.5 {
+        n += 1.0;
+        r -= 1.0;
+    } else if r < -0.5 {
+        n -= 1.0;
+        r += 1.0;
+    }
+
+    if 0.5 - r.abs() < MIDPOINT_TOLERANCE {
+        let above = above_midpoint(y.hi, mul_pow2(n, k), mul_pow2(0.5f64.copysign(r), k));
+        if r > 0.0 && above {
+            n += 1.0;
+        } else if r < 0.0 && !above {
+            n -= 1.0;
+        }
+    }
+
+    two_sum(y.hi, mul_pow2(n, k))
+}
+
+// Splits a finite nonzero value into a value m in [1, 2), up to the rounding
+// of the lower words, and an exponent k such that x = 2^k m.
+fn split_binade(x: ThreeFloat) -> (ThreeFloat, i64) {
+    let k = binade(TwoFloat::from(x.hi));
+    let m = ThreeFloat {
+        hi: mul_pow2(x.hi, -k),
+        mid: mul_pow2(x.mid, -k),
+        lo: mul_pow2(x.lo, -k),
+    };
+    (m, i64::from(k))
+}
+
+// Returns |x|^n for finite nonzero x and 0 < n <= POWI_LIMIT as a value m and
+// an exponent e such that |x|^n = 2^e m. Binary powering is carried out in
+// triple-word arithmetic on values scaled to [1, 2), which keeps every
+// product exact when the result is representable, and keeps the intermediate
+// values in range however large the result is.
+fn powi_parts(x: TwoFloat, mut n: u64) -> (TwoFloat, i64) {
+    let (mut base, mut base_exp) = split_binade(ThreeFloat::from(x.abs()));
+    let mut value = ThreeFloat::from(1.0);
+    let mut exp = 0;
+    loop {
+        if n & 1 != 0 {
+            let (m, k) = split_binade(value * base);
+            value = m;
+            exp += base_exp + k;
+        }
+        n >>= 1;
+        if n == 0 {
+            break;
+        }
+        let (m, k) = split_binade(base * base);
+        base = m;
+        base_exp = 2 * base_exp + k;
+    }
+    (TwoFloat::from(value), exp)
+}
+
+// Returns |x|^n for finite nonzero x and nonzero n with |n| <= POWI_LIMIT.
+// Negative powers take the reciprocal of the scaled positive power, so that
+// it cannot overflow or underflow before the result does.
+fn powi_abs(x: TwoFloat, n: i64, op: &'static str) -> TwoFloat {
+    if n == 1 {
+        return x;
+    }
+    let (m, e) = powi_parts(x, n.unsigned_abs());
+    let (m, e) = if n > 0 { (m, e) } else { (m.recip(), -e) };
+    let result = scale(m, e.clamp(-2200, 2200) as i32);
+    if result.hi == 0.0 {
+        status::raise(FpFlags::UNDERFLOW, op);
+    }
+    result
+}
+
+// Returns x^y for finite positive x and finite y as the exponential of
+// y ln(x). The logarithm and the product are carried in triple-word
+// arithmetic, and the part of the product lost when rounding it to
+// `TwoFloat` is applied as a first-order correction to the exponential.
+fn exp_ln(x: TwoFloat, y: TwoFloat) -> TwoFloat {
+    let z = ThreeFloat::from(y) * ln_three(x);
+    let z_hi = TwoFloat::from(z);
+    let e = z_hi.exp();
+    if e.hi == 0.0 || e.is_infinite() {
+        e
+    } else {
+        e + e * (z - ThreeFloat::from(z_hi)).hi
+    }
+}
+
+// Returns x^y following IEEE 754 `pow` for x zero or infinite and y nonzero
+// and not NAN, where `odd` indicates that y is an odd integer.
+fn pow_zero_inf(x: TwoFloat, y_positive: bool, odd: bool, op: &'static str) -> TwoFloat {
+    let magnitude = if (x.hi == 0.0) == y_positive {
+        TwoFloat::from(0.0)
+    } else {
+        if x.hi == 0.0 {
+            status::raise(FpFlags::DIVISION_BY_ZERO, op);
+        }
+        TwoFloat::INFINITY
+    };
+    if odd {
+        magnitude.copysign(x)
+    } else {
+        magnitude
+    }
+}
+
+// Returns x^n following IEEE 754 `pown`.
+fn powi_signed(x: TwoFloat, n: i64, op: &'static str) -> TwoFloat {
+    let odd = n % 2 != 0;
+    if n == 0 {
+        TwoFloat::from(1.0)
+    } else if x.is_nan() {
+        TwoFloat::NAN
+    } else if x.hi == 0.0 || x.is_infinite() {
+        pow_zero_inf(x, n > 0, odd, op)
+    } else {
+        let magnitude = if (n.unsigned_abs() as f64) <= POWI_LIMIT {
+            powi_abs(x.abs(), n, op)
+        } else {
+            exp_ln(x.abs(), TwoFloat::from(n))
+        };
+        if odd && x.hi < 0.0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+// Returns whether a finite value is an odd integer, or `None` if it is not an
+// integer.
+fn odd_integer(y: TwoFloat) -> Option<bool> {
+    fn is_odd(x: f64) -> bool {
+        x.abs() < 9007199254740992.0 && x % 2.0 != 0.0
+    }
+
+    if y.hi.fract() != 0.0 || y.lo.fract() != 0.0 {
+        None
+    } else {
+        Some(is_odd(y.hi) != is_odd(y.lo))
+    }
+}
 
 impl TwoFloat {
     /// Takes the reciprocal (inverse) of the number, `1/x`.
@@ -16,8 +259,13 @@ impl TwoFloat {
         1.0 / self
     }
 
-    /// Returns the square root of the number, using equation 4 from Karp &
-    /// Markstein (1997).
+    /// Returns the square root of the number, correctly rounded to the
+    /// nearest value with `MANTISSA_DIGITS` bits of precision, so that the
+    /// error is at most half a unit in the last place (see `ulp`).
+    ///
+    /// An initial approximation is found using the SQRTDWtoDW algorithm of
+    /// Lefèvre et al. (2023), then refined by a Newton step using the exact
+    /// residual. Cases too close to a rounding boundary are decided exactly.
     ///
     /// # Examples
     ///
@@ -26,17 +274,68 @@ impl TwoFloat {
     /// let a = TwoFloat::from(2.0);
     /// let b = a.sqrt();
     ///
-    /// assert!(b * b - a < 1e-16);
+    /// assert!((b * b - a).abs() <= a.ulp());
+    /// assert_eq!(TwoFloat::from(0.25).sqrt(), 0.5);
     pub fn sqrt(self) -> Self {
-        if self.hi < 0.0 || (self.hi == 0.0 && self.lo < 0.0) {
-            Self::NAN
-        } else if self.hi == 0.0 && self.lo == 0.0 {
-            Self { hi: 0.0, lo: 0.0 }
-        } else {
-            let x = self.hi.sqrt().recip();
-            let y = self.hi * x;
-            Self::new_add(y, (self - Self::new_mul(y, y)).hi * (x * 0.5))
-        }
+        status::function("sqrt", &[self], || {
+            if self.is_nan() || self.hi < 0.0 || (self.hi == 0.0 && self.lo < 0.0) {
+                Self::NAN
+            } else if self.hi == 0.0 || self.hi.is_infinite() {
+                Self {
+                    hi: self.hi,
+                    lo: 0.0,
+                }
+            } else {
+                let (x, s) = scale_root(self);
+                let y = approx_sqrt(x);
+                let d = sqrt_residual(x, y.hi, y.lo, 0.0) / (2.0 * y.hi);
+                let root = round_root(y, d, -105, |a, b, c| sqrt_residual(x, a, b, c) > 0.0);
+                Self {
+                    hi: mul_pow2(root.hi, s / 2),
+                    lo: mul_pow2(root.lo, s / 2),
+                }
+            }
+        })
+    }
+
+    /// Returns the reciprocal of the square root of the number, correctly
+    /// rounded to the nearest value with `MANTISSA_DIGITS` bits of
+    /// precision, so that the error is at most half a unit in the last place
+    /// (see `ulp`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.0);
+    /// let b = a.rsqrt();
+    ///
+    /// assert!((b * b * a - 1.0).abs() < 1e-30);
+    /// assert_eq!(TwoFloat::from(0.25).rsqrt(), 2.0);
+    /// assert_eq!(TwoFloat::from(0.0).rsqrt(), TwoFloat::INFINITY);
+    pub fn rsqrt(self) -> Self {
+        status::function("rsqrt", &[self], || {
+            if self.is_nan() || self.hi < 0.0 || (self.hi == 0.0 && self.lo < 0.0) {
+                Self::NAN
+            } else if self.hi == 0.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "rsqrt");
+                Self {
+                    hi: f64::INFINITY.copysign(self.hi),
+                    lo: 0.0,
+                }
+            } else if self.hi.is_infinite() {
+                Self::from(0.0)
+            } else {
+                let (x, s) = scale_root(self);
+                let y = 1.0 / approx_sqrt(x);
+                let d = rsqrt_residual(x, y.hi, y.lo, 0.0) * y.hi * 0.5;
+                let root = round_root(y, d, -106, |a, b, c| rsqrt_residual(x, a, b, c) > 0.0);
+                Self {
+                    hi: mul_pow2(root.hi, -s / 2),
+                    lo: mul_pow2(root.lo, -s / 2),
+                }
+            }
+        })
     }
 
     /// Returns the cube root of the number, using Newton-Raphson iteration.
@@ -50,15 +349,22 @@ impl TwoFloat {
     ///
     /// assert!(b.powi(3) - a < 1e-16);
     pub fn cbrt(self) -> Self {
-        let mut x = Self::from(self.hi.cbrt());
-        let mut x2 = x * x;
-        x -= (x2 * x - self) / (3.0 * x2);
-        x2 = x * x;
-        x - (x2 * x - self) / (3.0 * x2)
+        status::function("cbrt", &[self], || {
+            if self.hi == 0.0 || !self.is_finite() {
+                return self;
+            }
+            let mut x = Self::from(self.hi.cbrt());
+            let mut x2 = x * x;
+            x -= (x2 * x - self) / (3.0 * x2);
+            x2 = x * x;
+            x - (x2 * x - self) / (3.0 * x2)
+        })
     }
 
     /// Calculates the length of the hypotenuse of a right-angle triangle
-    /// given legs of length `self` and `other`.
+    /// given legs of length `self` and `other`. The legs are scaled by a
+    /// power of two before squaring, so that the result only overflows or
+    /// underflows if it is not representable.
     ///
     /// # Examples
     ///
@@ -70,10 +376,37 @@ impl TwoFloat {
     ///
     /// assert!((c - 5.0).abs() < 1e-10);
     pub fn hypot(self, other: Self) -> Self {
-        (self * self + other * other).sqrt()
+        status::function("hypot", &[self, other], || {
+            if self.is_infinite() || other.is_infinite() {
+                return Self::INFINITY;
+            } else if self.is_nan() || other.is_nan() {
+                return Self::NAN;
+            }
+            let (a, b) = if self.abs() >= other.abs() {
+                (self.abs(), other.abs())
+            } else {
+                (other.abs(), self.abs())
+            };
+            if b.hi == 0.0 {
+                return a;
+            }
+            let s = binade(a);
+            let (a, b) = (scale(a, -s), scale(b, -s));
+            let result = scale((a * a + b * b).sqrt(), s);
+            if result.hi.is_infinite() {
+                Self::INFINITY
+            } else {
+                result
+            }
+        })
     }
 
-    /// Raises the number to an integer power. Returns a NAN value for 0^0.
+    /// Raises the number to an integer power.
+    ///
+    /// Special values follow the IEEE 754 `pown` function, so any value
+    /// raised to the power 0, including 0 and `NAN`, is 1. The result is
+    /// exact when it is representable, and otherwise has a relative error
+    /// below 2^-100.
     ///
     /// # Examples
     ///
@@ -82,43 +415,40 @@ impl TwoFloat {
     /// let a = TwoFloat::from(2.0).powi(3);
     /// let b = TwoFloat::from(0.0).powi(0);
     ///
-    /// assert!(a - TwoFloat::from(8.0) <= 1e-16);
-    /// assert!(!b.is_valid());
+    /// assert_eq!(a, 8.0);
+    /// assert_eq!(b, 1.0);
+    /// assert_eq!(TwoFloat::from(10.0).powi(-3), TwoFloat::from(1000.0).recip());
     pub fn powi(self, n: i32) -> Self {
-        match n {
-            0 => {
-                if self.hi == 0.0 && self.lo == 0.0 {
-                    Self::NAN
-                } else {
-                    Self::from(1.0)
-                }
-            }
-            1 => self,
-            -1 => self.recip(),
-            _ => {
-                let mut result = Self::from(1.0);
-                let mut n_pos = n.abs();
-                let mut value = self;
-                while n_pos > 0 {
-                    if (n_pos & 1) != 0 {
-                        result *= &value;
-                    }
-                    value *= value;
-                    n_pos >>= 1;
-                }
-                if n > 0 {
-                    result
-                } else {
-                    result.recip()
-                }
-            }
-        }
+        status::function("powi", &[self], || powi_signed(self, i64::from(n), "powi"))
+    }
+
+    /// Raises the number to an integer power with an `i64` exponent.
+    ///
+    /// Special values follow the IEEE 754 `pown` function, as for `powi`. The
+    /// result is exact when it is representable, and otherwise has a relative
+    /// error below 2^-100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(-2.0).powi64(63);
+    /// let b = TwoFloat::new_add(1.0, 1e-20).powi64(10_000_000_000);
+    /// let c = TwoFloat::new_add(1.0000000001, -8.269037105394043e-18);
+    ///
+    /// assert_eq!(a, -9223372036854775808.0);
+    /// assert!(((b - c) / c).abs() < 1e-30);
+    pub fn powi64(self, n: i64) -> Self {
+        status::function("powi64", &[self], || powi_signed(self, n, "powi64"))
     }
 
     /// Returns the value raised to the power `y`.
     ///
-    /// This method is quite inaccurate, where possible `powi`, `sqrt` or
-    /// `cbrt` should be preferred.
+    /// The logarithm of the value is carried in triple-word arithmetic, so
+    /// that the result has a relative error below 2^-100 for all exponents.
+    /// The result is exact when it is representable and `y` is an integer or
+    /// ±0.5, and special values follow the IEEE 754 `pow` function, e.g.
+    /// `x^0` and `1^y` are 1 for any `x` and `y`.
     ///
     /// # Examples
     ///
@@ -128,32 +458,52 @@ impl TwoFloat {
     /// let b = TwoFloat::from(3.0);
     /// let c = a.powf(b);
     ///
-    /// assert!((c + 125.0).abs() < 1e-9, "{}", c);
+    /// assert_eq!(c, -125.0);
+    ///
+    /// let d = TwoFloat::from(2.0).powf(TwoFloat::from(1.5));
+    /// let e = TwoFloat::new_add(2.8284271247461903, -1.9334586626905827e-16);
+    ///
+    /// assert!(((d - e) / e).abs() < 1e-30);
+    /// assert_eq!(TwoFloat::NAN.powf(TwoFloat::from(0.0)), 1.0);
     pub fn powf(self, y: Self) -> Self {
-        match (self == 0.0, y == 0.0) {
-            (true, true) => Self::NAN,
-            (true, false) => Self::from(0.0),
-            (false, true) => Self::from(1.0),
-            (false, false) => {
-                if self.is_sign_positive() {
-                    (y * self.ln()).exp()
-                } else if self.hi.fract() != 0.0 || self.lo.fract() != 0.0 {
-                    Self::NAN
+        status::function("powf", &[self, y], || {
+            if y == 0.0 || self == 1.0 {
+                return Self::from(1.0);
+            } else if self.is_nan() || y.is_nan() {
+                return Self::NAN;
+            } else if y.is_infinite() {
+                let x = self.abs();
+                return if x == 1.0 {
+                    Self::from(1.0)
+                } else if (x < 1.0) == (y > 0.0) {
+                    Self::from(0.0)
+                } else {
+                    Self::INFINITY
+                };
+            }
+
+            let odd = odd_integer(y);
+            if self.hi == 0.0 || self.is_infinite() {
+                pow_zero_inf(self, y > 0.0, odd == Some(true), "powf")
+            } else if self.hi < 0.0 && odd.is_none() {
+                Self::NAN
+            } else {
+                let x = self.abs();
+                let magnitude = if odd.is_some() && y.hi.abs() <= POWI_LIMIT {
+                    powi_abs(x, y.hi as i64, "powf")
+                } else if y == 0.5 {
+                    x.sqrt()
+                } else if y == -0.5 {
+                    x.rsqrt()
+                } else {
+                    exp_ln(x, y)
+                };
+                if odd == Some(true) && self.hi < 0.0 {
+                    -magnitude
                 } else {
-                    let abs_result = (y * self.abs().ln()).exp();
-                    let low_trunc = if self.lo.trunc() == 0.0 {
-                        self.hi.trunc()
-                    } else {
-                        self.lo.trunc()
-                    };
-
-                    if low_trunc % 2.0 == 0.0 {
-                        abs_result
-                    } else {
-                        -abs_result
-                    }
+                    magnitude
                 }
             }
-        }
+        })
     }
 }
@@ -83,7 +83,7 @@ impl TwoFloat {
     ///
     /// * `1.0` if the number is positive or `+0.0`
     /// * `-1.0` if the number is negative or `-0.0`
-    /// * Invalid value otherwise
+    /// * `NAN` if the number is `NAN`
     ///
     /// # Examples
     /// # use twofloat::TwoFloat;
@@ -93,14 +93,12 @@ impl TwoFloat {
     /// assert_eq!(a.signum(), 1.0);
     /// assert_eq!(b.signum(), -1.0);
     pub fn signum(self) -> Self {
-        if self.is_valid() {
-            if self.is_sign_positive() {
-                Self::from(1.0)
-            } else {
-                Self::from(-1.0)
-            }
-        } else {
+        if self.is_nan() {
             Self::NAN
+        } else if self.is_sign_positive() {
+            Self::from(1.0)
+        } else {
+            Self::from(-1.0)
         }
     }
 }
@@ -0,0 +1,1040 @@
+use crate::{
+    arithmetic::two_prod,
+    base::{binade, scale},
+    consts::{FRAC_2_SQRT_PI, LN_2, PI},
+    status::{self, FpFlags},
+    TwoFloat,
+};
+
+// ln(2) split into three parts, where the first has 42 significant bits so
+// that its product with any exponent of the result is exact
+const LN_2_PARTS: [f64; 3] = [
+    0.6931471805598903,
+    5.497923018708371e-14,
+    1.94704509238075e-31,
+];
+
+// limits
+const ERFC_UPPER_LIMIT: f64 = 27.4;
+const ERFCX_LOWER_LIMIT: f64 = -26.7;
+const ERFCX_ASYMPTOTIC_LIMIT: f64 = 1e8;
+const GAMMA_UPPER_LIMIT: f64 = 171.7;
+const GAMMA_LOWER_LIMIT: f64 = -400.0;
+const STIRLING_LIMIT: f64 = 16.0;
+
+// Coefficients for polynomial approximation of erf(x)/x in terms of x^2 on
+// [-1, 1]
+const ERF_COEFFS: [TwoFloat; 21] = [
+    TwoFloat {
+        hi: 1.1283791670955126,
+        lo: 1.533545961316588e-17,
+    },
+    TwoFloat {
+        hi: -0.37612638903183754,
+        lo: 1.339189720603078e-17,
+    },
+    TwoFloat {
+        hi: 0.11283791670955126,
+        lo: -4.017569161828403e-18,
+    },
+    TwoFloat {
+        hi: -0.026866170645131252,
+        lo: 4.609288084142524e-19,
+    },
+    TwoFloat {
+        hi: 0.005223977625442188,
+        lo: -8.962508043758417e-20,
+    },
+    TwoFloat {
+        hi: -0.0008548327023450853,
+        lo: 5.014955045909527e-20,
+    },
+    TwoFloat {
+        hi: 0.00012055332981789664,
+        lo: 6.471996959784864e-21,
+    },
+    TwoFloat {
+        hi: -1.492565035840625e-05,
+        lo: -5.513021093901805e-22,
+    },
+    TwoFloat {
+        hi: 1.6462114365889242e-06,
+        lo: 4.846776537610472e-23,
+    },
+    TwoFloat {
+        hi: -1.6365844691234686e-07,
+        lo: -3.446006411623245e-24,
+    },
+    TwoFloat {
+        hi: 1.4807192815870185e-08,
+        lo: -8.045503993588663e-25,
+    },
+    TwoFloat {
+        hi: -1.229055530145034e-09,
+        lo: 2.5675087807124226e-26,
+    },
+    TwoFloat {
+        hi: 9.422759058419774e-11,
+        lo: 6.074743510551858e-27,
+    },
+    TwoFloat {
+        hi: -6.7113667406950235e-12,
+        lo: 4.089190453041908e-29,
+    },
+    TwoFloat {
+        hi: 4.4632226049435803e-13,
+        lo: 2.3959982254938873e-29,
+    },
+    TwoFloat {
+        hi: -2.7834973641135708e-14,
+        lo: -1.194234654664411e-30,
+    },
+    TwoFloat {
+        hi: 1.6340953506569751e-15,
+        lo: -2.24575165829093e-32,
+    },
+    TwoFloat {
+        hi: -9.052834408563129e-17,
+        lo: 1.5388834200936239e-33,
+    },
+    TwoFloat {
+        hi: 4.7082344761930055e-18,
+        lo: 1.1476601429075897e-35,
+    },
+    TwoFloat {
+        hi: -2.1870727134115755e-19,
+        lo: -2.0412591582063057e-35,
+    },
+    TwoFloat {
+        hi: 7.042541504460301e-21,
+        lo: 6.707302330492837e-37,
+    },
+];
+
+// Coefficients for polynomial approximation of erfcx(x) in terms of x - 3/2
+// on [1, 2]
+const ERFCX_COEFFS_1: [TwoFloat; 29] = [
+    TwoFloat {
+        hi: 0.3215854164543175,
+        lo: 1.7007985607722196e-17,
+    },
+    TwoFloat {
+        hi: -0.16362291773256007,
+        lo: 7.93292159437183e-18,
+    },
+    TwoFloat {
+        hi: 0.0761510398554774,
+        lo: 1.1517923836509653e-18,
+    },
+    TwoFloat {
+        hi: -0.03293090529956264,
+        lo: -4.984871240288824e-19,
+    },
+    TwoFloat {
+        hi: 0.013377340953066719,
+        lo: 2.0203084882351223e-19,
+    },
+    TwoFloat {
+        hi: -0.005145957547985025,
+        lo: -4.2512103209840873e-19,
+    },
+    TwoFloat {
+        hi: 0.001886134877029727,
+        lo: -7.293675736698496e-20,
+    },
+    TwoFloat {
+        hi: -0.0006619300664115528,
+        lo: -1.3324605649752738e-20,
+    },
+    TwoFloat {
+        hi: 0.00022330994435309945,
+        lo: -9.678233966781797e-21,
+    },
+    TwoFloat {
+        hi: -7.265892219597859e-05,
+        lo: -3.1637409476547675e-21,
+    },
+    TwoFloat {
+        hi: 2.2864312211826308e-05,
+        lo: 1.1750274499443243e-21,
+    },
+    TwoFloat {
+        hi: -6.974991614225297e-06,
+        lo: -2.699062682143912e-22,
+    },
+    TwoFloat {
+        hi: 2.0669707984147273e-06,
+        lo: 1.3586594655077559e-22,
+    },
+    TwoFloat {
+        hi: -5.960823717851027e-07,
+        lo: 2.0811349633897258e-23,
+    },
+    TwoFloat {
+        hi: 1.6754960581957938e-07,
+        lo: 9.424107015138277e-24,
+    },
+    TwoFloat {
+        hi: -4.596772840750756e-08,
+        lo: -2.7942381561929273e-24,
+    },
+    TwoFloat {
+        hi: 1.2324751651070142e-08,
+        lo: -5.9022450932039055e-25,
+    },
+    TwoFloat {
+        hi: -3.2330118735312717e-09,
+        lo: 3.9492912253405904e-26,
+    },
+    TwoFloat {
+        hi: 8.305815376027447e-10,
+        lo: -1.1031033107327487e-26,
+    },
+    TwoFloat {
+        hi: -2.0917259048584895e-10,
+        lo: 8.876620537640593e-27,
+    },
+    TwoFloat {
+        hi: 5.168226679818063e-11,
+        lo: -3.0047209490512615e-27,
+    },
+    TwoFloat {
+        hi: -1.253799767145074e-11,
+        lo: -5.520072902572154e-28,
+    },
+    TwoFloat {
+        hi: 2.9886538483621206e-12,
+        lo: -1.836648649219096e-28,
+    },
+    TwoFloat {
+        hi: -7.005009367741277e-13,
+        lo: -1.906905242764674e-29,
+    },
+    TwoFloat {
+        hi: 1.6151355234796046e-13,
+        lo: 4.0401274683478844e-30,
+    },
+    TwoFloat {
+        hi: -3.652387252870752e-14,
+        lo: -1.977507531888496e-30,
+    },
+    TwoFloat {
+        hi: 8.166008383860471e-15,
+        lo: -1.1783739987659073e-31,
+    },
+    TwoFloat {
+        hi: -1.9638063719165507e-15,
+        lo: -7.021732853103502e-33,
+    },
+    TwoFloat {
+        hi: 4.254579133929613e-16,
+        lo: 1.6227797028755253e-32,
+    },
+];
+
+// Coefficients for polynomial approximation of erfcx(x) in terms of x - 5/2
+// on [2, 3]
+const ERFCX_COEFFS_2: [TwoFloat; 28] = [
+    TwoFloat {
+        hi: 0.2108063640611436,
+        lo: -5.6277259093102524e-18,
+    },
+    TwoFloat {
+        hi: -0.07434734678979467,
+        lo: -1.840725736273771e-18,
+    },
+    TwoFloat {
+        hi: 0.024937997086656904,
+        lo: 1.788006058662164e-19,
+    },
+    TwoFloat {
+        hi: -0.008001569382101607,
+        lo: 2.273328362456194e-19,
+    },
+    TwoFloat {
+        hi: 0.002467036815701443,
+        lo: -6.011452076791223e-20,
+    },
+    TwoFloat {
+        hi: -0.0007335909371391998,
+        lo: 5.2502657196484784e-20,
+    },
+    TwoFloat {
+        hi: 0.00021101982428448125,
+        lo: -3.3910121528197694e-21,
+    },
+    TwoFloat {
+        hi: -5.886896469371332e-05,
+        lo: 9.62154534361736e-22,
+    },
+    TwoFloat {
+        hi: 1.596185313754949e-05,
+        lo: -2.4648226074781967e-22,
+    },
+    TwoFloat {
+        hi: -4.214295966631021e-06,
+        lo: -1.718092045224963e-23,
+    },
+    TwoFloat {
+        hi: 1.085222644194387e-06,
+        lo: 2.92431345965166e-23,
+    },
+    TwoFloat {
+        hi: -2.7295261020819155e-07,
+        lo: -2.5078172485877362e-23,
+    },
+    TwoFloat {
+        hi: 6.71401864456513e-08,
+        lo: 5.869973885416594e-24,
+    },
+    TwoFloat {
+        hi: -1.6169560629855864e-08,
+        lo: -5.584785281649068e-25,
+    },
+    TwoFloat {
+        hi: 3.816612124430943e-09,
+        lo: -3.4530443642689815e-26,
+    },
+    TwoFloat {
+        hi: -8.83737375837503e-10,
+        lo: 1.5383131160328812e-26,
+    },
+    TwoFloat {
+        hi: 2.00908585597708e-10,
+        lo: -2.109286910052722e-28,
+    },
+    TwoFloat {
+        hi: -4.4878342566449616e-11,
+        lo: -1.378316851502612e-27,
+    },
+    TwoFloat {
+        hi: 9.856969956978266e-12,
+        lo: -3.2084393819923056e-28,
+    },
+    TwoFloat {
+        hi: -2.1300966188573237e-12,
+        lo: -9.35247057281822e-29,
+    },
+    TwoFloat {
+        hi: 4.531726089272859e-13,
+        lo: -2.4116011910840213e-30,
+    },
+    TwoFloat {
+        hi: -9.496800565758917e-14,
+        lo: 1.9486068973998973e-30,
+    },
+    TwoFloat {
+        hi: 1.961464393093651e-14,
+        lo: -1.2338077945741985e-30,
+    },
+    TwoFloat {
+        hi: -3.994346930039578e-15,
+        lo: 2.9658812364692236e-31,
+    },
+    TwoFloat {
+        hi: 8.007201570429756e-16,
+        lo: -4.691377386655989e-32,
+    },
+    TwoFloat {
+        hi: -1.5875335305862382e-16,
+        lo: 1.0394977808485948e-32,
+    },
+    TwoFloat {
+        hi: 3.32124641262374e-17,
+        lo: 6.1728124705677705e-34,
+    },
+    TwoFloat {
+        hi: -6.409910278680403e-18,
+        lo: 2.431155188561508e-34,
+    },
+];
+
+// Coefficients for polynomial approximation of (1/gamma(1+x) - 1)/x on
+// [-1/2, 1/2]
+const RECIP_GAMMA_COEFFS: [TwoFloat; 26] = [
+    TwoFloat {
+        hi: 0.5772156649015329,
+        lo: -4.942915152430644e-18,
+    },
+    TwoFloat {
+        hi: -0.6558780715202539,
+        lo: 2.137185197068536e-17,
+    },
+    TwoFloat {
+        hi: -0.04200263503409524,
+        lo: 1.492030628564186e-18,
+    },
+    TwoFloat {
+        hi: 0.16653861138229148,
+        lo: 1.0189144546842163e-17,
+    },
+    TwoFloat {
+        hi: -0.04219773455554433,
+        lo: -3.3579992680543646e-18,
+    },
+    TwoFloat {
+        hi: -0.009621971527876973,
+        lo: -5.300031369139079e-19,
+    },
+    TwoFloat {
+        hi: 0.0072189432466631,
+        lo: -3.600653876736859e-19,
+    },
+    TwoFloat {
+        hi: -0.0011651675918590652,
+        lo: 5.659948125640917e-20,
+    },
+    TwoFloat {
+        hi: -0.00021524167411495098,
+        lo: 2.3766474821487702e-21,
+    },
+    TwoFloat {
+        hi: 0.0001280502823881162,
+        lo: -9.359248733322996e-21,
+    },
+    TwoFloat {
+        hi: -2.013485478078824e-05,
+        lo: 9.307355740360469e-24,
+    },
+    TwoFloat {
+        hi: -1.2504934821426706e-06,
+        lo: -2.324220223866598e-23,
+    },
+    TwoFloat {
+        hi: 1.1330272319816964e-06,
+        lo: -1.0011548981630233e-22,
+    },
+    TwoFloat {
+        hi: -2.0563384169776076e-07,
+        lo: -9.050053177071055e-24,
+    },
+    TwoFloat {
+        hi: 6.116095104477096e-09,
+        lo: -2.2776018358863418e-25,
+    },
+    TwoFloat {
+        hi: 5.002007644469913e-09,
+        lo: -2.1646159107103103e-25,
+    },
+    TwoFloat {
+        hi: -1.1812745704524735e-09,
+        lo: -6.627207250936153e-26,
+    },
+    TwoFloat {
+        hi: 1.0434267116359254e-10,
+        lo: 5.9019998706544206e-27,
+    },
+    TwoFloat {
+        hi: 7.782263250364422e-12,
+        lo: -1.6537701731442681e-28,
+    },
+    TwoFloat {
+        hi: -3.6968055883487186e-12,
+        lo: -2.746323686790834e-28,
+    },
+    TwoFloat {
+        hi: 5.100377303199282e-13,
+        lo: 4.3907323120284845e-29,
+    },
+    TwoFloat {
+        hi: -2.0583372788294037e-14,
+        lo: -4.21749882009565e-31,
+    },
+    TwoFloat {
+        hi: -5.349796096617638e-15,
+        lo: 3.23866416971513e-31,
+    },
+    TwoFloat {
+        hi: 1.2270468971706538e-15,
+        lo: -2.4008749816793196e-32,
+    },
+    TwoFloat {
+        hi: -1.1580576733890092e-16,
+        lo: 3.2508857096383146e-33,
+    },
+    TwoFloat {
+        hi: 8.133699330555492e-19,
+        lo: -1.2291710586239622e-35,
+    },
+];
+
+// Coefficients B_2k/(2k(2k-1)) of the Stirling series for ln(gamma(x)) in
+// terms of 1/x^2
+const STIRLING_COEFFS: [TwoFloat; 17] = [
+    TwoFloat {
+        hi: 0.08333333333333333,
+        lo: 4.625929269271485e-18,
+    },
+    TwoFloat {
+        hi: -0.002777777777777778,
+        lo: 1.0601087908747154e-19,
+    },
+    TwoFloat {
+        hi: 0.0007936507936507937,
+        lo: 6.883823317368282e-22,
+    },
+    TwoFloat {
+        hi: -0.0005952380952380953,
+        lo: 5.36938218754726e-20,
+    },
+    TwoFloat {
+        hi: 0.0008417508417508417,
+        lo: 3.6870174889237694e-20,
+    },
+    TwoFloat {
+        hi: -0.0019175269175269176,
+        lo: 1.0675702776872475e-19,
+    },
+    TwoFloat {
+        hi: 0.00641025641025641,
+        lo: 2.2240044563805217e-19,
+    },
+    TwoFloat {
+        hi: -0.029550653594771242,
+        lo: 4.861760957508855e-19,
+    },
+    TwoFloat {
+        hi: 0.17964437236883057,
+        lo: -6.401600482710946e-19,
+    },
+    TwoFloat {
+        hi: -1.3924322169059011,
+        lo: 1.5837056989230303e-17,
+    },
+    TwoFloat {
+        hi: 13.402864044168393,
+        lo: -6.154114101993966e-16,
+    },
+    TwoFloat {
+        hi: -156.84828462600203,
+        lo: 9.391823141715389e-15,
+    },
+    TwoFloat {
+        hi: 2193.1033333333335,
+        lo: -1.3339255626002948e-13,
+    },
+    TwoFloat {
+        hi: -36108.77125372499,
+        lo: 5.897583353514365e-13,
+    },
+    TwoFloat {
+        hi: 691472.268851313,
+        lo: 2.5585296305158e-11,
+    },
+    TwoFloat {
+        hi: -15238221.539407415,
+        lo: -8.76774522490625e-10,
+    },
+    TwoFloat {
+        hi: 382900751.39141417,
+        lo: -2.4082684757733585e-08,
+    },
+];
+
+// Exact values of n! for n up to 36
+const FACTORIALS: [TwoFloat; 37] = [
+    TwoFloat { hi: 1.0, lo: 0.0 },
+    TwoFloat { hi: 1.0, lo: 0.0 },
+    TwoFloat { hi: 2.0, lo: 0.0 },
+    TwoFloat { hi: 6.0, lo: 0.0 },
+    TwoFloat { hi: 24.0, lo: 0.0 },
+    TwoFloat { hi: 120.0, lo: 0.0 },
+    TwoFloat { hi: 720.0, lo: 0.0 },
+    TwoFloat {
+        hi: 5040.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 40320.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 362880.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 3628800.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 39916800.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 479001600.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 6227020800.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 87178291200.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 1307674368000.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 20922789888000.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 355687428096000.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 6402373705728000.0,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 1.21645100408832e17,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 2.43290200817664e18,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 5.109094217170944e19,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 1.1240007277776077e21,
+        lo: 0.0,
+    },
+    TwoFloat {
+        hi: 2.585201673888498e22,
+        lo: -1572864.0,
+    },
+    TwoFloat {
+        hi: 6.204484017332394e23,
+        lo: 29360128.0,
+    },
+    TwoFloat {
+        hi: 1.5511210043330986e25,
+        lo: -71303168.0,
+    },
+    TwoFloat {
+        hi: 4.0329146112660565e26,
+        lo: -14738784256.0,
+    },
+    TwoFloat {
+        hi: 1.0888869450418352e28,
+        lo: 220528115712.0,
+    },
+    TwoFloat {
+        hi: 3.0488834461171387e29,
+        lo: -11417398804480.0,
+    },
+    TwoFloat {
+        hi: 8.841761993739702e30,
+        lo: 55923527647232.0,
+    },
+    TwoFloat {
+        hi: 2.6525285981219107e32,
+        lo: -9581293239009280.0,
+    },
+    TwoFloat {
+        hi: 8.222838654177922e33,
+        lo: 3.875270529510277e17,
+    },
+    TwoFloat {
+        hi: 2.631308369336935e35,
+        lo: 1.2400865694432887e19,
+    },
+    TwoFloat {
+        hi: 8.683317618811886e36,
+        lo: 5.568025205059617e20,
+    },
+    TwoFloat {
+        hi: 2.9523279903960416e38,
+        lo: -1.6486462924319642e22,
+    },
+    TwoFloat {
+        hi: 1.0333147966386145e40,
+        lo: -5.0146833862527315e23,
+    },
+    TwoFloat {
+        hi: 3.7199332678990125e41,
+        lo: -3.73956733043439e25,
+    },
+];
+
+// ln(2π)/2
+const LN_SQRT_2_PI: TwoFloat = TwoFloat {
+    hi: 0.9189385332046728,
+    lo: -3.8782941580672414e-17,
+};
+
+// Returns exp(sign * x^2) as a value in [1/sqrt(2), sqrt(2)] and the power of
+// two by which it must be scaled. The square is split into exact parts so that
+// the reduced argument keeps its full precision when x^2 is large, which is
+// where the result of `exp` would lose accuracy. Requires |x| < 27.5.
+fn exp_square(x: TwoFloat, sign: f64) -> (TwoFloat, i32) {
+    let (p0, p1) = two_prod(x.hi, x.hi).into();
+    let (q0, q1) = two_prod(2.0 * x.hi, x.lo).into();
+    let k = (sign * (p0 + q0) / LN_2_PARTS[0]).round();
+
+    let tail = TwoFloat::new_mul(-k, LN_2_PARTS[1])
+        + sign * p1
+        + sign * q0
+        + (sign * (q1 + x.lo * x.lo) - k * LN_2_PARTS[2]);
+    let r = TwoFloat::new_sub(sign * p0, k * LN_2_PARTS[0]) + tail;
+
+    // Taylor series for exp(s) - 1 with s = r/256, followed by repeated
+    // squaring of exp(s)
+    let s = r / 256.0;
+    let mut m = TwoFloat::ONE;
+    for n in (2..=11).rev() {
+        m = m * s / f64::from(n) + 1.0;
+    }
+    m *= s;
+    for _ in 0..8 {
+        m *= m + 2.0;
+    }
+
+    (m + 1.0, k as i32)
+}
+
+// erf(x) for |x| < 1
+fn erf_small(x: TwoFloat) -> TwoFloat {
+    let x2 = x * x;
+    x * polynomial!(x2, ERF_COEFFS)
+}
+
+// erfcx(x) for x >= 1
+fn erfcx_large(x: TwoFloat) -> TwoFloat {
+    if x.hi < 2.0 {
+        let t = x - 1.5;
+        polynomial!(t, ERFCX_COEFFS_1)
+    } else if x.hi < 3.0 {
+        let t = x - 2.5;
+        polynomial!(t, ERFCX_COEFFS_2)
+    } else if x.hi < ERFCX_ASYMPTOTIC_LIMIT {
+        // Even part of the continued fraction of Laplace, evaluated from the
+        // back with a depth sufficient for full precision
+        let y = 2.0 * x * x;
+        let depth = (160.0 / x.hi) as i32 + 4;
+        let mut t = TwoFloat::ZERO;
+        for k in (1..=depth).rev() {
+            let k = f64::from(k);
+            t = (2.0 * k - 1.0) * (2.0 * k) / (y + (4.0 * k + 1.0) - t);
+        }
+        FRAC_2_SQRT_PI * x / (y + 1.0 - t)
+    } else if x.is_finite() {
+        // Asymptotic series, where the following terms are negligible
+        let r = 1.0 / x;
+        let r2 = r * r;
+        FRAC_2_SQRT_PI / 2.0 * r * (1.0 - r2 / 2.0 + 0.75 * r2 * r2)
+    } else {
+        TwoFloat::ZERO
+    }
+}
+
+// erfc(x) for x >= 1
+fn erfc_large(x: TwoFloat) -> TwoFloat {
+    if x.hi >= ERFC_UPPER_LIMIT {
+        if x.is_finite() {
+            status::raise(FpFlags::UNDERFLOW, "erfc");
+        }
+        TwoFloat::ZERO
+    } else {
+        let (m, k) = exp_square(x, -1.0);
+        scale(m * erfcx_large(x), k)
+    }
+}
+
+// Returns 1/gamma(1+x) for |x| <= 1/2
+fn recip_gamma(x: TwoFloat) -> TwoFloat {
+    1.0 + x * polynomial!(x, RECIP_GAMMA_COEFFS)
+}
+
+// Returns gamma(x) for 1/2 <= x <= 401 as a value and a power of two by which
+// it must be scaled, using the recurrence gamma(x+1) = x*gamma(x) from the
+// interval where 1/gamma is approximated.
+fn gamma_scaled(x: TwoFloat) -> (TwoFloat, i32) {
+    let n = x.round();
+    let z = x - n;
+    let mut product = TwoFloat::ONE;
+    let mut exponent = 0;
+    let mut k = 1.0;
+    while k < n.hi {
+        product *= z + k;
+        if product.hi > 1e150 {
+            let k = binade(product);
+            product = scale(product, -k);
+            exponent += k;
+        }
+        k += 1.0;
+    }
+    (product / recip_gamma(z), exponent)
+}
+
+// ln(gamma(x)) for x > 0
+fn ln_gamma_positive(x: TwoFloat) -> TwoFloat {
+    if x == 1.0 || x == 2.0 {
+        // The series below give -0 at the zeros of ln(gamma(x))
+        TwoFloat::from(0.0)
+    } else if x.hi < 0.5 {
+        -(x.ln() + (x * polynomial!(x, RECIP_GAMMA_COEFFS)).ln_1p())
+    } else if x.hi < 1.5 {
+        let z = x - 1.0;
+        -(z * polynomial!(z, RECIP_GAMMA_COEFFS)).ln_1p()
+    } else if x.hi < 2.5 {
+        // gamma(2+z) = (1+z)*gamma(1+z)
+        let z = x - 2.0;
+        z.ln_1p() - (z * polynomial!(z, RECIP_GAMMA_COEFFS)).ln_1p()
+    } else if x.hi < STIRLING_LIMIT {
+        let (value, exponent) = gamma_scaled(x);
+        value.ln() + f64::from(exponent) * LN_2
+    } else {
+        // Stirling series, arranged to avoid overflow of x*ln(x)
+        let r = x.recip();
+        let r2 = r * r;
+        (x - 0.5) * (x.ln() - 1.0) + (LN_SQRT_2_PI - 0.5) + r * polynomial!(r2, STIRLING_COEFFS)
+    }
+}
+
+// Returns true if x is a pole of the gamma function
+fn is_pole(x: TwoFloat) -> bool {
+    x.hi <= 0.0 && x.fract() == 0.0
+}
+
+impl TwoFloat {
+    /// Computes the error function, `erf(x) = 2/sqrt(π) ∫₀ˣ exp(-t²) dt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.5).erf();
+    /// let b = TwoFloat::new_add(0.5204998778130465, 1.900077467916287e-17);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    pub fn erf(self) -> Self {
+        status::function("erf", &[self], || {
+            if self.is_nan() {
+                Self::NAN
+            } else if self.hi.abs() < 1.0 {
+                erf_small(self)
+            } else {
+                (1.0 - erfc_large(self.abs())).copysign(self)
+            }
+        })
+    }
+
+    /// Computes the complementary error function, `erfc(x) = 1 - erf(x)`,
+    /// without the loss of accuracy of the subtraction for large `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(10.0).erfc();
+    /// let b = TwoFloat::new_add(2.088487583762545e-45, -1.2006565763501381e-61);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    pub fn erfc(self) -> Self {
+        status::function("erfc", &[self], || {
+            if self.is_nan() {
+                Self::NAN
+            } else if self.hi.abs() < 1.0 {
+                1.0 - erf_small(self)
+            } else if self.hi > 0.0 {
+                erfc_large(self)
+            } else {
+                2.0 - erfc_large(-self)
+            }
+        })
+    }
+
+    /// Computes the scaled complementary error function,
+    /// `erfcx(x) = exp(x²) erfc(x)`, which avoids the underflow of `erfc` for
+    /// large positive `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(100.0).erfcx();
+    /// let b = TwoFloat::new_add(0.005641613782989433, -1.186182619499244e-19);
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    pub fn erfcx(self) -> Self {
+        status::function("erfcx", &[self], || {
+            if self.is_nan() {
+                Self::NAN
+            } else if self.hi >= 1.0 {
+                erfcx_large(self)
+            } else if self.hi <= ERFCX_LOWER_LIMIT {
+                Self::INFINITY
+            } else {
+                let (m, k) = exp_square(self, 1.0);
+                if self.hi > -1.0 {
+                    scale(m * (1.0 - erf_small(self)), k)
+                } else {
+                    scale(m * 2.0, k) - erfcx_large(-self)
+                }
+            }
+        })
+    }
+
+    /// Computes the gamma function.
+    ///
+    /// Integers up to 37 return exact factorials, and negative arguments use
+    /// the reflection formula. The poles at zero return an infinity with the
+    /// sign of the zero, while negative integers return `NAN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(25.0).gamma();
+    /// assert_eq!(a, TwoFloat::new_add(6.204484017332394e23, 29360128.0));
+    ///
+    /// let b = TwoFloat::from(0.5).gamma();
+    /// let c = twofloat::consts::PI.sqrt();
+    /// assert!(((b - c) / c).abs() < 1e-30);
+    pub fn gamma(self) -> Self {
+        status::function("gamma", &[self], || {
+            if self.is_nan() || self == Self::NEG_INFINITY {
+                Self::NAN
+            } else if self.hi == 0.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "gamma");
+                Self::INFINITY.copysign(self)
+            } else if self.hi >= GAMMA_UPPER_LIMIT {
+                Self::INFINITY
+            } else if self.hi >= 0.5 {
+                if self.hi <= 37.0 && self.fract() == 0.0 {
+                    FACTORIALS[self.hi as usize - 1]
+                } else {
+                    let (value, exponent) = gamma_scaled(self);
+                    scale(value, exponent)
+                }
+            } else if self.hi > 0.0 {
+                // gamma(x) = gamma(1+x)/x
+                (self * recip_gamma(self)).recip()
+            } else if self.fract() == 0.0 {
+                Self::NAN
+            } else if self.hi < GAMMA_LOWER_LIMIT {
+                status::raise(FpFlags::UNDERFLOW, "gamma");
+                Self::ZERO.copysign(self.sin_pi())
+            } else {
+                let (value, exponent) = gamma_scaled(1.0 - self);
+                scale(PI / (self.sin_pi() * value), -exponent)
+            }
+        })
+    }
+
+    /// Computes the natural logarithm of the absolute value of the gamma
+    /// function, together with the sign of the gamma function as `1` or `-1`.
+    ///
+    /// Negative arguments use the reflection formula. The poles at zero and
+    /// the negative integers return positive infinity, with the sign taken
+    /// from a zero argument and positive otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let (a, sign) = TwoFloat::from(-0.5).ln_gamma();
+    /// let b = (twofloat::consts::PI.sqrt() * 2.0).ln();
+    ///
+    /// assert!((a - b).abs() < 1e-10);
+    /// assert_eq!(sign, -1);
+    pub fn ln_gamma(self) -> (Self, i32) {
+        status::function("ln_gamma", &[self], || {
+            if self.is_nan() {
+                (Self::NAN, 1)
+            } else if self.is_infinite() {
+                (Self::INFINITY, 1)
+            } else if self.hi > 0.0 {
+                (ln_gamma_positive(self), 1)
+            } else if is_pole(self) {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "ln_gamma");
+                let sign = if self.is_sign_negative() && self.hi == 0.0 {
+                    -1
+                } else {
+                    1
+                };
+                (Self::INFINITY, sign)
+            } else if self.hi > -1.0 {
+                // gamma(x) = gamma(1+x)/x
+                (ln_gamma_positive(1.0 + self) - (-self).ln(), -1)
+            } else {
+                let s = self.sin_pi();
+                let sign = if s.hi < 0.0 { -1 } else { 1 };
+                ((PI / s.abs()).ln() - ln_gamma_positive(1.0 - self), sign)
+            }
+        })
+    }
+
+    /// Computes the beta function, `B(a, b) = Γ(a)Γ(b)/Γ(a+b)`.
+    ///
+    /// Uses the gamma function where its values are finite, and otherwise
+    /// the exponential of `ln_beta`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(2.5).beta(TwoFloat::from(1.5));
+    /// let b = twofloat::consts::PI / 16.0;
+    ///
+    /// assert!(((a - b) / b).abs() < 1e-30);
+    pub fn beta(self, other: Self) -> Self {
+        status::function("beta", &[self, other], || {
+            let sum = self + other;
+            if self.is_nan() || other.is_nan() {
+                Self::NAN
+            } else if is_pole(sum) && !is_pole(self) && !is_pole(other) {
+                Self::ZERO
+            } else {
+                let (a, b, c) = (self.gamma(), other.gamma(), sum.gamma());
+                if [a, b, c].iter().all(|x| x.is_normal()) {
+                    a / c * b
+                } else {
+                    let (value, sign) = self.ln_beta(other);
+                    value.exp() * f64::from(sign)
+                }
+            }
+        })
+    }
+
+    /// Computes the natural logarithm of the absolute value of the beta
+    /// function, together with the sign of the beta function as `1` or `-1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let (a, sign) = TwoFloat::from(1000.0).ln_beta(TwoFloat::from(0.5));
+    /// let b = TwoFloat::from(1000.0).ln_gamma().0 + twofloat::consts::PI.sqrt().ln()
+    ///     - TwoFloat::from(1000.5).ln_gamma().0;
+    ///
+    /// assert!((a - b).abs() < 1e-10);
+    /// assert_eq!(sign, 1);
+    pub fn ln_beta(self, other: Self) -> (Self, i32) {
+        status::function("ln_beta", &[self, other], || {
+            let sum = self + other;
+            if self.is_nan() || other.is_nan() {
+                (Self::NAN, 1)
+            } else if is_pole(sum) && !is_pole(self) && !is_pole(other) {
+                (Self::NEG_INFINITY, 1)
+            } else {
+                let (a, b, c) = (self.gamma(), other.gamma(), sum.gamma());
+                if [a, b, c].iter().all(|x| x.is_normal()) {
+                    let value = a / c * b;
+                    let sign = if value.hi < 0.0 { -1 } else { 1 };
+                    (value.abs().ln(), sign)
+                } else {
+                    let ((a, sa), (b, sb), (c, sc)) =
+                        (self.ln_gamma(), other.ln_gamma(), sum.ln_gamma());
+                    (a + b - c, sa * sb * sc)
+                }
+            }
+        })
+    }
+}
@@ -1,8 +1,9 @@
-use core::convert::TryFrom;
-
 use crate::{
-    consts::{FRAC_PI_2, FRAC_PI_4, PI},
-    TwoFloat,
+    arithmetic::two_prod,
+    base::{binade, mul_pow2},
+    consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_4, PI},
+    status::{self, FpFlags},
+    Expansion, TwoFloat,
 };
 
 const DEG_PER_RAD: TwoFloat = TwoFloat {
@@ -15,6 +16,34 @@ const RAD_PER_DEG: TwoFloat = TwoFloat {
     lo: 2.9486522708701687e-19,
 };
 
+// Bounds on the absolute error of `sin` and `cos`, and on the relative error
+// of `tan` away from its zeros and poles. Used to widen the bounds of
+// `TwoFloatInterval`.
+pub(crate) const SIN_COS_ERROR_BOUND: f64 = 5.421010862427522e-20; // 2^-64
+pub(crate) const TAN_ERROR_BOUND: f64 = 8.881784197001252e-16; // 2^-50
+
+// Bits of 2/π in 24-bit chunks, where 2/π = Σ TWO_OVER_PI_BITS[i] 2^(-24(i+1)),
+// covering the exponent range of `f64` with 250 bits to spare
+const TWO_OVER_PI_BITS: [f64; 56] = [
+    10680707.0, 7228996.0, 1387004.0, 2578385.0, 16069853.0, 12639074.0, 9804092.0, 4427841.0,
+    16666979.0, 11263675.0, 12935607.0, 2387514.0, 4345298.0, 14681673.0, 3074569.0, 13734428.0,
+    16653803.0, 1880361.0, 10960616.0, 8533493.0, 3062596.0, 8710556.0, 7349940.0, 6258241.0,
+    3772886.0, 3769171.0, 3798172.0, 8675211.0, 12450088.0, 3874808.0, 9961438.0, 366607.0,
+    15675153.0, 9132554.0, 7151469.0, 3571407.0, 2607881.0, 12013382.0, 4155038.0, 6285869.0,
+    7677882.0, 13102053.0, 15825725.0, 473591.0, 9065106.0, 15363067.0, 6271263.0, 9264392.0,
+    5636912.0, 4652155.0, 7056368.0, 13614112.0, 10155062.0, 1944035.0, 9527646.0, 15080200.0,
+];
+
+// π/2 split into three words, for reducing arguments below
+// `CODY_WAITE_LIMIT`
+const FRAC_PI_2_WORDS: [f64; 3] = [
+    1.5707963267948966,
+    6.123233995736766e-17,
+    -1.4973849048591698e-33,
+];
+const CODY_WAITE_LIMIT: f64 = 67108864.0; // 2^26
+const CODY_WAITE_MIN_RESULT: f64 = 9.313225746154785e-10; // 2^-30
+
 // Polynomial coefficients of sin(x)-x on [0,pi/4]
 const SIN_COEFFS: [TwoFloat; 7] = [
     TwoFloat {
@@ -256,17 +285,69 @@ const ATAN_FRAC_3_2: TwoFloat = TwoFloat {
     lo: 1.3903311031230998e-17,
 };
 
-fn quadrant(value: TwoFloat) -> (TwoFloat, i8) {
+// Returns the value modulo 4 (π/2) with the quadrant, by the method of Cody
+// and Waite with a three-word π/2, whose error is below 2^-130 for the
+// arguments where it is used.
+fn reduce_cody_waite(value: TwoFloat) -> (TwoFloat, i8) {
+    let q = (value * FRAC_2_PI).round().hi;
+    let [p1, p2, p3] = FRAC_PI_2_WORDS;
+    let t1 = two_prod(q, p1);
+    let t2 = two_prod(q, p2);
+
+    // value.hi - t1.hi is exact, and the terms are added from the largest
+    let r = TwoFloat::from(value.hi - t1.hi) - t2.hi;
+    let r = r + (value.lo - t1.lo) - t2.lo - q * p3;
+    (r, q.rem_euclid(4.0) as i8)
+}
+
+// Returns the value modulo 4 (π/2) with the quadrant, using the reduction of
+// Payne and Hanek. Each word w of the value is multiplied by the bits of 2/π,
+// skipping the leading bits whose products with w are multiples of 4, and
+// the products are reduced modulo 4 and summed exactly.
+fn reduce_payne_hanek(value: TwoFloat) -> (TwoFloat, i8) {
+    let mut sum = Expansion::new();
+    for &word in [value.hi, value.lo].iter() {
+        if word == 0.0 {
+            continue;
+        }
+
+        // word = m 2^(exponent - 52) for an integer m
+        let exponent = binade(TwoFloat::from(word));
+        let first = ((exponent - 54).div_euclid(24)).max(0);
+        let last = (exponent + 251).div_euclid(24) + 1;
+        if last < 0 {
+            continue;
+        }
+        let scaled = mul_pow2(word, -24 * first);
+        for i in first..=last {
+            let bits = mul_pow2(TWO_OVER_PI_BITS[i as usize], -24 * (i - first + 1));
+            let product = two_prod(scaled, bits);
+            for &component in [product.hi, product.lo].iter() {
+                sum += component - 4.0 * (component / 4.0).round();
+            }
+        }
+    }
+
+    let q = f64::from(&sum).round();
+    sum -= q;
+    (TwoFloat::from(&sum) * FRAC_PI_2, q.rem_euclid(4.0) as i8)
+}
+
+// Returns the value modulo π/2, in about [-π/4, π/4], with the quadrant.
+pub(crate) fn quadrant(value: TwoFloat) -> (TwoFloat, i8) {
     if value.abs() < FRAC_PI_4 {
         (value, 0)
-    } else {
-        let quotient = (value / FRAC_PI_2).round();
-        let remainder = value - quotient * FRAC_PI_2;
-        match i8::try_from(quotient % 4.0) {
-            Ok(quadrant) if quadrant >= 0 => (remainder, quadrant),
-            Ok(quadrant) if quadrant >= -4 => (remainder, 4 + quadrant),
-            _ => (TwoFloat::NAN, 0),
+    } else if value.hi.abs() < CODY_WAITE_LIMIT {
+        // Close to a multiple of π/2 the error of the Cody-Waite reduction is
+        // significant relative to the result
+        let (r, q) = reduce_cody_waite(value);
+        if r.hi.abs() > CODY_WAITE_MIN_RESULT {
+            (r, q)
+        } else {
+            reduce_payne_hanek(value)
         }
+    } else {
+        reduce_payne_hanek(value)
     }
 }
 
@@ -295,6 +376,83 @@ fn restricted_atan(x: TwoFloat) -> TwoFloat {
     x * polynomial!(x2, 1.0, ATAN_COEFFS)
 }
 
+// Returns 2y = q + f for an integer q and |f| <= 1/2, with q reduced modulo 4.
+// Both operations are exact.
+fn split_halves(y: f64) -> (f64, f64) {
+    if y.abs() >= 9007199254740992.0 {
+        // 2^53, above which y is an even integer
+        (0.0, 0.0)
+    } else {
+        let a = 2.0 * y;
+        let q = a.round();
+        (a - q, q.rem_euclid(4.0))
+    }
+}
+
+// Reduces the value exactly to x = (q + f)/2 for an integer q and |f| <= 1/2,
+// returning f and the quadrant q modulo 4.
+fn quadrant_pi(value: TwoFloat) -> (TwoFloat, u8) {
+    let (f_hi, q_hi) = split_halves(value.hi);
+    let (f_lo, q_lo) = split_halves(value.lo);
+    let mut f = TwoFloat::new_add(f_hi, f_lo);
+    let mut q = q_hi + q_lo;
+    if f.hi > 0.5 {
+        f = TwoFloat::new_add(f.hi - 1.0, f.lo);
+        q += 1.0;
+    } else if f.hi < -0.5 {
+        f = TwoFloat::new_add(f.hi + 1.0, f.lo);
+        q -= 1.0;
+    }
+    (f, q.rem_euclid(4.0) as u8)
+}
+
+// Taylor series of sin(πf/2) for |f| <= 1/2
+fn restricted_sin_pi(f: TwoFloat) -> TwoFloat {
+    let y = FRAC_PI_2 * f;
+    let y2 = y * y;
+    let mut t = TwoFloat::ONE;
+    for n in (1..=13).rev() {
+        t = 1.0 - y2 * t / f64::from(2 * n * (2 * n + 1));
+    }
+    y * t
+}
+
+// Taylor series of cos(πf/2) for |f| <= 1/2
+fn restricted_cos_pi(f: TwoFloat) -> TwoFloat {
+    let y = FRAC_PI_2 * f;
+    let y2 = y * y;
+    let mut t = TwoFloat::ONE;
+    for n in (1..=13).rev() {
+        t = 1.0 - y2 * t / f64::from((2 * n - 1) * (2 * n));
+    }
+    t
+}
+
+// Returns sin(πx) and cos(πx) for finite x, with exact zeros taking the sign
+// of x for the sine and positive for the cosine.
+fn sin_cos_pi(x: TwoFloat) -> (TwoFloat, TwoFloat) {
+    let (f, quadrant) = quadrant_pi(x);
+    let (s, c) = if f.hi == 0.0 {
+        (TwoFloat::ZERO, TwoFloat::ONE)
+    } else {
+        (restricted_sin_pi(f), restricted_cos_pi(f))
+    };
+    let (sin, cos) = match quadrant {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    (
+        if sin.hi == 0.0 {
+            TwoFloat::ZERO.copysign(x)
+        } else {
+            sin
+        },
+        if cos.hi == 0.0 { TwoFloat::ZERO } else { cos },
+    )
+}
+
 impl TwoFloat {
     /// Converts degrees to radians.
     ///
@@ -335,16 +493,18 @@ impl TwoFloat {
     ///
     /// assert!((b - c).abs() < 1e-10);
     pub fn sin(self) -> Self {
-        if !self.is_valid() {
-            return Self::NAN;
-        }
-        let (x, quadrant) = quadrant(self);
-        match quadrant {
-            0 => restricted_sin(x),
-            1 => restricted_cos(x),
-            2 => -restricted_sin(x),
-            _ => -restricted_cos(x),
-        }
+        status::function("sin", &[self], || {
+            if !self.is_valid() {
+                return Self::NAN;
+            }
+            let (x, quadrant) = quadrant(self);
+            match quadrant {
+                0 => restricted_sin(x),
+                1 => restricted_cos(x),
+                2 => -restricted_sin(x),
+                _ => -restricted_cos(x),
+            }
+        })
     }
 
     /// Computes the cosine of the value (in radians)
@@ -359,16 +519,18 @@ impl TwoFloat {
     ///
     /// assert!((b - c).abs() < 1e-10);
     pub fn cos(self) -> Self {
-        if !self.is_valid() {
-            return Self::NAN;
-        }
-        let (x, quadrant) = quadrant(self);
-        match quadrant {
-            0 => restricted_cos(x),
-            1 => -restricted_sin(x),
-            2 => -restricted_cos(x),
-            _ => restricted_sin(x),
-        }
+        status::function("cos", &[self], || {
+            if !self.is_valid() {
+                return Self::NAN;
+            }
+            let (x, quadrant) = quadrant(self);
+            match quadrant {
+                0 => restricted_cos(x),
+                1 => -restricted_sin(x),
+                2 => -restricted_cos(x),
+                _ => restricted_sin(x),
+            }
+        })
     }
 
     /// Simultaneously computes the sine and cosine of the value. Returns a
@@ -385,18 +547,20 @@ impl TwoFloat {
     /// assert!((s - 2.5f64.sin()).abs() < 1e-10);
     /// assert!((c - 2.5f64.cos()).abs() < 1e-10);
     pub fn sin_cos(self) -> (Self, Self) {
-        if !self.is_valid() {
-            return (Self::NAN, Self::NAN);
-        }
-        let (x, quadrant) = quadrant(self);
-        let s = restricted_sin(x);
-        let c = restricted_cos(x);
-        match quadrant {
-            0 => (s, c),
-            1 => (c, -s),
-            2 => (-s, -c),
-            _ => (-c, s),
-        }
+        status::function("sin_cos", &[self], || {
+            if !self.is_valid() {
+                return (Self::NAN, Self::NAN);
+            }
+            let (x, quadrant) = quadrant(self);
+            let s = restricted_sin(x);
+            let c = restricted_cos(x);
+            match quadrant {
+                0 => (s, c),
+                1 => (c, -s),
+                2 => (-s, -c),
+                _ => (-c, s),
+            }
+        })
     }
 
     /// Computes the tangent of the value (in radians).
@@ -411,14 +575,114 @@ impl TwoFloat {
     ///
     /// assert!((b - c).abs() < 1e-10);
     pub fn tan(self) -> Self {
-        if !self.is_valid() {
-            return self;
-        }
-        let (x, quadrant) = quadrant(self);
-        match quadrant {
-            0 | 2 => restricted_tan(x),
-            _ => -1.0 / restricted_tan(x),
-        }
+        status::function("tan", &[self], || {
+            if !self.is_valid() {
+                return Self::NAN;
+            }
+            let (x, quadrant) = quadrant(self);
+            match quadrant {
+                0 | 2 => restricted_tan(x),
+                _ => -1.0 / restricted_tan(x),
+            }
+        })
+    }
+
+    /// Computes the sine of π times the value. The argument reduction is
+    /// exact, so the result is exactly zero at the integers and ±1 at the
+    /// half-integers, and large arguments do not lose precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_div(1.0, 6.0).sin_pi();
+    /// let b = TwoFloat::from(2.5).sin_pi();
+    /// let c = TwoFloat::from(1e20).sin_pi();
+    ///
+    /// assert!((a - 0.5).abs() < 1e-30);
+    /// assert_eq!(b, 1.0);
+    /// assert_eq!(c, 0.0);
+    pub fn sin_pi(self) -> Self {
+        status::function("sin_pi", &[self], || {
+            if !self.is_valid() {
+                return Self::NAN;
+            }
+            sin_cos_pi(self).0
+        })
+    }
+
+    /// Computes the cosine of π times the value. The argument reduction is
+    /// exact, so the result is exactly ±1 at the integers and zero at the
+    /// half-integers, and large arguments do not lose precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::new_div(1.0, 3.0).cos_pi();
+    /// let b = TwoFloat::from(2.5).cos_pi();
+    /// let c = TwoFloat::new_add(1e20, 1.0).cos_pi();
+    ///
+    /// assert!((a - 0.5).abs() < 1e-30);
+    /// assert_eq!(b, 0.0);
+    /// assert_eq!(c, -1.0);
+    pub fn cos_pi(self) -> Self {
+        status::function("cos_pi", &[self], || {
+            if !self.is_valid() {
+                return Self::NAN;
+            }
+            sin_cos_pi(self).1
+        })
+    }
+
+    /// Simultaneously computes the sine and cosine of π times the value.
+    /// Returns a tuple with the sine as the first element and the cosine as
+    /// the second element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.25);
+    /// let (s, c) = a.sin_cos_pi();
+    ///
+    /// assert!((s - c).abs() < 1e-30);
+    /// assert!((s * s - 0.5).abs() < 1e-30);
+    pub fn sin_cos_pi(self) -> (Self, Self) {
+        status::function("sin_cos_pi", &[self], || {
+            if !self.is_valid() {
+                return (Self::NAN, Self::NAN);
+            }
+            sin_cos_pi(self)
+        })
+    }
+
+    /// Computes the tangent of π times the value. The argument reduction is
+    /// exact, so the result is exactly zero at the integers and infinite at
+    /// the half-integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoFloat;
+    /// let a = TwoFloat::from(0.25).tan_pi();
+    /// let b = TwoFloat::from(-0.75).tan_pi();
+    ///
+    /// assert!((a - 1.0).abs() < 1e-30);
+    /// assert!((b - 1.0).abs() < 1e-30);
+    pub fn tan_pi(self) -> Self {
+        status::function("tan_pi", &[self], || {
+            if !self.is_valid() {
+                return Self::NAN;
+            }
+            let (s, c) = sin_cos_pi(self);
+            if c.hi == 0.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "tan_pi");
+                Self::INFINITY.copysign(s)
+            } else {
+                s / c
+            }
+        })
     }
 
     /// Computes the arcsine of the value. Return value is in radians in the
@@ -435,19 +699,21 @@ impl TwoFloat {
     ///
     /// assert!((b - c).abs() < 1e-10);
     pub fn asin(self) -> Self {
-        let abs_val = self.abs();
-        if !self.is_valid() || abs_val > 1.0 {
-            Self::NAN
-        } else if abs_val <= 0.5 {
-            restricted_asin(self)
-        } else {
-            let result = FRAC_PI_2 - 2.0 * restricted_asin(((1.0 - self.abs()) / 2.0).sqrt());
-            if self.is_sign_positive() {
-                result
+        status::function("asin", &[self], || {
+            let abs_val = self.abs();
+            if !self.is_valid() || abs_val > 1.0 {
+                Self::NAN
+            } else if abs_val <= 0.5 {
+                restricted_asin(self)
             } else {
-                -result
+                let result = FRAC_PI_2 - 2.0 * restricted_asin(((1.0 - self.abs()) / 2.0).sqrt());
+                if self.is_sign_positive() {
+                    result
+                } else {
+                    -result
+                }
             }
-        }
+        })
     }
 
     /// Computes the arccosine of the value. Return value is in radians in
@@ -464,12 +730,14 @@ impl TwoFloat {
     ///
     /// assert!((b - c).abs() < 1e-10);
     pub fn acos(self) -> Self {
-        let x = self.asin();
-        if x.is_valid() {
-            FRAC_PI_2 - x
-        } else {
-            x
-        }
+        status::function("acos", &[self], || {
+            let x = self.asin();
+            if x.is_valid() {
+                FRAC_PI_2 - x
+            } else {
+                x
+            }
+        })
     }
 
     /// Computes the arctangent of the value. Return value is in radians in
@@ -485,37 +753,39 @@ impl TwoFloat {
     ///
     /// assert!((b - c).abs() < 1e-10);
     pub fn atan(self) -> Self {
-        if !self.is_valid() {
-            Self::NAN
-        } else if self.hi.is_infinite() {
-            if self.hi.is_sign_positive() {
-                FRAC_PI_2
+        status::function("atan", &[self], || {
+            if self.is_nan() {
+                Self::NAN
+            } else if self.hi.is_infinite() {
+                if self.hi.is_sign_positive() {
+                    FRAC_PI_2
+                } else {
+                    -FRAC_PI_2
+                }
             } else {
-                -FRAC_PI_2
+                let x = self.abs();
+                let k = 4.0 * x + 0.25;
+                if k <= 2.0 {
+                    return restricted_atan(self);
+                }
+
+                let result = if k < 3.0 {
+                    ATAN_FRAC_1_2 + restricted_atan((x - 0.5) / (1.0 + 0.5 * x))
+                } else if k < 5.0 {
+                    FRAC_PI_4 + restricted_atan((x - 1.0) / (1.0 + x))
+                } else if k < 10.0 {
+                    ATAN_FRAC_3_2 + restricted_atan((x - 1.5) / (1.0 + 1.5 * x))
+                } else {
+                    FRAC_PI_2 - restricted_atan(x.recip())
+                };
+
+                if self.is_sign_positive() {
+                    result
+                } else {
+                    -result
+                }
             }
-        } else {
-            let x = self.abs();
-            let k = 4.0 * x + 0.25;
-            if k <= 2.0 {
-                return restricted_atan(self);
-            }
-
-            let result = if k < 3.0 {
-                ATAN_FRAC_1_2 + restricted_atan((x - 0.5) / (1.0 + 0.5 * x))
-            } else if k < 5.0 {
-                FRAC_PI_4 + restricted_atan((x - 1.0) / (1.0 + x))
-            } else if k < 10.0 {
-                ATAN_FRAC_3_2 + restricted_atan((x - 1.5) / (1.0 + 1.5 * x))
-            } else {
-                FRAC_PI_2 - restricted_atan(x.recip())
-            };
-
-            if self.is_sign_positive() {
-                result
-            } else {
-                -result
-            }
-        }
+        })
     }
 
     /// Computes the four quadrant arctangent of `self` (y) and `other` (x)
@@ -531,30 +801,51 @@ impl TwoFloat {
     ///
     /// assert!((theta + 3.0 * twofloat::consts::FRAC_PI_4).abs() < 1e-10);
     pub fn atan2(self, other: Self) -> Self {
-        if self.hi == 0.0 {
-            if other.hi.is_sign_positive() {
-                Self::from(0.0)
-            } else if self.hi.is_sign_positive() {
-                PI
-            } else {
-                -PI
-            }
-        } else if other.hi == 0.0 {
-            if self.hi.is_sign_positive() {
-                FRAC_PI_2
+        status::function("atan2", &[self, other], || {
+            if self.is_nan() || other.is_nan() {
+                Self::NAN
+            } else if self.hi.is_infinite() {
+                let result = if other.hi.is_infinite() {
+                    if other.hi > 0.0 {
+                        FRAC_PI_4
+                    } else {
+                        3.0 * FRAC_PI_4
+                    }
+                } else {
+                    FRAC_PI_2
+                };
+                result.copysign(self)
+            } else if other.hi.is_infinite() {
+                if other.hi > 0.0 {
+                    Self::from(0.0).copysign(self)
+                } else {
+                    PI.copysign(self)
+                }
+            } else if self.hi == 0.0 {
+                if other.hi.is_sign_positive() {
+                    Self::from(0.0)
+                } else if self.hi.is_sign_positive() {
+                    PI
+                } else {
+                    -PI
+                }
+            } else if other.hi == 0.0 {
+                if self.hi.is_sign_positive() {
+                    FRAC_PI_2
+                } else {
+                    -FRAC_PI_2
+                }
             } else {
-                -FRAC_PI_2
+                let a = (self / other).atan();
+                if other.hi.is_sign_positive() {
+                    a
+                } else if self.hi.is_sign_positive() {
+                    a + PI
+                } else {
+                    a - PI
+                }
             }
-        } else {
-            let a = (self / other).atan();
-            if other.hi.is_sign_positive() {
-                a
-            } else if self.hi.is_sign_positive() {
-                a + PI
-            } else {
-                a - PI
-            }
-        }
+        })
     }
 }
 
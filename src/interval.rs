@@ -0,0 +1,424 @@
+use core::{
+    convert::TryFrom,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    arithmetic::{ADD_ERROR_BOUND, DIV_ERROR_BOUND, MUL_ERROR_BOUND},
+    base::{binade, scale},
+    status, TwoFloat, TwoFloatError,
+};
+
+#[cfg(feature = "math_funcs")]
+mod functions;
+
+// Relative allowance for the rounding errors made when computing a widened
+// bound, covering the error of the subtraction or addition of the margin and
+// of its computation in f64.
+const WIDENING_ERROR: f64 = 4.930380657631324e-32; // 2^-104
+
+// Relative allowance for the rounding of the error bounds when multiplied by
+// the high word of a result.
+const BOUND_ROUNDING: f64 = 9.094947017729282e-13; // 2^-40
+
+// Absolute allowance for results in the subnormal range, where the relative
+// error bounds do not apply.
+pub(crate) const UNDERFLOW_ERROR: f64 = 8.095e-320; // 2^-1060
+
+// The smallest exact value that can be rounded to infinity by an operation or
+// mathematical function.
+const OVERFLOW_BOUND: TwoFloat = TwoFloat {
+    hi: 1.7976931348606807e308,
+    lo: 0.0,
+};
+
+// Returns a lower bound of the exact value of a result `x`, whose error is at
+// most `relative` times its magnitude plus `absolute`.
+pub(crate) fn round_down(x: TwoFloat, relative: f64, absolute: f64) -> TwoFloat {
+    if x.hi == f64::INFINITY {
+        OVERFLOW_BOUND
+    } else if !x.is_finite() {
+        x
+    } else {
+        x - error_margin(x, relative, absolute)
+    }
+}
+
+// Returns an upper bound of the exact value of a result `x`, whose error is
+// at most `relative` times its magnitude plus `absolute`.
+pub(crate) fn round_up(x: TwoFloat, relative: f64, absolute: f64) -> TwoFloat {
+    -round_down(-x, relative, absolute)
+}
+
+fn error_margin(x: TwoFloat, relative: f64, absolute: f64) -> f64 {
+    let scale = 1.0 + BOUND_ROUNDING;
+    x.hi.abs() * (relative * scale + WIDENING_ERROR) + absolute * scale + UNDERFLOW_ERROR
+}
+
+/// Represents a closed interval of real numbers with `TwoFloat` bounds, used
+/// to compute verified enclosures of results.
+///
+/// Arithmetic operators and, if the `math_funcs` feature is enabled, `sqrt`
+/// return intervals which are guaranteed to contain the exact result of the
+/// operation applied to every value of the arguments. The bounds are computed
+/// with the `TwoFloat` operations and then widened by the proven error bounds
+/// of the algorithms of Joldes et al. (2017), so the width of a result is a
+/// few units in the last place larger than the exact range.
+///
+/// The enclosures returned by `exp`, `ln` and the trigonometric functions are
+/// widened by bounds on the errors of the `TwoFloat` functions which have been
+/// validated empirically rather than proven, so they contain the exact result
+/// only as far as those bounds hold.
+///
+/// Infinite bounds represent intervals unbounded in that direction.
+/// Operations whose result is undefined for every value of the arguments,
+/// such as the square root of a negative interval, return the `EMPTY`
+/// interval, and division by an interval containing zero returns `ENTIRE`.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{TwoFloat, TwoFloatInterval};
+/// let third = TwoFloatInterval::from(1.0) / TwoFloatInterval::from(3.0);
+/// let one = third * TwoFloatInterval::from(3.0);
+///
+/// assert!(one.contains(TwoFloat::from(1.0)));
+/// assert!(one.width() < 1e-30);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoFloatInterval {
+    lower: TwoFloat,
+    upper: TwoFloat,
+}
+
+impl TwoFloatInterval {
+    /// The empty interval, containing no values.
+    pub const EMPTY: Self = Self {
+        lower: TwoFloat::NAN,
+        upper: TwoFloat::NAN,
+    };
+
+    /// The interval containing every real number.
+    pub const ENTIRE: Self = Self {
+        lower: TwoFloat::NEG_INFINITY,
+        upper: TwoFloat::INFINITY,
+    };
+
+    /// Creates a new interval from its lower and upper bounds.
+    ///
+    /// Returns an error if either bound is `NAN`, if `lower` is greater than
+    /// `upper`, or if the interval contains no real numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::new(TwoFloat::from(1.0), TwoFloat::from(2.0));
+    /// let b = TwoFloatInterval::new(TwoFloat::from(2.0), TwoFloat::from(1.0));
+    ///
+    /// assert!(a.is_ok());
+    /// assert!(b.is_err());
+    pub fn new(lower: TwoFloat, upper: TwoFloat) -> Result<Self, TwoFloatError> {
+        if lower <= upper && lower != f64::INFINITY && upper != f64::NEG_INFINITY {
+            Ok(Self { lower, upper })
+        } else {
+            Err(TwoFloatError::ConversionError)
+        }
+    }
+
+    /// Returns the lower bound of the interval, or `NAN` if it is empty.
+    pub fn lower(&self) -> TwoFloat {
+        self.lower
+    }
+
+    /// Returns the upper bound of the interval, or `NAN` if it is empty.
+    pub fn upper(&self) -> TwoFloat {
+        self.upper
+    }
+
+    /// Returns `true` if the interval contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_nan()
+    }
+
+    /// Returns `true` if `value` lies within the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::new(TwoFloat::from(1.0), TwoFloat::from(2.0)).unwrap();
+    ///
+    /// assert!(a.contains(TwoFloat::from(1.5)));
+    /// assert!(!a.contains(TwoFloat::from(3.0)));
+    pub fn contains(&self, value: TwoFloat) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+
+    /// Returns the width of the interval, rounded up, or `NAN` if it is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::new(TwoFloat::from(1.0), TwoFloat::from(2.0)).unwrap();
+    ///
+    /// assert!(a.width() >= 1.0);
+    /// assert!(a.width() - 1.0 < 1e-30);
+    pub fn width(&self) -> TwoFloat {
+        if self.is_empty() {
+            TwoFloat::NAN
+        } else if self.lower == self.upper {
+            TwoFloat::ZERO
+        } else {
+            let width = self.upper - self.lower;
+            if width.is_infinite() {
+                width
+            } else {
+                round_up(width, ADD_ERROR_BOUND, 0.0)
+            }
+        }
+    }
+
+    /// Returns a value within the interval close to its midpoint, or `NAN`
+    /// if it is empty. Unbounded intervals return zero if unbounded in both
+    /// directions, and otherwise the finite value of largest magnitude on the
+    /// unbounded side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::new(TwoFloat::from(1.0), TwoFloat::from(2.0)).unwrap();
+    ///
+    /// assert_eq!(a.midpoint(), 1.5);
+    /// assert_eq!(TwoFloatInterval::ENTIRE.midpoint(), 0.0);
+    pub fn midpoint(&self) -> TwoFloat {
+        if self.is_empty() {
+            TwoFloat::NAN
+        } else if self.lower.is_infinite() && self.upper.is_infinite() {
+            TwoFloat::ZERO
+        } else if self.lower.is_infinite() {
+            TwoFloat::MIN
+        } else if self.upper.is_infinite() {
+            TwoFloat::MAX
+        } else {
+            let midpoint = self.lower * 0.5 + self.upper * 0.5;
+            midpoint.max(self.lower).min(self.upper)
+        }
+    }
+
+    /// Returns the smallest interval containing both `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::from(1.0).hull(TwoFloatInterval::from(3.0));
+    ///
+    /// assert!(a.contains(TwoFloat::from(2.0)));
+    pub fn hull(self, other: Self) -> Self {
+        if self.is_empty() {
+            other
+        } else if other.is_empty() {
+            self
+        } else {
+            Self {
+                lower: self.lower.min(other.lower),
+                upper: self.upper.max(other.upper),
+            }
+        }
+    }
+
+    /// Returns the intersection of `self` and `other`, which is `EMPTY` if
+    /// they are disjoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::new(TwoFloat::from(1.0), TwoFloat::from(3.0)).unwrap();
+    /// let b = TwoFloatInterval::new(TwoFloat::from(2.0), TwoFloat::from(4.0)).unwrap();
+    ///
+    /// assert_eq!(a.intersection(b), TwoFloatInterval::new(2.0.into(), 3.0.into()).unwrap());
+    /// assert!(a.intersection(b + TwoFloatInterval::from(2.0)).is_empty());
+    pub fn intersection(self, other: Self) -> Self {
+        let lower = self.lower.max(other.lower);
+        let upper = self.upper.min(other.upper);
+        if self.is_empty() || other.is_empty() || lower > upper {
+            Self::EMPTY
+        } else {
+            Self { lower, upper }
+        }
+    }
+}
+
+// Evaluates an operation on intervals with the given bounds, returning
+// `EMPTY` if any argument is empty. Status flags are only raised for an empty
+// result from non-empty arguments.
+pub(crate) fn interval_op(
+    op: &'static str,
+    bounds: &[TwoFloat],
+    f: impl FnOnce() -> TwoFloatInterval,
+) -> TwoFloatInterval {
+    if bounds.iter().any(TwoFloat::is_nan) {
+        TwoFloatInterval::EMPTY
+    } else {
+        status::function(op, bounds, f)
+    }
+}
+
+// Returns the interval between the smallest and largest of the results of an
+// operation, each with relative error at most `relative`. `NAN` results, from
+// the products or quotients of zeros and infinities, are ignored.
+fn enclose(results: &[TwoFloat], relative: f64) -> TwoFloatInterval {
+    results.iter().fold(TwoFloatInterval::EMPTY, |hull, &x| {
+        if x.is_nan() {
+            hull
+        } else {
+            hull.hull(TwoFloatInterval {
+                lower: round_down(x, relative, 0.0),
+                upper: round_up(x, relative, 0.0),
+            })
+        }
+    })
+}
+
+// Divides `a` by `b` with both scaled to the range [1, 2), as the low word of
+// the reciprocal computed by the division operator loses precision for
+// divisors above about 2^969. The final scaling is exact unless the quotient
+// underflows.
+fn scaled_div(a: TwoFloat, b: TwoFloat) -> TwoFloat {
+    if a.is_finite() && b.is_finite() && a.hi != 0.0 {
+        let (ka, kb) = (binade(a), binade(b));
+        scale(scale(a, -ka) / scale(b, -kb), ka - kb)
+    } else {
+        a / b
+    }
+}
+
+impl From<TwoFloat> for TwoFloatInterval {
+    /// Returns the interval containing only `value`, or `EMPTY` if it is
+    /// `NAN`.
+    fn from(value: TwoFloat) -> Self {
+        Self::new(value, value).unwrap_or(Self::EMPTY)
+    }
+}
+
+impl From<f64> for TwoFloatInterval {
+    /// Returns the interval containing only `value`, or `EMPTY` if it is
+    /// `NAN`.
+    fn from(value: f64) -> Self {
+        Self::from(TwoFloat::from(value))
+    }
+}
+
+impl TryFrom<(TwoFloat, TwoFloat)> for TwoFloatInterval {
+    type Error = TwoFloatError;
+
+    fn try_from(value: (TwoFloat, TwoFloat)) -> Result<Self, Self::Error> {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl fmt::Display for TwoFloatInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("[empty]");
+        }
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.lower, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.upper, f)?;
+        f.write_str("]")
+    }
+}
+
+unary_ops! {
+    fn Neg::neg(self: &TwoFloatInterval) -> TwoFloatInterval {
+        Self::Output {
+            lower: -self.upper,
+            upper: -self.lower,
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements addition of two `TwoFloatInterval` values, widening the
+    /// bounds by the error bound of Joldes et al. (2017) Algorithm 6.
+    fn Add::add<'a, 'b>(self: &'a TwoFloatInterval, rhs: &'b TwoFloatInterval) -> TwoFloatInterval {
+        interval_op("interval add", &[self.lower, self.upper, rhs.lower, rhs.upper], || TwoFloatInterval {
+            lower: round_down(self.lower + rhs.lower, ADD_ERROR_BOUND, 0.0),
+            upper: round_up(self.upper + rhs.upper, ADD_ERROR_BOUND, 0.0),
+        })
+    }
+
+    /// Implements subtraction of two `TwoFloatInterval` values, widening the
+    /// bounds by the error bound of Joldes et al. (2017) Algorithm 6.
+    fn Sub::sub<'a, 'b>(self: &'a TwoFloatInterval, rhs: &'b TwoFloatInterval) -> TwoFloatInterval {
+        interval_op("interval sub", &[self.lower, self.upper, rhs.lower, rhs.upper], || TwoFloatInterval {
+            lower: round_down(self.lower - rhs.upper, ADD_ERROR_BOUND, 0.0),
+            upper: round_up(self.upper - rhs.lower, ADD_ERROR_BOUND, 0.0),
+        })
+    }
+
+    /// Implements multiplication of two `TwoFloatInterval` values, widening
+    /// the bounds by the error bound of Joldes et al. (2017) Algorithm 12.
+    fn Mul::mul<'a, 'b>(self: &'a TwoFloatInterval, rhs: &'b TwoFloatInterval) -> TwoFloatInterval {
+        interval_op("interval mul", &[self.lower, self.upper, rhs.lower, rhs.upper], || {
+            enclose(
+                &[
+                    self.lower * rhs.lower,
+                    self.lower * rhs.upper,
+                    self.upper * rhs.lower,
+                    self.upper * rhs.upper,
+                ],
+                MUL_ERROR_BOUND,
+            )
+        })
+    }
+
+    /// Implements division of two `TwoFloatInterval` values, widening the
+    /// bounds by the error bound of Joldes et al. (2017) Algorithm 18 applied
+    /// to the bounds scaled by powers of two.
+    /// Returns `ENTIRE` if the divisor contains zero.
+    fn Div::div<'a, 'b>(self: &'a TwoFloatInterval, rhs: &'b TwoFloatInterval) -> TwoFloatInterval {
+        interval_op("interval div", &[self.lower, self.upper, rhs.lower, rhs.upper], || {
+            if rhs.contains(TwoFloat::ZERO) {
+                TwoFloatInterval::ENTIRE
+            } else {
+                enclose(
+                    &[
+                        scaled_div(self.lower, rhs.lower),
+                        scaled_div(self.lower, rhs.upper),
+                        scaled_div(self.upper, rhs.lower),
+                        scaled_div(self.upper, rhs.upper),
+                    ],
+                    DIV_ERROR_BOUND,
+                )
+            }
+        })
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut TwoFloatInterval, rhs: &'a TwoFloatInterval) {
+        *self = *self + rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoFloatInterval, rhs: &'a TwoFloatInterval) {
+        *self = *self - rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoFloatInterval, rhs: &'a TwoFloatInterval) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoFloatInterval, rhs: &'a TwoFloatInterval) {
+        *self = *self / rhs;
+    }
+}
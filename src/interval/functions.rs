@@ -0,0 +1,209 @@
+use super::{interval_op, round_down, round_up, TwoFloatInterval};
+use crate::{
+    consts::FRAC_PI_2,
+    functions::{
+        explog::{EXP_ERROR_BOUND, LN_ERROR_BOUND},
+        power::SQRT_ERROR_BOUND,
+        trigonometry::{quadrant, SIN_COS_ERROR_BOUND, TAN_ERROR_BOUND},
+    },
+    TwoFloat,
+};
+
+// Unlike the bound for `sqrt`, the error bounds of the transcendental
+// functions are not derived but measured against mpmath over their domains,
+// with a margin of at least a factor of eight, so the enclosures of `exp`,
+// `ln`, `sin`, `cos` and `tan` are only as reliable as these measurements.
+
+// Allowance for the rounding errors in the position of a bound relative to
+// the critical points of a trigonometric function, in quarter periods. The
+// bounds are located with the exact argument reduction of `sin` and `cos`, so
+// the errors are below 2^-90 for all finite arguments.
+const PERIOD_TOLERANCE: f64 = 1e-20;
+
+// Returns a value congruent to `x / (π/2)` modulo 4.
+fn quarter_periods(x: TwoFloat) -> TwoFloat {
+    let (r, q) = quadrant(x);
+    r / FRAC_PI_2 + f64::from(q)
+}
+
+// Returns `true` if `x` may contain a point `offset + k * period` quarter
+// periods from zero for some integer k. Points close to the bounds are
+// always included.
+fn may_contain_periodic(x: &TwoFloatInterval, offset: f64, period: f64) -> bool {
+    let start = quarter_periods(x.lower) - offset;
+    let end = start + (x.upper - x.lower) / FRAC_PI_2;
+    let first = (start / period - PERIOD_TOLERANCE).ceil();
+    let last = (end / period + PERIOD_TOLERANCE).floor();
+    first <= last
+}
+
+// Returns `true` if `x` is unbounded or contains a whole period.
+fn covers_period(x: &TwoFloatInterval, period: f64) -> bool {
+    !x.lower.is_finite() || !x.upper.is_finite() || x.upper - x.lower >= period
+}
+
+// Returns the enclosure of sin or cos on `x`, which has its maximum at
+// `max_offset + 4k` quarter periods and its minimum at `max_offset + 2 + 4k`
+// quarter periods.
+fn sin_cos_enclosure(
+    x: &TwoFloatInterval,
+    f: impl Fn(TwoFloat) -> TwoFloat,
+    max_offset: f64,
+) -> TwoFloatInterval {
+    // 6 is slightly less than 2π, so smaller intervals are checked for
+    // extrema
+    if covers_period(x, 6.0) {
+        return TwoFloatInterval {
+            lower: TwoFloat::from(-1.0),
+            upper: TwoFloat::ONE,
+        };
+    }
+    let (a, b) = (f(x.lower), f(x.upper));
+    let lower = if may_contain_periodic(x, max_offset + 2.0, 4.0) {
+        TwoFloat::from(-1.0)
+    } else {
+        round_down(a.min(b), 0.0, SIN_COS_ERROR_BOUND)
+    };
+    let upper = if may_contain_periodic(x, max_offset, 4.0) {
+        TwoFloat::ONE
+    } else {
+        round_up(a.max(b), 0.0, SIN_COS_ERROR_BOUND)
+    };
+    TwoFloatInterval {
+        lower: lower.max(TwoFloat::from(-1.0)),
+        upper: upper.min(TwoFloat::ONE),
+    }
+}
+
+impl TwoFloatInterval {
+    /// Returns an enclosure of the square roots of the non-negative values in
+    /// the interval, or `EMPTY` if it only contains negative values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::from(2.0).sqrt();
+    ///
+    /// assert!(a.contains(TwoFloat::from(2.0).sqrt()));
+    /// assert!(TwoFloatInterval::from(-1.0).sqrt().is_empty());
+    pub fn sqrt(self) -> Self {
+        interval_op("interval sqrt", &[self.lower, self.upper], || {
+            if self.upper < 0.0 {
+                return Self::EMPTY;
+            }
+            let lower = self.lower.max(TwoFloat::ZERO).sqrt();
+            Self {
+                lower: round_down(lower, SQRT_ERROR_BOUND, 0.0).max(TwoFloat::ZERO),
+                upper: round_up(self.upper.sqrt(), SQRT_ERROR_BOUND, 0.0),
+            }
+        })
+    }
+
+    /// Returns an enclosure of `e^x` for the values `x` in the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::E, TwoFloatInterval};
+    /// let a = TwoFloatInterval::from(1.0).exp();
+    ///
+    /// assert!(a.contains(E));
+    pub fn exp(self) -> Self {
+        interval_op("interval exp", &[self.lower, self.upper], || Self {
+            lower: round_down(self.lower.exp(), EXP_ERROR_BOUND, 0.0).max(TwoFloat::ZERO),
+            upper: round_up(self.upper.exp(), EXP_ERROR_BOUND, 0.0),
+        })
+    }
+
+    /// Returns an enclosure of the natural logarithms of the non-negative
+    /// values in the interval, or `EMPTY` if it only contains negative
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::LN_2, TwoFloatInterval};
+    /// let a = TwoFloatInterval::from(2.0).ln();
+    ///
+    /// assert!(a.contains(LN_2));
+    pub fn ln(self) -> Self {
+        interval_op("interval ln", &[self.lower, self.upper], || {
+            if self.upper < 0.0 {
+                return Self::EMPTY;
+            }
+            let lower = if self.lower <= 0.0 {
+                TwoFloat::NEG_INFINITY
+            } else {
+                round_down(self.lower.ln(), LN_ERROR_BOUND, LN_ERROR_BOUND)
+            };
+            let upper = if self.upper == 0.0 {
+                TwoFloat::NEG_INFINITY
+            } else {
+                round_up(self.upper.ln(), LN_ERROR_BOUND, LN_ERROR_BOUND)
+            };
+            Self { lower, upper }
+        })
+    }
+
+    /// Returns an enclosure of the sine of the values in the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::new(TwoFloat::from(1.0), TwoFloat::from(2.0)).unwrap();
+    ///
+    /// assert_eq!(a.sin().upper(), 1.0);
+    /// assert!(a.sin().contains(TwoFloat::from(1.0).sin()));
+    pub fn sin(self) -> Self {
+        interval_op("interval sin", &[self.lower, self.upper], || {
+            sin_cos_enclosure(&self, TwoFloat::sin, 1.0)
+        })
+    }
+
+    /// Returns an enclosure of the cosine of the values in the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::PI, TwoFloatInterval};
+    /// let a = TwoFloatInterval::from(PI).cos();
+    ///
+    /// assert_eq!(a.lower(), -1.0);
+    pub fn cos(self) -> Self {
+        interval_op("interval cos", &[self.lower, self.upper], || {
+            sin_cos_enclosure(&self, TwoFloat::cos, 0.0)
+        })
+    }
+
+    /// Returns an enclosure of the tangent of the values in the interval,
+    /// which is `ENTIRE` if the interval contains a pole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoFloat, TwoFloatInterval};
+    /// let a = TwoFloatInterval::from(1.0).tan();
+    /// let b = TwoFloatInterval::new(TwoFloat::from(1.0), TwoFloat::from(2.0)).unwrap();
+    ///
+    /// assert!(a.contains(TwoFloat::from(1.0).tan()));
+    /// assert_eq!(b.tan(), TwoFloatInterval::ENTIRE);
+    pub fn tan(self) -> Self {
+        interval_op("interval tan", &[self.lower, self.upper], || {
+            // 3 is slightly less than π, so smaller intervals are checked for
+            // poles
+            if covers_period(&self, 3.0) || may_contain_periodic(&self, 1.0, 2.0) {
+                return Self::ENTIRE;
+            }
+            // The error of the argument reduction is scaled by the derivative
+            // 1 + tan(x)^2
+            let bound = |t: TwoFloat| SIN_COS_ERROR_BOUND * (1.0 + t.hi * t.hi);
+            let (a, b) = (self.lower.tan(), self.upper.tan());
+            Self {
+                lower: round_down(a, TAN_ERROR_BOUND, bound(a)),
+                upper: round_up(b, TAN_ERROR_BOUND, bound(b)),
+            }
+        })
+    }
+}
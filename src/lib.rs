@@ -29,8 +29,75 @@ Mathematical functions are provided if the `math_funcs` feature is enabled
 (this is enabled by default), though the implementations should be regarded
 as preliminary.
 
-Operations on non-finite values are not supported. At the moment this is not
-automatically checked. The `is_valid()` method is provided for this purpose.
+The error function `erf()` and the complementary and scaled complementary
+error functions `erfc()` and `erfcx()` are accurate to close to the full
+precision of the type, with `erfc()` underflowing gradually for large
+arguments.
+
+The gamma and beta functions are provided by `gamma()`, `ln_gamma()`,
+`beta()` and `ln_beta()`, where the logarithmic versions also return the sign
+of the function.
+
+The Bessel functions of the first and second kind are provided by `j0()`,
+`j1()`, `jn()`, `y0()`, `y1()` and `yn()`, and the modified Bessel functions
+by `i0()`, `i1()`, `k0()` and `k1()`.
+
+Non-finite values follow the IEEE 754 semantics of `f64`: an infinity is
+represented with the infinite value in the high word and zero in the low
+word, and any operation whose result overflows, or which involves infinite
+or `NAN` operands, returns the same special value as the corresponding `f64`
+operation. The sign of a zero result is taken from the high word. The
+`is_nan()`, `is_infinite()`, `is_finite()`, `is_normal()` and `classify()`
+methods can be used to inspect values, while `is_valid()` additionally checks
+that the two components do not overlap.
+
+For intermediate computations which need more precision, the `ThreeFloat`
+type represents the sum of three non-overlapping `f64` values, giving about
+159 bits of precision. It supports the same arithmetic operators and
+comparisons as `TwoFloat`, and converts exactly from `TwoFloat`. The
+`QuadFloat` type represents the sum of four words, giving about 212 bits of
+precision, and additionally provides `sqrt`, `exp`, `ln` and trigonometric
+functions and conversion to and from decimal strings. The `TwoF32` type
+applies the double-word algorithms of `TwoFloat` to pairs of `f32` values,
+giving about 48 bits of precision where `f64` arithmetic is slow or
+unavailable.
+
+Complex numbers with `TwoFloat` parts are represented by the `TwoComplex`
+type, which provides the arithmetic operators and, with the `math_funcs`
+feature, the elementary functions of a complex variable.
+
+The `TwoFloatInterval` type provides interval arithmetic with `TwoFloat`
+bounds, where the arithmetic operations and `sqrt` return an interval
+guaranteed to contain the exact result, for computing verified enclosures.
+The enclosures of the transcendental functions rely on empirically validated
+error bounds. For a cheaper check of accuracy, `TrackedTwoFloat` carries a
+value together with a worst-case bound on its absolute error, which is
+accumulated through each operation.
+
+The `DualN` type carries a `TwoFloat` value together with its partial
+derivatives with respect to `N` variables, for forward-mode automatic
+differentiation at double-double precision, with `Dual` as the single
+variable case. Truncated power series with `TwoFloat` coefficients, as used
+by Taylor series integrators, are represented by the `TaylorJet` type.
+
+For exact computations, such as determinants and sums of many terms, the
+`Expansion` type represents a value as a growable list of non-overlapping
+`f64` components, using the expansion arithmetic of Shewchuk (1997).
+Addition, subtraction and multiplication are exact, and the result can be
+compressed and rounded to `TwoFloat` or `f64`. The `predicates` module uses
+these to provide the `orient2d`, `orient3d`, `incircle` and `insphere`
+geometric predicates, which always return the correct sign.
+
+Checked versions of the arithmetic operators and mathematical functions, such
+as `checked_add()` and `checked_ln()`, return a `TwoFloatError` describing
+why the operation failed instead of a non-finite or underflowed result.
+
+If the `fp_status` feature is enabled, arithmetic operators and mathematical
+functions also record overflow, underflow, division by zero and invalid
+results in sticky status flags, in the same way as IEEE 754 status flags.
+The flags are held in a thread-local environment that is installed by
+creating an `FpEnv`, which can also trap flags to panic at the first
+exception, naming the operation that raised it.
 
 If the `serde_support` feature is enabled, serialization and deserialization
 is possible through the Serde library.
@@ -46,6 +113,23 @@ is possible through the Serde library.
   Transactions on Mathematical Software, Association for Computing Machinery,
   1997, 23 (4), pp. 561-589. 10.1145/279232.279237.
 
+* Vincent Lefèvre, Nicolas Louvet, Jean-Michel Muller, Joris Picot, Laurence
+  Rideau. Accurate Calculation of Euclidean Norms using Double-word
+  Arithmetic. ACM Transactions on Mathematical Software, 2023, 49 (1),
+  pp. 1 - 34.
+
+* Nicolas Fabiano, Jean-Michel Muller, Joris Picot. Algorithms for
+  triple-word arithmetic. IEEE Transactions on Computers, Institute of
+  Electrical and Electronics Engineers, 2019, 68 (11), pp. 1573-1583.
+
+* Jonathan Richard Shewchuk. Adaptive Precision Floating-Point Arithmetic and
+  Fast Robust Geometric Predicates. Discrete & Computational Geometry, 1997,
+  18 (3), pp. 305-363. 10.1007/PL00009321.
+
+* Yozo Hida, Xiaoye S. Li, David H. Bailey. Algorithms for Quad-Double
+  Precision Floating Point Arithmetic. Proceedings 15th IEEE Symposium on
+  Computer Arithmetic, 2001, pp. 155-162. 10.1109/ARITH.2001.930115.
+
 * S. Chevillard, M. Joldeș and C. Lauter. Sollya: an environment for the
   development of numerical codes. Mathematical Software - ICMS 2010, pp.
   28–31.
@@ -64,6 +148,7 @@ mod test_util;
 
 mod arithmetic;
 mod base;
+mod checked;
 
 /// Basic mathematical constants.
 ///
@@ -71,9 +156,41 @@ mod base;
 pub mod consts;
 
 mod convert;
+mod dual;
+mod expansion;
 mod functions;
+mod interval;
+
+/// Robust geometric predicates.
+///
+/// Each predicate is evaluated in `f64` arithmetic first, then in
+/// double-double arithmetic with a running error bound, and finally exactly
+/// with `Expansion` arithmetic if neither error bound certifies the sign of
+/// the result, following Shewchuk (1997). The sign of the result is always
+/// correct, provided that no intermediate result overflows or underflows.
+pub mod predicates;
+
+mod quadfloat;
+mod status;
+mod taylor;
+mod threefloat;
+mod tracked;
+mod twocomplex;
+mod twof32;
 
 pub use base::no_overlap;
+pub use dual::{Dual, DualN};
+pub use expansion::Expansion;
+pub use interval::TwoFloatInterval;
+pub use quadfloat::QuadFloat;
+pub use taylor::TaylorJet;
+pub use threefloat::ThreeFloat;
+pub use tracked::TrackedTwoFloat;
+pub use twocomplex::TwoComplex;
+pub use twof32::TwoF32;
+
+#[cfg(feature = "fp_status")]
+pub use status::{FpEnv, FpFlags};
 
 use core::fmt;
 use std::error;
@@ -94,16 +211,35 @@ pub struct TwoFloat {
 
 /// The error type for `TwoFloat` operations.
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TwoFloatError {
     /// Indicates invalid conversion to/from `TwoFloat`
     ConversionError,
+    /// Indicates that the result of an operation was too large to be
+    /// represented as a finite `TwoFloat`
+    Overflow,
+    /// Indicates that the result of an operation was too small to be
+    /// represented with the full precision of a `TwoFloat`
+    Underflow,
+    /// Indicates an operation with an exact infinite result from finite
+    /// arguments, such as division by zero or the logarithm of zero
+    DivisionByZero,
+    /// Indicates that an operation was called with arguments outside its
+    /// domain, or with non-finite arguments
+    DomainError {
+        /// The name of the operation
+        op: &'static str,
+    },
 }
 
 impl fmt::Display for TwoFloatError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::ConversionError => write!(f, "invalid TwoFloat conversion"),
+            Self::Overflow => write!(f, "TwoFloat overflow"),
+            Self::Underflow => write!(f, "TwoFloat underflow"),
+            Self::DivisionByZero => write!(f, "TwoFloat division by zero"),
+            Self::DomainError { op } => write!(f, "invalid argument to TwoFloat {}", op),
         }
     }
 }
@@ -2,11 +2,11 @@
 
 macro_rules! op_trait_impl {
     (
-        $trait:ident, $name:ident, $($ab:lifetime,)*
+        $trait:ident, $name:ident, [$($g:tt)*],
         $slf:ident, $lt:ty, $rhs:ident, $rt:ty,
         $ot:ty, $($meta:meta,)* $code:block
     ) => {
-        impl<$($ab,)*> $trait<$rt> for $lt {
+        impl<$($g)*> $trait<$rt> for $lt {
             type Output = $ot;
 
             $(#[$meta])*
@@ -14,21 +14,21 @@ macro_rules! op_trait_impl {
         }
     };
     (
-        $trait:ident, $name:ident, $($ab:lifetime,)*
+        $trait:ident, $name:ident, [$($g:tt)*],
         $slf:ident, $lt:ty, $rhs:ident, $rt:ty,
         $($meta:meta,)* $code:block
     ) => {
-        impl<$($ab,)*> $trait<$rt> for $lt {
+        impl<$($g)*> $trait<$rt> for $lt {
             $(#[$meta])*
             fn $name(&mut $slf, $rhs:$rt) $code
         }
     };
     (
-        $trait:ident, $name:ident, $($a:lifetime,)?
+        $trait:ident, $name:ident, [$($g:tt)*],
         $slf:ident, $t:ty, $ot:ty,
         $($meta:meta,)* $code:block
     ) => {
-        impl<$($a)?> $trait for $t {
+        impl<$($g)*> $trait for $t {
             type Output = $ot;
 
             $(#[$meta])*
@@ -40,37 +40,37 @@ macro_rules! op_trait_impl {
 macro_rules! binary_ops {
     (
         $(#[$meta:meta])*
-        fn $trait:ident::$name:ident<$($ab:lifetime),+>(
+        fn $trait:ident::$name:ident<$($ab:lifetime),+ $(; const $n:ident: usize)?>(
             $slf:ident: &$a:lifetime $lt:ty, $rhs:ident: &$b:lifetime $rt:ty) -> $ot:ty
         $code:block
     ) => {
-        op_trait_impl!($trait, $name, $($ab),+, $slf, &$a $lt, $rhs, &$b $rt, $ot, $($meta,)* $code);
-        op_trait_impl!($trait, $name, $a, $slf, &$a $lt, $rhs, $rt, $ot, $($meta,)* { $slf.$name(&$rhs) });
-        op_trait_impl!($trait, $name, $b, $slf, $lt, $rhs, &$b $rt, $ot, $($meta,)* { (&$slf).$name($rhs) });
-        op_trait_impl!($trait, $name, $slf, $lt, $rhs, $rt, $ot, $($meta,)* { (&$slf).$name(&$rhs) });
+        op_trait_impl!($trait, $name, [$($ab,)+ $(const $n: usize)?], $slf, &$a $lt, $rhs, &$b $rt, $ot, $($meta,)* $code);
+        op_trait_impl!($trait, $name, [$a, $(const $n: usize)?], $slf, &$a $lt, $rhs, $rt, $ot, $($meta,)* { $slf.$name(&$rhs) });
+        op_trait_impl!($trait, $name, [$b, $(const $n: usize)?], $slf, $lt, $rhs, &$b $rt, $ot, $($meta,)* { (&$slf).$name($rhs) });
+        op_trait_impl!($trait, $name, [$(const $n: usize)?], $slf, $lt, $rhs, $rt, $ot, $($meta,)* { (&$slf).$name(&$rhs) });
     };
     (
         $(#[$meta:meta])*
-        fn $trait:ident::$name:ident<$($ab:lifetime),+>(
+        fn $trait:ident::$name:ident<$($ab:lifetime),+ $(; const $n:ident: usize)?>(
             $slf:ident: &$a:lifetime $lt:ty, $rhs:ident: &$b:lifetime $rt:ty) -> $ot:ty
         $code:block
         $(
             $(#[$metas:meta])*
-            fn $traits:ident::$names:ident<$($abs:lifetime),+>(
+            fn $traits:ident::$names:ident<$($abs:lifetime),+ $(; const $ns:ident: usize)?>(
                 $slfs:ident: &$as:lifetime $lts:ty, $rhss:ident: &$bs:lifetime $rts:ty) -> $ots:ty
             $codes:block
         )+
     ) => {
         binary_ops! {
             $(#[$meta])*
-            fn $trait::$name<$($ab),+>($slf: &$a $lt, $rhs: &$b $rt) -> $ot
+            fn $trait::$name<$($ab),+ $(; const $n: usize)?>($slf: &$a $lt, $rhs: &$b $rt) -> $ot
             $code
         }
 
         binary_ops! {
             $(
                 $(#[$metas])*
-                fn $traits::$names<$($abs),+>($slfs: &$as $lts, $rhss: &$bs $rts) -> $ots
+                fn $traits::$names<$($abs),+ $(; const $ns: usize)?>($slfs: &$as $lts, $rhss: &$bs $rts) -> $ots
                 $codes
             )+
         }
@@ -80,31 +80,31 @@ macro_rules! binary_ops {
 macro_rules! assign_ops {
     (
         $(#[$meta:meta])*
-        fn $trait:ident::$name:ident<$a:lifetime>(
+        fn $trait:ident::$name:ident<$a:lifetime $(; const $n:ident: usize)?>(
             $slf:ident: &mut $lt:ty, $rhs:ident: &$aa:lifetime $rt:ty) $code:block
     ) => {
-        op_trait_impl!($trait, $name, $a, $slf, $lt, $rhs, &$aa $rt, $($meta,)* $code);
-        op_trait_impl!($trait, $name, $a, $slf, $lt, $rhs, $rt, $($meta,)* { $slf.$name(&$rhs); });
+        op_trait_impl!($trait, $name, [$a, $(const $n: usize)?], $slf, $lt, $rhs, &$aa $rt, $($meta,)* $code);
+        op_trait_impl!($trait, $name, [$(const $n: usize)?], $slf, $lt, $rhs, $rt, $($meta,)* { $slf.$name(&$rhs); });
     };
     (
         $(#[$meta:meta])*
-        fn $trait:ident::$name:ident<$a:lifetime>(
+        fn $trait:ident::$name:ident<$a:lifetime $(; const $n:ident: usize)?>(
             $slf:ident: &mut $lt:ty, $rhs:ident: &$aa:lifetime $rt:ty) $code:block
         $(
             $(#[$metas:meta])*
-            fn $traits:ident::$names:ident<$as:lifetime>(
+            fn $traits:ident::$names:ident<$as:lifetime $(; const $ns:ident: usize)?>(
                 $slfs:ident: &mut $lts:ty, $rhss:ident: &$aas:lifetime $rts:ty) $codes:block
         )+
     ) => {
         assign_ops! {
             $(#[$meta])*
-            fn $trait::$name<$a>($slf: &mut $lt, $rhs: &$aa $rt) $code
+            fn $trait::$name<$a $(; const $n: usize)?>($slf: &mut $lt, $rhs: &$aa $rt) $code
         }
 
         assign_ops! {
             $(
                 $(#[$metas])*
-                fn $traits::$names<$as>($slfs: &mut $lts, $rhss: &$aas $rts) $codes
+                fn $traits::$names<$as $(; const $ns: usize)?>($slfs: &mut $lts, $rhss: &$aas $rts) $codes
             )+
         }
     };
@@ -113,9 +113,9 @@ macro_rules! assign_ops {
 macro_rules! unary_ops {
     (
         $(#[$meta:meta])*
-        fn $trait:ident::$name:ident($slf:ident: &$t:ty) -> $ot:ty $code:block
+        fn $trait:ident::$name:ident$(<const $n:ident: usize>)?($slf:ident: &$t:ty) -> $ot:ty $code:block
     ) => {
-        op_trait_impl!($trait, $name, 'a, $slf, &'a $t, $ot, $($meta,)* $code);
-        op_trait_impl!($trait, $name, $slf, $t, $ot, $($meta,)* { (&$slf).$name() });
+        op_trait_impl!($trait, $name, ['a, $(const $n: usize)?], $slf, &'a $t, $ot, $($meta,)* $code);
+        op_trait_impl!($trait, $name, [$(const $n: usize)?], $slf, $t, $ot, $($meta,)* { (&$slf).$name() });
     };
 }
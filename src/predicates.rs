@@ -0,0 +1,316 @@
+use crate::{Expansion, TrackedTwoFloat, TwoFloat};
+
+// Relative error bounds for the f64 evaluation of each determinant, in terms
+// of the permanent, from Shewchuk (1997).
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT2D_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ORIENT3D_ERROR_BOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const INCIRCLE_ERROR_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const INSPHERE_ERROR_BOUND: f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+
+/// Returns a positive value if the points `a`, `b` and `c` occur in
+/// counterclockwise order, a negative value if they occur in clockwise
+/// order, and zero if they are collinear. The result approximates twice the
+/// signed area of the triangle.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::predicates::orient2d;
+/// assert!(orient2d([0.0, 0.0], [1.0, 0.0], [0.0, 1.0]) > 0.0);
+/// assert!(orient2d([0.0, 0.0], [0.0, 1.0], [1.0, 0.0]) < 0.0);
+///
+/// // Nearly collinear points
+/// let c = [1.0 + f64::EPSILON, 1.0 + 2.0 * f64::EPSILON];
+/// assert!(orient2d([0.5, 0.5], [12.0, 12.0], c) > 0.0);
+/// assert_eq!(orient2d([0.5, 0.5], [12.0, 12.0], [24.0, 24.0]), 0.0);
+/// ```
+pub fn orient2d(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    let left = (a[0] - c[0]) * (b[1] - c[1]);
+    let right = (a[1] - c[1]) * (b[0] - c[0]);
+    let det = left - right;
+
+    let permanent = left.abs() + right.abs();
+    if det.abs() > ORIENT2D_ERROR_BOUND * permanent {
+        return det;
+    }
+
+    let (ac, bc) = (differences(a, c), differences(b, c));
+    adaptive(orient2d_det(ac, bc), || orient2d_det(ac, bc))
+}
+
+/// Returns a positive value if the point `d` lies below the plane through
+/// `a`, `b` and `c`, a negative value if it lies above the plane, and zero
+/// if the points are coplanar. Here, "below" is defined so that `a`, `b` and
+/// `c` appear in counterclockwise order when viewed from above the plane.
+/// The result approximates six times the signed volume of the tetrahedron.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::predicates::orient3d;
+/// let (a, b, c) = ([0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]);
+///
+/// assert_eq!(orient3d(a, b, c, [0.0, 0.0, -1.0]), 1.0);
+/// assert_eq!(orient3d(a, b, c, [0.0, 0.0, 1.0]), -1.0);
+/// assert_eq!(orient3d(a, b, c, [0.3, 0.7, 0.0]), 0.0);
+/// ```
+pub fn orient3d(a: [f64; 3], b: [f64; 3], c: [f64; 3], d: [f64; 3]) -> f64 {
+    let [adx, ady, adz] = [a[0] - d[0], a[1] - d[1], a[2] - d[2]];
+    let [bdx, bdy, bdz] = [b[0] - d[0], b[1] - d[1], b[2] - d[2]];
+    let [cdx, cdy, cdz] = [c[0] - d[0], c[1] - d[1], c[2] - d[2]];
+
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let det = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs()
+        + (cdxady.abs() + adxcdy.abs()) * bdz.abs()
+        + (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+    if det.abs() > ORIENT3D_ERROR_BOUND * permanent {
+        return det;
+    }
+
+    let (ad, bd, cd) = (differences(a, d), differences(b, d), differences(c, d));
+    adaptive(orient3d_det(ad, bd, cd), || orient3d_det(ad, bd, cd))
+}
+
+/// Returns a positive value if the point `d` lies inside the circle through
+/// `a`, `b` and `c`, a negative value if it lies outside, and zero if the
+/// four points are cocircular, where `a`, `b` and `c` must occur in
+/// counterclockwise order. The sign is reversed if they occur in clockwise
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::predicates::incircle;
+/// let (a, b, c) = ([1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]);
+///
+/// assert!(incircle(a, b, c, [0.0, 0.5]) > 0.0);
+/// assert!(incircle(a, b, c, [0.0, -2.0]) < 0.0);
+/// assert_eq!(incircle(a, b, c, [0.0, -1.0]), 0.0);
+/// ```
+pub fn incircle(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> f64 {
+    let [adx, ady] = [a[0] - d[0], a[1] - d[1]];
+    let [bdx, bdy] = [b[0] - d[0], b[1] - d[1]];
+    let [cdx, cdy] = [c[0] - d[0], c[1] - d[1]];
+
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    if det.abs() > INCIRCLE_ERROR_BOUND * permanent {
+        return det;
+    }
+
+    let (ad, bd, cd) = (differences(a, d), differences(b, d), differences(c, d));
+    adaptive(incircle_det(ad, bd, cd), || incircle_det(ad, bd, cd))
+}
+
+/// Returns a positive value if the point `e` lies inside the sphere through
+/// `a`, `b`, `c` and `d`, a negative value if it lies outside, and zero if
+/// the five points are cospherical, where `orient3d(a, b, c, d)` must be
+/// positive. The sign is reversed if it is negative.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::predicates::insphere;
+/// let (a, b, c, d) = ([0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]);
+///
+/// assert!(insphere(a, b, c, d, [0.25, 0.25, 0.25]) > 0.0);
+/// assert!(insphere(a, b, c, d, [2.0, 2.0, 2.0]) < 0.0);
+/// assert_eq!(insphere(a, b, c, d, [1.0, 1.0, 1.0]), 0.0);
+/// ```
+pub fn insphere(a: [f64; 3], b: [f64; 3], c: [f64; 3], d: [f64; 3], e: [f64; 3]) -> f64 {
+    let [aex, aey, aez] = [a[0] - e[0], a[1] - e[1], a[2] - e[2]];
+    let [bex, bey, bez] = [b[0] - e[0], b[1] - e[1], b[2] - e[2]];
+    let [cex, cey, cez] = [c[0] - e[0], c[1] - e[1], c[2] - e[2]];
+    let [dex, dey, dez] = [d[0] - e[0], d[1] - e[1], d[2] - e[2]];
+
+    let (aexbey, bexaey) = (aex * bey, bex * aey);
+    let (bexcey, cexbey) = (bex * cey, cex * bey);
+    let (cexdey, dexcey) = (cex * dey, dex * cey);
+    let (dexaey, aexdey) = (dex * aey, aex * dey);
+    let (aexcey, cexaey) = (aex * cey, cex * aey);
+    let (bexdey, dexbey) = (bex * dey, dex * bey);
+    let (ab, bc, cd, da) = (
+        aexbey - bexaey,
+        bexcey - cexbey,
+        cexdey - dexcey,
+        dexaey - aexdey,
+    );
+    let (ac, bd) = (aexcey - cexaey, bexdey - dexbey);
+
+    let abc = aez * bc - bez * ac + cez * ab;
+    let bcd = bez * cd - cez * bd + dez * bc;
+    let cda = cez * da + dez * ac + aez * cd;
+    let dab = dez * ab + aez * bd + bez * da;
+    let alift = aex * aex + aey * aey + aez * aez;
+    let blift = bex * bex + bey * bey + bez * bez;
+    let clift = cex * cex + cey * cey + cez * cez;
+    let dlift = dex * dex + dey * dey + dez * dez;
+    let det = (dlift * abc - clift * dab) + (blift * cda - alift * bcd);
+
+    let (aez, bez, cez, dez) = (aez.abs(), bez.abs(), cez.abs(), dez.abs());
+    let ab = aexbey.abs() + bexaey.abs();
+    let bc = bexcey.abs() + cexbey.abs();
+    let cd = cexdey.abs() + dexcey.abs();
+    let da = dexaey.abs() + aexdey.abs();
+    let ac = aexcey.abs() + cexaey.abs();
+    let bd = bexdey.abs() + dexbey.abs();
+    let permanent = (cd * bez + bd * cez + bc * dez) * alift
+        + (da * cez + ac * dez + cd * aez) * blift
+        + (ab * dez + bd * aez + da * bez) * clift
+        + (bc * aez + ac * bez + ab * cez) * dlift;
+    if det.abs() > INSPHERE_ERROR_BOUND * permanent {
+        return det;
+    }
+
+    let (ae, be) = (differences(a, e), differences(b, e));
+    let (ce, de) = (differences(c, e), differences(d, e));
+    adaptive(insphere_det(ae, be, ce, de), || {
+        insphere_det(ae, be, ce, de)
+    })
+}
+
+// Returns the exact differences of the coordinates of two points.
+fn differences<const N: usize>(p: [f64; N], q: [f64; N]) -> [TwoFloat; N] {
+    let mut result = [TwoFloat::ZERO; N];
+    for (r, (x, y)) in result.iter_mut().zip(p.iter().zip(q.iter())) {
+        *r = TwoFloat::new_sub(*x, *y);
+    }
+    result
+}
+
+// Returns the sign of a determinant evaluated in double-double arithmetic if
+// its error bound certifies it, and otherwise evaluates the determinant
+// exactly.
+fn adaptive(tracked: TrackedTwoFloat, exact: impl FnOnce() -> Expansion) -> f64 {
+    let value = tracked.value();
+    if value.abs() > tracked.error() {
+        value.hi()
+    } else {
+        f64::from(exact())
+    }
+}
+
+// Arithmetic in which the determinants are evaluated when the f64 error
+// bound cannot certify their sign, either with a running error bound or
+// exactly.
+trait Arithmetic: From<TwoFloat> {
+    fn add(&self, rhs: &Self) -> Self;
+    fn sub(&self, rhs: &Self) -> Self;
+    fn mul(&self, rhs: &Self) -> Self;
+}
+
+impl Arithmetic for TrackedTwoFloat {
+    fn add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+}
+
+impl Arithmetic for Expansion {
+    fn add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+}
+
+// Returns a d - b c.
+fn minor<T: Arithmetic>(a: &T, b: &T, c: &T, d: &T) -> T {
+    a.mul(d).sub(&b.mul(c))
+}
+
+// Returns the dot product of two vectors.
+fn dot<T: Arithmetic>(a: [&T; 3], b: [&T; 3]) -> T {
+    a[0].mul(b[0]).add(&a[1].mul(b[1])).add(&a[2].mul(b[2]))
+}
+
+fn sum_of_squares<T: Arithmetic>(v: &[T]) -> T {
+    let squares = v.iter().map(|x| x.mul(x));
+    squares.fold(T::from(TwoFloat::ZERO), |sum, x| sum.add(&x))
+}
+
+fn orient2d_det<T: Arithmetic>(ac: [TwoFloat; 2], bc: [TwoFloat; 2]) -> T {
+    let [acx, acy] = ac.map(T::from);
+    let [bcx, bcy] = bc.map(T::from);
+    minor(&acx, &acy, &bcx, &bcy)
+}
+
+fn orient3d_det<T: Arithmetic>(ad: [TwoFloat; 3], bd: [TwoFloat; 3], cd: [TwoFloat; 3]) -> T {
+    let [adx, ady, adz] = ad.map(T::from);
+    let [bdx, bdy, bdz] = bd.map(T::from);
+    let [cdx, cdy, cdz] = cd.map(T::from);
+    let bc = minor(&bdx, &bdy, &cdx, &cdy);
+    let ca = minor(&cdx, &cdy, &adx, &ady);
+    let ab = minor(&adx, &ady, &bdx, &bdy);
+    dot([&adz, &bdz, &cdz], [&bc, &ca, &ab])
+}
+
+fn incircle_det<T: Arithmetic>(ad: [TwoFloat; 2], bd: [TwoFloat; 2], cd: [TwoFloat; 2]) -> T {
+    let [adx, ady] = ad.map(T::from);
+    let [bdx, bdy] = bd.map(T::from);
+    let [cdx, cdy] = cd.map(T::from);
+    let bc = minor(&bdx, &bdy, &cdx, &cdy);
+    let ca = minor(&cdx, &cdy, &adx, &ady);
+    let ab = minor(&adx, &ady, &bdx, &bdy);
+    let alift = sum_of_squares(&[adx, ady]);
+    let blift = sum_of_squares(&[bdx, bdy]);
+    let clift = sum_of_squares(&[cdx, cdy]);
+    dot([&alift, &blift, &clift], [&bc, &ca, &ab])
+}
+
+fn insphere_det<T: Arithmetic>(
+    ae: [TwoFloat; 3],
+    be: [TwoFloat; 3],
+    ce: [TwoFloat; 3],
+    de: [TwoFloat; 3],
+) -> T {
+    let [aex, aey, aez] = ae.map(T::from);
+    let [bex, bey, bez] = be.map(T::from);
+    let [cex, cey, cez] = ce.map(T::from);
+    let [dex, dey, dez] = de.map(T::from);
+    let ab = minor(&aex, &aey, &bex, &bey);
+    let bc = minor(&bex, &bey, &cex, &cey);
+    let cd = minor(&cex, &cey, &dex, &dey);
+    let da = minor(&dex, &dey, &aex, &aey);
+    let ac = minor(&aex, &aey, &cex, &cey);
+    let ca = minor(&cex, &cey, &aex, &aey);
+    let bd = minor(&bex, &bey, &dex, &dey);
+    let db = minor(&dex, &dey, &bex, &bey);
+
+    let abc = dot([&aez, &bez, &cez], [&bc, &ca, &ab]);
+    let bcd = dot([&bez, &cez, &dez], [&cd, &db, &bc]);
+    let cda = dot([&cez, &dez, &aez], [&da, &ac, &cd]);
+    let dab = dot([&dez, &aez, &bez], [&ab, &bd, &da]);
+    let alift = sum_of_squares(&[aex, aey, aez]);
+    let blift = sum_of_squares(&[bex, bey, bez]);
+    let clift = sum_of_squares(&[cex, cey, cez]);
+    let dlift = sum_of_squares(&[dex, dey, dez]);
+    minor(&dlift, &clift, &dab, &abc).add(&minor(&blift, &alift, &bcd, &cda))
+}
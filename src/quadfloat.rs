@@ -0,0 +1,381 @@
+use core::cmp::Ordering;
+
+#[cfg(feature = "serde_support")]
+use serde::{Deserialize, Serialize};
+
+use crate::{base::no_overlap, TwoFloat};
+
+mod arithmetic;
+mod convert;
+#[cfg(feature = "math_funcs")]
+mod functions;
+
+pub(crate) use arithmetic::from_terms;
+
+/// Represents a four-word floating point type, represented as the sum of
+/// four non-overlapping f64 values.
+///
+/// `QuadFloat` provides about 212 bits of precision, for problems such as
+/// integer relation detection where `TwoFloat` is not sufficient. As in the
+/// quad-double arithmetic of Hida et al. (2001), results are computed from
+/// the partial products of the words, which are renormalized as for
+/// `ThreeFloat`. If the `math_funcs` feature is
+/// enabled, `sqrt`, `exp`, `ln` and the trigonometric functions are also
+/// provided.
+///
+/// Values are formatted in scientific notation with 64 significant digits
+/// by default, and may be parsed from decimal strings. Conversion from
+/// `TwoFloat` is exact, and converting the result back to `TwoFloat` returns
+/// the original value.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{QuadFloat, TwoFloat};
+/// let a: QuadFloat = "0.1".parse().unwrap();
+/// let b = QuadFloat::from(1.0) / 10.0;
+///
+/// assert_eq!(a, b);
+/// assert_eq!(
+///     format!("{:.40}", a * 3.0),
+///     "3.0000000000000000000000000000000000000000e-1"
+/// );
+///
+/// let c = TwoFloat::new_add(1.0, 1e-20);
+/// assert_eq!(TwoFloat::from(QuadFloat::from(c)), c);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_support", serde(try_from = "[f64; 4]"))]
+#[cfg_attr(feature = "serde_support", serde(into = "[f64; 4]"))]
+pub struct QuadFloat {
+    pub(crate) words: [f64; 4],
+}
+
+impl QuadFloat {
+    /// Represents an error value equivalent to `f64::NAN`.
+    pub const NAN: Self = Self {
+        words: [f64::NAN; 4],
+    };
+
+    /// Positive infinity.
+    pub const INFINITY: Self = Self {
+        words: [f64::INFINITY, 0.0, 0.0, 0.0],
+    };
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Self {
+        words: [f64::NEG_INFINITY, 0.0, 0.0, 0.0],
+    };
+
+    /// The value zero.
+    pub const ZERO: Self = Self { words: [0.0; 4] };
+
+    /// The value one.
+    pub const ONE: Self = Self {
+        words: [1.0, 0.0, 0.0, 0.0],
+    };
+
+    /// Number of significant binary digits, treating a `QuadFloat` as having
+    /// the combined precision of its four `f64` components.
+    pub const MANTISSA_DIGITS: u32 = 4 * f64::MANTISSA_DIGITS;
+
+    /// Machine epsilon value for `QuadFloat`: 2<sup>-211</sup>.
+    pub const EPSILON: Self = Self {
+        words: [3.0385816786431356e-64, 0.0, 0.0, 0.0],
+    };
+
+    /// Smallest positive value retaining the full `MANTISSA_DIGITS` bits of
+    /// precision: 2<sup>-863</sup>.
+    pub const MIN_POSITIVE: Self = Self {
+        words: [1.6259745436952323e-260, 0.0, 0.0, 0.0],
+    };
+
+    /// Archimedes' constant (π).
+    pub const PI: Self = Self {
+        words: [
+            3.141592653589793,
+            1.2246467991473532e-16,
+            -2.9947698097183397e-33,
+            1.1124542208633653e-49,
+        ],
+    };
+
+    /// π/2
+    pub const FRAC_PI_2: Self = Self {
+        words: [
+            1.5707963267948966,
+            6.123233995736766e-17,
+            -1.4973849048591698e-33,
+            5.562271104316826e-50,
+        ],
+    };
+
+    /// π/4
+    pub const FRAC_PI_4: Self = Self {
+        words: [
+            0.7853981633974483,
+            3.061616997868383e-17,
+            -7.486924524295849e-34,
+            2.781135552158413e-50,
+        ],
+    };
+
+    /// Euler's number (e).
+    pub const E: Self = Self {
+        words: [
+            2.718281828459045,
+            1.4456468917292502e-16,
+            -2.1277171080381768e-33,
+            1.5156301598412191e-49,
+        ],
+    };
+
+    /// ln(2)
+    pub const LN_2: Self = Self {
+        words: [
+            0.6931471805599453,
+            2.3190468138462996e-17,
+            5.707708438416212e-34,
+            -3.5824322106018114e-50,
+        ],
+    };
+
+    /// ln(10)
+    pub const LN_10: Self = Self {
+        words: [
+            2.302585092994046,
+            -2.1707562233822494e-16,
+            -9.984262454465777e-33,
+            -4.023357454450206e-49,
+        ],
+    };
+
+    /// Returns the high word of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let value = QuadFloat::from(1.0) + 1.0e-200;
+    /// assert_eq!(value.hi(), 1.0);
+    pub fn hi(&self) -> f64 {
+        self.words[0]
+    }
+
+    /// Returns the words of `self`, in order of decreasing magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let value = QuadFloat::from(1.0) + 1.0e-200;
+    /// assert_eq!(value.words(), [1.0, 1.0e-200, 0.0, 0.0]);
+    pub fn words(&self) -> [f64; 4] {
+        self.words
+    }
+
+    /// Returns `true` if `self` is a valid value, where all components are
+    /// finite and do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = (QuadFloat::from(1.0) / 3.0).is_valid();
+    /// let b = QuadFloat::NAN.is_valid();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    pub fn is_valid(&self) -> bool {
+        self.is_finite() && self.words.windows(2).all(|w| no_overlap(w[0], w[1]))
+    }
+
+    /// Returns `true` if `self` is `NAN`.
+    pub fn is_nan(&self) -> bool {
+        self.words.iter().any(|w| w.is_nan())
+    }
+
+    /// Returns `true` if `self` is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        self.words[0].is_infinite() && !self.words[1..].iter().any(|w| w.is_nan())
+    }
+
+    /// Returns `true` if `self` is neither infinite nor `NAN`.
+    pub fn is_finite(&self) -> bool {
+        self.words.iter().all(|w| w.is_finite())
+    }
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0`.
+    pub fn is_sign_positive(&self) -> bool {
+        self.words[0].is_sign_positive()
+    }
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0`.
+    pub fn is_sign_negative(&self) -> bool {
+        self.words[0].is_sign_negative()
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-3.5);
+    ///
+    /// assert_eq!(a.abs(), 3.5);
+    pub fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Returns the largest integer less than or equal to the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1.0) + 1e-60;
+    /// let b = QuadFloat::from(1.0) - 1e-60;
+    ///
+    /// assert_eq!(a.floor(), 1.0);
+    /// assert_eq!(b.floor(), 0.0);
+    pub fn floor(self) -> Self {
+        if !self.is_finite() {
+            return self;
+        }
+        match self.words.iter().position(|w| w.fract() != 0.0) {
+            Some(i) => {
+                let mut terms = self.words;
+                terms[i] = terms[i].floor();
+                terms[i + 1..].iter_mut().for_each(|w| *w = 0.0);
+                from_terms(&mut terms)
+            }
+            None => self,
+        }
+    }
+
+    /// Returns the smallest integer greater than or equal to the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1.0) + 1e-60;
+    /// let b = QuadFloat::from(1.0) - 1e-60;
+    ///
+    /// assert_eq!(a.ceil(), 2.0);
+    /// assert_eq!(b.ceil(), 1.0);
+    pub fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+
+    /// Returns the integer part of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(-1.0) + 1e-60;
+    ///
+    /// assert_eq!(a.trunc(), 0.0);
+    pub fn trunc(self) -> Self {
+        if self.is_sign_positive() {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+
+    /// Returns the nearest integer to the value, rounding halfway cases away
+    /// from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.5) - 1e-60;
+    ///
+    /// assert_eq!(a.round(), 2.0);
+    /// assert_eq!(QuadFloat::from(-2.5).round(), -3.0);
+    pub fn round(self) -> Self {
+        let truncated = self.trunc();
+        if (self - truncated).abs() >= 0.5 {
+            if self.is_sign_positive() {
+                truncated + 1.0
+            } else {
+                truncated - 1.0
+            }
+        } else {
+            truncated
+        }
+    }
+}
+
+impl PartialEq<f64> for QuadFloat {
+    fn eq(&self, other: &f64) -> bool {
+        self.eq(&Self::from(*other))
+    }
+}
+
+impl PartialEq<QuadFloat> for f64 {
+    fn eq(&self, other: &QuadFloat) -> bool {
+        QuadFloat::from(*self).eq(other)
+    }
+}
+
+impl PartialOrd<f64> for QuadFloat {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.partial_cmp(&Self::from(*other))
+    }
+}
+
+impl PartialOrd<QuadFloat> for f64 {
+    fn partial_cmp(&self, other: &QuadFloat) -> Option<Ordering> {
+        QuadFloat::from(*self).partial_cmp(other)
+    }
+}
+
+impl PartialEq<TwoFloat> for QuadFloat {
+    fn eq(&self, other: &TwoFloat) -> bool {
+        self.eq(&Self::from(*other))
+    }
+}
+
+impl PartialEq<QuadFloat> for TwoFloat {
+    fn eq(&self, other: &QuadFloat) -> bool {
+        QuadFloat::from(*self).eq(other)
+    }
+}
+
+impl PartialOrd<TwoFloat> for QuadFloat {
+    fn partial_cmp(&self, other: &TwoFloat) -> Option<Ordering> {
+        self.partial_cmp(&Self::from(*other))
+    }
+}
+
+impl PartialOrd<QuadFloat> for TwoFloat {
+    fn partial_cmp(&self, other: &QuadFloat) -> Option<Ordering> {
+        QuadFloat::from(*self).partial_cmp(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuadFloat;
+
+    #[test]
+    fn is_infinite_test() {
+        let nan_lo = QuadFloat {
+            words: [f64::INFINITY, 0.0, 0.0, f64::NAN],
+        };
+
+        assert!(!nan_lo.is_infinite());
+        assert!(nan_lo.is_nan());
+        assert!(QuadFloat::NEG_INFINITY.is_infinite());
+    }
+}
@@ -0,0 +1,326 @@
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use super::QuadFloat;
+use crate::{
+    arithmetic::{self, classify_words, two_prod, Operation, UNDERFLOW_SCALE},
+    status, TwoFloat,
+};
+
+// Renormalizes the terms into four words, discarding the error beyond the
+// last word.
+pub(crate) fn from_terms(terms: &mut [f64]) -> QuadFloat {
+    let mut words = [0.0; 4];
+    arithmetic::renormalize(terms, &mut words);
+    QuadFloat { words }
+}
+
+// Replaces a non-finite or zero result with the IEEE result of the
+// equivalent f64 operation on the high words `a` and `b`, using the
+// classification of `TwoFloat` arithmetic, and raises any exception in the
+// current status flags. A tiny result only underflows if `lost_precision`
+// returns true.
+#[inline]
+fn check_result(
+    result: QuadFloat,
+    op: Operation,
+    a: f64,
+    b: f64,
+    lost_precision: impl FnOnce(&QuadFloat) -> bool,
+) -> QuadFloat {
+    let (value, flags) = classify_words(
+        result.words[0],
+        QuadFloat::MIN_POSITIVE.words[0],
+        op,
+        a,
+        b,
+        || result.abs() < QuadFloat::MIN_POSITIVE && lost_precision(&result),
+    );
+    status::raise(flags, op.name());
+    match value {
+        None => result,
+        Some(value) if value.is_nan() => QuadFloat::NAN,
+        Some(value) => QuadFloat::from(value),
+    }
+}
+
+// Checks a sum or difference, which is exact when tiny, so never underflows.
+#[inline]
+fn check_special(result: QuadFloat, op: Operation, a: f64, b: f64) -> QuadFloat {
+    check_result(result, op, a, b, |_| false)
+}
+
+// Checks a product or quotient, where `scaled` repeats the operation with an
+// operand multiplied by `UNDERFLOW_SCALE`, as for `TwoFloat`.
+#[inline]
+fn check_scaled(
+    result: QuadFloat,
+    op: Operation,
+    a: f64,
+    b: f64,
+    scaled: impl FnOnce() -> QuadFloat,
+) -> QuadFloat {
+    check_result(result, op, a, b, |r| scaled() != scale_up(r))
+}
+
+#[inline]
+fn scale_up(x: &QuadFloat) -> QuadFloat {
+    let [a0, a1, a2, a3] = x.words;
+    QuadFloat {
+        words: [
+            a0 * UNDERFLOW_SCALE,
+            a1 * UNDERFLOW_SCALE,
+            a2 * UNDERFLOW_SCALE,
+            a3 * UNDERFLOW_SCALE,
+        ],
+    }
+}
+
+// The kernels below implement the arithmetic algorithms without handling
+// special values, as for `TwoFloat`.
+
+#[inline]
+fn add_f64(a: &QuadFloat, b: f64) -> QuadFloat {
+    let [a0, a1, a2, a3] = a.words;
+    from_terms(&mut [a0, a1, a2, a3, b])
+}
+
+#[inline]
+fn add(a: &QuadFloat, b: &QuadFloat) -> QuadFloat {
+    let [a0, a1, a2, a3] = a.words;
+    let [b0, b1, b2, b3] = b.words;
+    from_terms(&mut [a0, a1, a2, a3, b0, b1, b2, b3])
+}
+
+#[inline]
+fn mul_f64(a: &QuadFloat, b: f64) -> QuadFloat {
+    let (p0, e0) = two_prod(a.words[0], b).into();
+    let (p1, e1) = two_prod(a.words[1], b).into();
+    let (p2, e2) = two_prod(a.words[2], b).into();
+    let (p3, e3) = two_prod(a.words[3], b).into();
+    from_terms(&mut [p0, e0, p1, e1, p2, e2, p3, e3])
+}
+
+fn mul_f64_scaled(a: &QuadFloat, b: f64) -> QuadFloat {
+    if a.words[0].abs() < b.abs() {
+        mul_f64(&scale_up(a), b)
+    } else {
+        mul_f64(a, b * UNDERFLOW_SCALE)
+    }
+}
+
+#[inline]
+fn mul(a: &QuadFloat, b: &QuadFloat) -> QuadFloat {
+    // Products are split exactly down to the order of 2^-159 relative to the
+    // result, with the terms of order 2^-212 accumulated in a single word
+    // and smaller terms neglected.
+    let [a0, a1, a2, a3] = a.words;
+    let [b0, b1, b2, b3] = b.words;
+    let (p00, e00) = two_prod(a0, b0).into();
+    let (p01, e01) = two_prod(a0, b1).into();
+    let (p10, e10) = two_prod(a1, b0).into();
+    let (p02, e02) = two_prod(a0, b2).into();
+    let (p11, e11) = two_prod(a1, b1).into();
+    let (p20, e20) = two_prod(a2, b0).into();
+    let (p03, e03) = two_prod(a0, b3).into();
+    let (p12, e12) = two_prod(a1, b2).into();
+    let (p21, e21) = two_prod(a2, b1).into();
+    let (p30, e30) = two_prod(a3, b0).into();
+    let t = a1.mul_add(b3, a2.mul_add(b2, a3 * b1)) + ((e03 + e12) + (e21 + e30));
+    from_terms(&mut [
+        p00, e00, p01, p10, e01, e10, p02, p11, p20, e02, e11, e20, p03, p12, p21, p30, t,
+    ])
+}
+
+// Returns the residual a - b * q, rounded once to four words.
+#[inline]
+fn residual(a: &QuadFloat, b: &QuadFloat, q: f64) -> QuadFloat {
+    let [a0, a1, a2, a3] = a.words;
+    let (p0, e0) = two_prod(b.words[0], -q).into();
+    let (p1, e1) = two_prod(b.words[1], -q).into();
+    let (p2, e2) = two_prod(b.words[2], -q).into();
+    let (p3, e3) = two_prod(b.words[3], -q).into();
+    from_terms(&mut [a0, a1, a2, a3, p0, e0, p1, e1, p2, e2, p3, e3])
+}
+
+#[inline]
+fn div(a: &QuadFloat, b: &QuadFloat) -> QuadFloat {
+    // Long division, with each quotient word computed from the residual of
+    // the previous ones
+    let mut q = [0.0; 5];
+    let mut r = *a;
+    for qi in q.iter_mut().take(4) {
+        *qi = r.words[0] / b.words[0];
+        r = residual(&r, b, *qi);
+    }
+    q[4] = r.words[0] / b.words[0];
+    from_terms(&mut q)
+}
+
+// Gives a zero remainder the sign of the dividend, as for f64.
+#[inline]
+fn rem_sign(result: QuadFloat, lhs: f64) -> QuadFloat {
+    if result.words[0] == 0.0 {
+        QuadFloat::from(0.0f64.copysign(lhs))
+    } else {
+        result
+    }
+}
+
+#[inline]
+fn rem(a: &QuadFloat, b: &QuadFloat) -> QuadFloat {
+    status::function("rem", &[TwoFloat::from(a), TwoFloat::from(b)], || {
+        if b.is_infinite() && a.is_finite() {
+            return *a;
+        }
+        let quotient = (a / b).trunc();
+        rem_sign(a - quotient * b, a.words[0])
+    })
+}
+
+unary_ops! {
+    fn Neg::neg(self: &QuadFloat) -> QuadFloat {
+        let [a0, a1, a2, a3] = self.words;
+        Self::Output {
+            words: [-a0, -a1, -a2, -a3],
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements addition of `QuadFloat` and `f64`.
+    fn Add::add<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        check_special(add_f64(self, *rhs), Operation::Add, self.words[0], *rhs)
+    }
+
+    /// Implements addition of `f64` and `QuadFloat`.
+    fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        check_special(add_f64(rhs, *self), Operation::Add, *self, rhs.words[0])
+    }
+
+    /// Implements addition of two `QuadFloat` values by exact summation of
+    /// their words.
+    fn Add::add<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        check_special(add(self, rhs), Operation::Add, self.words[0], rhs.words[0])
+    }
+
+    /// Implements subtraction of `QuadFloat` and `f64`.
+    fn Sub::sub<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        check_special(add_f64(self, -rhs), Operation::Sub, self.words[0], *rhs)
+    }
+
+    /// Implements subtraction of `f64` and `QuadFloat`.
+    fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        check_special(add_f64(&-rhs, *self), Operation::Sub, *self, rhs.words[0])
+    }
+
+    /// Implements subtraction of two `QuadFloat` values by exact summation
+    /// of their words.
+    fn Sub::sub<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        check_special(add(self, &-rhs), Operation::Sub, self.words[0], rhs.words[0])
+    }
+
+    /// Implements multiplication of `QuadFloat` and `f64`.
+    fn Mul::mul<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        check_scaled(mul_f64(self, *rhs), Operation::Mul, self.words[0], *rhs, || {
+            mul_f64_scaled(self, *rhs)
+        })
+    }
+
+    /// Implements multiplication of `f64` and `QuadFloat`.
+    fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        check_scaled(mul_f64(rhs, *self), Operation::Mul, *self, rhs.words[0], || {
+            mul_f64_scaled(rhs, *self)
+        })
+    }
+
+    /// Implements multiplication of two `QuadFloat` values.
+    fn Mul::mul<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        check_scaled(mul(self, rhs), Operation::Mul, self.words[0], rhs.words[0], || {
+            if self.words[0].abs() < rhs.words[0].abs() {
+                mul(&scale_up(self), rhs)
+            } else {
+                mul(self, &scale_up(rhs))
+            }
+        })
+    }
+
+    /// Implements division of `QuadFloat` and `f64`.
+    fn Div::div<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        let b = QuadFloat::from(*rhs);
+        check_scaled(div(self, &b), Operation::Div, self.words[0], *rhs, || {
+            div(&scale_up(self), &b)
+        })
+    }
+
+    /// Implements division of `f64` and `QuadFloat`.
+    fn Div::div<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        check_scaled(div(&QuadFloat::from(*self), rhs), Operation::Div, *self, rhs.words[0], || {
+            div(&QuadFloat::from(*self * UNDERFLOW_SCALE), rhs)
+        })
+    }
+
+    /// Implements division of two `QuadFloat` values by long division.
+    fn Div::div<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        check_scaled(div(self, rhs), Operation::Div, self.words[0], rhs.words[0], || {
+            div(&scale_up(self), rhs)
+        })
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a QuadFloat, rhs: &'b f64) -> QuadFloat {
+        rem(self, &QuadFloat::from(*rhs))
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a f64, rhs: &'b QuadFloat) -> QuadFloat {
+        rem(&QuadFloat::from(*self), rhs)
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a QuadFloat, rhs: &'b QuadFloat) -> QuadFloat {
+        rem(self, rhs)
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = *self + rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = *self - rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = *self / rhs;
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut QuadFloat, rhs: &'a f64) {
+        *self = *self % rhs;
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut QuadFloat, rhs: &'a QuadFloat) {
+        *self = *self % rhs;
+    }
+}
@@ -0,0 +1,339 @@
+use core::{
+    convert::{From, TryFrom},
+    fmt,
+    str::FromStr,
+};
+
+use super::{from_terms, QuadFloat};
+use crate::{arithmetic::fast_two_sum, base::no_overlap, ThreeFloat, TwoFloat, TwoFloatError};
+
+// Significant digits written when no precision is given, enough to
+// distinguish values differing in the last bit of the mantissa.
+const DEFAULT_DIGITS: usize = 64;
+
+// Significant digits accumulated when parsing, beyond which further digits
+// cannot affect the rounded result.
+const MAX_PARSE_DIGITS: usize = 72;
+
+// Largest power of ten applied in a single step, chosen so that the power
+// itself is finite.
+const MAX_POW10_STEP: i32 = 300;
+
+impl From<f64> for QuadFloat {
+    fn from(value: f64) -> Self {
+        Self {
+            words: [value, 0.0, 0.0, 0.0],
+        }
+    }
+}
+
+impl From<QuadFloat> for f64 {
+    fn from(value: QuadFloat) -> Self {
+        value.words[0]
+    }
+}
+
+impl From<TwoFloat> for QuadFloat {
+    fn from(value: TwoFloat) -> Self {
+        Self {
+            words: [value.hi, value.lo, 0.0, 0.0],
+        }
+    }
+}
+
+impl<'a> From<&'a TwoFloat> for QuadFloat {
+    fn from(value: &'a TwoFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<ThreeFloat> for QuadFloat {
+    fn from(value: ThreeFloat) -> Self {
+        Self {
+            words: [value.hi, value.mid, value.lo, 0.0],
+        }
+    }
+}
+
+impl<'a> From<&'a ThreeFloat> for QuadFloat {
+    fn from(value: &'a ThreeFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<QuadFloat> for TwoFloat {
+    /// Rounds the value to the nearest `TwoFloat`.
+    fn from(value: QuadFloat) -> Self {
+        let [a0, a1, a2, a3] = value.words;
+        if a0.is_finite() && (a2 != 0.0 || a3 != 0.0) {
+            fast_two_sum(a0, a1 + (a2 + a3))
+        } else {
+            Self { hi: a0, lo: a1 }
+        }
+    }
+}
+
+impl<'a> From<&'a QuadFloat> for TwoFloat {
+    fn from(value: &'a QuadFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<QuadFloat> for ThreeFloat {
+    /// Rounds the value to the nearest `ThreeFloat`.
+    fn from(value: QuadFloat) -> Self {
+        let [a0, a1, a2, a3] = value.words;
+        if a0.is_finite() && a3 != 0.0 {
+            let rounded = from_terms(&mut [a0, a1, a2 + a3]);
+            Self {
+                hi: rounded.words[0],
+                mid: rounded.words[1],
+                lo: rounded.words[2],
+            }
+        } else {
+            Self {
+                hi: a0,
+                mid: a1,
+                lo: a2,
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a QuadFloat> for ThreeFloat {
+    fn from(value: &'a QuadFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<QuadFloat> for [f64; 4] {
+    fn from(value: QuadFloat) -> Self {
+        value.words
+    }
+}
+
+impl TryFrom<[f64; 4]> for QuadFloat {
+    type Error = TwoFloatError;
+
+    fn try_from(value: [f64; 4]) -> Result<Self, Self::Error> {
+        if value.windows(2).all(|w| no_overlap(w[0], w[1])) {
+            Ok(Self { words: value })
+        } else {
+            Err(Self::Error::ConversionError {})
+        }
+    }
+}
+
+// Computes 10^n for 0 <= n <= MAX_POW10_STEP by binary exponentiation. The
+// result is exact for n <= 91.
+fn pow10(n: i32) -> QuadFloat {
+    let mut result = QuadFloat::ONE;
+    let mut base = QuadFloat::from(10.0);
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        n >>= 1;
+    }
+    result
+}
+
+// Computes value * 10^n, dividing by exact powers of ten where possible and
+// splitting large exponents to avoid overflow of the intermediate powers.
+fn mul_pow10(value: QuadFloat, n: i32) -> QuadFloat {
+    let mut result = value;
+    let mut n = n;
+    while n != 0 && result.is_finite() && result.words[0] != 0.0 {
+        let step = n.clamp(-MAX_POW10_STEP, MAX_POW10_STEP);
+        if step > 0 {
+            result *= pow10(step);
+        } else {
+            result /= pow10(-step);
+        }
+        n -= step;
+    }
+    result
+}
+
+// Returns the decimal digits of the finite, positive `value` rounded to
+// `count` significant digits, together with the decimal exponent of the
+// first digit.
+fn to_digits(value: QuadFloat, count: usize) -> (Vec<u8>, i32) {
+    let mut exponent = value.words[0].log10().floor() as i32;
+    let mut scaled = mul_pow10(value, -exponent);
+    if scaled >= 10.0 {
+        scaled /= 10.0;
+        exponent += 1;
+    } else if scaled < 1.0 {
+        scaled *= 10.0;
+        exponent -= 1;
+    }
+
+    let mut digits = Vec::with_capacity(count);
+    for _ in 0..count {
+        let digit = scaled.words[0].floor().clamp(0.0, 9.0);
+        digits.push(digit as u8);
+        scaled = (scaled - digit) * 10.0;
+    }
+
+    if scaled >= 5.0 {
+        match digits.iter().rposition(|&d| d != 9) {
+            Some(i) => {
+                digits[i] += 1;
+                digits[i + 1..].iter_mut().for_each(|d| *d = 0);
+            }
+            None => {
+                digits.iter_mut().for_each(|d| *d = 0);
+                digits[0] = 1;
+                exponent += 1;
+            }
+        }
+    }
+
+    (digits, exponent)
+}
+
+// Writes the value in scientific notation, with `precision` digits after
+// the decimal point if given, or up to `DEFAULT_DIGITS` significant digits
+// with trailing zeros removed.
+fn fmt_exp(value: &QuadFloat, f: &mut fmt::Formatter<'_>, exp_char: char) -> fmt::Result {
+    let count = f.precision().map_or(DEFAULT_DIGITS, |p| p + 1);
+    let abs = value.abs();
+    let (mut digits, exponent) = if abs.words[0] == 0.0 {
+        (vec![0; count], 0)
+    } else {
+        to_digits(abs, count)
+    };
+    if f.precision().is_none() {
+        let len = digits.iter().rposition(|&d| d != 0).unwrap_or(0) + 1;
+        digits.truncate(len);
+    }
+
+    let mut buffer = String::with_capacity(digits.len() + 8);
+    buffer.push(char::from(b'0' + digits[0]));
+    if digits.len() > 1 {
+        buffer.push('.');
+        buffer.extend(digits[1..].iter().map(|&d| char::from(b'0' + d)));
+    }
+    buffer.push(exp_char);
+    buffer.push_str(&exponent.to_string());
+    f.pad_integral(value.is_sign_positive(), "", &buffer)
+}
+
+impl fmt::Display for QuadFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return fmt::Display::fmt(&f64::NAN, f);
+        } else if self.is_infinite() {
+            return fmt::Display::fmt(&self.words[0], f);
+        }
+        fmt_exp(self, f, 'e')
+    }
+}
+
+impl fmt::LowerExp for QuadFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return fmt::LowerExp::fmt(&f64::NAN, f);
+        } else if self.is_infinite() {
+            return fmt::LowerExp::fmt(&self.words[0], f);
+        }
+        fmt_exp(self, f, 'e')
+    }
+}
+
+impl fmt::UpperExp for QuadFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return fmt::UpperExp::fmt(&f64::NAN, f);
+        } else if self.is_infinite() {
+            return fmt::UpperExp::fmt(&self.words[0], f);
+        }
+        fmt_exp(self, f, 'E')
+    }
+}
+
+impl FromStr for QuadFloat {
+    type Err = TwoFloatError;
+
+    /// Parses a decimal number with an optional sign, fractional part and
+    /// exponent, or one of `inf`, `infinity` and `nan` in any case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let magnitude =
+            if unsigned.eq_ignore_ascii_case("inf") || unsigned.eq_ignore_ascii_case("infinity") {
+                Self::INFINITY
+            } else if unsigned.eq_ignore_ascii_case("nan") {
+                Self::NAN
+            } else {
+                parse_decimal(unsigned).ok_or(TwoFloatError::ConversionError)?
+            };
+
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+}
+
+// Parses an unsigned decimal number, returning `None` if it is malformed.
+fn parse_decimal(s: &str) -> Option<QuadFloat> {
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+
+    let mut value = QuadFloat::ZERO;
+    let mut significant = 0;
+    let mut shift = 0i32;
+    let mut seen_digit = false;
+    let mut seen_point = false;
+    for c in mantissa.bytes() {
+        match c {
+            b'0'..=b'9' => {
+                seen_digit = true;
+                let digit = f64::from(c - b'0');
+                if significant < MAX_PARSE_DIGITS {
+                    if significant > 0 || digit != 0.0 {
+                        significant += 1;
+                    }
+                    value = value * 10.0 + digit;
+                    if seen_point {
+                        shift -= 1;
+                    }
+                } else if !seen_point {
+                    shift += 1;
+                }
+            }
+            b'.' if !seen_point => seen_point = true,
+            _ => return None,
+        }
+    }
+    if !seen_digit {
+        return None;
+    }
+
+    if let Some(exponent) = exponent {
+        let (negative, digits) = match exponent.as_bytes().first() {
+            Some(b'-') => (true, &exponent[1..]),
+            Some(b'+') => (false, &exponent[1..]),
+            _ => (false, exponent),
+        };
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        // Saturate the exponent, as any larger value overflows or underflows
+        let magnitude = digits.bytes().fold(0i32, |acc, c| {
+            acc.saturating_mul(10)
+                .saturating_add(i32::from(c - b'0'))
+                .min(100_000)
+        });
+        shift += if negative { -magnitude } else { magnitude };
+    }
+
+    Some(mul_pow10(value, shift))
+}
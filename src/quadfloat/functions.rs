@@ -0,0 +1,423 @@
+use super::{from_terms, QuadFloat};
+use crate::{
+    arithmetic::two_prod,
+    base::mul_pow2,
+    status::{self, FpFlags},
+    TwoFloat,
+};
+
+// ln(2) and π/2 to eight words, for reduction of arguments which are large
+// multiples of these constants.
+const LN_2_WORDS: [f64; 8] = [
+    0.6931471805599453,
+    2.3190468138462996e-17,
+    5.707708438416212e-34,
+    -3.5824322106018114e-50,
+    -1.352169675798863e-66,
+    6.080638740240814e-83,
+    2.8955024332347147e-99,
+    2.351386712145641e-116,
+];
+
+const FRAC_PI_2_WORDS: [f64; 8] = [
+    1.5707963267948966,
+    6.123233995736766e-17,
+    -1.4973849048591698e-33,
+    5.562271104316826e-50,
+    2.836115989820158e-66,
+    8.724931080676243e-84,
+    3.01468636612477e-100,
+    9.55061773439995e-117,
+];
+
+// limits
+const EXP_UPPER_LIMIT: f64 = 709.782712893384;
+const EXP_LOWER_LIMIT: f64 = -745.1332191019412;
+
+// Number of halvings of the reduced argument of exp before the Taylor
+// series is evaluated.
+const EXP_HALVINGS: i32 = 9;
+
+// Ratio of a series term to the sum below which the term no longer affects
+// the result: 2^-215.
+const SERIES_TOLERANCE: f64 = 1.900960968449019e-65;
+
+// Returns x * 2^n. Precision is lost if the words of the result are
+// subnormal.
+fn ldexp(x: QuadFloat, n: i32) -> QuadFloat {
+    let [a0, a1, a2, a3] = x.words;
+    QuadFloat {
+        words: [
+            mul_pow2(a0, n),
+            mul_pow2(a1, n),
+            mul_pow2(a2, n),
+            mul_pow2(a3, n),
+        ],
+    }
+}
+
+// Returns x - k * c, where c is a constant given to eight words, rounded
+// once to four words.
+fn reduce(x: &QuadFloat, k: f64, c: &[f64; 8]) -> QuadFloat {
+    let mut terms = [0.0; 20];
+    terms[..4].copy_from_slice(&x.words);
+    for (i, &w) in c.iter().enumerate() {
+        let (p, e) = two_prod(w, -k).into();
+        terms[4 + 2 * i] = p;
+        terms[5 + 2 * i] = e;
+    }
+    from_terms(&mut terms)
+}
+
+// Returns (m, k) such that exp(x) = 2^k * (1 + m), for finite x within the
+// limits of the exponential function.
+fn exp_parts(x: &QuadFloat) -> (QuadFloat, i32) {
+    // reduce value to range |r| <= ln(2)/2 where x = k*ln(2) + r, then
+    // evaluate expm1(r / 2^9) by its Taylor series and scale back using
+    // expm1(2s) = expm1(s) * (expm1(s) + 2)
+    let k = (x.words[0] / LN_2_WORDS[0]).round();
+    let s = reduce(x, k, &LN_2_WORDS) * mul_pow2(1.0, -EXP_HALVINGS);
+
+    let mut term = s;
+    let mut sum = s;
+    let mut i = 2.0;
+    while term.words[0].abs() > sum.words[0].abs() * SERIES_TOLERANCE {
+        term = term * s / i;
+        sum += term;
+        i += 1.0;
+    }
+
+    for _ in 0..EXP_HALVINGS {
+        sum *= sum + 2.0;
+    }
+    (sum, k as i32)
+}
+
+// Returns exp(x) - 1 without cancellation for small x.
+fn exp_m1(x: &QuadFloat) -> QuadFloat {
+    let (m, k) = exp_parts(x);
+    if k == 0 {
+        m
+    } else {
+        ldexp(m + 1.0, k) - 1.0
+    }
+}
+
+// Reduces x to the range |r| <= π/4 where x = k*π/2 + r, returning r and
+// k mod 4. Accuracy is lost for arguments much larger than 2^200, as π/2 is
+// only known to eight words.
+fn quadrant(x: &QuadFloat) -> (QuadFloat, u8) {
+    let mut r = *x;
+    let mut quadrant = 0.0;
+    while r.abs() > QuadFloat::FRAC_PI_4 {
+        let k = (r.words[0] / FRAC_PI_2_WORDS[0]).round();
+        if k == 0.0 {
+            break;
+        }
+        r = reduce(&r, k, &FRAC_PI_2_WORDS);
+        quadrant = (quadrant + k % 4.0 + 4.0) % 4.0;
+    }
+    (r, quadrant as u8)
+}
+
+// Computes the sine of x for |x| <= π/4 by its Taylor series.
+fn restricted_sin(x: &QuadFloat) -> QuadFloat {
+    let x2 = x * x;
+    let mut term = *x;
+    let mut sum = *x;
+    let mut i = 3.0;
+    while term.words[0].abs() > sum.words[0].abs() * SERIES_TOLERANCE {
+        term = -term * x2 / (i * (i - 1.0));
+        sum += term;
+        i += 2.0;
+    }
+    sum
+}
+
+// Computes the sine and cosine of a valid value.
+fn sin_cos(x: &QuadFloat) -> (QuadFloat, QuadFloat) {
+    let (r, quadrant) = quadrant(x);
+    let s = restricted_sin(&r);
+    let c = (1.0 - s * s).sqrt();
+    match quadrant {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+impl QuadFloat {
+    /// Returns the square root of the number, using Newton–Raphson iteration
+    /// for the reciprocal square root followed by a final correction step
+    /// (Karp and Markstein, 1997).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(2.0).sqrt();
+    ///
+    /// assert!((a * a - 2.0).abs() < 1e-62);
+    pub fn sqrt(self) -> Self {
+        status::function("sqrt", &[TwoFloat::from(self)], || {
+            if self.words[0] == 0.0 || self.is_nan() || self == Self::INFINITY {
+                return self;
+            } else if self.words[0] < 0.0 {
+                return Self::NAN;
+            }
+
+            // scale the value to [1, 4) to avoid overflow of x^2
+            let k = (self.words[0].log2() / 2.0).floor() as i32;
+            let a = ldexp(self, -2 * k);
+
+            let mut x = Self::from(1.0 / a.words[0].sqrt());
+            for _ in 0..2 {
+                x += x * (1.0 - a * (x * x)) * 0.5;
+            }
+            let s = a * x;
+            ldexp(s + x * (a - s * s) * 0.5, k)
+        })
+    }
+
+    /// Returns `e^(self)`, (the exponential function).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1.0).exp();
+    ///
+    /// assert!((a - QuadFloat::E).abs() < 1e-62);
+    pub fn exp(self) -> Self {
+        status::function("exp", &[TwoFloat::from(self)], || {
+            if self.is_nan() {
+                Self::NAN
+            } else if self.words[0] <= EXP_LOWER_LIMIT {
+                if self.is_finite() {
+                    status::raise(FpFlags::UNDERFLOW, "exp");
+                }
+                Self::ZERO
+            } else if self.words[0] >= EXP_UPPER_LIMIT {
+                Self::INFINITY
+            } else if self.words[0] == 0.0 {
+                Self::ONE
+            } else {
+                let (m, k) = exp_parts(&self);
+                ldexp(m + 1.0, k)
+            }
+        })
+    }
+
+    /// Returns the natural logarithm of the value.
+    ///
+    /// Uses Newton–Raphson iteration on the reduced argument, which is
+    /// accurate to the full precision of a `QuadFloat` even for values close
+    /// to one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::E.ln();
+    ///
+    /// assert!((a - 1.0).abs() < 1e-62);
+    pub fn ln(self) -> Self {
+        status::function("ln", &[TwoFloat::from(self)], || {
+            if self == 1.0 {
+                Self::ZERO
+            } else if self.words[0] == 0.0 {
+                status::raise(FpFlags::DIVISION_BY_ZERO, "ln");
+                Self::NEG_INFINITY
+            } else if self.is_nan() || self.words[0] < 0.0 {
+                Self::NAN
+            } else if self.is_infinite() {
+                self
+            } else {
+                // self = 2^k * m with m close to 1, and the logarithm of m is
+                // refined from d = m - 1 by x += d*exp(-x) + expm1(-x)
+                let k = self.words[0].log2().round();
+                let m = ldexp(self, -(k as i32));
+                let d = m - 1.0;
+                let mut x = Self::from(d.words[0].ln_1p());
+                for _ in 0..2 {
+                    x += d + exp_m1(&-x) * m;
+                }
+                reduce(&x, -k, &LN_2_WORDS)
+            }
+        })
+    }
+
+    /// Computes the sine of the value (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::PI / 6.0;
+    ///
+    /// assert!((a.sin() - 0.5).abs() < 1e-62);
+    pub fn sin(self) -> Self {
+        status::function("sin", &[TwoFloat::from(self)], || {
+            if !self.is_valid() {
+                Self::NAN
+            } else {
+                sin_cos(&self).0
+            }
+        })
+    }
+
+    /// Computes the cosine of the value (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::PI / 3.0;
+    ///
+    /// assert!((a.cos() - 0.5).abs() < 1e-62);
+    pub fn cos(self) -> Self {
+        status::function("cos", &[TwoFloat::from(self)], || {
+            if !self.is_valid() {
+                Self::NAN
+            } else {
+                sin_cos(&self).1
+            }
+        })
+    }
+
+    /// Computes the tangent of the value (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::FRAC_PI_4;
+    ///
+    /// assert!((a.tan() - 1.0).abs() < 1e-62);
+    pub fn tan(self) -> Self {
+        status::function("tan", &[TwoFloat::from(self)], || {
+            if !self.is_valid() {
+                Self::NAN
+            } else {
+                let (s, c) = sin_cos(&self);
+                s / c
+            }
+        })
+    }
+
+    /// Computes the arcsine of the value. Return value is in radians and
+    /// in the range [-π/2, π/2] or `NAN` if the number is outside the range
+    /// [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(0.5).asin();
+    ///
+    /// assert!((a - QuadFloat::PI / 6.0).abs() < 1e-62);
+    pub fn asin(self) -> Self {
+        status::function("asin", &[TwoFloat::from(self)], || {
+            if !self.is_valid() || self.abs() > 1.0 {
+                Self::NAN
+            } else {
+                self.atan2(((1.0 - self) * (1.0 + self)).sqrt())
+            }
+        })
+    }
+
+    /// Computes the arccosine of the value. Return value is in radians and
+    /// in the range [0, π] or `NAN` if the number is outside the range
+    /// [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(0.5).acos();
+    ///
+    /// assert!((a - QuadFloat::PI / 3.0).abs() < 1e-62);
+    pub fn acos(self) -> Self {
+        status::function("acos", &[TwoFloat::from(self)], || {
+            if !self.is_valid() || self.abs() > 1.0 {
+                Self::NAN
+            } else {
+                ((1.0 - self) * (1.0 + self)).sqrt().atan2(self)
+            }
+        })
+    }
+
+    /// Computes the arctangent of the value. Return value is in radians and
+    /// in the range [-π/2, π/2].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let a = QuadFloat::from(1.0).atan();
+    ///
+    /// assert!((a - QuadFloat::FRAC_PI_4).abs() < 1e-62);
+    pub fn atan(self) -> Self {
+        status::function("atan", &[TwoFloat::from(self)], || self.atan2(Self::ONE))
+    }
+
+    /// Computes the four quadrant arctangent of `self` (y) and `other` (x)
+    /// in radians, refining the `f64` result by Newton–Raphson iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::QuadFloat;
+    /// let y = QuadFloat::from(-1.0);
+    /// let x = QuadFloat::from(-1.0);
+    /// let theta = y.atan2(x);
+    ///
+    /// assert!((theta + 3.0 * QuadFloat::FRAC_PI_4).abs() < 1e-62);
+    pub fn atan2(self, other: Self) -> Self {
+        let args = [TwoFloat::from(self), TwoFloat::from(other)];
+        status::function("atan2", &args, || {
+            let (y, x) = (self.words[0], other.words[0]);
+            let result = if self.is_nan() || other.is_nan() {
+                return Self::NAN;
+            } else if y.is_infinite() {
+                if x.is_infinite() {
+                    if x > 0.0 {
+                        Self::FRAC_PI_4
+                    } else {
+                        Self::FRAC_PI_4 * 3.0
+                    }
+                } else {
+                    Self::FRAC_PI_2
+                }
+            } else if x.is_infinite() {
+                if x > 0.0 {
+                    Self::ZERO
+                } else {
+                    Self::PI
+                }
+            } else if y == 0.0 {
+                if x.is_sign_positive() {
+                    return Self::ZERO;
+                }
+                Self::PI
+            } else if x == 0.0 {
+                Self::FRAC_PI_2
+            } else {
+                // the correction tan(atan2(y, x) - theta) converges cubically
+                let mut theta = Self::from(y.atan2(x));
+                for _ in 0..2 {
+                    let (s, c) = sin_cos(&theta);
+                    theta += (self * c - other * s) / (other * c + self * s);
+                }
+                return theta;
+            };
+
+            if y.is_sign_positive() {
+                result
+            } else {
+                -result
+            }
+        })
+    }
+}
@@ -0,0 +1,389 @@
+#![cfg_attr(not(feature = "fp_status"), allow(dead_code))]
+
+use core::{fmt, ops::BitAnd, ops::BitOr, ops::BitOrAssign};
+
+#[cfg(feature = "fp_status")]
+use core::{cell::Cell, marker::PhantomData};
+
+use crate::{QuadFloat, ThreeFloat, TwoComplex, TwoF32, TwoFloat, TwoFloatInterval};
+
+/// A set of floating-point status flags, as defined by IEEE 754.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FpFlags(u8);
+
+impl FpFlags {
+    /// Set when an operation produces `NAN` from arguments that are not
+    /// `NAN`.
+    pub const INVALID: Self = Self(1);
+    /// Set when an operation produces an exact infinite result from finite
+    /// arguments, such as division by zero or the logarithm of zero.
+    pub const DIVISION_BY_ZERO: Self = Self(2);
+    /// Set when the result of an operation on finite arguments is too large
+    /// to be represented as a finite `TwoFloat`.
+    pub const OVERFLOW: Self = Self(4);
+    /// Set when the result of an operation is non-zero but smaller in
+    /// magnitude than `TwoFloat::MIN_POSITIVE` and has lost precision, or is
+    /// rounded to zero. Exact tiny results do not underflow.
+    pub const UNDERFLOW: Self = Self(8);
+
+    const NAMES: [(Self, &'static str); 4] = [
+        (Self::INVALID, "invalid operation"),
+        (Self::DIVISION_BY_ZERO, "division by zero"),
+        (Self::OVERFLOW, "overflow"),
+        (Self::UNDERFLOW, "underflow"),
+    ];
+
+    /// Returns the empty set of flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the set of all flags.
+    pub const fn all() -> Self {
+        Self(15)
+    }
+
+    /// Returns `true` if no flags are set.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any flag in `other` is set.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for FpFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for FpFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for FpFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Display for FpFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut names = Self::NAMES
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| name);
+        match names.next() {
+            Some(name) => {
+                write!(f, "{}", name)?;
+                names.try_for_each(|name| write!(f, ", {}", name))
+            }
+            None => write!(f, "none"),
+        }
+    }
+}
+
+#[cfg(feature = "fp_status")]
+#[derive(Debug, Clone, Copy)]
+struct State {
+    flags: FpFlags,
+    traps: FpFlags,
+}
+
+#[cfg(feature = "fp_status")]
+thread_local! {
+    static STATE: Cell<State> = const {
+        Cell::new(State {
+            flags: FpFlags::empty(),
+            traps: FpFlags::empty(),
+        })
+    };
+}
+
+#[cfg(feature = "fp_status")]
+fn replace_state(state: State) -> State {
+    STATE.with(|s| s.replace(state))
+}
+
+/// A scoped floating-point environment holding the sticky status flags of
+/// the current thread.
+///
+/// Creating an `FpEnv` installs a new environment with all flags cleared.
+/// Arithmetic operators and mathematical functions then set the flags of the
+/// innermost environment of the thread as exceptions occur, and flags remain
+/// set until cleared. When the `FpEnv` is dropped the previous environment is
+/// restored, with any flags raised in the scope added to it.
+///
+/// An environment may also trap flags, in which case raising the flag panics
+/// with a message naming the operation. Trapping `FpFlags::INVALID` is useful
+/// as a debug mode to find where a `NAN` is first produced.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{FpEnv, FpFlags, TwoFloat};
+/// let env = FpEnv::new();
+/// let a = TwoFloat::from(1e300) * 1e300;
+/// let b = TwoFloat::from(-1.0).sqrt();
+///
+/// assert!(a.is_infinite() && b.is_nan());
+/// assert_eq!(env.flags(), FpFlags::OVERFLOW | FpFlags::INVALID);
+/// ```
+#[cfg(feature = "fp_status")]
+#[derive(Debug)]
+pub struct FpEnv {
+    saved: State,
+    // The environment is tied to the thread in which it was created
+    _marker: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "fp_status")]
+impl FpEnv {
+    /// Installs a new environment for the current thread with all flags
+    /// cleared and no traps.
+    pub fn new() -> Self {
+        Self::with_traps(FpFlags::empty())
+    }
+
+    /// Installs a new environment for the current thread with all flags
+    /// cleared, which panics when any of the flags in `traps` is raised.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use twofloat::{FpEnv, FpFlags, TwoFloat};
+    /// let _env = FpEnv::with_traps(FpFlags::INVALID);
+    /// // panics with "invalid operation in TwoFloat ln"
+    /// let a = TwoFloat::from(-2.0).ln();
+    /// ```
+    pub fn with_traps(traps: FpFlags) -> Self {
+        let saved = replace_state(State {
+            flags: FpFlags::empty(),
+            traps,
+        });
+        Self {
+            saved,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the flags raised in the current environment of the thread.
+    pub fn flags(&self) -> FpFlags {
+        STATE.with(|s| s.get().flags)
+    }
+
+    /// Clears all flags in the current environment of the thread.
+    pub fn clear(&self) {
+        STATE.with(|s| {
+            let mut state = s.get();
+            state.flags = FpFlags::empty();
+            s.set(state);
+        });
+    }
+
+    /// Returns the flags trapped by the current environment of the thread.
+    pub fn traps(&self) -> FpFlags {
+        STATE.with(|s| s.get().traps)
+    }
+
+    /// Sets the flags trapped by the current environment of the thread.
+    pub fn set_traps(&self, traps: FpFlags) {
+        STATE.with(|s| {
+            let mut state = s.get();
+            state.traps = traps;
+            s.set(state);
+        });
+    }
+}
+
+#[cfg(feature = "fp_status")]
+impl Default for FpEnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "fp_status")]
+impl Drop for FpEnv {
+    fn drop(&mut self) {
+        let state = replace_state(self.saved);
+        STATE.with(|s| {
+            let mut saved = s.get();
+            saved.flags |= state.flags;
+            s.set(saved);
+        });
+    }
+}
+
+// Raises the flags in the current environment, panicking if any of them are
+// trapped.
+#[inline]
+pub(crate) fn raise(flags: FpFlags, op: &'static str) {
+    #[cfg(feature = "fp_status")]
+    if !flags.is_empty() {
+        let traps = STATE.with(|s| {
+            let mut state = s.get();
+            state.flags |= flags;
+            s.set(state);
+            state.traps
+        });
+        if let Some((_, name)) = FpFlags::NAMES
+            .iter()
+            .find(|(flag, _)| traps.contains(*flag) && flags.contains(*flag))
+        {
+            panic!("{} in TwoFloat {}", name, op);
+        }
+    }
+    #[cfg(not(feature = "fp_status"))]
+    let _ = (flags, op);
+}
+
+// Results of a mathematical function that can be classified for raising
+// status flags.
+pub(crate) trait FunctionResult: Copy {
+    fn flags(self, args: &[TwoFloat], raised: FpFlags) -> FpFlags;
+}
+
+impl FunctionResult for TwoFloat {
+    fn flags(self, args: &[TwoFloat], raised: FpFlags) -> FpFlags {
+        if args.iter().any(|x| x.is_nan()) {
+            FpFlags::empty()
+        } else if self.is_nan() {
+            FpFlags::INVALID
+        } else if self.is_infinite() {
+            if !args.iter().all(TwoFloat::is_finite) {
+                FpFlags::empty()
+            } else if raised.contains(FpFlags::DIVISION_BY_ZERO) {
+                FpFlags::DIVISION_BY_ZERO
+            } else {
+                FpFlags::OVERFLOW
+            }
+        } else if self.hi == 0.0 {
+            raised & FpFlags::UNDERFLOW
+        } else if self.abs() < TwoFloat::MIN_POSITIVE {
+            FpFlags::UNDERFLOW
+        } else {
+            FpFlags::empty()
+        }
+    }
+}
+
+impl FunctionResult for ThreeFloat {
+    fn flags(self, args: &[TwoFloat], raised: FpFlags) -> FpFlags {
+        let flags = TwoFloat::from(self).flags(args, raised);
+        if flags.is_empty()
+            && self.is_finite()
+            && self.hi != 0.0
+            && self.abs() < ThreeFloat::MIN_POSITIVE
+            && !args.iter().any(|x| x.is_nan())
+        {
+            FpFlags::UNDERFLOW
+        } else {
+            flags
+        }
+    }
+}
+
+impl FunctionResult for QuadFloat {
+    fn flags(self, args: &[TwoFloat], raised: FpFlags) -> FpFlags {
+        let flags = TwoFloat::from(self).flags(args, raised);
+        if flags.is_empty()
+            && self.is_finite()
+            && self.hi() != 0.0
+            && self.abs() < QuadFloat::MIN_POSITIVE
+            && !args.iter().any(|x| x.is_nan())
+        {
+            FpFlags::UNDERFLOW
+        } else {
+            flags
+        }
+    }
+}
+
+impl FunctionResult for TwoF32 {
+    fn flags(self, args: &[TwoFloat], raised: FpFlags) -> FpFlags {
+        let flags = TwoFloat::from(self).flags(args, raised);
+        if flags.is_empty()
+            && self.is_finite()
+            && self.hi() != 0.0
+            && self.abs() < TwoF32::MIN_POSITIVE
+            && !args.iter().any(|x| x.is_nan())
+        {
+            FpFlags::UNDERFLOW
+        } else {
+            flags
+        }
+    }
+}
+
+impl FunctionResult for (TwoFloat, TwoFloat) {
+    fn flags(self, args: &[TwoFloat], raised: FpFlags) -> FpFlags {
+        self.0.flags(args, raised) | self.1.flags(args, raised)
+    }
+}
+
+impl FunctionResult for (TwoFloat, i32) {
+    fn flags(self, args: &[TwoFloat], raised: FpFlags) -> FpFlags {
+        self.0.flags(args, raised)
+    }
+}
+
+impl FunctionResult for TwoComplex {
+    fn flags(self, args: &[TwoFloat], raised: FpFlags) -> FpFlags {
+        (self.re, self.im).flags(args, raised)
+    }
+}
+
+// Interval results enclose any overflow or underflow of their bounds, so
+// only an empty result is an exception.
+impl FunctionResult for TwoFloatInterval {
+    fn flags(self, _args: &[TwoFloat], _raised: FpFlags) -> FpFlags {
+        if self.is_empty() {
+            FpFlags::INVALID
+        } else {
+            FpFlags::empty()
+        }
+    }
+}
+
+// Evaluates a mathematical function, raising the flags determined by its
+// result instead of those raised by the intermediate operations. Flags raised
+// within `f` are only used to distinguish a pole from an overflow, and an
+// underflow to zero from an exact zero.
+#[inline]
+pub(crate) fn function<R: FunctionResult>(
+    op: &'static str,
+    args: &[TwoFloat],
+    f: impl FnOnce() -> R,
+) -> R {
+    #[cfg(feature = "fp_status")]
+    {
+        let saved = replace_state(State {
+            flags: FpFlags::empty(),
+            traps: FpFlags::empty(),
+        });
+        let result = f();
+        let inner = replace_state(saved);
+        raise(result.flags(args, inner.flags), op);
+        result
+    }
+    #[cfg(not(feature = "fp_status"))]
+    {
+        let _ = (op, args);
+        f()
+    }
+}
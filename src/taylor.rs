@@ -0,0 +1,364 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Range, Sub, SubAssign};
+
+use crate::TwoFloat;
+
+#[cfg(feature = "math_funcs")]
+mod functions;
+
+/// Represents a power series in a variable `h` truncated to its first `N`
+/// coefficients, which are `TwoFloat` values.
+///
+/// Arithmetic operators are provided for combinations of `TaylorJet`,
+/// `TwoFloat` and `f64` values, where `TwoFloat` and `f64` values are
+/// treated as constant series. Multiplication and division use the truncated
+/// Cauchy product. If the `math_funcs` feature is enabled, the elementary
+/// functions are also provided, using the scalar `TwoFloat` function for the
+/// zeroth coefficient and the standard recurrences for the higher order
+/// coefficients. Operations panic if `N` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{TaylorJet, TwoFloat};
+/// // Series of 1 / (1 - h)
+/// let x = TaylorJet::<4>::variable(TwoFloat::ZERO);
+/// let y = 1.0 / (1.0 - x);
+///
+/// assert_eq!(y.coefficients, [TwoFloat::ONE; 4]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaylorJet<const N: usize> {
+    /// The coefficients of the series, in increasing order of the power of
+    /// `h`.
+    pub coefficients: [TwoFloat; N],
+}
+
+impl<const N: usize> TaylorJet<N> {
+    /// Creates a new `TaylorJet` from its coefficients.
+    pub const fn new(coefficients: [TwoFloat; N]) -> Self {
+        Self { coefficients }
+    }
+
+    /// Creates a constant series, whose higher order coefficients are zero.
+    pub fn constant(value: TwoFloat) -> Self {
+        let mut result = Self::zero();
+        result.coefficients[0] = value;
+        result
+    }
+
+    /// Creates the series of the independent variable `value + h`.
+    pub fn variable(value: TwoFloat) -> Self {
+        let mut result = Self::constant(value);
+        if N > 1 {
+            result.coefficients[1] = TwoFloat::ONE;
+        }
+        result
+    }
+
+    /// Returns the value of the series at `h = 0`.
+    pub fn value(&self) -> TwoFloat {
+        self.coefficients[0]
+    }
+
+    /// Returns `true` if any of the coefficients is `NAN`.
+    pub fn is_nan(&self) -> bool {
+        self.coefficients.iter().any(TwoFloat::is_nan)
+    }
+
+    /// Returns `true` if all the coefficients are finite.
+    pub fn is_finite(&self) -> bool {
+        self.coefficients.iter().all(TwoFloat::is_finite)
+    }
+
+    /// Evaluates the truncated series at `h` using Horner's method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TaylorJet, TwoFloat};
+    /// let x = TaylorJet::<3>::variable(TwoFloat::from(2.0));
+    /// let y = x * x;
+    ///
+    /// assert_eq!(y.evaluate(TwoFloat::from(0.5)), 6.25);
+    pub fn evaluate(&self, h: TwoFloat) -> TwoFloat {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(TwoFloat::ZERO, |sum, c| sum * h + c)
+    }
+
+    /// Returns the series of the derivative with respect to `h`, whose last
+    /// coefficient is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TaylorJet, TwoFloat};
+    /// let x = TaylorJet::<3>::variable(TwoFloat::from(2.0));
+    /// let y = (x * x).derivative();
+    ///
+    /// assert_eq!(y.coefficients, [TwoFloat::from(4.0), TwoFloat::from(2.0), TwoFloat::ZERO]);
+    pub fn derivative(&self) -> Self {
+        let mut result = Self::zero();
+        for k in 1..N {
+            result.coefficients[k - 1] = self.coefficients[k] * k as f64;
+        }
+        result
+    }
+
+    /// Returns the series of the integral with respect to `h` whose value at
+    /// `h = 0` is `constant`, dropping the last coefficient. This gives the
+    /// coefficients of the solution of `x' = f(x)` from those of `f(x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TaylorJet, TwoFloat};
+    /// let x = TaylorJet::<3>::new([TwoFloat::from(4.0), TwoFloat::from(2.0), TwoFloat::ZERO]);
+    /// let y = x.integral(TwoFloat::ONE);
+    ///
+    /// assert_eq!(y.coefficients, [TwoFloat::ONE, TwoFloat::from(4.0), TwoFloat::ONE]);
+    pub fn integral(&self, constant: TwoFloat) -> Self {
+        let mut result = Self::constant(constant);
+        for k in 1..N {
+            result.coefficients[k] = self.coefficients[k - 1] / k as f64;
+        }
+        result
+    }
+
+    fn zero() -> Self {
+        Self {
+            coefficients: [TwoFloat::ZERO; N],
+        }
+    }
+
+    // Returns the sum of `a[j] * b[k - j]` for `j` in `range`.
+    fn convolution(
+        a: &[TwoFloat; N],
+        b: &[TwoFloat; N],
+        k: usize,
+        range: Range<usize>,
+    ) -> TwoFloat {
+        range.fold(TwoFloat::ZERO, |sum, j| sum + a[j] * b[k - j])
+    }
+
+    fn map(&self, f: impl Fn(TwoFloat) -> TwoFloat) -> Self {
+        let mut result = *self;
+        for c in result.coefficients.iter_mut() {
+            *c = f(*c);
+        }
+        result
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(TwoFloat, TwoFloat) -> TwoFloat) -> Self {
+        let mut result = *self;
+        for (c, d) in result
+            .coefficients
+            .iter_mut()
+            .zip(other.coefficients.iter())
+        {
+            *c = f(*c, *d);
+        }
+        result
+    }
+
+    fn with_value(&self, value: TwoFloat) -> Self {
+        let mut result = *self;
+        result.coefficients[0] = value;
+        result
+    }
+}
+
+impl<const N: usize> Default for TaylorJet<N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize> From<TwoFloat> for TaylorJet<N> {
+    fn from(value: TwoFloat) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<const N: usize> From<f64> for TaylorJet<N> {
+    fn from(value: f64) -> Self {
+        Self::constant(TwoFloat::from(value))
+    }
+}
+
+// Divides `a` by `b`, where c[k] = (a[k] - sum(b[j] c[k - j], j = 1..k)) / b[0]
+fn div<const N: usize>(a: &TaylorJet<N>, b: &TaylorJet<N>) -> TaylorJet<N> {
+    let mut c = TaylorJet::zero();
+    for k in 0..N {
+        let sum = TaylorJet::convolution(&b.coefficients, &c.coefficients, k, 1..k + 1);
+        c.coefficients[k] = (a.coefficients[k] - sum) / b.coefficients[0];
+    }
+    c
+}
+
+unary_ops! {
+    fn Neg::neg<const N: usize>(self: &TaylorJet<N>) -> TaylorJet<N> {
+        self.map(|c| -c)
+    }
+}
+
+binary_ops! {
+    /// Implements addition of two `TaylorJet` values.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+
+    /// Implements addition of `TaylorJet` and a constant `TwoFloat`.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b TwoFloat) -> TaylorJet<N> {
+        self.with_value(self.coefficients[0] + rhs)
+    }
+
+    /// Implements addition of a constant `TwoFloat` and `TaylorJet`.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a TwoFloat, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        rhs + self
+    }
+
+    /// Implements addition of `TaylorJet` and a constant `f64`.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b f64) -> TaylorJet<N> {
+        self.with_value(self.coefficients[0] + rhs)
+    }
+
+    /// Implements addition of a constant `f64` and `TaylorJet`.
+    fn Add::add<'a, 'b; const N: usize>(self: &'a f64, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        rhs + self
+    }
+
+    /// Implements subtraction of two `TaylorJet` values.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+
+    /// Implements subtraction of a constant `TwoFloat` from `TaylorJet`.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b TwoFloat) -> TaylorJet<N> {
+        self.with_value(self.coefficients[0] - rhs)
+    }
+
+    /// Implements subtraction of `TaylorJet` from a constant `TwoFloat`.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a TwoFloat, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        -rhs + self
+    }
+
+    /// Implements subtraction of a constant `f64` from `TaylorJet`.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b f64) -> TaylorJet<N> {
+        self.with_value(self.coefficients[0] - rhs)
+    }
+
+    /// Implements subtraction of `TaylorJet` from a constant `f64`.
+    fn Sub::sub<'a, 'b; const N: usize>(self: &'a f64, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        -rhs + self
+    }
+
+    /// Implements multiplication of two `TaylorJet` values as the truncated
+    /// Cauchy product.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        let mut result = TaylorJet::zero();
+        for k in 0..N {
+            result.coefficients[k] =
+                TaylorJet::convolution(&self.coefficients, &rhs.coefficients, k, 0..k + 1);
+        }
+        result
+    }
+
+    /// Implements multiplication of `TaylorJet` and a constant `TwoFloat`.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b TwoFloat) -> TaylorJet<N> {
+        self.map(|c| c * rhs)
+    }
+
+    /// Implements multiplication of a constant `TwoFloat` and `TaylorJet`.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a TwoFloat, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        rhs * self
+    }
+
+    /// Implements multiplication of `TaylorJet` and a constant `f64`.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b f64) -> TaylorJet<N> {
+        self.map(|c| c * rhs)
+    }
+
+    /// Implements multiplication of a constant `f64` and `TaylorJet`.
+    fn Mul::mul<'a, 'b; const N: usize>(self: &'a f64, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        rhs * self
+    }
+
+    /// Implements division of two `TaylorJet` values.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        div(self, rhs)
+    }
+
+    /// Implements division of `TaylorJet` by a constant `TwoFloat`.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b TwoFloat) -> TaylorJet<N> {
+        self.map(|c| c / rhs)
+    }
+
+    /// Implements division of a constant `TwoFloat` by `TaylorJet`.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a TwoFloat, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        div(&TaylorJet::constant(*self), rhs)
+    }
+
+    /// Implements division of `TaylorJet` by a constant `f64`.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a TaylorJet<N>, rhs: &'b f64) -> TaylorJet<N> {
+        self.map(|c| c / rhs)
+    }
+
+    /// Implements division of a constant `f64` by `TaylorJet`.
+    fn Div::div<'a, 'b; const N: usize>(self: &'a f64, rhs: &'b TaylorJet<N>) -> TaylorJet<N> {
+        div(&TaylorJet::from(*self), rhs)
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a TaylorJet<N>) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a TwoFloat) {
+        self.coefficients[0] += rhs;
+    }
+
+    fn AddAssign::add_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a f64) {
+        self.coefficients[0] += rhs;
+    }
+
+    fn SubAssign::sub_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a TaylorJet<N>) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a TwoFloat) {
+        self.coefficients[0] -= rhs;
+    }
+
+    fn SubAssign::sub_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a f64) {
+        self.coefficients[0] -= rhs;
+    }
+
+    fn MulAssign::mul_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a TaylorJet<N>) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a TwoFloat) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a f64) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a TaylorJet<N>) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a TwoFloat) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a; const N: usize>(self: &mut TaylorJet<N>, rhs: &'a f64) {
+        *self = *self / rhs;
+    }
+}
@@ -0,0 +1,148 @@
+use super::TaylorJet;
+use crate::TwoFloat;
+
+impl<const N: usize> TaylorJet<N> {
+    /// Returns the series of `e^x`, where `x` is the series, using
+    /// `e[k] = sum(j x[j] e[k - j], j = 1..k) / k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TaylorJet, TwoFloat};
+    /// let a = TaylorJet::<4>::variable(TwoFloat::ZERO).exp();
+    ///
+    /// assert_eq!(a.coefficients[0], 1.0);
+    /// assert_eq!(a.coefficients[2], 0.5);
+    /// assert!((a.coefficients[3] - TwoFloat::ONE / 6.0).abs() < 1e-30);
+    pub fn exp(self) -> Self {
+        let x = &self.coefficients;
+        let mut e = Self::constant(x[0].exp());
+        for k in 1..N {
+            let sum = (1..=k).fold(TwoFloat::ZERO, |sum, j| {
+                sum + x[j] * e.coefficients[k - j] * j as f64
+            });
+            e.coefficients[k] = sum / k as f64;
+        }
+        e
+    }
+
+    /// Returns the series of the natural logarithm of the series, using
+    /// `l[k] = (x[k] - sum(j l[j] x[k - j], j = 1..k - 1) / k) / x[0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TaylorJet, TwoFloat};
+    /// let a = TaylorJet::<4>::variable(TwoFloat::ONE).ln();
+    ///
+    /// assert_eq!(a.coefficients[0], 0.0);
+    /// assert_eq!(a.coefficients[2], -0.5);
+    pub fn ln(self) -> Self {
+        let x = &self.coefficients;
+        let mut l = Self::constant(x[0].ln());
+        for k in 1..N {
+            let sum = (1..k).fold(TwoFloat::ZERO, |sum, j| {
+                sum + l.coefficients[j] * x[k - j] * j as f64
+            });
+            l.coefficients[k] = (x[k] - sum / k as f64) / x[0];
+        }
+        l
+    }
+
+    /// Returns the series of the square root of the series, using
+    /// `r[k] = (x[k] - sum(r[j] r[k - j], j = 1..k - 1)) / 2 r[0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TaylorJet, TwoFloat};
+    /// let a = TaylorJet::<3>::variable(TwoFloat::from(4.0)).sqrt();
+    ///
+    /// assert_eq!(a.coefficients, [
+    ///     TwoFloat::from(2.0),
+    ///     TwoFloat::from(0.25),
+    ///     TwoFloat::from(-1.0 / 64.0),
+    /// ]);
+    pub fn sqrt(self) -> Self {
+        let x = &self.coefficients;
+        let mut r = Self::constant(x[0].sqrt());
+        for (k, xk) in x.iter().enumerate().skip(1) {
+            let sum = Self::convolution(&r.coefficients, &r.coefficients, k, 1..k);
+            r.coefficients[k] = (xk - sum) / (r.coefficients[0] * 2.0);
+        }
+        r
+    }
+
+    /// Returns the series of the series raised to a constant power, which
+    /// requires a non-zero value, using
+    /// `w[k] = sum((p (k - j) - j) x[k - j] w[j], j = 0..k - 1) / k x[0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TaylorJet, TwoFloat};
+    /// let a = TaylorJet::<4>::variable(TwoFloat::ONE).powf(TwoFloat::from(3.0));
+    ///
+    /// for (c, expected) in a.coefficients.iter().zip([1.0, 3.0, 3.0, 1.0].iter()) {
+    ///     assert!((c - expected).abs() < 1e-9);
+    /// }
+    pub fn powf(self, p: TwoFloat) -> Self {
+        let x = &self.coefficients;
+        let mut w = Self::constant(x[0].powf(p));
+        for k in 1..N {
+            let sum = (0..k).fold(TwoFloat::ZERO, |sum, j| {
+                let factor = p * (k - j) as f64 - j as f64;
+                sum + factor * x[k - j] * w.coefficients[j]
+            });
+            w.coefficients[k] = sum / (x[0] * k as f64);
+        }
+        w
+    }
+
+    /// Simultaneously computes the series of the sine and cosine of the
+    /// series, using `s[k] = sum(j x[j] c[k - j], j = 1..k) / k` and
+    /// `c[k] = -sum(j x[j] s[k - j], j = 1..k) / k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TaylorJet, TwoFloat};
+    /// let (s, c) = TaylorJet::<4>::variable(TwoFloat::ZERO).sin_cos();
+    ///
+    /// assert_eq!(s.coefficients[1], 1.0);
+    /// assert_eq!(c.coefficients[2], -0.5);
+    pub fn sin_cos(self) -> (Self, Self) {
+        let x = &self.coefficients;
+        let (s0, c0) = x[0].sin_cos();
+        let (mut s, mut c) = (Self::constant(s0), Self::constant(c0));
+        for k in 1..N {
+            let (sum_s, sum_c) = (1..=k).fold((TwoFloat::ZERO, TwoFloat::ZERO), |sums, j| {
+                let jx = x[j] * j as f64;
+                (
+                    sums.0 + jx * c.coefficients[k - j],
+                    sums.1 + jx * s.coefficients[k - j],
+                )
+            });
+            s.coefficients[k] = sum_s / k as f64;
+            c.coefficients[k] = -sum_c / k as f64;
+        }
+        (s, c)
+    }
+
+    /// Returns the series of the sine of the series.
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Returns the series of the cosine of the series.
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Returns the series of the tangent of the series, as the quotient of
+    /// the sine and cosine series.
+    pub fn tan(self) -> Self {
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+}
@@ -0,0 +1,733 @@
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+#[cfg(feature = "serde_support")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    arithmetic::{self, classify_words, fast_two_sum, two_prod, Operation, UNDERFLOW_SCALE},
+    base::no_overlap,
+    status, TwoFloat, TwoFloatError,
+};
+
+/// Represents a three-word floating point type, represented as the sum of
+/// three non-overlapping f64 values.
+///
+/// `ThreeFloat` provides about 159 bits of precision for intermediate
+/// computations where `TwoFloat` is not quite enough. Each operation sorts
+/// the words and the exact partial products of its operands and renormalizes
+/// them with the VecSum and VecSumErrBranch algorithms of Fabiano et al.
+/// (2019). Conversion from `TwoFloat` is exact, and conversion back rounds to
+/// the nearest `TwoFloat`.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{ThreeFloat, TwoFloat};
+/// let third = ThreeFloat::from(1.0) / 3.0;
+/// let a = TwoFloat::from(third);
+///
+/// assert_eq!(a, TwoFloat::from(1.0) / 3.0);
+/// assert!((third * 3.0 - 1.0).abs() < 1e-47);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_support", serde(try_from = "(f64, f64, f64)"))]
+#[cfg_attr(feature = "serde_support", serde(into = "(f64, f64, f64)"))]
+pub struct ThreeFloat {
+    pub(crate) hi: f64,
+    pub(crate) mid: f64,
+    pub(crate) lo: f64,
+}
+
+// Renormalizes the terms into three words, discarding the error beyond the
+// last word.
+fn renormalize(terms: &mut [f64]) -> ThreeFloat {
+    let mut words = [0.0; 3];
+    arithmetic::renormalize(terms, &mut words);
+    ThreeFloat {
+        hi: words[0],
+        mid: words[1],
+        lo: words[2],
+    }
+}
+
+// Replaces a non-finite or zero result with the IEEE result of the
+// equivalent f64 operation on the high words `a` and `b`, using the
+// classification of `TwoFloat` arithmetic, and raises any exception in the
+// current status flags. A tiny result only underflows if `lost_precision`
+// returns true.
+#[inline]
+fn check_result(
+    result: ThreeFloat,
+    op: Operation,
+    a: f64,
+    b: f64,
+    lost_precision: impl FnOnce(&ThreeFloat) -> bool,
+) -> ThreeFloat {
+    let (value, flags) = classify_words(result.hi, ThreeFloat::MIN_POSITIVE.hi, op, a, b, || {
+        result.abs() < ThreeFloat::MIN_POSITIVE && lost_precision(&result)
+    });
+    status::raise(flags, op.name());
+    match value {
+        None => result,
+        Some(value) if value.is_nan() => ThreeFloat::NAN,
+        Some(value) => ThreeFloat::from(value),
+    }
+}
+
+// Checks a sum or difference, which is exact when tiny, so never underflows.
+#[inline]
+fn check_special(result: ThreeFloat, op: Operation, a: f64, b: f64) -> ThreeFloat {
+    check_result(result, op, a, b, |_| false)
+}
+
+// Checks a product or quotient, where `scaled` repeats the operation with an
+// operand multiplied by `UNDERFLOW_SCALE`, as for `TwoFloat`.
+#[inline]
+fn check_scaled(
+    result: ThreeFloat,
+    op: Operation,
+    a: f64,
+    b: f64,
+    scaled: impl FnOnce() -> ThreeFloat,
+) -> ThreeFloat {
+    check_result(result, op, a, b, |r| scaled() != scale_up(r))
+}
+
+#[inline]
+fn scale_up(x: &ThreeFloat) -> ThreeFloat {
+    ThreeFloat {
+        hi: x.hi * UNDERFLOW_SCALE,
+        mid: x.mid * UNDERFLOW_SCALE,
+        lo: x.lo * UNDERFLOW_SCALE,
+    }
+}
+
+// The kernels below implement the arithmetic algorithms without handling
+// special values, as for `TwoFloat`.
+
+#[inline]
+fn add_f64(a: &ThreeFloat, b: f64) -> ThreeFloat {
+    let mut terms = [a.hi, a.mid, a.lo, b];
+    renormalize(&mut terms)
+}
+
+#[inline]
+fn add(a: &ThreeFloat, b: &ThreeFloat) -> ThreeFloat {
+    let mut terms = [a.hi, a.mid, a.lo, b.hi, b.mid, b.lo];
+    renormalize(&mut terms)
+}
+
+#[inline]
+fn mul_f64(a: &ThreeFloat, b: f64) -> ThreeFloat {
+    let (p0, e0) = two_prod(a.hi, b).into();
+    let (p1, e1) = two_prod(a.mid, b).into();
+    let (p2, e2) = two_prod(a.lo, b).into();
+    let mut terms = [p0, e0, p1, e1, p2, e2];
+    renormalize(&mut terms)
+}
+
+fn mul_f64_scaled(a: &ThreeFloat, b: f64) -> ThreeFloat {
+    if a.hi.abs() < b.abs() {
+        mul_f64(&scale_up(a), b)
+    } else {
+        mul_f64(a, b * UNDERFLOW_SCALE)
+    }
+}
+
+#[inline]
+fn mul(a: &ThreeFloat, b: &ThreeFloat) -> ThreeFloat {
+    // Products are split exactly down to the order of 2^-106 relative to the
+    // result, with the terms of order 2^-159 accumulated in a single word
+    // and smaller terms neglected.
+    let (p00, e00) = two_prod(a.hi, b.hi).into();
+    let (p01, e01) = two_prod(a.hi, b.mid).into();
+    let (p10, e10) = two_prod(a.mid, b.hi).into();
+    let (p02, e02) = two_prod(a.hi, b.lo).into();
+    let (p11, e11) = two_prod(a.mid, b.mid).into();
+    let (p20, e20) = two_prod(a.lo, b.hi).into();
+    let t = a.mid.mul_add(b.lo, a.lo * b.mid) + (e02 + e11 + e20);
+    let mut terms = [p00, e00, p01, p10, e01, e10, p02, p11, p20, t];
+    renormalize(&mut terms)
+}
+
+// Returns the residual a - b * q, rounded once to three words.
+#[inline]
+fn residual(a: &ThreeFloat, b: &ThreeFloat, q: f64) -> ThreeFloat {
+    let (p0, e0) = two_prod(b.hi, -q).into();
+    let (p1, e1) = two_prod(b.mid, -q).into();
+    let (p2, e2) = two_prod(b.lo, -q).into();
+    let mut terms = [a.hi, a.mid, a.lo, p0, e0, p1, e1, p2, e2];
+    renormalize(&mut terms)
+}
+
+#[inline]
+fn div(a: &ThreeFloat, b: &ThreeFloat) -> ThreeFloat {
+    // Long division, with each quotient word computed from the residual of
+    // the previous ones
+    let q0 = a.hi / b.hi;
+    let r = residual(a, b, q0);
+    let q1 = r.hi / b.hi;
+    let r = residual(&r, b, q1);
+    let q2 = r.hi / b.hi;
+    let r = residual(&r, b, q2);
+    let q3 = r.hi / b.hi;
+    let mut terms = [q0, q1, q2, q3];
+    renormalize(&mut terms)
+}
+
+// Gives a zero remainder the sign of the dividend, as for f64.
+#[inline]
+fn rem_sign(result: ThreeFloat, lhs: f64) -> ThreeFloat {
+    if result.hi == 0.0 {
+        ThreeFloat::from(0.0f64.copysign(lhs))
+    } else {
+        result
+    }
+}
+
+#[inline]
+fn rem(a: &ThreeFloat, b: &ThreeFloat) -> ThreeFloat {
+    status::function("rem", &[TwoFloat::from(a), TwoFloat::from(b)], || {
+        if b.is_infinite() && a.is_finite() {
+            return *a;
+        }
+        let quotient = (a / b).trunc();
+        rem_sign(a - quotient * b, a.hi)
+    })
+}
+
+unary_ops! {
+    fn Neg::neg(self: &ThreeFloat) -> ThreeFloat {
+        Self::Output {
+            hi: -self.hi,
+            mid: -self.mid,
+            lo: -self.lo,
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements addition of `ThreeFloat` and `f64`.
+    fn Add::add<'a, 'b>(self: &'a ThreeFloat, rhs: &'b f64) -> ThreeFloat {
+        check_special(add_f64(self, *rhs), Operation::Add, self.hi, *rhs)
+    }
+
+    /// Implements addition of `f64` and `ThreeFloat`.
+    fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b ThreeFloat) -> ThreeFloat {
+        check_special(add_f64(rhs, *self), Operation::Add, *self, rhs.hi)
+    }
+
+    /// Implements addition of two `ThreeFloat` values by renormalizing the
+    /// six words of the operands.
+    fn Add::add<'a, 'b>(self: &'a ThreeFloat, rhs: &'b ThreeFloat) -> ThreeFloat {
+        check_special(add(self, rhs), Operation::Add, self.hi, rhs.hi)
+    }
+
+    /// Implements subtraction of `ThreeFloat` and `f64`.
+    fn Sub::sub<'a, 'b>(self: &'a ThreeFloat, rhs: &'b f64) -> ThreeFloat {
+        check_special(add_f64(self, -rhs), Operation::Sub, self.hi, *rhs)
+    }
+
+    /// Implements subtraction of `f64` and `ThreeFloat`.
+    fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b ThreeFloat) -> ThreeFloat {
+        check_special(add_f64(&-rhs, *self), Operation::Sub, *self, rhs.hi)
+    }
+
+    /// Implements subtraction of two `ThreeFloat` values by renormalizing the
+    /// words of the left-hand side and the negated right-hand side.
+    fn Sub::sub<'a, 'b>(self: &'a ThreeFloat, rhs: &'b ThreeFloat) -> ThreeFloat {
+        check_special(add(self, &-rhs), Operation::Sub, self.hi, rhs.hi)
+    }
+
+    /// Implements multiplication of `ThreeFloat` and `f64`.
+    fn Mul::mul<'a, 'b>(self: &'a ThreeFloat, rhs: &'b f64) -> ThreeFloat {
+        check_scaled(mul_f64(self, *rhs), Operation::Mul, self.hi, *rhs, || {
+            mul_f64_scaled(self, *rhs)
+        })
+    }
+
+    /// Implements multiplication of `f64` and `ThreeFloat`.
+    fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b ThreeFloat) -> ThreeFloat {
+        check_scaled(mul_f64(rhs, *self), Operation::Mul, *self, rhs.hi, || {
+            mul_f64_scaled(rhs, *self)
+        })
+    }
+
+    /// Implements multiplication of two `ThreeFloat` values.
+    fn Mul::mul<'a, 'b>(self: &'a ThreeFloat, rhs: &'b ThreeFloat) -> ThreeFloat {
+        check_scaled(mul(self, rhs), Operation::Mul, self.hi, rhs.hi, || {
+            if self.hi.abs() < rhs.hi.abs() {
+                mul(&scale_up(self), rhs)
+            } else {
+                mul(self, &scale_up(rhs))
+            }
+        })
+    }
+
+    /// Implements division of `ThreeFloat` and `f64`.
+    fn Div::div<'a, 'b>(self: &'a ThreeFloat, rhs: &'b f64) -> ThreeFloat {
+        let b = ThreeFloat::from(*rhs);
+        check_scaled(div(self, &b), Operation::Div, self.hi, *rhs, || {
+            div(&scale_up(self), &b)
+        })
+    }
+
+    /// Implements division of `f64` and `ThreeFloat`.
+    fn Div::div<'a, 'b>(self: &'a f64, rhs: &'b ThreeFloat) -> ThreeFloat {
+        check_scaled(div(&ThreeFloat::from(*self), rhs), Operation::Div, *self, rhs.hi, || {
+            div(&ThreeFloat::from(*self * UNDERFLOW_SCALE), rhs)
+        })
+    }
+
+    /// Implements division of two `ThreeFloat` values by long division.
+    fn Div::div<'a, 'b>(self: &'a ThreeFloat, rhs: &'b ThreeFloat) -> ThreeFloat {
+        check_scaled(div(self, rhs), Operation::Div, self.hi, rhs.hi, || {
+            div(&scale_up(self), rhs)
+        })
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a ThreeFloat, rhs: &'b f64) -> ThreeFloat {
+        rem(self, &ThreeFloat::from(*rhs))
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a f64, rhs: &'b ThreeFloat) -> ThreeFloat {
+        rem(&ThreeFloat::from(*self), rhs)
+    }
+
+    fn Rem::rem<'a, 'b>(self: &'a ThreeFloat, rhs: &'b ThreeFloat) -> ThreeFloat {
+        rem(self, rhs)
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut ThreeFloat, rhs: &'a f64) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut ThreeFloat, rhs: &'a ThreeFloat) {
+        *self = *self + rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut ThreeFloat, rhs: &'a f64) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut ThreeFloat, rhs: &'a ThreeFloat) {
+        *self = *self - rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut ThreeFloat, rhs: &'a f64) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut ThreeFloat, rhs: &'a ThreeFloat) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut ThreeFloat, rhs: &'a f64) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut ThreeFloat, rhs: &'a ThreeFloat) {
+        *self = *self / rhs;
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut ThreeFloat, rhs: &'a f64) {
+        *self = *self % rhs;
+    }
+
+    fn RemAssign::rem_assign<'a>(self: &mut ThreeFloat, rhs: &'a ThreeFloat) {
+        *self = *self % rhs;
+    }
+}
+
+impl ThreeFloat {
+    /// Represents an error value equivalent to `f64::NAN`.
+    pub const NAN: Self = Self {
+        hi: f64::NAN,
+        mid: f64::NAN,
+        lo: f64::NAN,
+    };
+
+    /// Positive infinity.
+    pub const INFINITY: Self = Self {
+        hi: f64::INFINITY,
+        mid: 0.0,
+        lo: 0.0,
+    };
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Self {
+        hi: f64::NEG_INFINITY,
+        mid: 0.0,
+        lo: 0.0,
+    };
+
+    /// The value zero.
+    pub const ZERO: Self = Self {
+        hi: 0.0,
+        mid: 0.0,
+        lo: 0.0,
+    };
+
+    /// The value one.
+    pub const ONE: Self = Self {
+        hi: 1.0,
+        mid: 0.0,
+        lo: 0.0,
+    };
+
+    /// Number of significant binary digits, treating a `ThreeFloat` as having
+    /// the combined precision of its three `f64` components.
+    pub const MANTISSA_DIGITS: u32 = 3 * f64::MANTISSA_DIGITS;
+
+    /// Machine epsilon value for `ThreeFloat`: 2<sup>-158</sup>.
+    pub const EPSILON: Self = Self {
+        hi: 2.7369110631344083e-48,
+        mid: 0.0,
+        lo: 0.0,
+    };
+
+    /// Smallest positive value retaining the full `MANTISSA_DIGITS` bits of
+    /// precision: 2<sup>-916</sup>.
+    pub const MIN_POSITIVE: Self = Self {
+        hi: 1.8051943758648296e-276,
+        mid: 0.0,
+        lo: 0.0,
+    };
+
+    /// Returns the high word of `self`.
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the middle word of `self`.
+    pub fn mid(&self) -> f64 {
+        self.mid
+    }
+
+    /// Returns the low word of `self`.
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Returns `true` if `self` is a valid value, where all components are
+    /// finite and do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::ThreeFloat;
+    /// let a = (ThreeFloat::from(1.0) / 3.0).is_valid();
+    /// let b = ThreeFloat::INFINITY.is_valid();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    pub fn is_valid(&self) -> bool {
+        self.is_finite() && no_overlap(self.hi, self.mid) && no_overlap(self.mid, self.lo)
+    }
+
+    /// Returns `true` if `self` is `NAN`.
+    pub fn is_nan(&self) -> bool {
+        self.hi.is_nan() || self.mid.is_nan() || self.lo.is_nan()
+    }
+
+    /// Returns `true` if `self` is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        self.hi.is_infinite() && !self.mid.is_nan() && !self.lo.is_nan()
+    }
+
+    /// Returns `true` if `self` is neither infinite nor `NAN`.
+    pub fn is_finite(&self) -> bool {
+        self.hi.is_finite() && self.mid.is_finite() && self.lo.is_finite()
+    }
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0`.
+    pub fn is_sign_positive(&self) -> bool {
+        self.hi.is_sign_positive()
+    }
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0`.
+    pub fn is_sign_negative(&self) -> bool {
+        self.hi.is_sign_negative()
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::ThreeFloat;
+    /// let a = ThreeFloat::from(-3.5);
+    ///
+    /// assert_eq!(a.abs(), 3.5);
+    pub fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smallest integer greater than or equal to the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::ThreeFloat;
+    /// let a = ThreeFloat::from(1.0) + 1e-40;
+    /// let b = ThreeFloat::from(1.0) - 1e-40;
+    ///
+    /// assert_eq!(a.ceil(), 2.0);
+    /// assert_eq!(b.ceil(), 1.0);
+    pub fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+
+    /// Returns the largest integer less than or equal to the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::ThreeFloat;
+    /// let a = ThreeFloat::from(1.0) + 1e-40;
+    /// let b = ThreeFloat::from(1.0) - 1e-40;
+    ///
+    /// assert_eq!(a.floor(), 1.0);
+    /// assert_eq!(b.floor(), 0.0);
+    pub fn floor(self) -> Self {
+        if self.hi.fract() != 0.0 {
+            self.hi.floor().into()
+        } else if self.mid.fract() != 0.0 {
+            let (hi, mid) = fast_two_sum(self.hi, self.mid.floor()).into();
+            Self { hi, mid, lo: 0.0 }
+        } else {
+            let mut terms = [self.hi, self.mid, self.lo.floor()];
+            renormalize(&mut terms)
+        }
+    }
+
+    /// Returns the integer part of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::ThreeFloat;
+    /// let a = ThreeFloat::from(-1.0) + 1e-40;
+    ///
+    /// assert_eq!(a.trunc(), 0.0);
+    pub fn trunc(self) -> Self {
+        if self.is_sign_positive() {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+}
+
+impl From<f64> for ThreeFloat {
+    fn from(value: f64) -> Self {
+        Self {
+            hi: value,
+            mid: 0.0,
+            lo: 0.0,
+        }
+    }
+}
+
+impl From<ThreeFloat> for f64 {
+    fn from(value: ThreeFloat) -> Self {
+        value.hi
+    }
+}
+
+impl From<TwoFloat> for ThreeFloat {
+    fn from(value: TwoFloat) -> Self {
+        Self {
+            hi: value.hi,
+            mid: value.lo,
+            lo: 0.0,
+        }
+    }
+}
+
+impl<'a> From<&'a TwoFloat> for ThreeFloat {
+    fn from(value: &'a TwoFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<ThreeFloat> for TwoFloat {
+    /// Rounds the value to the nearest `TwoFloat`.
+    fn from(value: ThreeFloat) -> Self {
+        if value.hi.is_finite() {
+            fast_two_sum(value.hi, value.mid + value.lo)
+        } else {
+            Self {
+                hi: value.hi,
+                lo: value.mid,
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a ThreeFloat> for TwoFloat {
+    fn from(value: &'a ThreeFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<ThreeFloat> for (f64, f64, f64) {
+    fn from(value: ThreeFloat) -> Self {
+        (value.hi, value.mid, value.lo)
+    }
+}
+
+impl TryFrom<(f64, f64, f64)> for ThreeFloat {
+    type Error = TwoFloatError;
+
+    fn try_from(value: (f64, f64, f64)) -> Result<Self, Self::Error> {
+        let result = Self {
+            hi: value.0,
+            mid: value.1,
+            lo: value.2,
+        };
+        if no_overlap(result.hi, result.mid) && no_overlap(result.mid, result.lo) {
+            Ok(result)
+        } else {
+            Err(Self::Error::ConversionError {})
+        }
+    }
+}
+
+impl From<ThreeFloat> for [f64; 3] {
+    fn from(value: ThreeFloat) -> Self {
+        [value.hi, value.mid, value.lo]
+    }
+}
+
+impl TryFrom<[f64; 3]> for ThreeFloat {
+    type Error = TwoFloatError;
+
+    fn try_from(value: [f64; 3]) -> Result<Self, Self::Error> {
+        Self::try_from((value[0], value[1], value[2]))
+    }
+}
+
+impl fmt::Display for ThreeFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return fmt::Display::fmt(&f64::NAN, f);
+        } else if self.hi.is_infinite() {
+            return fmt::Display::fmt(&self.hi, f);
+        }
+        let sign_char = |x: f64| if x.is_sign_positive() { '+' } else { '-' };
+        let (mid_sign, lo_sign) = (sign_char(self.mid), sign_char(self.lo));
+        let (mid, lo) = (self.mid.abs(), self.lo.abs());
+        match (f.sign_plus(), f.precision()) {
+            (true, Some(p)) => write!(
+                f,
+                "{:+.*} {} {:.*} {} {:.*}",
+                p, self.hi, mid_sign, p, mid, lo_sign, p, lo
+            ),
+            (true, None) => write!(f, "{:+} {} {} {} {}", self.hi, mid_sign, mid, lo_sign, lo),
+            (false, Some(p)) => write!(
+                f,
+                "{:.*} {} {:.*} {} {:.*}",
+                p, self.hi, mid_sign, p, mid, lo_sign, p, lo
+            ),
+            (false, None) => write!(f, "{} {} {} {} {}", self.hi, mid_sign, mid, lo_sign, lo),
+        }
+    }
+}
+
+impl PartialEq<f64> for ThreeFloat {
+    fn eq(&self, other: &f64) -> bool {
+        self.hi.eq(other) && self.mid == 0.0 && self.lo == 0.0
+    }
+}
+
+impl PartialEq<ThreeFloat> for f64 {
+    fn eq(&self, other: &ThreeFloat) -> bool {
+        other.eq(self)
+    }
+}
+
+impl PartialOrd<f64> for ThreeFloat {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.partial_cmp(&Self::from(*other))
+    }
+}
+
+impl PartialOrd<ThreeFloat> for f64 {
+    fn partial_cmp(&self, other: &ThreeFloat) -> Option<Ordering> {
+        ThreeFloat::from(*self).partial_cmp(other)
+    }
+}
+
+impl PartialEq<TwoFloat> for ThreeFloat {
+    fn eq(&self, other: &TwoFloat) -> bool {
+        self.eq(&Self::from(other))
+    }
+}
+
+impl PartialEq<ThreeFloat> for TwoFloat {
+    fn eq(&self, other: &ThreeFloat) -> bool {
+        ThreeFloat::from(self).eq(other)
+    }
+}
+
+impl PartialOrd<TwoFloat> for ThreeFloat {
+    fn partial_cmp(&self, other: &TwoFloat) -> Option<Ordering> {
+        self.partial_cmp(&Self::from(other))
+    }
+}
+
+impl PartialOrd<ThreeFloat> for TwoFloat {
+    fn partial_cmp(&self, other: &ThreeFloat) -> Option<Ordering> {
+        ThreeFloat::from(self).partial_cmp(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{renormalize, ThreeFloat};
+    use crate::test_util::{get_valid_pair, repeated_test};
+
+    #[test]
+    fn renormalize_test() {
+        repeated_test(|| {
+            let (a, b) = get_valid_pair(|x, y| x.abs() < 1e300 && y.abs() < 1e300);
+            let mut terms = [a, b, a * 0.75, -b * 1.5, 1.0];
+            let result = renormalize(&mut terms);
+
+            assert!(
+                result.is_valid(),
+                "Invalid result of renormalizing {}, {}",
+                a,
+                b
+            );
+        });
+    }
+
+    #[test]
+    fn is_infinite_test() {
+        let nan_lo = ThreeFloat {
+            hi: f64::INFINITY,
+            mid: 0.0,
+            lo: f64::NAN,
+        };
+
+        assert!(!nan_lo.is_infinite());
+        assert!(nan_lo.is_nan());
+        assert!(ThreeFloat::NEG_INFINITY.is_infinite());
+    }
+}
@@ -0,0 +1,346 @@
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    arithmetic::{
+        ADD_ERROR_BOUND, ADD_WORD_ERROR_BOUND, DIV_ERROR_BOUND, DIV_WORD_ERROR_BOUND,
+        MUL_ERROR_BOUND, MUL_WORD_ERROR_BOUND, RECIP_UNDERFLOW_ERROR,
+    },
+    interval::{round_down, round_up, UNDERFLOW_ERROR},
+    TwoFloat, TwoFloatInterval,
+};
+
+#[cfg(feature = "math_funcs")]
+mod functions;
+
+// Factor by which error bounds computed in f64 are increased to cover the
+// rounding errors of their computation, which take a few operations with a
+// relative error of at most 2^-53 each.
+const ERROR_ROUNDING: f64 = 1.0000000000000009; // 1 + 2^-50
+
+// Returns a bound on the rounding error of an operation with result `value`
+// and relative error at most `relative`.
+pub(crate) fn rounding_error(value: TwoFloat, relative: f64) -> f64 {
+    value.hi.abs() * relative * (1.0 + relative) * ERROR_ROUNDING + UNDERFLOW_ERROR
+}
+
+// Returns `value` with its low word at most half a unit in the last place of
+// the high word, as assumed by the error bounds. Valid values may have a low
+// word of up to one unit in the last place, as accepted by `TryFrom`.
+fn normalized(value: TwoFloat) -> TwoFloat {
+    if value.is_finite() {
+        TwoFloat::new_add(value.hi, value.lo)
+    } else {
+        value
+    }
+}
+
+/// Represents a `TwoFloat` value together with a bound on its absolute error,
+/// which is accumulated through each operation.
+///
+/// Each arithmetic operator adds the proven error bound of the algorithm
+/// used for the operation, from Joldes et al. (2017), to the error
+/// propagated from its arguments. If the `math_funcs` feature is enabled,
+/// `sqrt`, `exp`, `ln` and the trigonometric functions are also provided,
+/// adding the error bounds documented for the `TwoFloat` functions. The
+/// error bound can be used to decide whether a computation needs a higher
+/// precision than `TwoFloat`.
+///
+/// Values created from a `TwoFloat` or `f64` are taken to be exact, while
+/// `with_error` creates a value with a known error, such as a measurement or
+/// the result of an earlier computation.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{TrackedTwoFloat, TwoFloat};
+/// let a = TrackedTwoFloat::from(1.0) / 3.0;
+/// let b = a * 3.0 - 1.0;
+///
+/// assert!(b.value().abs() <= b.error());
+/// assert!(b.error() < 1e-30);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TrackedTwoFloat {
+    value: TwoFloat,
+    error: f64,
+}
+
+impl TrackedTwoFloat {
+    /// Creates a new value whose error is at most `error` in magnitude.
+    pub fn with_error(value: TwoFloat, error: f64) -> Self {
+        Self::tracked(normalized(value), error.abs())
+    }
+
+    // Returns a new value, with an infinite error bound if the value
+    // overflowed and a `NAN` error bound if it is `NAN`.
+    fn tracked(value: TwoFloat, error: f64) -> Self {
+        let error = if value.is_nan() {
+            f64::NAN
+        } else if value.is_infinite() {
+            f64::INFINITY
+        } else {
+            error
+        };
+        Self { value, error }
+    }
+
+    /// Returns the computed value.
+    pub fn value(&self) -> TwoFloat {
+        self.value
+    }
+
+    /// Returns the bound on the absolute error of the value.
+    pub fn error(&self) -> f64 {
+        self.error
+    }
+
+    /// Returns the bound on the error of the value relative to its
+    /// magnitude, which is infinite for a zero value with a non-zero error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::with_error(TwoFloat::from(2.0), 1e-20);
+    ///
+    /// assert_eq!(a.relative_error(), 5e-21);
+    pub fn relative_error(&self) -> f64 {
+        if self.error == 0.0 {
+            0.0
+        } else {
+            self.error / self.value.hi.abs()
+        }
+    }
+
+    /// Returns the number of leading bits of the value that are guaranteed to
+    /// be correct, from zero up to `TwoFloat::MANTISSA_DIGITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::from(1.0) / 3.0;
+    /// let b = TrackedTwoFloat::with_error(TwoFloat::from(1.0), 1e-10);
+    ///
+    /// assert!(a.correct_bits() >= 100);
+    /// assert_eq!(b.correct_bits(), 33);
+    pub fn correct_bits(&self) -> u32 {
+        let bits = -self.relative_error().log2();
+        if bits >= TwoFloat::MANTISSA_DIGITS as f64 {
+            TwoFloat::MANTISSA_DIGITS
+        } else if bits >= 0.0 {
+            bits as u32
+        } else {
+            0
+        }
+    }
+
+    /// Returns the interval of values within the error bound of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::with_error(TwoFloat::from(1.0), 0.5).to_interval();
+    ///
+    /// assert!(a.contains(TwoFloat::from(0.5)) && a.contains(TwoFloat::from(1.5)));
+    pub fn to_interval(&self) -> TwoFloatInterval {
+        let lower = round_down(self.value, 0.0, self.error);
+        let upper = round_up(self.value, 0.0, self.error);
+        TwoFloatInterval::new(lower, upper).unwrap_or(TwoFloatInterval::EMPTY)
+    }
+}
+
+impl From<TwoFloat> for TrackedTwoFloat {
+    /// Returns an exact tracked value.
+    fn from(value: TwoFloat) -> Self {
+        Self::tracked(normalized(value), 0.0)
+    }
+}
+
+impl From<f64> for TrackedTwoFloat {
+    /// Returns an exact tracked value.
+    fn from(value: f64) -> Self {
+        Self::tracked(TwoFloat::from(value), 0.0)
+    }
+}
+
+impl fmt::Display for TrackedTwoFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, " ± {:e}", self.error)
+    }
+}
+
+// The errors propagated by each operation follow from the errors `ea` and
+// `eb` of the arguments, as:
+//
+//   |(a + da)(b + db) - ab| <= |a| eb + |b| ea + ea eb
+//   |(a + da)/(b + db) - a/b| <= (ea + |a/b| eb) / (|b| - eb)
+
+fn add_error(a: &TrackedTwoFloat, b_error: f64, value: TwoFloat, relative: f64) -> f64 {
+    (a.error + b_error) * ERROR_ROUNDING + rounding_error(value, relative)
+}
+
+fn mul_error(
+    a: &TrackedTwoFloat,
+    b: TwoFloat,
+    b_error: f64,
+    value: TwoFloat,
+    relative: f64,
+) -> f64 {
+    let propagated = a.value.hi.abs() * b_error + b.hi.abs() * a.error + a.error * b_error;
+    propagated * ERROR_ROUNDING + rounding_error(value, relative)
+}
+
+fn div_error(a_error: f64, b: TwoFloat, b_error: f64, value: TwoFloat, relative: f64) -> f64 {
+    let divisor = b.hi.abs() * (1.0 - f64::EPSILON) - b_error;
+    let propagated = if b_error == 0.0 {
+        a_error / b.hi.abs()
+    } else if divisor > 0.0 {
+        (a_error + value.hi.abs() * b_error) / divisor
+    } else {
+        f64::INFINITY
+    };
+    let relative = relative + b.hi.abs() * RECIP_UNDERFLOW_ERROR;
+    propagated * ERROR_ROUNDING + rounding_error(value, relative)
+}
+
+unary_ops! {
+    fn Neg::neg(self: &TrackedTwoFloat) -> TrackedTwoFloat {
+        Self::Output {
+            value: -self.value,
+            error: self.error,
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements addition of two `TrackedTwoFloat` values, adding the error
+    /// bound of Joldes et al. (2017) Algorithm 6.
+    fn Add::add<'a, 'b>(self: &'a TrackedTwoFloat, rhs: &'b TrackedTwoFloat) -> TrackedTwoFloat {
+        let value = self.value + rhs.value;
+        TrackedTwoFloat::tracked(value, add_error(self, rhs.error, value, ADD_ERROR_BOUND))
+    }
+
+    /// Implements addition of `TrackedTwoFloat` and an exact `f64`, adding
+    /// the error bound of Joldes et al. (2017) Algorithm 4.
+    fn Add::add<'a, 'b>(self: &'a TrackedTwoFloat, rhs: &'b f64) -> TrackedTwoFloat {
+        let value = self.value + rhs;
+        TrackedTwoFloat::tracked(value, add_error(self, 0.0, value, ADD_WORD_ERROR_BOUND))
+    }
+
+    /// Implements addition of an exact `f64` and `TrackedTwoFloat`, adding
+    /// the error bound of Joldes et al. (2017) Algorithm 4.
+    fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b TrackedTwoFloat) -> TrackedTwoFloat {
+        rhs + self
+    }
+
+    /// Implements subtraction of two `TrackedTwoFloat` values, adding the
+    /// error bound of Joldes et al. (2017) Algorithm 6.
+    fn Sub::sub<'a, 'b>(self: &'a TrackedTwoFloat, rhs: &'b TrackedTwoFloat) -> TrackedTwoFloat {
+        let value = self.value - rhs.value;
+        TrackedTwoFloat::tracked(value, add_error(self, rhs.error, value, ADD_ERROR_BOUND))
+    }
+
+    /// Implements subtraction of an exact `f64` from `TrackedTwoFloat`,
+    /// adding the error bound of Joldes et al. (2017) Algorithm 4.
+    fn Sub::sub<'a, 'b>(self: &'a TrackedTwoFloat, rhs: &'b f64) -> TrackedTwoFloat {
+        let value = self.value - rhs;
+        TrackedTwoFloat::tracked(value, add_error(self, 0.0, value, ADD_WORD_ERROR_BOUND))
+    }
+
+    /// Implements subtraction of `TrackedTwoFloat` from an exact `f64`,
+    /// adding the error bound of Joldes et al. (2017) Algorithm 4.
+    fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b TrackedTwoFloat) -> TrackedTwoFloat {
+        let value = self - rhs.value;
+        TrackedTwoFloat::tracked(value, add_error(rhs, 0.0, value, ADD_WORD_ERROR_BOUND))
+    }
+
+    /// Implements multiplication of two `TrackedTwoFloat` values, adding the
+    /// error bound of Joldes et al. (2017) Algorithm 12.
+    fn Mul::mul<'a, 'b>(self: &'a TrackedTwoFloat, rhs: &'b TrackedTwoFloat) -> TrackedTwoFloat {
+        let value = self.value * rhs.value;
+        let error = mul_error(self, rhs.value, rhs.error, value, MUL_ERROR_BOUND);
+        TrackedTwoFloat::tracked(value, error)
+    }
+
+    /// Implements multiplication of `TrackedTwoFloat` and an exact `f64`,
+    /// adding the error bound of Joldes et al. (2017) Algorithm 9.
+    fn Mul::mul<'a, 'b>(self: &'a TrackedTwoFloat, rhs: &'b f64) -> TrackedTwoFloat {
+        let value = self.value * rhs;
+        let error = mul_error(self, TwoFloat::from(*rhs), 0.0, value, MUL_WORD_ERROR_BOUND);
+        TrackedTwoFloat::tracked(value, error)
+    }
+
+    /// Implements multiplication of an exact `f64` and `TrackedTwoFloat`,
+    /// adding the error bound of Joldes et al. (2017) Algorithm 9.
+    fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b TrackedTwoFloat) -> TrackedTwoFloat {
+        rhs * self
+    }
+
+    /// Implements division of two `TrackedTwoFloat` values, adding the error
+    /// bound of Joldes et al. (2017) Algorithm 18. The error bound is
+    /// infinite if the error of the divisor is not smaller than its
+    /// magnitude.
+    fn Div::div<'a, 'b>(self: &'a TrackedTwoFloat, rhs: &'b TrackedTwoFloat) -> TrackedTwoFloat {
+        let value = self.value / rhs.value;
+        let error = div_error(self.error, rhs.value, rhs.error, value, DIV_ERROR_BOUND);
+        TrackedTwoFloat::tracked(value, error)
+    }
+
+    /// Implements division of `TrackedTwoFloat` by an exact `f64`, adding
+    /// the error bound of Joldes et al. (2017) Algorithm 15.
+    fn Div::div<'a, 'b>(self: &'a TrackedTwoFloat, rhs: &'b f64) -> TrackedTwoFloat {
+        let value = self.value / rhs;
+        let error = div_error(self.error, TwoFloat::from(*rhs), 0.0, value, DIV_WORD_ERROR_BOUND);
+        TrackedTwoFloat::tracked(value, error)
+    }
+
+    /// Implements division of an exact `f64` by `TrackedTwoFloat`, adding
+    /// the error bound of Joldes et al. (2017) Algorithm 18.
+    fn Div::div<'a, 'b>(self: &'a f64, rhs: &'b TrackedTwoFloat) -> TrackedTwoFloat {
+        let value = self / rhs.value;
+        let error = div_error(0.0, rhs.value, rhs.error, value, DIV_ERROR_BOUND);
+        TrackedTwoFloat::tracked(value, error)
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut TrackedTwoFloat, rhs: &'a TrackedTwoFloat) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut TrackedTwoFloat, rhs: &'a f64) {
+        *self = *self + rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TrackedTwoFloat, rhs: &'a TrackedTwoFloat) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TrackedTwoFloat, rhs: &'a f64) {
+        *self = *self - rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TrackedTwoFloat, rhs: &'a TrackedTwoFloat) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TrackedTwoFloat, rhs: &'a f64) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TrackedTwoFloat, rhs: &'a TrackedTwoFloat) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TrackedTwoFloat, rhs: &'a f64) {
+        *self = *self / rhs;
+    }
+}
@@ -0,0 +1,141 @@
+use super::{rounding_error, TrackedTwoFloat, ERROR_ROUNDING};
+use crate::{
+    functions::{
+        explog::{EXP_ERROR_BOUND, LN_ERROR_BOUND},
+        power::SQRT_ERROR_BOUND,
+        trigonometry::SIN_COS_ERROR_BOUND,
+    },
+    TwoFloat,
+};
+
+// Returns the error bound of sin or cos of `x` with result `value`. Both
+// functions have a derivative of at most one in magnitude, and their results
+// differ by at most 2 over any interval.
+fn sin_cos_error(x: &TrackedTwoFloat, value: TwoFloat) -> f64 {
+    (x.error.min(2.0) + SIN_COS_ERROR_BOUND) * ERROR_ROUNDING + rounding_error(value, 0.0)
+}
+
+impl TrackedTwoFloat {
+    /// Returns the square root of the value. The propagated error assumes
+    /// that the exact value is non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::with_error(TwoFloat::from(4.0), 1e-20).sqrt();
+    ///
+    /// assert_eq!(a.value(), 2.0);
+    /// assert!(a.error() > 2.4e-21 && a.error() < 2.6e-21);
+    pub fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        let root = self.value.hi.abs().sqrt();
+        let propagated = if self.error == 0.0 {
+            0.0
+        } else {
+            // |sqrt(x) - sqrt(a)| = |x - a| / (sqrt(x) + sqrt(a)), which is
+            // also at most sqrt(|x - a|)
+            let lower = (self.value.hi - self.error).max(0.0).sqrt();
+            (self.error / (root + lower)).min(self.error.sqrt())
+        };
+        Self::tracked(
+            value,
+            propagated * ERROR_ROUNDING + rounding_error(value, SQRT_ERROR_BOUND),
+        )
+    }
+
+    /// Returns `e^x`, where `x` is the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::with_error(TwoFloat::from(1.0), 1e-20).exp();
+    ///
+    /// assert!(a.error() > 2.7e-20 && a.error() < 1e-9);
+    pub fn exp(self) -> Self {
+        let value = self.value.exp();
+        let propagated = value.hi.abs() * (1.0 + EXP_ERROR_BOUND) * self.error.exp_m1();
+        Self::tracked(
+            value,
+            propagated * ERROR_ROUNDING + rounding_error(value, EXP_ERROR_BOUND),
+        )
+    }
+
+    /// Returns the natural logarithm of the value. The error bound is
+    /// infinite if the error of the value is not smaller than its magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::with_error(TwoFloat::from(2.0), 1e-20).ln();
+    ///
+    /// assert!(a.error() > 5e-21 && a.error() < 1e-9);
+    pub fn ln(self) -> Self {
+        let value = self.value.ln();
+        let magnitude = self.value.hi.abs();
+        let propagated = if self.error == 0.0 {
+            0.0
+        } else if self.error < magnitude {
+            -(-self.error / magnitude).ln_1p()
+        } else {
+            f64::INFINITY
+        };
+        Self::tracked(
+            value,
+            propagated * ERROR_ROUNDING
+                + rounding_error(value, LN_ERROR_BOUND)
+                + LN_ERROR_BOUND * ERROR_ROUNDING,
+        )
+    }
+
+    /// Returns the sine of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::with_error(TwoFloat::from(1.0), 1e-25).sin();
+    ///
+    /// assert!(a.error() > 1e-25 && a.error() < 1e-18);
+    pub fn sin(self) -> Self {
+        let value = self.value.sin();
+        Self::tracked(value, sin_cos_error(&self, value))
+    }
+
+    /// Returns the cosine of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::with_error(TwoFloat::from(1.0), 1e-25).cos();
+    ///
+    /// assert!(a.error() > 1e-25 && a.error() < 1e-18);
+    pub fn cos(self) -> Self {
+        let value = self.value.cos();
+        Self::tracked(value, sin_cos_error(&self, value))
+    }
+
+    /// Returns the tangent of the value. The error bound follows from the
+    /// bounds of the sine and cosine, and is infinite if the value may be
+    /// a pole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TrackedTwoFloat, TwoFloat};
+    /// let a = TrackedTwoFloat::with_error(TwoFloat::from(1.0), 1e-25).tan();
+    ///
+    /// assert!(a.error() > 1e-25 && a.error() < 1e-15);
+    pub fn tan(self) -> Self {
+        let value = self.value.tan();
+        let quotient = self.sin() / self.cos();
+        let difference = (value - quotient.value).hi.abs();
+        Self::tracked(
+            value,
+            (quotient.error + difference) * ERROR_ROUNDING + rounding_error(value, 0.0),
+        )
+    }
+}
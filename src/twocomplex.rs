@@ -0,0 +1,423 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "serde_support")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    base::{binade, scale},
+    TwoFloat,
+};
+
+#[cfg(feature = "math_funcs")]
+mod functions;
+
+/// Represents a complex number with `TwoFloat` real and imaginary parts.
+///
+/// Arithmetic operators are provided for combinations of `TwoComplex`,
+/// `TwoFloat` and `f64` values. Division uses the algorithm of Smith (1962)
+/// on operands scaled by powers of two, so that intermediate results only
+/// overflow if the quotient does. If the
+/// `math_funcs` feature is enabled, the modulus, argument, square root,
+/// exponential, logarithm, power and trigonometric functions are also
+/// provided, returning principal values with branch cuts on the negative real
+/// axis.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{TwoComplex, TwoFloat};
+/// let a = TwoComplex::new(TwoFloat::from(1.0), TwoFloat::from(2.0));
+/// let b = a * a.conj();
+///
+/// assert_eq!(b, TwoComplex::from(5.0));
+/// assert_eq!(b / a, a.conj());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct TwoComplex {
+    /// The real part.
+    pub re: TwoFloat,
+    /// The imaginary part.
+    pub im: TwoFloat,
+}
+
+impl TwoComplex {
+    /// The value zero.
+    pub const ZERO: Self = Self {
+        re: TwoFloat::ZERO,
+        im: TwoFloat::ZERO,
+    };
+
+    /// The value one.
+    pub const ONE: Self = Self {
+        re: TwoFloat::ONE,
+        im: TwoFloat::ZERO,
+    };
+
+    /// The imaginary unit.
+    pub const I: Self = Self {
+        re: TwoFloat::ZERO,
+        im: TwoFloat::ONE,
+    };
+
+    /// Represents an error value with `NAN` real and imaginary parts.
+    pub const NAN: Self = Self {
+        re: TwoFloat::NAN,
+        im: TwoFloat::NAN,
+    };
+
+    /// Creates a new `TwoComplex` from its real and imaginary parts.
+    pub const fn new(re: TwoFloat, im: TwoFloat) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns `true` if either part is `NAN`.
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Returns `true` if either part is infinite, and neither is `NAN`.
+    pub fn is_infinite(&self) -> bool {
+        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+    }
+
+    /// Returns `true` if both parts are finite.
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Returns the complex conjugate of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let a = TwoComplex::new(TwoFloat::from(1.0), TwoFloat::from(2.0));
+    ///
+    /// assert_eq!(a.conj().im, -2.0);
+    pub fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Returns the square of the modulus of `self`, which may overflow or
+    /// underflow where the modulus itself would not.
+    pub fn norm_sqr(self) -> TwoFloat {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Returns the multiplicative inverse of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let a = TwoComplex::new(TwoFloat::from(3.0), TwoFloat::from(4.0));
+    /// let b = a.recip();
+    ///
+    /// assert!((a * b - 1.0).norm_sqr() < 1e-60);
+    pub fn recip(self) -> Self {
+        div(&Self::ONE, &self)
+    }
+}
+
+// Returns the exponent of the larger part of a finite non-zero value.
+pub(crate) fn max_binade(z: &TwoComplex) -> i32 {
+    if z.re.abs() >= z.im.abs() {
+        binade(z.re)
+    } else {
+        binade(z.im)
+    }
+}
+
+// Divides `a` by `b` using Smith's algorithm, which scales by the ratio of
+// the parts of the divisor instead of forming the squared modulus. Finite
+// operands are first scaled by powers of two so that their larger parts are
+// close to one, so that the result only overflows or underflows if the
+// quotient is not representable.
+fn div(a: &TwoComplex, b: &TwoComplex) -> TwoComplex {
+    if b.im == 0.0 {
+        return div_real(a, b.re);
+    } else if b.re == 0.0 {
+        return TwoComplex {
+            re: a.im / b.im,
+            im: -(a.re / b.im),
+        };
+    } else if !a.is_finite() || !b.is_finite() || (a.re == 0.0 && a.im == 0.0) {
+        return smith_div(a, b);
+    }
+
+    let (ka, kb) = (max_binade(a), max_binade(b));
+    let a = TwoComplex::new(scale(a.re, -ka), scale(a.im, -ka));
+    let b = TwoComplex::new(scale(b.re, -kb), scale(b.im, -kb));
+    let q = smith_div(&a, &b);
+    TwoComplex::new(scale(q.re, ka - kb), scale(q.im, ka - kb))
+}
+
+fn smith_div(a: &TwoComplex, b: &TwoComplex) -> TwoComplex {
+    if b.re.abs() >= b.im.abs() {
+        let r = b.im / b.re;
+        let d = b.re + b.im * r;
+        TwoComplex {
+            re: (a.re + a.im * r) / d,
+            im: (a.im - a.re * r) / d,
+        }
+    } else {
+        let r = b.re / b.im;
+        let d = b.re * r + b.im;
+        TwoComplex {
+            re: (a.re * r + a.im) / d,
+            im: (a.im * r - a.re) / d,
+        }
+    }
+}
+
+fn div_real(a: &TwoComplex, b: TwoFloat) -> TwoComplex {
+    TwoComplex {
+        re: a.re / b,
+        im: a.im / b,
+    }
+}
+
+impl From<f64> for TwoComplex {
+    fn from(value: f64) -> Self {
+        Self::from(TwoFloat::from(value))
+    }
+}
+
+impl From<TwoFloat> for TwoComplex {
+    fn from(value: TwoFloat) -> Self {
+        Self {
+            re: value,
+            im: TwoFloat::ZERO,
+        }
+    }
+}
+
+impl From<(TwoFloat, TwoFloat)> for TwoComplex {
+    fn from(value: (TwoFloat, TwoFloat)) -> Self {
+        Self {
+            re: value.0,
+            im: value.1,
+        }
+    }
+}
+
+impl From<TwoComplex> for (TwoFloat, TwoFloat) {
+    fn from(value: TwoComplex) -> Self {
+        (value.re, value.im)
+    }
+}
+
+impl PartialEq<TwoFloat> for TwoComplex {
+    fn eq(&self, other: &TwoFloat) -> bool {
+        self.eq(&Self::from(*other))
+    }
+}
+
+impl PartialEq<f64> for TwoComplex {
+    fn eq(&self, other: &f64) -> bool {
+        self.eq(&Self::from(*other))
+    }
+}
+
+unary_ops! {
+    fn Neg::neg(self: &TwoComplex) -> TwoComplex {
+        Self::Output {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements addition of two `TwoComplex` values.
+    fn Add::add<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+
+    /// Implements addition of `TwoComplex` and `TwoFloat`.
+    fn Add::add<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoFloat) -> TwoComplex {
+        TwoComplex {
+            re: self.re + rhs,
+            im: self.im,
+        }
+    }
+
+    /// Implements addition of `TwoFloat` and `TwoComplex`.
+    fn Add::add<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoComplex) -> TwoComplex {
+        rhs + self
+    }
+
+    /// Implements addition of `TwoComplex` and `f64`.
+    fn Add::add<'a, 'b>(self: &'a TwoComplex, rhs: &'b f64) -> TwoComplex {
+        TwoComplex {
+            re: self.re + rhs,
+            im: self.im,
+        }
+    }
+
+    /// Implements addition of `f64` and `TwoComplex`.
+    fn Add::add<'a, 'b>(self: &'a f64, rhs: &'b TwoComplex) -> TwoComplex {
+        rhs + self
+    }
+
+    /// Implements subtraction of two `TwoComplex` values.
+    fn Sub::sub<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+
+    /// Implements subtraction of `TwoComplex` and `TwoFloat`.
+    fn Sub::sub<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoFloat) -> TwoComplex {
+        TwoComplex {
+            re: self.re - rhs,
+            im: self.im,
+        }
+    }
+
+    /// Implements subtraction of `TwoFloat` and `TwoComplex`.
+    fn Sub::sub<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex {
+            re: self - rhs.re,
+            im: -rhs.im,
+        }
+    }
+
+    /// Implements subtraction of `TwoComplex` and `f64`.
+    fn Sub::sub<'a, 'b>(self: &'a TwoComplex, rhs: &'b f64) -> TwoComplex {
+        TwoComplex {
+            re: self.re - rhs,
+            im: self.im,
+        }
+    }
+
+    /// Implements subtraction of `f64` and `TwoComplex`.
+    fn Sub::sub<'a, 'b>(self: &'a f64, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex {
+            re: self - rhs.re,
+            im: -rhs.im,
+        }
+    }
+
+    /// Implements multiplication of two `TwoComplex` values.
+    fn Mul::mul<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoComplex) -> TwoComplex {
+        TwoComplex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+
+    /// Implements multiplication of `TwoComplex` and `TwoFloat`.
+    fn Mul::mul<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoFloat) -> TwoComplex {
+        TwoComplex {
+            re: self.re * rhs,
+            im: self.im * rhs,
+        }
+    }
+
+    /// Implements multiplication of `TwoFloat` and `TwoComplex`.
+    fn Mul::mul<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoComplex) -> TwoComplex {
+        rhs * self
+    }
+
+    /// Implements multiplication of `TwoComplex` and `f64`.
+    fn Mul::mul<'a, 'b>(self: &'a TwoComplex, rhs: &'b f64) -> TwoComplex {
+        TwoComplex {
+            re: self.re * rhs,
+            im: self.im * rhs,
+        }
+    }
+
+    /// Implements multiplication of `f64` and `TwoComplex`.
+    fn Mul::mul<'a, 'b>(self: &'a f64, rhs: &'b TwoComplex) -> TwoComplex {
+        rhs * self
+    }
+
+    /// Implements division of two `TwoComplex` values using Smith's
+    /// algorithm.
+    fn Div::div<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoComplex) -> TwoComplex {
+        div(self, rhs)
+    }
+
+    /// Implements division of `TwoComplex` and `TwoFloat`.
+    fn Div::div<'a, 'b>(self: &'a TwoComplex, rhs: &'b TwoFloat) -> TwoComplex {
+        div_real(self, *rhs)
+    }
+
+    /// Implements division of `TwoFloat` and `TwoComplex` using Smith's
+    /// algorithm.
+    fn Div::div<'a, 'b>(self: &'a TwoFloat, rhs: &'b TwoComplex) -> TwoComplex {
+        div(&TwoComplex::from(*self), rhs)
+    }
+
+    /// Implements division of `TwoComplex` and `f64`.
+    fn Div::div<'a, 'b>(self: &'a TwoComplex, rhs: &'b f64) -> TwoComplex {
+        div_real(self, TwoFloat::from(*rhs))
+    }
+
+    /// Implements division of `f64` and `TwoComplex` using Smith's
+    /// algorithm.
+    fn Div::div<'a, 'b>(self: &'a f64, rhs: &'b TwoComplex) -> TwoComplex {
+        div(&TwoComplex::from(*self), rhs)
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoComplex) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoFloat) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut TwoComplex, rhs: &'a f64) {
+        *self = *self + rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoComplex) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoFloat) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoComplex, rhs: &'a f64) {
+        *self = *self - rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoComplex) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoFloat) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoComplex, rhs: &'a f64) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoComplex) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoComplex, rhs: &'a TwoFloat) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoComplex, rhs: &'a f64) {
+        *self = *self / rhs;
+    }
+}
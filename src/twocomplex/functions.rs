@@ -0,0 +1,212 @@
+use super::{max_binade, TwoComplex};
+use crate::{base::scale, status, Expansion, TwoFloat};
+
+// Above this magnitude of the imaginary part, sinh(y) cosh(y) overflows in
+// the formula for tan, which is replaced by its asymptotic form.
+const TAN_ASYMPTOTIC_LIMIT: f64 = 350.0;
+
+impl TwoComplex {
+    /// Returns the modulus of `self`, computed without overflow using
+    /// `TwoFloat::hypot`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let a = TwoComplex::new(TwoFloat::from(3e300), TwoFloat::from(4e300));
+    ///
+    /// assert!((a.norm() / 1e300 - 5.0).abs() < 1e-30);
+    pub fn norm(self) -> TwoFloat {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the argument of `self` in the range [-π, π].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::FRAC_PI_2, TwoComplex};
+    /// assert_eq!(TwoComplex::I.arg(), FRAC_PI_2);
+    pub fn arg(self) -> TwoFloat {
+        self.im.atan2(self.re)
+    }
+
+    /// Returns the principal square root of `self`, with a non-negative real
+    /// part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let a = TwoComplex::new(TwoFloat::from(-3.0), TwoFloat::from(4.0));
+    ///
+    /// assert_eq!(a.sqrt(), TwoComplex::new(TwoFloat::from(1.0), TwoFloat::from(2.0)));
+    pub fn sqrt(self) -> Self {
+        status::function("csqrt", &[self.re, self.im], || {
+            if self.im == 0.0 {
+                return if self.re.is_sign_positive() {
+                    Self::new(self.re.sqrt(), self.im)
+                } else {
+                    Self::new(TwoFloat::ZERO, (-self.re).sqrt().copysign(self.im))
+                };
+            }
+            if self.im.is_infinite() {
+                return Self::new(TwoFloat::INFINITY, self.im);
+            } else if self.re.is_infinite() && !self.im.is_nan() {
+                return if self.re > 0.0 {
+                    Self::new(self.re, TwoFloat::ZERO.copysign(self.im))
+                } else {
+                    Self::new(TwoFloat::ZERO, TwoFloat::INFINITY.copysign(self.im))
+                };
+            } else if self.is_nan() {
+                return Self::NAN;
+            }
+            // Scale by an even power of two so that the larger part is close
+            // to one, avoiding overflow and underflow of the modulus
+            let k = max_binade(&self).div_euclid(2);
+            let (re, im) = (scale(self.re, -2 * k), scale(self.im, -2 * k));
+            let t = ((re.hypot(im) + re.abs()) / 2.0).sqrt();
+            let (root_re, root_im) = if re.is_sign_positive() {
+                (t, im / (2.0 * t))
+            } else {
+                (im.abs() / (2.0 * t), t.copysign(im))
+            };
+            Self::new(scale(root_re, k), scale(root_im, k))
+        })
+    }
+
+    /// Returns `e^(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::PI, TwoComplex};
+    /// let a = (TwoComplex::I * PI).exp();
+    ///
+    /// assert!((a + 1.0).norm() < 1e-15);
+    pub fn exp(self) -> Self {
+        status::function("cexp", &[self.re, self.im], || {
+            if self.im == 0.0 {
+                return Self::new(self.re.exp(), self.im);
+            }
+            let (s, c) = self.im.sin_cos();
+            let r = self.re.exp();
+            Self::new(r * c, r * s)
+        })
+    }
+
+    /// Returns the principal value of the natural logarithm of `self`, with
+    /// imaginary part in the range [-π, π]. Near the unit circle the real
+    /// part is computed as `ln_1p((re - 1)(re + 1) + im²) / 2`, with the
+    /// argument of `ln_1p` evaluated exactly, to keep its relative accuracy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{consts::PI, TwoComplex};
+    /// let a = TwoComplex::from(-1.0).ln();
+    ///
+    /// assert_eq!(a, TwoComplex::new(0.0.into(), PI));
+    pub fn ln(self) -> Self {
+        status::function("cln", &[self.re, self.im], || {
+            let norm = self.norm();
+            let re = if norm > 0.5 && norm < 2.0 {
+                let t = (Expansion::from(self.re) - 1.0) * (Expansion::from(self.re) + 1.0)
+                    + Expansion::from(self.im) * self.im;
+                TwoFloat::from(&t).ln_1p() / 2.0
+            } else {
+                norm.ln()
+            };
+            Self::new(re, self.arg())
+        })
+    }
+
+    /// Returns the principal value of `self` raised to the complex power
+    /// `exponent`, computed as `exp(exponent * ln(self))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoComplex;
+    /// let a = TwoComplex::I.powc(TwoComplex::I);
+    /// let expected = (-core::f64::consts::FRAC_PI_2).exp();
+    ///
+    /// assert!((a.re - expected).abs() < 1e-12);
+    /// assert!(a.im.abs() < 1e-30);
+    pub fn powc(self, exponent: Self) -> Self {
+        status::function(
+            "powc",
+            &[self.re, self.im, exponent.re, exponent.im],
+            || {
+                if self.re == 0.0 && self.im == 0.0 {
+                    if exponent.re > 0.0 {
+                        Self::ZERO
+                    } else {
+                        Self::NAN
+                    }
+                } else {
+                    (exponent * self.ln()).exp()
+                }
+            },
+        )
+    }
+
+    /// Computes the sine of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let a = TwoComplex::new(TwoFloat::from(1.0), TwoFloat::from(0.5));
+    /// let b = a.sin() * a.sin() + a.cos() * a.cos();
+    ///
+    /// assert!((b - 1.0).norm() < 1e-15);
+    pub fn sin(self) -> Self {
+        status::function("csin", &[self.re, self.im], || {
+            if self.im == 0.0 {
+                return Self::new(self.re.sin(), self.im);
+            }
+            let (s, c) = self.re.sin_cos();
+            Self::new(s * self.im.cosh(), c * self.im.sinh())
+        })
+    }
+
+    /// Computes the cosine of `self`.
+    pub fn cos(self) -> Self {
+        status::function("ccos", &[self.re, self.im], || {
+            if self.im == 0.0 {
+                return Self::new(self.re.cos(), -self.im);
+            }
+            let (s, c) = self.re.sin_cos();
+            Self::new(c * self.im.cosh(), -(s * self.im.sinh()))
+        })
+    }
+
+    /// Computes the tangent of `self`, using the formula
+    /// `(sin(x) cos(x) + i sinh(y) cosh(y)) / (cos²(x) + sinh²(y))`, which
+    /// avoids the overflow of the sine and cosine for large imaginary parts
+    /// and the cancellation in the denominator near the real poles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::{TwoComplex, TwoFloat};
+    /// let a = TwoComplex::new(TwoFloat::from(1.0), TwoFloat::from(1000.0));
+    ///
+    /// assert_eq!(a.tan().im, 1.0);
+    pub fn tan(self) -> Self {
+        status::function("ctan", &[self.re, self.im], || {
+            if self.im == 0.0 {
+                return Self::new(self.re.tan(), self.im);
+            }
+            let (s, c) = self.re.sin_cos();
+            if self.im.abs() > TAN_ASYMPTOTIC_LIMIT {
+                let scale = (self.im.abs() * -2.0).exp();
+                return Self::new(s * c * scale * 4.0, TwoFloat::ONE.copysign(self.im));
+            }
+            let sh = self.im.sinh();
+            let d = c * c + sh * sh;
+            Self::new(s * c / d, sh * self.im.cosh() / d)
+        })
+    }
+}
@@ -0,0 +1,552 @@
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+#[cfg(feature = "serde_support")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    arithmetic::Operation,
+    base::no_overlap_f32,
+    status::{self, FpFlags},
+    TwoFloat, TwoFloatError,
+};
+
+/// Represents a two-word floating point type, represented as the sum of two
+/// non-overlapping f32 values.
+///
+/// `TwoF32` provides about 48 bits of precision using only `f32`
+/// arithmetic, for targets such as GPUs where `f64` is slow or unavailable.
+/// The arithmetic uses the same double-word algorithms of Joldes et al.
+/// (2017) as `TwoFloat`, with the same handling of special values.
+/// Conversion to `TwoFloat` is exact, and conversion back rounds to the
+/// nearest `TwoF32`.
+///
+/// # Examples
+///
+/// ```
+/// # use twofloat::{TwoF32, TwoFloat};
+/// let third = TwoF32::from(1.0f32) / 3.0;
+///
+/// assert!((third * 3.0 - 1.0).abs() < TwoF32::EPSILON);
+/// assert_eq!(TwoF32::from(TwoFloat::from(third)), third);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_support", serde(try_from = "(f32, f32)"))]
+#[cfg_attr(feature = "serde_support", serde(into = "(f32, f32)"))]
+pub struct TwoF32 {
+    pub(crate) hi: f32,
+    pub(crate) lo: f32,
+}
+
+double_word_arithmetic!(TwoF32, f32, 8.507059e37, 9.223372e18);
+
+impl TwoF32 {
+    /// Represents an error value equivalent to `f32::NAN`.
+    pub const NAN: Self = Self {
+        hi: f32::NAN,
+        lo: f32::NAN,
+    };
+
+    /// Positive infinity.
+    pub const INFINITY: Self = Self {
+        hi: f32::INFINITY,
+        lo: 0.0,
+    };
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Self {
+        hi: f32::NEG_INFINITY,
+        lo: 0.0,
+    };
+
+    /// The value zero.
+    pub const ZERO: Self = Self { hi: 0.0, lo: 0.0 };
+
+    /// The value one.
+    pub const ONE: Self = Self { hi: 1.0, lo: 0.0 };
+
+    /// Number of significant binary digits, treating a `TwoF32` as having
+    /// the combined precision of its two `f32` components.
+    pub const MANTISSA_DIGITS: u32 = 2 * f32::MANTISSA_DIGITS;
+
+    /// Machine epsilon value for `TwoF32`: 2<sup>-47</sup>.
+    pub const EPSILON: Self = Self {
+        hi: 7.1054274e-15,
+        lo: 0.0,
+    };
+
+    /// Largest finite `TwoF32` value, `2^128 - 2^103 - 2^80`.
+    pub const MAX: Self = Self {
+        hi: f32::MAX,
+        lo: 1.01412036e31,
+    };
+
+    /// Smallest finite `TwoF32` value, equal to `-MAX`.
+    pub const MIN: Self = Self {
+        hi: f32::MIN,
+        lo: -1.01412036e31,
+    };
+
+    /// Smallest positive value retaining the full `MANTISSA_DIGITS` bits of
+    /// precision: 2<sup>-102</sup>.
+    pub const MIN_POSITIVE: Self = Self {
+        hi: 1.9721523e-31,
+        lo: 0.0,
+    };
+
+    /// Creates a new `TwoF32` by adding two `f32` values using Algorithm 2
+    /// from Joldes et al. (2017).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let value = TwoF32::new_add(1.0, 1.0e-10);
+    ///
+    /// assert_eq!(value.hi(), 1.0);
+    /// assert_eq!(value.lo(), 1.0e-10);
+    pub fn new_add(a: f32, b: f32) -> Self {
+        check_special(two_sum(a, b), Operation::Add, a, b)
+    }
+
+    /// Creates a new `TwoF32` by subtracting two `f32` values using
+    /// Algorithm 2 from Joldes et al. (2017) modified for negative
+    /// right-hand side.
+    pub fn new_sub(a: f32, b: f32) -> Self {
+        check_special(two_diff(a, b), Operation::Sub, a, b)
+    }
+
+    /// Creates a new `TwoF32` by multiplying two `f32` values using
+    /// Algorithm 3 from Joldes et al. (2017).
+    pub fn new_mul(a: f32, b: f32) -> Self {
+        check_scaled(two_prod(a, b), Operation::Mul, a, b, || {
+            two_prod_scaled(a, b)
+        })
+    }
+
+    /// Creates a new `TwoF32` by dividing two `f32` values using Algorithm
+    /// 15 from Joldes et al. (2017) modified for the left-hand-side having a
+    /// zero value in the low word.
+    pub fn new_div(a: f32, b: f32) -> Self {
+        check_scaled(div_words(a, b), Operation::Div, a, b, || {
+            div_words_scaled(a, b)
+        })
+    }
+
+    /// Returns the high word of `self`.
+    pub fn hi(&self) -> f32 {
+        self.hi
+    }
+
+    /// Returns the low word of `self`.
+    pub fn lo(&self) -> f32 {
+        self.lo
+    }
+
+    /// Returns `true` if `self` is a valid value, where both components are
+    /// finite (not infinity or `NAN`) and do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(1.0, 1.0e-30).is_valid();
+    /// let b = TwoF32::new_mul(1.0e30, 1.0e30).is_valid();
+    ///
+    /// assert!(a);
+    /// assert!(!b);
+    pub fn is_valid(&self) -> bool {
+        self.hi.is_finite() && self.lo.is_finite() && no_overlap_f32(self.hi, self.lo)
+    }
+
+    /// Returns `true` if `self` is `NAN`.
+    pub fn is_nan(&self) -> bool {
+        self.hi.is_nan() || self.lo.is_nan()
+    }
+
+    /// Returns `true` if `self` is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        self.hi.is_infinite() && !self.lo.is_nan()
+    }
+
+    /// Returns `true` if `self` is neither infinite nor `NAN`.
+    pub fn is_finite(&self) -> bool {
+        self.hi.is_finite() && self.lo.is_finite()
+    }
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0`.
+    pub fn is_sign_positive(&self) -> bool {
+        self.hi.is_sign_positive()
+    }
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0`.
+    pub fn is_sign_negative(&self) -> bool {
+        self.hi.is_sign_negative()
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::new_add(-1.0, 1.0e-10);
+    ///
+    /// assert_eq!(a.abs(), -a);
+    pub fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Returns the square root of the number, using the SQRTDWtoDW
+    /// algorithm of Lefèvre et al. (2023) with a relative error of at most
+    /// 25/8 * 2<sup>-48</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twofloat::TwoF32;
+    /// let a = TwoF32::from(2.0f32).sqrt();
+    ///
+    /// assert!((a * a - 2.0).abs() < 1e-13);
+    #[cfg(feature = "math_funcs")]
+    pub fn sqrt(self) -> Self {
+        status::function("sqrt", &[TwoFloat::from(self)], || {
+            if self.hi == 0.0 || self.hi == f32::INFINITY {
+                return Self::from(self.hi);
+            } else if self.hi < 0.0 || self.is_nan() {
+                return Self::NAN;
+            }
+            let sh = self.hi.sqrt();
+            let rho = (-sh).mul_add(sh, self.hi) + self.lo;
+            fast_two_sum(sh, rho / (2.0 * sh))
+        })
+    }
+}
+
+unary_ops! {
+    fn Neg::neg(self: &TwoF32) -> TwoF32 {
+        Self::Output {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+binary_ops! {
+    /// Implements addition of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 4.
+    fn Add::add<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        check_special(add_word(self, *rhs), Operation::Add, self.hi, *rhs)
+    }
+
+    /// Implements addition of `f32` and `TwoF32` using Joldes et al.
+    /// (2017) Algorithm 4.
+    fn Add::add<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        check_special(add_word(rhs, *self), Operation::Add, *self, rhs.hi)
+    }
+
+    /// Implements addition of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 6.
+    fn Add::add<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        check_special(add(self, rhs), Operation::Add, self.hi, rhs.hi)
+    }
+
+    /// Implements subtraction of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 4 modified for negative right-hand side.
+    fn Sub::sub<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        check_special(sub_word(self, *rhs), Operation::Sub, self.hi, *rhs)
+    }
+
+    /// Implements subtraction of `f32` and `TwoF32` using Joldes et al.
+    /// (2017) Algorithm 4 modified for negative left-hand side.
+    fn Sub::sub<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        check_special(word_sub(*self, rhs), Operation::Sub, *self, rhs.hi)
+    }
+
+    /// Implements subtraction of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 6 modified for a negative right-hand side.
+    fn Sub::sub<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        check_special(sub(self, rhs), Operation::Sub, self.hi, rhs.hi)
+    }
+
+    /// Implements multiplication of `TwoF32` and `f32` using Joldes et al.
+    /// (2017) Algorithm 9.
+    fn Mul::mul<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        check_scaled(mul_word(self, *rhs), Operation::Mul, self.hi, *rhs, || {
+            mul_word_scaled(self, *rhs)
+        })
+    }
+
+    /// Implements multiplication of `f32` and `TwoF32` using Joldes et al.
+    /// (2017) Algorithm 9.
+    fn Mul::mul<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        check_scaled(mul_word(rhs, *self), Operation::Mul, *self, rhs.hi, || {
+            mul_word_scaled(rhs, *self)
+        })
+    }
+
+    /// Implements multiplication of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 12.
+    fn Mul::mul<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        check_scaled(mul(self, rhs), Operation::Mul, self.hi, rhs.hi, || {
+            mul_scaled(self, rhs)
+        })
+    }
+
+    /// Implements division of `TwoF32` and `f32` using Joldes et al. (2017)
+    /// Algorithm 15.
+    fn Div::div<'a, 'b>(self: &'a TwoF32, rhs: &'b f32) -> TwoF32 {
+        check_scaled(div_word(self, *rhs), Operation::Div, self.hi, *rhs, || {
+            div_word_scaled(self, *rhs)
+        })
+    }
+
+    /// Implements division of `f32` and `TwoF32` using Joldes et al. (2017)
+    /// Algorithm 18 modified for the left-hand side having a zero value in
+    /// the low word.
+    fn Div::div<'a, 'b>(self: &'a f32, rhs: &'b TwoF32) -> TwoF32 {
+        check_scaled(word_div(*self, rhs), Operation::Div, *self, rhs.hi, || {
+            word_div_scaled(*self, rhs)
+        })
+    }
+
+    /// Implements division of two `TwoF32` values using Joldes et al.
+    /// (2017) Algorithm 18.
+    fn Div::div<'a, 'b>(self: &'a TwoF32, rhs: &'b TwoF32) -> TwoF32 {
+        check_scaled(div(self, rhs), Operation::Div, self.hi, rhs.hi, || {
+            div_scaled(self, rhs)
+        })
+    }
+}
+
+// Self-assignment operators
+
+assign_ops! {
+    fn AddAssign::add_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self + rhs;
+    }
+
+    fn AddAssign::add_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self + rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self - rhs;
+    }
+
+    fn SubAssign::sub_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self - rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self * rhs;
+    }
+
+    fn MulAssign::mul_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self * rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoF32, rhs: &'a f32) {
+        *self = *self / rhs;
+    }
+
+    fn DivAssign::div_assign<'a>(self: &mut TwoF32, rhs: &'a TwoF32) {
+        *self = *self / rhs;
+    }
+}
+
+impl From<f32> for TwoF32 {
+    fn from(value: f32) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+}
+
+impl From<TwoF32> for f32 {
+    fn from(value: TwoF32) -> Self {
+        value.hi
+    }
+}
+
+impl From<f64> for TwoF32 {
+    /// Rounds the value to the nearest `TwoF32`.
+    fn from(value: f64) -> Self {
+        Self::from(TwoFloat::from(value))
+    }
+}
+
+impl From<TwoF32> for f64 {
+    fn from(value: TwoF32) -> Self {
+        f64::from(value.hi) + f64::from(value.lo)
+    }
+}
+
+impl From<TwoF32> for TwoFloat {
+    fn from(value: TwoF32) -> Self {
+        if value.is_nan() {
+            TwoFloat::NAN
+        } else if value.lo == 0.0 || value.is_infinite() {
+            TwoFloat::from(f64::from(value.hi))
+        } else {
+            TwoFloat::new_add(f64::from(value.hi), f64::from(value.lo))
+        }
+    }
+}
+
+impl<'a> From<&'a TwoF32> for TwoFloat {
+    fn from(value: &'a TwoF32) -> Self {
+        Self::from(*value)
+    }
+}
+
+// Rounds the exact sum of the words of `value` to the nearest `f32`. The sum
+// is first rounded to odd in `f64`, which has enough extra bits that the
+// final rounding gives the same result as rounding the exact sum directly.
+fn round_to_f32(value: TwoFloat) -> f32 {
+    let (hi, lo) = (value.hi(), value.lo());
+    let bits = hi.to_bits();
+    let odd = if lo != 0.0 && bits & 1 == 0 {
+        // The exact sum lies strictly between `hi` and its odd neighbour in
+        // the direction of `lo`
+        f64::from_bits(if (lo > 0.0) == (hi > 0.0) {
+            bits + 1
+        } else {
+            bits - 1
+        })
+    } else {
+        hi
+    };
+    odd as f32
+}
+
+impl From<TwoFloat> for TwoF32 {
+    /// Converts the value to a `TwoF32`, whose high word is the high word of
+    /// the value rounded to `f32` and whose low word is the remainder
+    /// rounded to the nearest `f32`.
+    fn from(value: TwoFloat) -> Self {
+        if value.is_nan() {
+            return TwoF32::NAN;
+        }
+        let hi = value.hi() as f32;
+        if !hi.is_finite() || hi == 0.0 {
+            return Self::from(hi);
+        }
+        // The difference between the high words and its sum with the low word
+        // are exact
+        let rest = TwoFloat::new_add(value.hi() - f64::from(hi), value.lo());
+        fast_two_sum(hi, round_to_f32(rest))
+    }
+}
+
+impl<'a> From<&'a TwoFloat> for TwoF32 {
+    fn from(value: &'a TwoFloat) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<TwoF32> for (f32, f32) {
+    fn from(value: TwoF32) -> Self {
+        (value.hi, value.lo)
+    }
+}
+
+impl TryFrom<(f32, f32)> for TwoF32 {
+    type Error = TwoFloatError;
+
+    fn try_from(value: (f32, f32)) -> Result<Self, Self::Error> {
+        if no_overlap_f32(value.0, value.1) {
+            Ok(Self {
+                hi: value.0,
+                lo: value.1,
+            })
+        } else {
+            Err(Self::Error::ConversionError {})
+        }
+    }
+}
+
+impl From<TwoF32> for [f32; 2] {
+    fn from(value: TwoF32) -> Self {
+        [value.hi, value.lo]
+    }
+}
+
+impl TryFrom<[f32; 2]> for TwoF32 {
+    type Error = TwoFloatError;
+
+    fn try_from(value: [f32; 2]) -> Result<Self, Self::Error> {
+        Self::try_from((value[0], value[1]))
+    }
+}
+
+impl fmt::Display for TwoF32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return fmt::Display::fmt(&f32::NAN, f);
+        } else if self.hi.is_infinite() {
+            return fmt::Display::fmt(&self.hi, f);
+        }
+        let sign_char = if self.lo.is_sign_positive() { '+' } else { '-' };
+        match (f.sign_plus(), f.precision()) {
+            (true, Some(p)) => write!(
+                f,
+                "{:+.*} {} {:.*}",
+                p,
+                self.hi,
+                sign_char,
+                p,
+                self.lo.abs()
+            ),
+            (true, None) => write!(f, "{:+} {} {}", self.hi, sign_char, self.lo.abs()),
+            (false, Some(p)) => {
+                write!(f, "{:.*} {} {:.*}", p, self.hi, sign_char, p, self.lo.abs())
+            }
+            (false, None) => write!(f, "{} {} {}", self.hi, sign_char, self.lo.abs()),
+        }
+    }
+}
+
+impl PartialEq<f32> for TwoF32 {
+    fn eq(&self, other: &f32) -> bool {
+        self.eq(&Self::from(*other))
+    }
+}
+
+impl PartialEq<TwoF32> for f32 {
+    fn eq(&self, other: &TwoF32) -> bool {
+        TwoF32::from(*self).eq(other)
+    }
+}
+
+impl PartialOrd<f32> for TwoF32 {
+    fn partial_cmp(&self, other: &f32) -> Option<Ordering> {
+        self.partial_cmp(&Self::from(*other))
+    }
+}
+
+impl PartialOrd<TwoF32> for f32 {
+    fn partial_cmp(&self, other: &TwoF32) -> Option<Ordering> {
+        TwoF32::from(*self).partial_cmp(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TwoF32;
+
+    #[test]
+    fn display_test() {
+        let value = TwoF32 { hi: 1.0, lo: 0.25 };
+        assert_eq!(format!("{}", value), "1 + 0.25");
+        assert_eq!(format!("{}", -value), "-1 - 0.25");
+        assert_eq!(format!("{:+.2}", value), "+1.00 + 0.25");
+    }
+}
@@ -102,6 +102,24 @@ fn new_div_test() {
     });
 }
 
+#[test]
+fn div_accuracy_test() {
+    repeated_test(|| {
+        let a = get_valid_twofloat(|x, _| x.abs() > 1e-100 && x.abs() < 1e100);
+        let b = get_valid_twofloat(|x, _| x.abs() > 1e-100 && x.abs() < 1e100);
+        let q = a / b;
+
+        // The error bound of 9.8u^2 plus the error of the residual
+        let residual = (a - q * b) / a;
+        assert!(
+            residual.abs() < 2e-31,
+            "Inaccurate result of {:?} / {:?}",
+            a,
+            b
+        );
+    });
+}
+
 // Test for negation operator
 
 #[test]
@@ -332,6 +350,7 @@ fn f64_twofloat_reversible(op: Operator) {
     });
 }
 
+#[allow(clippy::needless_borrows_for_generic_args)]
 fn f64_twofloat_assign(op: Operator) {
     repeated_test(|| {
         let c = random_float();
@@ -441,6 +460,79 @@ fn twofloat_assign_op(op: Operator) {
     });
 }
 
+// Special values
+
+const SPECIAL_VALUES: [f64; 8] = [
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    f64::MAX,
+];
+
+fn check_special<L: Debug, R: Debug>(
+    op: &Operator,
+    expected: f64,
+    result: TwoFloat,
+    lhs: L,
+    rhs: R,
+) {
+    if expected.is_nan() {
+        assert!(
+            result.is_nan(),
+            "Operation {:?} {} {:?} did not produce NaN",
+            lhs,
+            op.symbol(),
+            rhs
+        );
+    } else {
+        assert_eq!(
+            (result.hi(), result.lo()),
+            (expected, 0.0),
+            "Operation {:?} {} {:?} gave wrong result",
+            lhs,
+            op.symbol(),
+            rhs
+        );
+        assert_eq!(
+            result.is_sign_negative(),
+            expected.is_sign_negative(),
+            "Operation {:?} {} {:?} gave wrong sign",
+            lhs,
+            op.symbol(),
+            rhs
+        );
+    }
+}
+
+fn special_values(op: Operator) {
+    for &a in SPECIAL_VALUES.iter() {
+        for &b in SPECIAL_VALUES.iter() {
+            let expected: f64 = op.apply(a, b);
+            if expected.is_finite() && expected != 0.0 {
+                continue;
+            }
+
+            let a_two = TwoFloat::from(a);
+            let b_two = TwoFloat::from(b);
+            check_special(&op, expected, op.apply(a_two, b_two), a_two, b_two);
+            check_special(&op, expected, op.apply(a_two, b), a_two, b);
+            check_special(&op, expected, op.apply(a, b_two), a, b_two);
+
+            let mut assigned = a_two;
+            op.apply_assign(&mut assigned, b_two);
+            check_special(&op, expected, assigned, a_two, b_two);
+
+            let mut assigned = a_two;
+            op.apply_assign(&mut assigned, b);
+            check_special(&op, expected, assigned, a_two, b);
+        }
+    }
+}
+
 macro_rules! op_test {
     ($name:ident($op:expr);) => {
         mod $name {
@@ -475,6 +567,11 @@ macro_rules! op_test {
             fn twofloat_assign_op() {
                 super::twofloat_assign_op($op);
             }
+
+            #[test]
+            fn special_values() {
+                super::special_values($op);
+            }
         }
     };
     ($name:ident($op:expr); $($names:ident($ops:expr);)+) => {
@@ -230,3 +230,150 @@ fn max_test() {
         );
     });
 }
+
+#[test]
+fn constants_test() {
+    assert!(TwoFloat::MAX.is_valid());
+    assert!(TwoFloat::MIN.is_valid());
+    assert_eq!(TwoFloat::MIN, -TwoFloat::MAX);
+    assert_eq!(TwoFloat::MAX.hi() + TwoFloat::MAX.lo(), f64::MAX);
+    assert_eq!(TwoFloat::ONE - TwoFloat::ONE, TwoFloat::ZERO);
+    assert_eq!(TwoFloat::ONE + TwoFloat::EPSILON, TwoFloat::ONE.next_up());
+    assert_eq!(
+        TwoFloat::EPSILON.hi(),
+        (1.0 - TwoFloat::MANTISSA_DIGITS as f64).exp2()
+    );
+    assert_eq!(TwoFloat::MIN_POSITIVE.ulp().hi(), f64::from_bits(1));
+    assert!(TwoFloat::MIN_POSITIVE.next_down().ulp() == TwoFloat::MIN_POSITIVE.ulp());
+    assert!(TwoFloat::INFINITY > TwoFloat::MAX);
+    assert!(TwoFloat::NEG_INFINITY < TwoFloat::MIN);
+}
+
+#[test]
+fn next_up_down_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let up = a.next_up();
+        let down = a.next_down();
+
+        assert!(up - a > 0.0, "next_up({:?}) produced {:?}", a, up);
+        assert!(a - down > 0.0, "next_down({:?}) produced {:?}", a, down);
+        if up.is_valid() {
+            assert!(
+                up - a <= a.ulp(),
+                "next_up({:?}) stepped further than ulp",
+                a
+            );
+            assert_eq!(
+                up.next_down().next_up(),
+                up,
+                "next_down did not invert next_up for {:?}",
+                up
+            );
+        }
+        if down.is_valid() {
+            assert_eq!(
+                down.next_up().next_down(),
+                down,
+                "next_up did not invert next_down for {:?}",
+                down
+            );
+        }
+    });
+}
+
+#[test]
+fn next_up_down_special_test() {
+    let tiny = TwoFloat::from(f64::from_bits(1));
+    assert_eq!(TwoFloat::ZERO.next_up(), tiny);
+    assert_eq!(TwoFloat::ZERO.next_down(), -tiny);
+    assert_eq!(tiny.next_down(), TwoFloat::ZERO);
+    assert_eq!(TwoFloat::MAX.next_up(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::MIN.next_down(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.next_down(), TwoFloat::MAX);
+    assert_eq!(TwoFloat::NEG_INFINITY.next_up(), TwoFloat::MIN);
+    assert_eq!(TwoFloat::INFINITY.next_up(), TwoFloat::INFINITY);
+    assert!(!TwoFloat::NAN.next_up().is_valid());
+
+    let one = TwoFloat::ONE;
+    assert_eq!(
+        one.next_down(),
+        TwoFloat::new_sub(1.0, TwoFloat::EPSILON.hi() / 2.0)
+    );
+    assert_eq!((-one).next_up(), -one.next_down());
+    assert_eq!(TwoFloat::new_add(1.0, 1e-300).next_up(), one.next_up());
+    assert_eq!(TwoFloat::new_add(1.0, 1e-300).next_down(), one);
+}
+
+#[test]
+fn ulp_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let ulp = a.ulp();
+        assert!(ulp.lo() == 0.0 && ulp.hi() > 0.0);
+        if a.abs() >= 1e-250 && a.abs() <= 1e250 {
+            let scaled = a.abs() * TwoFloat::EPSILON;
+            assert!(
+                ulp <= scaled && ulp > scaled / 2.0,
+                "ulp({:?}) = {:?} has wrong magnitude",
+                a,
+                ulp
+            );
+        }
+    });
+
+    assert_eq!(TwoFloat::ONE.ulp(), TwoFloat::EPSILON);
+    assert_eq!(
+        TwoFloat::new_sub(1.0, 1e-300).ulp(),
+        TwoFloat::EPSILON / 2.0
+    );
+    assert!(!TwoFloat::INFINITY.ulp().is_valid());
+}
+
+#[test]
+fn classify_test() {
+    use core::num::FpCategory;
+
+    assert_eq!(TwoFloat::ZERO.classify(), FpCategory::Zero);
+    assert_eq!(TwoFloat::from(-0.0).classify(), FpCategory::Zero);
+    assert_eq!(TwoFloat::MIN_POSITIVE.classify(), FpCategory::Normal);
+    assert_eq!(
+        TwoFloat::MIN_POSITIVE.next_down().classify(),
+        FpCategory::Subnormal
+    );
+    assert_eq!(TwoFloat::MAX.classify(), FpCategory::Normal);
+    assert_eq!(TwoFloat::INFINITY.classify(), FpCategory::Infinite);
+    assert_eq!(TwoFloat::NEG_INFINITY.classify(), FpCategory::Infinite);
+    assert_eq!(TwoFloat::NAN.classify(), FpCategory::Nan);
+
+    repeated_test(|| {
+        let a = get_twofloat();
+        assert!(a.is_finite(), "{:?} is not finite", a);
+        assert!(!a.is_infinite(), "{:?} is infinite", a);
+        assert!(!a.is_nan(), "{:?} is NaN", a);
+        assert_eq!(
+            a.is_normal(),
+            a.abs() >= TwoFloat::MIN_POSITIVE,
+            "is_normal mismatch for {:?}",
+            a
+        );
+    });
+}
+
+#[test]
+fn min_max_special_test() {
+    let a = get_twofloat();
+    assert_eq!(a.min(TwoFloat::NAN), a);
+    assert_eq!(TwoFloat::NAN.max(a), a);
+    assert_eq!(a.min(TwoFloat::NEG_INFINITY), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.max(a), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::INFINITY.min(a), a);
+}
+
+#[test]
+fn display_special_test() {
+    assert_eq!(format!("{}", TwoFloat::INFINITY), "inf");
+    assert_eq!(format!("{:+}", TwoFloat::INFINITY), "+inf");
+    assert_eq!(format!("{:e}", TwoFloat::NEG_INFINITY), "-inf");
+    assert_eq!(format!("{:E}", TwoFloat::NAN), "NaN");
+}
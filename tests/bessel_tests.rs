@@ -0,0 +1,437 @@
+#![cfg(feature = "math_funcs")]
+#![allow(clippy::float_cmp)]
+
+pub mod common;
+
+use common::*;
+use rand::Rng;
+use twofloat::TwoFloat;
+
+// Reference values computed with mpmath at 80 digits
+const J0_CASES: [(f64, [f64; 2]); 14] = [
+    (-3.5, [-0.3801277399872634, 2.4809484081072208e-17]),
+    (-0.75, [0.8642422751666486, -2.001381357057117e-17]),
+    (1e-08, [1.0, -2.5e-17]),
+    (0.125, [0.9960975630419852, 4.78698008549742e-17]),
+    (0.6, [0.9120048634972108, 1.0815171536138752e-17]),
+    (0.99, [0.7695818809651686, -2.5056793190228408e-17]),
+    (1.0, [0.7651976865579666, -5.3942094227986187e-17]),
+    (1.7, [0.3979848594461095, 9.980145018266516e-18]),
+    (3.25, [-0.33275080217061154, 2.7701069803676658e-17]),
+    (7.5, [0.2663396578803784, 8.13368192556792e-18]),
+    (12.0, [0.047689310796833535, 1.3240681441307869e-18]),
+    (19.9, [0.17287775639261846, 3.2922621855092695e-18]),
+    (27.3, [0.02936397401852779, -1.1275914124763292e-18]),
+    (36.0, [-0.10556738166868807, 2.8443059465920923e-18]),
+];
+
+const J1_CASES: [(f64, [f64; 2]); 14] = [
+    (-3.5, [-0.1373775273623272, 9.587716373484852e-18]),
+    (-0.75, [-0.34924360217486217, -2.5902752901990523e-17]),
+    (1e-08, [5e-09, -6.250000000000001e-26]),
+    (0.125, [0.062378009134494684, -3.2792086744998663e-18]),
+    (0.6, [0.28670098806391575, -2.1419474325013592e-17]),
+    (0.99, [0.43678289579482477, 1.0666428621002335e-17]),
+    (1.0, [0.4400505857449335, 1.8081531429378303e-17]),
+    (1.7, [0.5777652315290233, -4.2642997678239686e-17]),
+    (3.25, [0.2411196880152039, -4.1477391699609966e-18]),
+    (7.5, [0.1352484275797055, -5.033050548631172e-18]),
+    (12.0, [-0.2234471044906276, -1.0764816502670533e-17]),
+    (19.9, [0.05011742480737974, 7.743089124650937e-19]),
+    (27.3, [0.15040682155860058, 9.281658806844906e-19]),
+    (36.0, [-0.08232980948644893, -2.7945167142315235e-18]),
+];
+
+const JN_CASES: [(i32, f64, [f64; 2]); 9] = [
+    (2, 0.5, [0.03060402345868264, -2.0454950963502384e-19]),
+    (-3, 1.5, [-0.06096395114113963, -5.47339213222004e-19]),
+    (5, -2.5, [-0.01950162513450322, -6.976302011873019e-19]),
+    (7, 10.0, [0.21671091768505152, -4.359656708845467e-18]),
+    (12, 3.0, [2.275725448320572e-07, -4.4792027108680426e-24]),
+    (30, 8.0, [2.5830997825663075e-15, 2.375638061126735e-32]),
+    (60, 45.5, [3.163490145983839e-05, 1.9693386339068264e-21]),
+    (150, 20.0, [9.012891695587592e-114, 6.907065466287592e-130]),
+    (400, 1.0, [0.0, 0.0]),
+];
+
+const Y0_CASES: [(f64, [f64; 2]); 12] = [
+    (1e-08, [-11.80077387717953, -4.526561825137764e-16]),
+    (0.125, [-1.3896806251438405, -3.138532510018452e-17]),
+    (0.6, [-0.3085098701155904, 1.5900368762332813e-17]),
+    (0.99, [0.08040121621363991, 2.8609919332821118e-18]),
+    (1.0, [0.08825696421567696, 2.273393719067021e-18]),
+    (1.7, [0.4520270001816346, 6.0103913742389705e-18]),
+    (3.25, [0.28828690267308704, 1.182712448503512e-17]),
+    (7.5, [0.11731328614820863, 2.1826142438147822e-18]),
+    (12.0, [-0.22523731263436145, 1.365111928638023e-17]),
+    (19.9, [0.04576209415938548, -1.4825047511046223e-18]),
+    (27.3, [0.1498441199253975, -2.1900197557091376e-18]),
+    (36.0, [-0.08085608872560611, -6.121797190280456e-18]),
+];
+
+const Y1_CASES: [(f64, [f64; 2]); 12] = [
+    (1e-08, [-63661977.236758195, 1.2927955032675706e-09]),
+    (0.125, [-5.199936112534775, 3.938447662536259e-16]),
+    (0.6, [-1.2603913471773875, -8.839664184323154e-17]),
+    (0.99, [-0.7899515491621414, -1.960242719514815e-17]),
+    (1.0, [-0.7812128213002887, -3.2035379956875093e-17]),
+    (1.7, [-0.2847262450640684, 1.0302085638733463e-19]),
+    (3.25, [0.37977777371708354, -2.1144447549025225e-17]),
+    (7.5, [-0.25912851048611624, -1.4854430987195036e-17]),
+    (12.0, [-0.05709921826089652, -1.189501160158063e-18]),
+    (19.9, [-0.17178303121049257, 5.9439560242671264e-18]),
+    (27.3, [-0.02662540749804394, 1.514471509518222e-18]),
+    (36.0, [0.10445476633847657, -6.357226627688797e-18]),
+];
+
+const YN_CASES: [(i32, f64, [f64; 2]); 7] = [
+    (2, 0.5, [-5.441370837174266, 2.312581426308556e-16]),
+    (-3, 1.5, [2.073541399060686, -1.2339158795702945e-16]),
+    (5, 2.5, [-3.8301760007407517, -1.557180448907294e-16]),
+    (7, 10.0, [0.201020023779585, 7.225095751194373e-19]),
+    (12, 3.0, [-120415.14950438803, -5.59613037654984e-12]),
+    (30, 8.0, [-4262139336611.7324, -0.00012526046308515375]),
+    (100, 20.0, [-8.200264814468194e+55, 4.65980278467849e+39]),
+];
+
+const I0_CASES: [(f64, [f64; 2]); 13] = [
+    (-5.0, [27.239871823604446, 9.727705008330191e-16]),
+    (-0.3, [1.022626879351597, 8.254941294482692e-17]),
+    (1e-08, [1.0, 2.5000000000000003e-17]),
+    (0.5, [1.0634833707413236, -7.776752973879811e-17]),
+    (1.0, [1.2660658777520084, -7.057804265547195e-17]),
+    (2.0, [2.2795853023360673, 2.320678481355194e-18]),
+    (6.5, [106.2928582439956, -6.310678867384644e-16]),
+    (15.0, [339649.3732979139, 6.419185694017088e-12]),
+    (39.0, [5549742955314184.0, -0.2350716146136656]),
+    (41.0, [3.998826282831435e+16, 3.9750584415581613]),
+    (80.0, [2.4751784043341704e+33, 6.72361378732299e+16]),
+    (300.0, [4.475847367935052e+128, 3.0245237482481075e+112]),
+    (700.0, [1.5295933476718737e+302, 6.076761631139411e+285]),
+];
+
+const I1_CASES: [(f64, [f64; 2]); 13] = [
+    (-5.0, [-24.335642142450528, 6.147919179834455e-16]),
+    (-0.3, [-0.15169384000359276, -1.3155094471761637e-17]),
+    (1e-08, [5e-09, 6.250000000000001e-26]),
+    (0.5, [0.2578943053908963, 1.0688304222361744e-17]),
+    (1.0, [0.565159103992485, -7.387764974417287e-18]),
+    (2.0, [1.590636854637329, -3.947888181806809e-18]),
+    (6.5, [97.73501077403152, -5.722162158934491e-15]),
+    (15.0, [328124.9219702064, 2.569082071456178e-11]),
+    (39.0, [5478124121309490.0, -0.15297315997276004]),
+    (41.0, [3.949755210006231e+16, -2.6648919593445033]),
+    (80.0, [2.459659579567541e+33, -5.171019492975507e+16]),
+    (300.0, [4.468381385036955e+128, -3.4023253388820893e+112]),
+    (700.0, [1.5285003902339006e+302, 8.942962893949464e+285]),
+];
+
+const K0_CASES: [(f64, [f64; 2]); 11] = [
+    (1e-08, [18.536612259610777, 1.2015118044205794e-15]),
+    (0.125, [2.20786908674497, 1.0470271629704684e-16]),
+    (0.5, [0.9244190712276659, -5.4589060988523284e-18]),
+    (1.0, [0.42102443824070834, -9.659705789588543e-18]),
+    (1.99, [0.1153017675517768, -4.526366426934127e-18]),
+    (2.0, [0.11389387274953344, -6.7706223918546385e-18]),
+    (3.5, [0.01959889717036849, -1.5813013155700144e-18]),
+    (10.0, [1.778006231616765e-05, 1.6302858289134316e-21]),
+    (50.0, [3.4101677497894956e-23, -4.97621873242755e-40]),
+    (300.0, [3.7236948548891435e-132, -2.2275802811711173e-148]),
+    (700.0, [4.669776431685377e-306, -2.4e-322]),
+];
+
+const K1_CASES: [(f64, [f64; 2]); 11] = [
+    (1e-08, [99999999.9999999, 7.0328109758668536e-09]),
+    (0.125, [7.831118299115751, 3.053006733410506e-16]),
+    (0.5, [1.656441120003301, -1.047677030977042e-17]),
+    (1.0, [0.6019072301972346, -2.58045953609113e-18]),
+    (1.99, [0.14171756162240132, -9.802535932674134e-18]),
+    (2.0, [0.13986588181652243, -3.1740059239940707e-18]),
+    (3.5, [0.022239392925923834, -5.824477688243525e-19]),
+    (10.0, [1.8648773453825585e-05, -8.577301565852997e-22]),
+    (50.0, [3.4441022267175555e-23, 1.4774020857008647e-39]),
+    (300.0, [3.7298958583323724e-132, 3.119050109573743e-148]),
+    (700.0, [4.6731107967079664e-306, -2.96e-322]),
+];
+
+const J0_LARGE_CASES: [(f64, [f64; 2]); 6] = [
+    (41.0, [-0.1007457891244798, 5.9800369865465645e-18]),
+    (50.5, [0.09551989154970057, -5.302557161034392e-18]),
+    (77.0, [0.06237977708964741, 2.3601760737117532e-18]),
+    (123.25, [-0.07174647981800912, -5.050625907089175e-18]),
+    (1000.0, [0.024786686152420176, -1.3601539818285269e-18]),
+    (12345.5, [-0.0012413659758697551, -3.880464533046855e-21]),
+];
+
+const J1_LARGE_CASES: [(f64, [f64; 2]); 6] = [
+    (41.0, [0.07210126160497939, 5.956806592290277e-19]),
+    (50.5, [-0.058062876421320686, -4.3094020656351483e-19]),
+    (77.0, [0.06656064247057206, 3.1974713370745375e-18]),
+    (123.25, [0.003912800826252135, 3.09893149435509e-19]),
+    (1000.0, [0.004728311907089524, -3.4609600427379497e-20]),
+    (12345.5, [-0.007072953487339537, -3.1845060256725807e-19]),
+];
+
+const JN_LARGE_CASES: [(i32, f64, [f64; 2]); 1] =
+    [(-4, 100.0, [0.02610580944772528, 1.0755782847707135e-18])];
+
+const YN_LARGE_CASES: [(i32, f64, [f64; 2]); 1] =
+    [(-4, 100.0, [-0.0754301199237623, 4.871796808034955e-18])];
+
+const Y0_LARGE_CASES: [(f64, [f64; 2]); 6] = [
+    (41.0, [0.07332423904628867, -1.976347062086181e-18]),
+    (50.5, [-0.05900563494765445, 9.000145786639563e-19]),
+    (77.0, [0.06615420170392486, 5.715976344705498e-18]),
+    (123.25, [0.004203822218343866, -1.575187325965759e-20]),
+    (1000.0, [0.0047159179776228135, -5.779892029551405e-20]),
+    (12345.5, [-0.007072903205487761, -4.1251285167787516e-19]),
+];
+
+const Y1_LARGE_CASES: [(f64, [f64; 2]); 6] = [
+    (41.0, [0.10164733899741435, -4.628565146028793e-18]),
+    (50.5, [-0.09610872758567612, 2.3634535405670813e-18]),
+    (77.0, [-0.06195153724951892, 2.630539176592707e-18]),
+    (123.25, [0.07176412390916302, 1.4009688223142616e-19]),
+    (1000.0, [-0.024784331292351778, -9.89264611640787e-19]),
+    (12345.5, [0.0012410795201548848, 7.750104799137184e-20]),
+];
+
+// Checks the relative error of the results, allowing for the reduced precision
+// of the low word near the subnormal range.
+fn check_cases(
+    cases: &[(f64, [f64; 2])],
+    f: impl Fn(TwoFloat) -> TwoFloat,
+    tolerance: f64,
+    name: &str,
+) {
+    for (x, [hi, lo]) in cases.iter() {
+        let result = f(TwoFloat::from(*x));
+        let expected = TwoFloat::new_add(*hi, *lo);
+
+        assert!(result.is_valid(), "{}({}) produced invalid value", name, x);
+        let difference = (result - expected).abs();
+        assert!(
+            difference <= expected.abs() * tolerance || difference.hi() < 1e-322,
+            "Mismatch in {}({}): {:?} vs {:?}",
+            name,
+            x,
+            expected,
+            result
+        );
+    }
+}
+
+fn check_order_cases(
+    cases: &[(i32, f64, [f64; 2])],
+    f: impl Fn(TwoFloat, i32) -> TwoFloat,
+    tolerance: f64,
+    name: &str,
+) {
+    for (n, x, [hi, lo]) in cases.iter() {
+        let result = f(TwoFloat::from(*x), *n);
+        let expected = TwoFloat::new_add(*hi, *lo);
+
+        assert!(
+            result.is_valid(),
+            "{}({}, {}) produced invalid value",
+            name,
+            n,
+            x
+        );
+        let difference = (result - expected).abs();
+        assert!(
+            difference <= expected.abs() * tolerance,
+            "Mismatch in {}({}, {}): {:?} vs {:?}",
+            name,
+            n,
+            x,
+            expected,
+            result
+        );
+    }
+}
+
+#[test]
+fn j0_test() {
+    check_cases(&J0_CASES, TwoFloat::j0, 1e-30, "j0");
+
+    // Limited by the accuracy of `sin_cos`
+    check_cases(&J0_LARGE_CASES, TwoFloat::j0, 1e-18, "j0");
+
+    assert_eq!(TwoFloat::from(0.0).j0(), 1.0);
+    assert_eq!(TwoFloat::INFINITY.j0(), 0.0);
+    assert_eq!(TwoFloat::NEG_INFINITY.j0(), 0.0);
+    assert!(TwoFloat::NAN.j0().is_nan());
+}
+
+#[test]
+fn j1_test() {
+    check_cases(&J1_CASES, TwoFloat::j1, 1e-30, "j1");
+
+    // Limited by the accuracy of `sin_cos`
+    check_cases(&J1_LARGE_CASES, TwoFloat::j1, 1e-18, "j1");
+
+    assert_eq!(TwoFloat::from(0.0).j1(), 0.0);
+    assert!(TwoFloat::from(-0.0).j1().is_sign_negative());
+    assert_eq!(TwoFloat::INFINITY.j1(), 0.0);
+    assert!(TwoFloat::NAN.j1().is_nan());
+}
+
+#[test]
+fn jn_test() {
+    check_order_cases(&JN_CASES, TwoFloat::jn, 1e-30, "jn");
+
+    // The case for x = 100 is limited by the accuracy of `sin_cos`
+    check_order_cases(&JN_LARGE_CASES, TwoFloat::jn, 1e-18, "jn");
+
+    assert_eq!(TwoFloat::from(0.0).jn(3), 0.0);
+    assert_eq!(TwoFloat::from(0.0).jn(-3), 0.0);
+    assert_eq!(TwoFloat::from(1.0).jn(1_000_000), 0.0);
+    assert_eq!(TwoFloat::INFINITY.jn(5), 0.0);
+    assert!(TwoFloat::NAN.jn(2).is_nan());
+}
+
+#[test]
+fn y0_test() {
+    check_cases(&Y0_CASES, TwoFloat::y0, 1e-30, "y0");
+
+    // Limited by the accuracy of `sin_cos`
+    check_cases(&Y0_LARGE_CASES, TwoFloat::y0, 1e-18, "y0");
+
+    assert_eq!(TwoFloat::from(0.0).y0(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.y0(), 0.0);
+    assert!(TwoFloat::from(-1.0).y0().is_nan());
+    assert!(TwoFloat::NAN.y0().is_nan());
+}
+
+#[test]
+fn y1_test() {
+    check_cases(&Y1_CASES, TwoFloat::y1, 1e-29, "y1");
+
+    // Limited by the accuracy of `sin_cos`
+    check_cases(&Y1_LARGE_CASES, TwoFloat::y1, 1e-18, "y1");
+
+    assert_eq!(TwoFloat::from(0.0).y1(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.y1(), 0.0);
+    assert!(TwoFloat::from(-1.0).y1().is_nan());
+    assert!(TwoFloat::NAN.y1().is_nan());
+}
+
+#[test]
+fn yn_test() {
+    check_order_cases(&YN_CASES, TwoFloat::yn, 1e-30, "yn");
+
+    // The case for x = 100 is limited by the accuracy of `sin_cos`
+    check_order_cases(&YN_LARGE_CASES, TwoFloat::yn, 1e-18, "yn");
+
+    assert_eq!(TwoFloat::from(0.0).yn(4), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::from(1e-3).yn(1000), TwoFloat::NEG_INFINITY);
+    assert!(TwoFloat::from(-2.0).yn(3).is_nan());
+    assert!(TwoFloat::NAN.yn(2).is_nan());
+}
+
+#[test]
+fn i0_test() {
+    check_cases(&I0_CASES, TwoFloat::i0, 1e-30, "i0");
+
+    assert_eq!(TwoFloat::from(0.0).i0(), 1.0);
+    assert_eq!(TwoFloat::from(800.0).i0(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::INFINITY.i0(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::NEG_INFINITY.i0(), TwoFloat::INFINITY);
+    assert!(TwoFloat::NAN.i0().is_nan());
+}
+
+#[test]
+fn i1_test() {
+    check_cases(&I1_CASES, TwoFloat::i1, 1e-30, "i1");
+
+    assert_eq!(TwoFloat::from(0.0).i1(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.i1(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::NEG_INFINITY.i1(), TwoFloat::NEG_INFINITY);
+    assert!(TwoFloat::NAN.i1().is_nan());
+}
+
+#[test]
+fn k0_test() {
+    check_cases(&K0_CASES, TwoFloat::k0, 1e-30, "k0");
+
+    assert_eq!(TwoFloat::from(0.0).k0(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(800.0).k0(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.k0(), 0.0);
+    assert!(TwoFloat::from(-1.0).k0().is_nan());
+    assert!(TwoFloat::NAN.k0().is_nan());
+}
+
+#[test]
+fn k1_test() {
+    check_cases(&K1_CASES, TwoFloat::k1, 1e-30, "k1");
+
+    assert_eq!(TwoFloat::from(0.0).k1(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(800.0).k1(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.k1(), 0.0);
+    assert!(TwoFloat::from(-1.0).k1().is_nan());
+    assert!(TwoFloat::NAN.k1().is_nan());
+}
+
+#[test]
+fn bessel_recurrence_test() {
+    let mut rng = rand::thread_rng();
+    let src_dist = rand::distributions::Uniform::new(-40.0, 40.0);
+
+    for _ in 0..1000 {
+        // Avoid the loss of relative accuracy in 2n/x near zero
+        let hi = get_valid_f64_gen(|| rng.sample(src_dist), |x| x.abs() > 1e-3);
+        let x = TwoFloat::new_add(hi, rng.gen_range(-1e-16..1e-16));
+        let n = rng.gen_range(1..50);
+        let (previous, current, next) = (x.jn(n - 1), x.jn(n), x.jn(n + 1));
+        assert!(
+            current.is_valid(),
+            "jn({}, {:?}) produced invalid value",
+            n,
+            x
+        );
+
+        // J_(n-1)(x) + J_(n+1)(x) = (2n/x) J_n(x)
+        let scale = previous.abs().max(next.abs()).max(TwoFloat::from(1e-300));
+        let difference = ((previous + next - 2.0 * f64::from(n) * current / x) / scale).abs();
+        assert!(
+            difference < 1e-28,
+            "Mismatch in jn({}, {:?}) recurrence: {:?}",
+            n,
+            x,
+            difference
+        );
+    }
+}
+
+#[test]
+fn bessel_wronskian_test() {
+    let mut rng = rand::thread_rng();
+    let src_dist = rand::distributions::Uniform::new(0.01, 100.0);
+
+    for _ in 0..1000 {
+        let x = TwoFloat::from(rng.sample(src_dist));
+
+        // J_1(x) Y_0(x) - J_0(x) Y_1(x) = 2 / (πx)
+        let expected = 2.0 / (twofloat::consts::PI * x);
+        let wronskian = x.j1() * x.y0() - x.j0() * x.y1();
+        assert!(
+            ((wronskian - expected) / expected).abs() < 1e-18,
+            "Mismatch in Wronskian of J and Y at {:?}: {:?} vs {:?}",
+            x,
+            expected,
+            wronskian
+        );
+
+        // I_0(x) K_1(x) + I_1(x) K_0(x) = 1 / x
+        let expected = x.recip();
+        let wronskian = x.i0() * x.k1() + x.i1() * x.k0();
+        assert!(
+            ((wronskian - expected) / expected).abs() < 1e-29,
+            "Mismatch in Wronskian of I and K at {:?}: {:?} vs {:?}",
+            x,
+            expected,
+            wronskian
+        );
+    }
+}
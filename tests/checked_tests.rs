@@ -0,0 +1,181 @@
+#![allow(clippy::float_cmp)]
+
+use twofloat::{TwoFloat, TwoFloatError};
+
+pub mod common;
+use common::*;
+
+fn is_domain_error<T>(result: Result<T, TwoFloatError>) -> bool {
+    matches!(result, Err(TwoFloatError::DomainError { .. }))
+}
+
+#[test]
+fn checked_arithmetic_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let b = get_twofloat();
+
+        let checks = [
+            (a.checked_add(b), a + b),
+            (a.checked_sub(b), a - b),
+            (a.checked_mul(b), a * b),
+            (a.checked_div(b), a / b),
+        ];
+
+        for (checked, unchecked) in checks.iter() {
+            match checked {
+                Ok(value) => {
+                    assert_eq!(*value, *unchecked);
+                    assert!(value.is_finite());
+                }
+                Err(TwoFloatError::Overflow) => assert!(unchecked.is_infinite()),
+                Err(TwoFloatError::Underflow) => {
+                    assert!(unchecked.abs() < TwoFloat::MIN_POSITIVE)
+                }
+                Err(err) => panic!("Unexpected error {:?} for {:?}, {:?}", err, a, b),
+            }
+        }
+    });
+}
+
+#[test]
+fn checked_arithmetic_special_test() {
+    let inf = TwoFloat::INFINITY;
+    let one = TwoFloat::ONE;
+    let zero = TwoFloat::ZERO;
+
+    assert_eq!(
+        TwoFloat::MAX.checked_mul(TwoFloat::from(2.0)),
+        Err(TwoFloatError::Overflow)
+    );
+    assert_eq!(
+        TwoFloat::MIN.checked_sub(TwoFloat::MAX),
+        Err(TwoFloatError::Overflow)
+    );
+    assert_eq!(
+        TwoFloat::MIN_POSITIVE.checked_div(TwoFloat::from(3.0)),
+        Err(TwoFloatError::Underflow)
+    );
+    assert_eq!(one.checked_div(zero), Err(TwoFloatError::DivisionByZero));
+    assert_eq!(one.checked_recip(), Ok(one));
+    assert_eq!(
+        zero.checked_div(zero),
+        Err(TwoFloatError::DomainError { op: "div" })
+    );
+    assert_eq!(
+        one.checked_rem(zero),
+        Err(TwoFloatError::DomainError { op: "rem" })
+    );
+    assert_eq!(one.checked_add(-one), Ok(zero));
+
+    assert!(is_domain_error(inf.checked_add(one)));
+    assert!(is_domain_error(one.checked_mul(TwoFloat::NAN)));
+    assert!(is_domain_error(inf.checked_div(inf)));
+}
+
+#[test]
+fn checked_arithmetic_exact_tiny_test() {
+    let tiny = TwoFloat::from(1e-300);
+    let half = TwoFloat::from(0.5);
+
+    assert_eq!(tiny.checked_add(TwoFloat::ZERO), Ok(tiny));
+    assert_eq!(tiny.checked_sub(tiny * half), Ok(tiny * half));
+    assert_eq!(tiny.checked_mul(TwoFloat::ONE), Ok(tiny));
+    assert_eq!(
+        TwoFloat::from(2f64.powi(-500)).checked_mul(TwoFloat::from(2f64.powi(-500))),
+        Ok(TwoFloat::from(2f64.powi(-1000)))
+    );
+    assert_eq!(
+        TwoFloat::MIN_POSITIVE.checked_div(TwoFloat::from(2.0)),
+        Ok(TwoFloat::MIN_POSITIVE * half)
+    );
+    assert_eq!(
+        TwoFloat::from(f64::MIN_POSITIVE).checked_div(TwoFloat::from(4.0)),
+        Ok(TwoFloat::from(f64::MIN_POSITIVE / 4.0))
+    );
+    assert_eq!(tiny.checked_rem(TwoFloat::ONE), Ok(tiny));
+}
+
+#[cfg(feature = "math_funcs")]
+#[test]
+fn checked_functions_test() {
+    let inf = TwoFloat::INFINITY;
+    let two = TwoFloat::from(2.0);
+
+    assert_eq!(two.checked_sqrt(), Ok(two.sqrt()));
+    assert_eq!(TwoFloat::ZERO.checked_sqrt(), Ok(TwoFloat::ZERO));
+    assert!(is_domain_error((-two).checked_sqrt()));
+    assert!(is_domain_error(inf.checked_sqrt()));
+
+    assert_eq!(two.checked_rsqrt(), Ok(two.rsqrt()));
+    assert_eq!(
+        TwoFloat::ZERO.checked_rsqrt(),
+        Err(TwoFloatError::DivisionByZero)
+    );
+    assert!(is_domain_error((-two).checked_rsqrt()));
+    assert!(is_domain_error(inf.checked_rsqrt()));
+
+    assert_eq!(two.checked_ln(), Ok(two.ln()));
+    assert_eq!(
+        TwoFloat::ZERO.checked_ln(),
+        Err(TwoFloatError::DivisionByZero)
+    );
+    assert_eq!(
+        (-two).checked_ln(),
+        Err(TwoFloatError::DomainError { op: "ln" })
+    );
+    assert_eq!(
+        (-two).checked_ln_1p(),
+        Err(TwoFloatError::DomainError { op: "ln_1p" })
+    );
+    assert!(is_domain_error(TwoFloat::NAN.checked_log2()));
+
+    assert_eq!(two.checked_exp(), Ok(two.exp()));
+    assert_eq!(
+        TwoFloat::from(710.0).checked_exp(),
+        Err(TwoFloatError::Overflow)
+    );
+    assert_eq!(
+        TwoFloat::from(-1100.0).checked_exp2(),
+        Err(TwoFloatError::Underflow)
+    );
+    assert!(is_domain_error(inf.checked_exp()));
+
+    assert_eq!(two.checked_powi(10), Ok(TwoFloat::from(1024.0)));
+    assert_eq!(
+        TwoFloat::ZERO.checked_powi(-1),
+        Err(TwoFloatError::DivisionByZero)
+    );
+    assert_eq!(TwoFloat::ZERO.checked_powi(0), Ok(TwoFloat::ONE));
+    assert_eq!(
+        TwoFloat::ZERO.checked_powf(TwoFloat::ZERO),
+        Ok(TwoFloat::ONE)
+    );
+    assert_eq!(two.checked_powi(-1100), Err(TwoFloatError::Underflow));
+    assert_eq!(
+        TwoFloat::ZERO.checked_powf(-two),
+        Err(TwoFloatError::DivisionByZero)
+    );
+    assert_eq!(
+        (-two).checked_powf(TwoFloat::from(0.5)),
+        Err(TwoFloatError::DomainError { op: "powf" })
+    );
+
+    assert!(is_domain_error(two.checked_asin()));
+    assert!(is_domain_error(two.checked_acos()));
+    assert!(is_domain_error(TwoFloat::from(0.5).checked_acosh()));
+    assert_eq!(
+        TwoFloat::ONE.checked_atanh(),
+        Err(TwoFloatError::DivisionByZero)
+    );
+    assert!(is_domain_error(two.checked_atanh()));
+}
+
+#[test]
+fn error_display_test() {
+    assert_eq!(format!("{}", TwoFloatError::Overflow), "TwoFloat overflow");
+    assert_eq!(
+        format!("{}", TwoFloatError::DomainError { op: "ln" }),
+        "invalid argument to TwoFloat ln"
+    );
+}
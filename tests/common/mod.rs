@@ -1,13 +1,136 @@
 #![macro_use]
 
-use core::convert::TryFrom;
+use core::{convert::TryFrom, ops::Range};
 
+use num_bigint::BigInt;
 use rand::Rng;
 
-use twofloat::{TwoFloat, TwoFloatError};
+use twofloat::{Expansion, QuadFloat, ThreeFloat, TwoF32, TwoFloat, TwoFloatError};
 
 const TEST_ITERS: usize = 100000;
 
+// Exponent below that of any f64 value, used as the common scale for exact
+// integer comparisons.
+pub const BIG_SCALE: i32 = -1100;
+
+// Converts the sum of the given values to an exact integer multiple of
+// 2^BIG_SCALE.
+pub fn to_big(values: &[f64]) -> BigInt {
+    values.iter().fold(BigInt::from(0), |sum, &x| {
+        if x == 0.0 {
+            return sum;
+        }
+        let bits = x.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let (mantissa, exponent) = if exponent == 0 {
+            (bits & ((1 << 52) - 1), -1074)
+        } else {
+            ((bits & ((1 << 52) - 1)) | (1 << 52), exponent - 1075)
+        };
+        let value = BigInt::from(mantissa) << ((exponent - BIG_SCALE) as usize);
+        if x < 0.0 {
+            sum - value
+        } else {
+            sum + value
+        }
+    })
+}
+
+// Values that are an exact sum of words, compared exactly with `to_big`.
+pub trait Words {
+    fn to_words(self) -> Vec<f64>;
+}
+
+// Multi-word floating point values, whose arithmetic results are compared
+// with the exact results.
+pub trait MultiWord: Words + Copy {
+    // Relative error allowed in arithmetic results, as a power of two.
+    const TOLERANCE_BITS: usize;
+
+    fn valid(self) -> bool;
+}
+
+impl Words for TwoFloat {
+    fn to_words(self) -> Vec<f64> {
+        vec![self.hi(), self.lo()]
+    }
+}
+
+impl MultiWord for TwoFloat {
+    const TOLERANCE_BITS: usize = 100;
+
+    fn valid(self) -> bool {
+        self.is_valid()
+    }
+}
+
+impl Words for ThreeFloat {
+    fn to_words(self) -> Vec<f64> {
+        vec![self.hi(), self.mid(), self.lo()]
+    }
+}
+
+impl MultiWord for ThreeFloat {
+    const TOLERANCE_BITS: usize = 154;
+
+    fn valid(self) -> bool {
+        self.is_valid()
+    }
+}
+
+impl Words for QuadFloat {
+    fn to_words(self) -> Vec<f64> {
+        self.words().to_vec()
+    }
+}
+
+impl MultiWord for QuadFloat {
+    const TOLERANCE_BITS: usize = 207;
+
+    fn valid(self) -> bool {
+        self.is_valid()
+    }
+}
+
+impl Words for TwoF32 {
+    fn to_words(self) -> Vec<f64> {
+        vec![f64::from(self.hi()), f64::from(self.lo())]
+    }
+}
+
+impl MultiWord for TwoF32 {
+    const TOLERANCE_BITS: usize = 44;
+
+    fn valid(self) -> bool {
+        self.is_valid()
+    }
+}
+
+impl Words for &Expansion {
+    fn to_words(self) -> Vec<f64> {
+        self.components().to_vec()
+    }
+}
+
+pub fn big<T: Words>(value: T) -> BigInt {
+    to_big(&value.to_words())
+}
+
+// Checks that `result` is within 2^-bits of the exact value, where both are
+// multiples of the same power of two.
+pub fn assert_close(result: &BigInt, exact: &BigInt, bits: usize, description: &str) {
+    let error: BigInt = result - exact;
+    assert!(
+        (error.magnitude() << bits) <= *exact.magnitude(),
+        "Inaccurate result of {}",
+        description
+    );
+}
+
+pub fn assert_valid<T: MultiWord>(result: T, description: &str) {
+    assert!(result.valid(), "Invalid result of {}", description);
+}
+
 pub fn random_float() -> f64 {
     let mut engine = rand::thread_rng();
     let mantissa_dist = rand::distributions::Uniform::new(0, 1u64 << 52);
@@ -65,6 +188,14 @@ pub fn get_twofloat() -> TwoFloat {
     }
 }
 
+// Returns a value whose high word has random sign and magnitude up to 10^e
+// for a random `e` in `exponents`, with a random low word.
+pub fn get_twofloat_decades(exponents: Range<i32>) -> TwoFloat {
+    let mut rng = rand::thread_rng();
+    let x = rng.gen_range(-1.0..1.0) * 10f64.powi(rng.gen_range(exponents));
+    TwoFloat::new_add(x, x * rng.gen_range(-1e-17..1e-17))
+}
+
 pub fn try_get_twofloat_with_hi(hi: f64) -> Result<TwoFloat, TwoFloatError> {
     if hi == 0.0 {
         return Ok(TwoFloat::from(0.0));
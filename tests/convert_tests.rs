@@ -95,6 +95,62 @@ fn try_from_array_overlap_test() {
     });
 }
 
+#[test]
+fn normalize_no_overlap_test() {
+    repeated_test(|| {
+        let (a, b) = get_valid_pair(no_overlap);
+        let result = TwoFloat::normalize(a, b);
+        assert_eq!(
+            (result.hi(), result.lo()),
+            (a, b),
+            "Normalization changed non-overlapping pair {}, {}",
+            a,
+            b
+        );
+
+        let unchecked = TwoFloat::from_parts_unchecked(a, b);
+        assert_eq!(unchecked, result);
+    });
+}
+
+#[test]
+fn normalize_overlap_test() {
+    repeated_test(|| {
+        let (a, b) = get_valid_pair(|x, y| !no_overlap(x, y) && (x + y).is_finite());
+        let result = TwoFloat::normalize(a, b);
+        assert!(
+            result.is_valid(),
+            "Normalization of overlapping pair {}, {} gave invalid result",
+            a,
+            b
+        );
+
+        // Knuth's TwoSum gives the exact rounding error of a + b
+        let s = a + b;
+        let bb = s - a;
+        let err = (a - (s - bb)) + (b - bb);
+        assert_eq!(
+            (result.hi(), result.lo()),
+            (s, err),
+            "Normalization of overlapping pair {}, {} changed value",
+            a,
+            b
+        );
+        assert_eq!(TwoFloat::from_sum(b, a), result);
+    });
+}
+
+#[test]
+fn normalize_special_test() {
+    assert_eq!(TwoFloat::normalize(1.0, 0.25), TwoFloat::from(1.25));
+    assert_eq!(TwoFloat::normalize(0.25, 1.0), TwoFloat::from(1.25));
+    assert_eq!(TwoFloat::normalize(0.0, 0.0), TwoFloat::from(0.0));
+    assert_eq!(TwoFloat::normalize(f64::MAX, f64::MAX), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::normalize(f64::INFINITY, 1.0), TwoFloat::INFINITY);
+    assert!(TwoFloat::normalize(f64::INFINITY, f64::NEG_INFINITY).is_nan());
+    assert!(TwoFloat::normalize(f64::NAN, 1.0).is_nan());
+}
+
 fn from_float<F>()
 where
     F: num_traits::Float + Into<TwoFloat>,
@@ -0,0 +1,321 @@
+#![allow(clippy::float_cmp)]
+
+pub mod common;
+
+use common::*;
+use rand::Rng;
+use twofloat::{Dual, DualN, QuadFloat, TwoFloat};
+
+// Checks that `result` is within `tolerance` of `expected` relative to its
+// magnitude.
+fn assert_close(result: TwoFloat, expected: QuadFloat, tolerance: f64, description: &str) {
+    let difference = (QuadFloat::from(result) - expected).abs();
+    assert!(
+        difference <= expected.abs() * QuadFloat::from(tolerance),
+        "Derivative {:?} of {} differs from {}",
+        result,
+        description,
+        expected
+    );
+}
+
+#[test]
+fn constructor_test() {
+    let x = DualN::<3>::variable(TwoFloat::from(2.0), 1);
+    assert_eq!(x.value, 2.0);
+    assert_eq!(
+        x.derivatives,
+        [TwoFloat::ZERO, TwoFloat::ONE, TwoFloat::ZERO]
+    );
+
+    let c = DualN::<3>::from(5.0);
+    assert_eq!(c, DualN::constant(TwoFloat::from(5.0)));
+    assert_eq!(c.derivatives, [TwoFloat::ZERO; 3]);
+    assert_eq!(Dual::default(), Dual::from(0.0));
+}
+
+#[test]
+#[should_panic]
+fn variable_index_test() {
+    let _ = DualN::<2>::variable(TwoFloat::ONE, 2);
+}
+
+#[test]
+fn add_sub_test() {
+    repeated_test(|| {
+        let (a, b, c) = (get_twofloat(), get_twofloat(), random_float());
+        let x = DualN::<2>::variable(a, 0);
+        let y = DualN::<2>::variable(b, 1);
+
+        let sum = x + y;
+        assert_eq!(sum.value, a + b);
+        assert_eq!(sum.derivatives, [TwoFloat::ONE; 2]);
+
+        let difference = x - y;
+        assert_eq!(difference.value, a - b);
+        assert_eq!(difference.derivatives, [TwoFloat::ONE, -TwoFloat::ONE]);
+
+        assert_eq!((x + c).derivatives, x.derivatives);
+        assert_eq!((c - x).value, c - a);
+        assert_eq!((b - x).derivatives, (-x).derivatives);
+    });
+}
+
+#[test]
+fn mul_div_test() {
+    repeated_test(|| {
+        let (a, b) = (get_twofloat_decades(-10..10), get_twofloat_decades(-10..10));
+        let x = DualN::<2>::variable(a, 0);
+        let y = DualN::<2>::variable(b, 1);
+
+        let product = x * y;
+        assert_eq!(product.value, a * b);
+        assert_eq!(product.derivatives, [b, a]);
+
+        let (qa, qb) = (QuadFloat::from(a), QuadFloat::from(b));
+        let quotient = x / y;
+        let description = format!("{:?} / {:?}", a, b);
+        assert_close(
+            quotient.derivatives[0],
+            QuadFloat::from(1.0) / qb,
+            1e-30,
+            &description,
+        );
+        assert_close(
+            quotient.derivatives[1],
+            -qa / (qb * qb),
+            1e-30,
+            &description,
+        );
+
+        let description = format!("{:?} / {:?}", b, a);
+        let reciprocal = b / x;
+        assert_close(
+            reciprocal.derivatives[0],
+            -qb / (qa * qa),
+            1e-30,
+            &description,
+        );
+    });
+}
+
+#[test]
+fn assign_test() {
+    let mut x = Dual::variable(TwoFloat::from(3.0), 0);
+    x *= x;
+    x += 1.0;
+    x /= TwoFloat::from(2.0);
+    x -= Dual::variable(TwoFloat::from(1.0), 0);
+
+    assert_eq!(x.value, 4.0);
+    assert_eq!(x.derivatives[0], 2.0);
+}
+
+#[test]
+fn chain_test() {
+    // Horner evaluation of a polynomial and its derivative
+    repeated_test(|| {
+        let mut rng = rand::thread_rng();
+        let coefficients: Vec<f64> = (0..6).map(|_| rng.gen_range(-1.0..1.0)).collect();
+        let point = TwoFloat::from(rng.gen_range(-2.0..2.0));
+        let x = Dual::variable(point, 0);
+
+        let mut result = Dual::default();
+        let mut derivative = TwoFloat::ZERO;
+        let mut value = TwoFloat::ZERO;
+        for c in coefficients.iter() {
+            result = result * x + *c;
+            derivative = derivative * point + value;
+            value = value * point + *c;
+        }
+
+        assert_eq!(result.value, value);
+        assert!((result.derivatives[0] - derivative).abs() < 1e-28);
+    });
+}
+
+// Mathematical functions, with derivatives checked against their closed forms
+// evaluated at quad-word precision.
+#[cfg(feature = "math_funcs")]
+mod functions {
+    use super::*;
+
+    type Function = (
+        &'static str,
+        fn(Dual) -> Dual,
+        fn(QuadFloat) -> QuadFloat,
+        fn(f64) -> bool,
+        f64,
+    );
+
+    fn cosh(x: QuadFloat) -> QuadFloat {
+        (x.exp() + (-x).exp()) / 2.0
+    }
+
+    const FUNCTIONS: [Function; 17] = [
+        ("exp", Dual::exp, QuadFloat::exp, |x| x.abs() < 700.0, 1e-30),
+        (
+            "exp_m1",
+            Dual::exp_m1,
+            QuadFloat::exp,
+            |x| x.abs() < 700.0,
+            1e-9,
+        ),
+        ("ln", Dual::ln, |x| 1.0 / x, |x| x > 0.0, 1e-30),
+        (
+            "ln_1p",
+            Dual::ln_1p,
+            |x| 1.0 / (x + 1.0),
+            |x| x > -1.0,
+            1e-30,
+        ),
+        ("sqrt", Dual::sqrt, |x| 0.5 / x.sqrt(), |x| x > 0.0, 1e-30),
+        (
+            "cbrt",
+            Dual::cbrt,
+            |x| 1.0 / ((x.ln() * 2.0 / 3.0).exp() * 3.0),
+            |x| x > 0.0,
+            1e-9,
+        ),
+        ("sin", Dual::sin, QuadFloat::cos, |x| x.abs() < 1e6, 1e-12),
+        ("cos", Dual::cos, |x| -x.sin(), |x| x.abs() < 1e6, 1e-12),
+        (
+            "tan",
+            Dual::tan,
+            |x| 1.0 / (x.cos() * x.cos()),
+            |x| x.abs() < 1e6,
+            1e-12,
+        ),
+        (
+            "asin",
+            Dual::asin,
+            |x| 1.0 / ((1.0 - x) * (1.0 + x)).sqrt(),
+            |x| x.abs() < 1.0,
+            1e-30,
+        ),
+        (
+            "acos",
+            Dual::acos,
+            |x| -1.0 / ((1.0 - x) * (1.0 + x)).sqrt(),
+            |x| x.abs() < 1.0,
+            1e-30,
+        ),
+        ("atan", Dual::atan, |x| 1.0 / (x * x + 1.0), |_| true, 1e-30),
+        ("sinh", Dual::sinh, cosh, |x| x.abs() < 700.0, 1e-9),
+        (
+            "tanh",
+            Dual::tanh,
+            |x| 1.0 / (cosh(x) * cosh(x)),
+            |x| x.abs() < 300.0,
+            1e-9,
+        ),
+        (
+            "asinh",
+            Dual::asinh,
+            |x| 1.0 / (x * x + 1.0).sqrt(),
+            |_| true,
+            1e-30,
+        ),
+        (
+            "acosh",
+            Dual::acosh,
+            |x| 1.0 / ((x - 1.0) * (x + 1.0)).sqrt(),
+            |x| x > 1.0,
+            1e-30,
+        ),
+        (
+            "atanh",
+            Dual::atanh,
+            |x| 1.0 / ((1.0 - x) * (1.0 + x)),
+            |x| x.abs() < 1.0,
+            1e-30,
+        ),
+    ];
+
+    #[test]
+    fn functions_test() {
+        for (name, f, derivative, valid, tolerance) in FUNCTIONS.iter() {
+            for _ in 0..1000 {
+                let a = loop {
+                    let a = get_twofloat_decades(-10..10);
+                    if valid(a.hi()) {
+                        break a;
+                    }
+                };
+                let result = f(Dual::variable(a, 0));
+                let description = format!("{} at {:?}", name, a);
+                assert_close(
+                    result.derivatives[0],
+                    derivative(QuadFloat::from(a)),
+                    *tolerance,
+                    &description,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn binary_functions_test() {
+        for _ in 0..1000 {
+            let (a, b) = (get_twofloat_decades(-10..10), get_twofloat_decades(-10..10));
+            let x = DualN::<2>::variable(a, 0);
+            let y = DualN::<2>::variable(b, 1);
+            let (qa, qb) = (QuadFloat::from(a), QuadFloat::from(b));
+            let r2 = qa * qa + qb * qb;
+            let description = format!("{:?} and {:?}", a, b);
+
+            let h = x.hypot(y);
+            assert_close(h.derivatives[0], qa / r2.sqrt(), 1e-30, &description);
+            assert_close(h.derivatives[1], qb / r2.sqrt(), 1e-30, &description);
+
+            let t = x.atan2(y);
+            assert_close(t.derivatives[0], qb / r2, 1e-30, &description);
+            assert_close(t.derivatives[1], -qa / r2, 1e-30, &description);
+
+            // Positive base with an exponent of moderate magnitude
+            let (base, exponent) = (x.abs(), y / b.abs().max(TwoFloat::ONE) * 10.0);
+            let p = base.powf(exponent);
+            let (qx, qy) = (QuadFloat::from(base.value), QuadFloat::from(exponent.value));
+            let value = (qy * qx.ln()).exp();
+            assert_close(
+                p.derivatives[0],
+                value * qy / qx * QuadFloat::from(a.signum()),
+                1e-9,
+                &description,
+            );
+        }
+    }
+
+    #[test]
+    fn jacobian_test() {
+        // Polar to Cartesian coordinates, whose Jacobian has determinant r
+        let r = DualN::<2>::variable(TwoFloat::from(2.0), 0);
+        let theta = DualN::<2>::variable(TwoFloat::from(0.75), 1);
+        let (s, c) = theta.sin_cos();
+        let (x, y) = (r * c, r * s);
+
+        let determinant = x.derivatives[0] * y.derivatives[1] - x.derivatives[1] * y.derivatives[0];
+        assert!((determinant - 2.0).abs() < 1e-19);
+        assert_eq!(x.derivatives[0], TwoFloat::from(0.75).cos());
+    }
+
+    #[test]
+    fn special_cases_test() {
+        let x = Dual::variable(TwoFloat::from(-2.0), 0);
+        assert_eq!(x.powi(3).derivatives[0], 12.0);
+        assert_eq!(x.powi(0).derivatives[0], 0.0);
+        assert!((x.powf(Dual::from(2.0)).derivatives[0] + 4.0).abs() < 1e-9);
+
+        let zero = Dual::variable(TwoFloat::ZERO, 0);
+        assert!(zero.ln().derivatives[0].is_infinite());
+        assert_eq!(zero.exp().derivatives[0], 1.0);
+
+        let degrees = Dual::variable(TwoFloat::from(180.0), 0).to_radians();
+        assert_eq!(degrees.derivatives[0], TwoFloat::ONE.to_radians());
+
+        let base = Dual::variable(TwoFloat::from(8.0), 0);
+        let log = base.log(Dual::from(2.0));
+        assert!((log.value - 3.0).abs() < 1e-9);
+        assert!((log.derivatives[0] - base.log2().derivatives[0]).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,184 @@
+#![allow(clippy::float_cmp)]
+
+pub mod common;
+
+use common::*;
+use rand::Rng;
+use twofloat::{Expansion, TwoFloat};
+
+// Returns a value of random sign and magnitude between 1e-90 and 1e90, so
+// that products neither overflow nor underflow.
+fn get_value() -> f64 {
+    get_valid_f64(|x| x.abs() > 1e-90 && x.abs() < 1e90)
+}
+
+// Returns an expansion of the sum of up to eight random values.
+fn get_expansion() -> Expansion {
+    let count = rand::thread_rng().gen_range(0..8);
+    (0..count).fold(Expansion::new(), |e, _| e + get_value())
+}
+
+// Checks that the components are non-zero, non-overlapping and in order of
+// increasing magnitude.
+fn assert_valid(value: &Expansion, description: &str) {
+    let components = value.components();
+    assert!(
+        components.iter().all(|x| x.is_finite() && *x != 0.0),
+        "Invalid component in {}: {:?}",
+        description,
+        components
+    );
+    for pair in components.windows(2) {
+        let (small, large) = (to_big(&pair[..1]), to_big(&pair[1..]));
+        assert!(
+            small.magnitude().bits() <= large.trailing_zeros().unwrap(),
+            "Overlapping components in {}: {:?}",
+            description,
+            components
+        );
+    }
+}
+
+#[test]
+fn add_sub_test() {
+    for _ in 0..10000 {
+        let (a, b) = (get_expansion(), get_expansion());
+        let x = get_value();
+        let t = TwoFloat::new_add(get_value(), get_value());
+
+        let cases = [
+            (&a + &b, big(&a) + big(&b), "add"),
+            (&a - &b, big(&a) - big(&b), "sub"),
+            (&a + x, big(&a) + to_big(&[x]), "add f64"),
+            (x - &a, to_big(&[x]) - big(&a), "f64 sub"),
+            (&a - t, big(&a) - big(t), "sub TwoFloat"),
+            (t + &a, big(&a) + big(t), "TwoFloat add"),
+        ];
+
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?} / {:?}", op, a, b, x, t);
+            assert_valid(result, &description);
+            assert_eq!(big(result), *exact, "Inexact result of {}", description);
+        }
+
+        assert!((&a - &a).is_zero());
+    }
+}
+
+#[test]
+fn sum_test() {
+    // Sums of values across the whole range, where the result is exact
+    // regardless of cancellation
+    repeated_test(|| {
+        let values: Vec<f64> = (0..20)
+            .map(|_| get_valid_f64(|x| x.is_finite() && x.abs() < 1e300))
+            .collect();
+        let mut sum = Expansion::new();
+        for x in values.iter() {
+            sum += x;
+        }
+        for x in values.iter().rev() {
+            sum -= *x;
+        }
+
+        let description = format!("sum of {:?}", values);
+        assert_valid(&sum, &description);
+        assert!(sum.is_zero(), "Non-zero {}", description);
+    });
+}
+
+#[test]
+fn mul_test() {
+    let scale = to_big(&[1.0]);
+    for _ in 0..10000 {
+        let (a, b) = (get_expansion(), get_expansion());
+        let x = get_value();
+        let t = TwoFloat::new_add(get_value(), get_value());
+
+        let cases = [
+            (&a * &b, big(&a) * big(&b), "mul"),
+            (a.scale(x), big(&a) * to_big(&[x]), "scale"),
+            (x * &a, big(&a) * to_big(&[x]), "f64 mul"),
+            (&a * t, big(&a) * big(t), "mul TwoFloat"),
+        ];
+
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?} / {:?}", op, a, b, x, t);
+            assert_valid(result, &description);
+            assert_eq!(
+                big(result) * &scale,
+                *exact,
+                "Inexact result of {}",
+                description
+            );
+        }
+    }
+}
+
+#[test]
+fn assign_test() {
+    let d = 2f64.powi(-70);
+    let mut a = Expansion::from(3.0);
+    a *= TwoFloat::new_add(1.0, d);
+    a -= 3.0;
+    a += &Expansion::from(1.0);
+    a *= 2.0;
+    a -= TwoFloat::from(2.0);
+    a *= a.clone();
+
+    assert_eq!(a.components(), [36.0 * d * d]);
+    assert_eq!(f64::from(-a), -36.0 * d * d);
+}
+
+#[test]
+fn compress_test() {
+    for _ in 0..10000 {
+        let (a, b) = (get_expansion(), get_expansion());
+        let product = &a * &b;
+        let mut result = product.clone();
+        result.compress();
+
+        let description = format!("compression of {:?}", product);
+        assert_valid(&result, &description);
+        assert_eq!(
+            big(&result),
+            big(&product),
+            "Changed value in {}",
+            description
+        );
+        assert!(result.components().len() <= product.components().len());
+    }
+}
+
+#[test]
+fn conversion_test() {
+    for _ in 0..10000 {
+        let (x, y) = (get_value(), get_value());
+        let sum = Expansion::from(x) + y;
+        assert_eq!(TwoFloat::from(&sum), TwoFloat::new_add(x, y));
+        let product = Expansion::from(x) * y;
+        assert_eq!(TwoFloat::from(&product), TwoFloat::new_mul(x, y));
+
+        let a = get_twofloat();
+        let expansion = Expansion::from(a);
+        assert_valid(&expansion, &format!("conversion of {:?}", a));
+        assert_eq!(big(&expansion), big(a));
+
+        // Rounding of longer expansions to two words
+        let e = get_expansion() * get_expansion();
+        let result = TwoFloat::from(&e);
+        let error = big(result) - big(&e);
+        assert!(
+            (error.magnitude() << 104) <= *big(&e).magnitude(),
+            "Inaccurate rounding of {:?} to {:?}",
+            e,
+            result
+        );
+        assert!(result.is_valid());
+        assert_eq!(f64::from(&e), result.hi());
+    }
+
+    assert_eq!(TwoFloat::from(Expansion::new()), 0.0);
+    assert!(Expansion::from(0.0).is_zero());
+    assert!(f64::from(Expansion::from(f64::INFINITY)).is_infinite());
+}
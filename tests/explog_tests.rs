@@ -8,6 +8,223 @@ use twofloat::TwoFloat;
 
 const EXP_UPPER_LIMIT: f64 = 709.782712893384;
 
+// Reference values computed with mpmath
+const EXP_CASES: [([f64; 2], [f64; 2]); 12] = [
+    ([-745.0, 0.0], [5e-324, -0.0]),
+    (
+        [-650.5, 0.0],
+        [3.1005555878346677e-283, 1.1934860708013095e-299],
+    ),
+    (
+        [-20.25, 0.0],
+        [1.6052280551856116e-09, -3.657643988865463e-26],
+    ),
+    ([-1.0, 0.0], [0.36787944117144233, -1.2428753672788363e-17]),
+    ([-0.001, 0.0], [0.999000499833375, -3.026024053145243e-17]),
+    ([1e-20, 0.0], [1.0, 1e-20]),
+    ([0.0054, 0.0], [1.0054146062794678, -7.222965820335253e-17]),
+    ([0.5, 1e-17], [1.6487212707001282, -3.082847208735705e-17]),
+    ([1.5, 0.0], [4.4816890703380645, 3.0481759556536343e-16]),
+    ([25.125, 0.0], [81592240297.39134, 6.568083623956019e-06]),
+    (
+        [300.0, 0.0],
+        [1.9424263952412558e+130, 1.1143145370175699e+114],
+    ),
+    (
+        [709.78, 0.0],
+        [1.7928227943945155e+308, 8.276293660642251e+291],
+    ),
+];
+
+const EXP2_CASES: [([f64; 2], [f64; 2]); 9] = [
+    ([-1074.5, 0.0], [5e-324, -0.0]),
+    ([-1000.25, 0.0], [7.847780312860669e-302, 3.82592e-318]),
+    ([-3.3, 0.0], [0.10153154954452945, 4.801659176626923e-18]),
+    ([-0.0001, 0.0], [0.9999306876841536, -3.545424496808183e-17]),
+    ([0.75, -1e-18], [1.681792830507429, 8.082437024706282e-17]),
+    ([1.0, 1e-20], [2.0, 1.3862943611198905e-20]),
+    ([12.34, 0.0], [5184.539008902266, 4.1425695471582326e-13]),
+    (
+        [512.5, 0.0],
+        [1.8961503816218355e+154, -1.2961721194919502e+138],
+    ),
+    (
+        [1023.99, 0.0],
+        [1.7852755613304564e+308, 3.21391909124397e+291],
+    ),
+];
+
+const EXP_M1_CASES: [([f64; 2], [f64; 2]); 11] = [
+    ([-50.0, 0.0], [-1.0, 1.9287498479639178e-22]),
+    ([-0.75, 0.0], [-0.5276334472589853, 1.7984004434373214e-17]),
+    (
+        [-0.006, 0.0],
+        [-0.005982035946064735, -1.6883690878268042e-20],
+    ),
+    (
+        [-1e-10, 0.0],
+        [-9.999999999500001e-11, 3.38967998878844e-27],
+    ),
+    ([1e-300, 0.0], [1e-300, 0.0]),
+    (
+        [0.003, 1e-20],
+        [0.003004504503377026, -2.95666225673503e-20],
+    ),
+    ([0.0055, 0.0], [0.005515152767336249, 3.957537648608259e-19]),
+    ([0.05, 0.0], [0.05127109637602404, 2.2068111176530193e-18]),
+    ([0.99, 0.0], [1.6912344723492623, -9.251817881359191e-18]),
+    ([1.25, 0.0], [2.4903429574618414, -4.604261945372796e-17]),
+    (
+        [100.0, 0.0],
+        [2.6881171418161356e+43, -1.6101271449201627e+27],
+    ),
+];
+
+const LN_CASES: [([f64; 2], [f64; 2]); 12] = [
+    ([0.625, 0.0], [-0.4700036292457356, 2.3229412495470032e-17]),
+    (
+        [0.999, 0.0],
+        [-0.0010005003335835344, -2.5644777003677798e-20],
+    ),
+    ([1.0, 1e-20], [1e-20, -5e-41]),
+    (
+        [1.004, 0.0],
+        [0.003992021269537457, -1.6371387346732893e-19],
+    ),
+    (
+        [1.4143, -9e-17],
+        [0.3466347090443169, 2.350858417151173e-17],
+    ),
+    ([3.0, 0.0], [1.0986122886681098, -9.07129723500153e-17]),
+    ([12.0, 0.0], [2.4849066497880004, -4.433203607308931e-17]),
+    (
+        [123456.789, 0.0],
+        [11.723646487185881, -4.1025541885795297e-16],
+    ),
+    ([1e-300, 0.0], [-690.7755278982137, -2.3670096176709832e-14]),
+    ([5e-324, 0.0], [-744.4400719213812, -4.422444340918698e-14]),
+    (
+        [1.7976931348623157e+308, 0.0],
+        [709.782712893384, 2.3636017071323592e-14],
+    ),
+    (
+        [0.75, -1e-18],
+        [-0.2876820724517809, -2.7404939497758972e-17],
+    ),
+];
+
+const LOG2_CASES: [([f64; 2], [f64; 2]); 12] = [
+    ([0.625, 0.0], [-0.6780719051126377, 5.513944923484356e-17]),
+    (
+        [0.999, 0.0],
+        [-0.0014434168696687186, -2.4743794559692712e-20],
+    ),
+    (
+        [1.0, 1e-20],
+        [1.4426950408889633e-20, -1.5227480963563783e-37],
+    ),
+    ([1.004, 0.0], [0.005759269288684953, -2.281709444370384e-19]),
+    (
+        [1.4143, -9e-17],
+        [0.5000881757382247, 4.5641352302358934e-17],
+    ),
+    ([3.0, 0.0], [1.584962500721156, 1.0579781240112554e-16]),
+    ([12.0, 0.0], [3.584962500721156, 1.0579781240112554e-16]),
+    (
+        [123456.789, 0.0],
+        [16.913646648198387, -5.399682860398606e-16],
+    ),
+    ([1e-300, 0.0], [-996.5784284662087, 1.591283017612134e-14]),
+    ([5e-324, 0.0], [-1074.0, 0.0]),
+    (
+        [1.7976931348623157e+308, 0.0],
+        [1024.0, -1.6017132519074588e-16],
+    ),
+    ([0.75, -1e-18], [-0.4150374992788438, -7.14808344924206e-18]),
+];
+
+const LOG10_CASES: [([f64; 2], [f64; 2]); 12] = [
+    ([0.625, 0.0], [-0.2041199826559248, 1.1214912511140682e-17]),
+    (
+        [0.999, 0.0],
+        [-0.0004345117740176917, -8.604045253255576e-21],
+    ),
+    ([1.0, 1e-20], [4.342944819032518e-21, 8.685180975701874e-38]),
+    (
+        [1.004, 0.0],
+        [0.0017337128090005314, -7.448201341501967e-20],
+    ),
+    (
+        [1.4143, -9e-17],
+        [0.15054154137408607, 7.118933492193332e-19],
+    ),
+    ([3.0, 0.0], [0.47712125471966244, 1.8999057013019158e-18]),
+    ([12.0, 0.0], [1.0791812460476249, -5.921870178552625e-17]),
+    (
+        [123456.789, 0.0],
+        [5.09151497716927, 4.2289001278739406e-16],
+    ),
+    ([1e-300, 0.0], [-300.0, 1.0883025305537996e-17]),
+    ([5e-324, 0.0], [-323.3062153431158, 5.786761570804164e-15]),
+    (
+        [1.7976931348623157e+308, 0.0],
+        [308.25471555991675, -2.9192339761796788e-15],
+    ),
+    (
+        [0.75, -1e-18],
+        [-0.12493873660829996, 6.9283026476679204e-18],
+    ),
+];
+
+const LN_1P_CASES: [([f64; 2], [f64; 2]); 8] = [
+    ([1e-20, 0.0], [1e-20, -5e-41]),
+    (
+        [-0.001, 0.0],
+        [-0.0010005003335835335, -2.4776547035721437e-20],
+    ),
+    (
+        [0.004, 0.0],
+        [0.003992021269537453, -1.4989137565078065e-19],
+    ),
+    ([-0.375, 0.0], [-0.4700036292457356, 2.3229412495470032e-17]),
+    ([1.5, 0.0], [0.9162907318741551, -4.141195369011963e-17]),
+    (
+        [10000000000.0, 0.0],
+        [23.025850930040455, 1.3736784183183428e-15],
+    ),
+    (
+        [-0.9999999999999999, 0.0],
+        [-36.7368005696771, -6.739832990259606e-16],
+    ),
+    ([0.25, 1e-18], [0.22314355131420976, -8.291270597324799e-18]),
+];
+
+// Checks the relative error of the results, allowing for the reduced precision
+// of the low word near the subnormal range.
+fn check_cases(cases: &[([f64; 2], [f64; 2])], f: impl Fn(TwoFloat) -> TwoFloat, name: &str) {
+    for ([x_hi, x_lo], [hi, lo]) in cases.iter() {
+        let x = TwoFloat::new_add(*x_hi, *x_lo);
+        let result = f(x);
+        let expected = TwoFloat::new_add(*hi, *lo);
+
+        assert!(
+            result.is_valid(),
+            "{}({:?}) produced invalid value",
+            name,
+            x
+        );
+        let difference = (result - expected).abs();
+        assert!(
+            difference <= expected.abs() * 1e-30 || difference.hi() < 1e-322,
+            "Mismatch in {}({:?}): {:?} vs {:?}",
+            name,
+            x,
+            expected,
+            result
+        );
+    }
+}
+
 #[test]
 fn exp_test() {
     let mut rng = rand::thread_rng();
@@ -86,6 +303,70 @@ fn ln_test() {
     });
 }
 
+#[test]
+fn exp_accuracy_test() {
+    check_cases(&EXP_CASES, TwoFloat::exp, "exp");
+    check_cases(&EXP2_CASES, TwoFloat::exp2, "exp2");
+    check_cases(&EXP_M1_CASES, TwoFloat::exp_m1, "exp_m1");
+}
+
+#[test]
+fn exp_overflow_test() {
+    // ln(MAX) is 709.782712893384 + 2.369e-14, so the exponential of the
+    // largest f64 below it is finite
+    let largest = [(
+        [709.782712893384, 0.0],
+        [1.7976931348622732e308, 2.1092968483114987e291],
+    )];
+    check_cases(&largest, TwoFloat::exp, "exp");
+
+    let limit = TwoFloat::new_add(709.782712893384, 2.3e-14).exp();
+    assert!(limit.is_finite() && limit > 1.7976931348623e308);
+    assert_eq!(TwoFloat::from(709.7827128933841).exp(), TwoFloat::INFINITY);
+}
+
+#[test]
+fn exp_underflow_test() {
+    // ln(2^-1075) is -745.1332191019412 + 1.4483164125345e-14, above which
+    // the exponential rounds to the smallest subnormal value
+    let min_subnormal = TwoFloat::from(5e-324);
+    let above = TwoFloat::new_add(-745.1332191019412, 1.44831642e-14);
+    let below = TwoFloat::new_add(-745.1332191019412, 1.44831641e-14);
+    assert_eq!(above.exp(), min_subnormal);
+    assert_eq!(below.exp(), 0.0);
+    assert_eq!(TwoFloat::from(-745.13).exp(), min_subnormal);
+    assert_eq!(TwoFloat::from(-745.0).exp(), min_subnormal);
+    assert_eq!(TwoFloat::from(-744.5).exp(), min_subnormal);
+    assert_eq!(TwoFloat::from(-745.14).exp(), 0.0);
+}
+
+#[test]
+fn exp2_exact_test() {
+    for n in -1074..1024 {
+        let expected = 2f64.powi(n / 2) * 2f64.powi(n - n / 2);
+        let result = TwoFloat::from(f64::from(n)).exp2();
+        assert_eq!(result, expected, "Mismatch in exp2({})", n);
+    }
+}
+
+#[test]
+fn log_accuracy_test() {
+    check_cases(&LN_CASES, TwoFloat::ln, "ln");
+    check_cases(&LOG2_CASES, TwoFloat::log2, "log2");
+    check_cases(&LOG10_CASES, TwoFloat::log10, "log10");
+    check_cases(&LN_1P_CASES, TwoFloat::ln_1p, "ln_1p");
+}
+
+#[test]
+fn log_exact_test() {
+    assert_eq!(TwoFloat::ONE.ln(), 0.0);
+    assert_eq!(TwoFloat::ONE.log10(), 0.0);
+    for n in -1074..1024 {
+        let x = TwoFloat::from(2f64.powi(n / 2) * 2f64.powi(n - n / 2));
+        assert_eq!(x.log2(), f64::from(n), "Mismatch in log2(2^{})", n);
+    }
+}
+
 #[test]
 fn ln_negative_test() {
     repeated_test(|| {
@@ -120,3 +401,26 @@ fn ln_1p_test() {
         );
     });
 }
+
+#[test]
+fn explog_special_test() {
+    assert_eq!(TwoFloat::INFINITY.exp(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::NEG_INFINITY.exp(), 0.0);
+    assert!(TwoFloat::NAN.exp().is_nan());
+    assert_eq!(TwoFloat::from(1100.0).exp2(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::INFINITY.exp_m1(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::NEG_INFINITY.exp_m1(), -1.0);
+
+    assert_eq!(TwoFloat::ZERO.ln(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::from(-0.0).ln(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.ln(), TwoFloat::INFINITY);
+    assert!(TwoFloat::NEG_INFINITY.ln().is_nan());
+    assert!(TwoFloat::NAN.ln().is_nan());
+    assert_eq!(TwoFloat::from(-1.0).ln_1p(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::ZERO.log2(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::ONE.log2(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.log10(), TwoFloat::INFINITY);
+    assert!(TwoFloat::NAN.ln_1p().is_nan());
+    assert!(TwoFloat::NAN.log2().is_nan());
+    assert!(TwoFloat::NAN.log10().is_nan());
+}
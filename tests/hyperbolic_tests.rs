@@ -7,6 +7,139 @@ use core::convert::TryFrom;
 use rand::Rng;
 use twofloat::TwoFloat;
 
+// Reference values computed with mpmath
+const COSH_CASES: [([f64; 2], [f64; 2]); 8] = [
+    ([1e-20, 0.0], [1.0, 5e-41]),
+    ([-0.001, 0.0], [1.0000005000000416, 7.443797190204747e-17]),
+    ([0.75, 1e-17], [1.2946832846768448, -9.61510129419141e-17]),
+    ([-5.5, 0.0], [122.34800951782942, 1.0830048406214577e-15]),
+    ([39.9, 0.0], [1.0649269854425712e+17, 0.7325202457746316]),
+    ([-40.5, 0.0], [1.9404234812181018e+17, -13.98841390621365]),
+    (
+        [700.0, 0.0],
+        [5.0711602736750225e+303, 8.333285960367336e+286],
+    ),
+    (
+        [710.4, 0.0],
+        [1.6663642832806496e+308, 6.844817567326341e+290],
+    ),
+];
+
+const SINH_CASES: [([f64; 2], [f64; 2]); 8] = [
+    ([1e-300, 0.0], [1e-300, 0.0]),
+    ([-1e-20, 0.0], [-1e-20, -1.6666666666666664e-61]),
+    ([0.003, 0.0], [0.003000004500002025, -8.90270231585565e-20]),
+    ([-0.25, 0.0], [-0.2526123168081683, 5.550352555023456e-18]),
+    ([1.5, -1e-17], [2.1292794550948173, 1.6507420320417148e-16]),
+    ([20.0, 0.0], [242582597.70489514, -7.865629467297586e-10]),
+    ([-45.0, 0.0], [-1.7467135528742547e+19, -218.01739861670305]),
+    (
+        [710.4, 0.0],
+        [1.6663642832806496e+308, 6.844817567326341e+290],
+    ),
+];
+
+const TANH_CASES: [([f64; 2], [f64; 2]); 7] = [
+    ([1e-300, 0.0], [1e-300, -0.0]),
+    ([1e-20, 0.0], [1e-20, -3.333333333333333e-61]),
+    ([-0.002, 0.0], [-0.0019999973333376, -4.796422724791771e-21]),
+    ([0.3, 0.0], [0.2913126124515909, -6.4602656586469586e-18]),
+    ([-1.25, 0.0], [-0.8482836399575129, -1.7099441972242e-17]),
+    ([8.0, 0.0], [0.9999997749296758, 4.556169644130666e-17]),
+    ([-30.0, 0.0], [-1.0, 1.751302152539304e-26]),
+];
+
+const ACOSH_CASES: [([f64; 2], [f64; 2]); 8] = [
+    (
+        [1.0, 2.2e-16],
+        [2.097617696340303e-08, 7.919592020653828e-26],
+    ),
+    (
+        [1.0000000001, 0.0],
+        [1.4142136208675862e-05, -3.539065447850652e-22],
+    ),
+    ([1.001, 0.0], [0.04471763360830685, -1.5112553013717277e-18]),
+    ([1.5, 0.0], [0.9624236501192069, -4.651563402692547e-17]),
+    ([3.0, -1e-16], [1.762747174039086, -8.03662569158447e-17]),
+    (
+        [10000000000.0, 0.0],
+        [23.7189981105004, 1.2941231210941988e-15],
+    ),
+    ([1e+20, 0.0], [46.74484904044086, -2.652987441688301e-15]),
+    ([1e+300, 0.0], [691.4686750787737, -3.4959947505732075e-14]),
+];
+
+const ASINH_CASES: [([f64; 2], [f64; 2]); 9] = [
+    ([1e-300, 0.0], [1e-300, -0.0]),
+    ([-1e-20, 0.0], [-1e-20, 1.6666666666666664e-61]),
+    ([0.004, 0.0], [0.003999989333410133, 4.7920071016776796e-20]),
+    ([-0.6, 0.0], [-0.5688248987322475, -2.9655243320403865e-18]),
+    ([0.999, 0.0], [0.8806663034322094, 4.224107114790325e-17]),
+    ([2.5, 1e-16], [1.6472311463710958, -5.23987942335664e-17]),
+    (
+        [-1000000000000.0, 0.0],
+        [-28.324168296488494, 9.163824627325016e-16],
+    ),
+    ([1e+20, 0.0], [46.74484904044086, -2.652987441688301e-15]),
+    ([-1e+300, 0.0], [-691.4686750787737, 3.4959947505732075e-14]),
+];
+
+const ATANH_CASES: [([f64; 2], [f64; 2]); 8] = [
+    ([1e-300, 0.0], [1e-300, 0.0]),
+    ([-1e-20, 0.0], [-1e-20, -3.333333333333333e-61]),
+    ([0.005, 0.0], [0.005000041667291678, 8.096878894064939e-20]),
+    ([-0.3, 0.0], [-0.3095196042031117, -5.4139184190139844e-18]),
+    ([0.5, 1e-17], [0.5493061443340549, -3.2023152841674313e-17]),
+    ([0.9, 0.0], [1.4722194895832204, -6.2981627878629994e-18]),
+    (
+        [-0.999999, 0.0],
+        [-7.254328619247669, -1.153045648593053e-16],
+    ),
+    (
+        [0.9999999999999999, 0.0],
+        [18.714973875118524, -6.228582629648002e-16],
+    ),
+];
+
+// Checks the relative error of the results
+fn check_cases(cases: &[([f64; 2], [f64; 2])], f: impl Fn(TwoFloat) -> TwoFloat, name: &str) {
+    for ([x_hi, x_lo], [hi, lo]) in cases.iter() {
+        let x = TwoFloat::new_add(*x_hi, *x_lo);
+        let result = f(x);
+        let expected = TwoFloat::new_add(*hi, *lo);
+
+        assert!(
+            result.is_valid(),
+            "{}({:?}) produced invalid value",
+            name,
+            x
+        );
+        let difference = (result - expected).abs();
+        assert!(
+            difference <= expected.abs() * 1e-30,
+            "Mismatch in {}({:?}): {:?} vs {:?}",
+            name,
+            x,
+            expected,
+            result
+        );
+    }
+}
+
+#[test]
+fn hyperbolic_accuracy_test() {
+    check_cases(&COSH_CASES, TwoFloat::cosh, "cosh");
+    check_cases(&SINH_CASES, TwoFloat::sinh, "sinh");
+    check_cases(&TANH_CASES, TwoFloat::tanh, "tanh");
+}
+
+#[test]
+fn inverse_hyperbolic_accuracy_test() {
+    check_cases(&ACOSH_CASES, TwoFloat::acosh, "acosh");
+    check_cases(&ASINH_CASES, TwoFloat::asinh, "asinh");
+    check_cases(&ATANH_CASES, TwoFloat::atanh, "atanh");
+}
+
 #[test]
 fn cosh_sinh_test() {
     let mut rng = rand::thread_rng();
@@ -57,7 +190,7 @@ fn sinh_asinh_test() {
             source
         );
         assert!(
-            (source - result).abs() < 1e-5,
+            (source - result).abs() < 1e-28,
             "Angle {:?} does not return same value after sinh/asinh round trip ({:?})",
             source,
             result
@@ -78,7 +211,7 @@ fn cosh_acosh_test() {
             source
         );
         assert!(
-            (source - result).abs() < 1e-5,
+            (source - result).abs() < 1e-20,
             "Angle {:?} does not return same value after cosh/acosh round trip ({:?})",
             source,
             result
@@ -99,10 +232,44 @@ fn tanh_atanh_test() {
             source
         );
         assert!(
-            (source - result).abs() < 1e-5,
+            (source - result).abs() < 1e-20,
             "Angle {:?} does not return same value after tanh/atanh round trip ({:?})",
             source,
             result
         );
     });
 }
+
+#[test]
+fn hyperbolic_special_test() {
+    let inf = TwoFloat::INFINITY;
+    assert_eq!(inf.cosh(), inf);
+    assert_eq!((-inf).cosh(), inf);
+    assert_eq!(inf.sinh(), inf);
+    assert_eq!((-inf).sinh(), -inf);
+    assert_eq!(inf.tanh(), 1.0);
+    assert_eq!(TwoFloat::from(-800.0).tanh(), -1.0);
+    assert_eq!(inf.asinh(), inf);
+    assert_eq!((-inf).asinh(), -inf);
+    assert_eq!(inf.acosh(), inf);
+    assert_eq!(TwoFloat::ONE.atanh(), inf);
+    assert_eq!((-TwoFloat::ONE).atanh(), -inf);
+    assert!(TwoFloat::NAN.tanh().is_nan());
+    assert!(TwoFloat::NAN.sinh().is_nan());
+    assert!(TwoFloat::NAN.acosh().is_nan());
+    assert!(TwoFloat::from(0.5).acosh().is_nan());
+    assert!(TwoFloat::from(-1.5).atanh().is_nan());
+
+    assert_eq!(TwoFloat::ONE.acosh(), 0.0);
+    assert!(TwoFloat::from(-0.0).sinh().is_sign_negative());
+    assert!(TwoFloat::from(-0.0).tanh().is_sign_negative());
+    assert!(TwoFloat::from(-0.0).asinh().is_sign_negative());
+    assert!(TwoFloat::from(-0.0).atanh().is_sign_negative());
+
+    assert!(TwoFloat::from(710.0).cosh().is_finite());
+    assert!(TwoFloat::from(-710.0).sinh().is_finite());
+    assert_eq!(TwoFloat::from(711.0).cosh(), inf);
+    assert_eq!(TwoFloat::from(-711.0).sinh(), -inf);
+    assert!(TwoFloat::MAX.asinh().is_finite());
+    assert!(TwoFloat::MAX.acosh().is_finite());
+}
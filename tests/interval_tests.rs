@@ -0,0 +1,484 @@
+#![allow(clippy::float_cmp)]
+
+pub mod common;
+
+use common::*;
+use num_bigint::BigInt;
+use twofloat::{TwoFloat, TwoFloatError, TwoFloatInterval};
+
+// Checks that `exact` lies within `result`, where the bounds of `result` are
+// scaled by `scale` to match the scale of `exact`.
+fn assert_encloses(result: TwoFloatInterval, exact: &BigInt, scale: &BigInt, description: &str) {
+    assert!(!result.is_empty(), "Empty result of {}", description);
+    assert!(
+        result.lower().is_infinite() || big(result.lower()) * scale <= *exact,
+        "Lower bound of {} too large",
+        description
+    );
+    assert!(
+        result.upper().is_infinite() || big(result.upper()) * scale >= *exact,
+        "Upper bound of {} too small",
+        description
+    );
+}
+
+fn interval(lower: f64, upper: f64) -> TwoFloatInterval {
+    TwoFloatInterval::new(TwoFloat::from(lower), TwoFloat::from(upper)).unwrap()
+}
+
+// Returns an interval with bounds of random sign and magnitude up to 1e100.
+fn get_interval() -> (TwoFloat, TwoFloat) {
+    let (a, b) = (
+        get_twofloat_decades(-100..100),
+        get_twofloat_decades(-100..100),
+    );
+    (a.min(b), a.max(b))
+}
+
+#[test]
+fn add_sub_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let b = get_twofloat();
+        let (x, y) = (TwoFloatInterval::from(a), TwoFloatInterval::from(b));
+
+        let one = to_big(&[1.0]).pow(0);
+        let description = format!("{:?} and {:?}", a, b);
+        assert_encloses(x + y, &(big(a) + big(b)), &one, &description);
+        assert_encloses(x - y, &(big(a) - big(b)), &one, &description);
+    });
+}
+
+#[test]
+fn mul_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let b = get_twofloat();
+        let result = TwoFloatInterval::from(a) * TwoFloatInterval::from(b);
+
+        // Products carry twice the scale of their arguments
+        let description = format!("product of {:?} and {:?}", a, b);
+        assert_encloses(result, &(big(a) * big(b)), &to_big(&[1.0]), &description);
+    });
+}
+
+#[test]
+fn div_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let b = get_twofloat();
+        let result = TwoFloatInterval::from(a) / TwoFloatInterval::from(b);
+
+        // The bounds times the divisor must enclose the dividend
+        let description = format!("quotient of {:?} and {:?}", a, b);
+        let (lower, upper) = if b > 0.0 {
+            (result.lower(), result.upper())
+        } else {
+            (-result.upper(), -result.lower())
+        };
+        let product = |x: TwoFloat| big(x) * big(b.abs());
+        let dividend = big(a) * to_big(&[1.0]);
+        assert!(
+            lower.is_infinite() || product(lower) <= dividend,
+            "Lower bound of {} too large",
+            description
+        );
+        assert!(
+            upper.is_infinite() || product(upper) >= dividend,
+            "Upper bound of {} too small",
+            description
+        );
+    });
+}
+
+#[test]
+fn interval_ops_test() {
+    repeated_test(|| {
+        let (a_lower, a_upper) = get_interval();
+        let (b_lower, b_upper) = get_interval();
+        let a = TwoFloatInterval::new(a_lower, a_upper).unwrap();
+        let b = TwoFloatInterval::new(b_lower, b_upper).unwrap();
+        let (sum, product, quotient) = (a + b, a * b, a / b);
+
+        let scale = to_big(&[1.0]);
+        let one = scale.pow(0);
+        for &x in [a_lower, a.midpoint(), a_upper].iter() {
+            for &y in [b_lower, b.midpoint(), b_upper].iter() {
+                let description = format!("{:?} and {:?}", x, y);
+                assert_encloses(sum, &(big(x) + big(y)), &one, &description);
+                assert_encloses(product, &(big(x) * big(y)), &scale, &description);
+                if !b.contains(TwoFloat::ZERO) {
+                    assert_encloses(
+                        quotient * TwoFloatInterval::from(y),
+                        &(big(x) * &scale),
+                        &scale,
+                        &description,
+                    );
+                }
+            }
+        }
+        assert!((a - a).contains(TwoFloat::ZERO));
+    });
+}
+
+#[test]
+fn width_test() {
+    repeated_test(|| {
+        let (lower, upper) = get_interval();
+        let a = TwoFloatInterval::new(lower, upper).unwrap();
+        let midpoint = a.midpoint();
+
+        assert!(big(a.width()) >= big(upper) - big(lower));
+        assert!(a.contains(midpoint));
+        assert!(
+            (midpoint - lower - (upper - midpoint)).abs() <= (lower.abs() + upper.abs()) * 1e-30,
+            "Inaccurate midpoint of {}",
+            a
+        );
+    });
+
+    assert_eq!(TwoFloatInterval::from(1.0).width(), 0.0);
+    assert_eq!(TwoFloatInterval::ENTIRE.width(), TwoFloat::INFINITY);
+    assert!(TwoFloatInterval::EMPTY.width().is_nan());
+    assert_eq!(interval(1.0, f64::INFINITY).midpoint(), TwoFloat::MAX);
+    assert_eq!(interval(f64::NEG_INFINITY, 1.0).midpoint(), TwoFloat::MIN);
+    assert_eq!(interval(-f64::MAX, f64::MAX).midpoint(), 0.0);
+    assert!(TwoFloatInterval::EMPTY.midpoint().is_nan());
+}
+
+#[test]
+fn set_ops_test() {
+    let a = interval(1.0, 3.0);
+    let b = interval(2.0, 4.0);
+    let c = interval(5.0, 6.0);
+
+    assert_eq!(a.hull(b), interval(1.0, 4.0));
+    assert_eq!(a.hull(c), interval(1.0, 6.0));
+    assert_eq!(a.hull(TwoFloatInterval::EMPTY), a);
+    assert_eq!(TwoFloatInterval::EMPTY.hull(a), a);
+    assert_eq!(a.intersection(b), interval(2.0, 3.0));
+    assert_eq!(a.intersection(interval(3.0, 4.0)), interval(3.0, 3.0));
+    assert!(a.intersection(c).is_empty());
+    assert!(a.intersection(TwoFloatInterval::EMPTY).is_empty());
+    assert_eq!(TwoFloatInterval::ENTIRE.intersection(a), a);
+
+    assert!(a.contains(TwoFloat::from(1.0)) && a.contains(TwoFloat::from(3.0)));
+    assert!(!a.contains(TwoFloat::new_add(3.0, 1e-30)));
+    assert!(!a.contains(TwoFloat::NAN));
+    assert!(!TwoFloatInterval::EMPTY.contains(TwoFloat::ZERO));
+    assert!(TwoFloatInterval::ENTIRE.contains(TwoFloat::MAX));
+}
+
+#[test]
+fn special_values_test() {
+    let unbounded = interval(1.0, f64::INFINITY);
+    let zero = TwoFloatInterval::from(0.0);
+    let a = interval(-2.0, 3.0);
+
+    assert_eq!(
+        unbounded + a,
+        interval(-1.0, f64::INFINITY).hull(unbounded + a)
+    );
+    assert_eq!((unbounded + a).upper(), TwoFloat::INFINITY);
+    assert_eq!((-unbounded).lower(), TwoFloat::NEG_INFINITY);
+    assert!((zero * unbounded).contains(TwoFloat::ZERO));
+    assert_eq!((a * unbounded).lower(), TwoFloat::NEG_INFINITY);
+    assert_eq!((a * unbounded).upper(), TwoFloat::INFINITY);
+    assert!((unbounded / unbounded).contains(TwoFloat::ZERO));
+    assert_eq!((unbounded / unbounded).upper(), TwoFloat::INFINITY);
+    assert_eq!(a / a, TwoFloatInterval::ENTIRE);
+    assert_eq!(unbounded / zero, TwoFloatInterval::ENTIRE);
+
+    let large = TwoFloatInterval::from(TwoFloat::MAX);
+    assert_eq!((large + large).upper(), TwoFloat::INFINITY);
+    assert!((large + large).lower() >= TwoFloat::MAX * (1.0 - 1e-10));
+    let tiny = TwoFloatInterval::from(1e-300) * TwoFloatInterval::from(1e-300);
+    assert!(tiny.contains(TwoFloat::ZERO) && tiny.width() < 1e-300);
+
+    assert!((a + TwoFloatInterval::EMPTY).is_empty());
+    assert!((TwoFloatInterval::EMPTY * a).is_empty());
+    assert!((-TwoFloatInterval::EMPTY).is_empty());
+    assert!(TwoFloatInterval::from(f64::NAN).is_empty());
+}
+
+#[test]
+fn new_test() {
+    let (one, two) = (TwoFloat::from(1.0), TwoFloat::from(2.0));
+    assert_eq!(TwoFloatInterval::new(one, two).unwrap().lower(), 1.0);
+    assert_eq!(TwoFloatInterval::new(one, two).unwrap().upper(), 2.0);
+    assert!(TwoFloatInterval::new(one, one).is_ok());
+    assert!(TwoFloatInterval::new(TwoFloat::NEG_INFINITY, TwoFloat::INFINITY).is_ok());
+
+    let invalid = [
+        (two, one),
+        (TwoFloat::NAN, one),
+        (one, TwoFloat::NAN),
+        (TwoFloat::INFINITY, TwoFloat::INFINITY),
+        (TwoFloat::NEG_INFINITY, TwoFloat::NEG_INFINITY),
+    ];
+    for (lower, upper) in invalid.iter() {
+        assert_eq!(
+            TwoFloatInterval::new(*lower, *upper),
+            Err(TwoFloatError::ConversionError)
+        );
+    }
+}
+
+#[test]
+fn assign_ops_test() {
+    let mut value = TwoFloatInterval::from(1.0);
+    value += interval(1.0, 2.0);
+    value *= TwoFloatInterval::from(3.0);
+    value -= TwoFloatInterval::from(6.0);
+    value /= TwoFloatInterval::from(3.0);
+
+    assert!(value.contains(TwoFloat::ZERO) && value.contains(TwoFloat::ONE));
+    assert!(value.width() - 1.0 < 1e-29);
+}
+
+#[test]
+fn display_test() {
+    assert_eq!(format!("{}", interval(1.0, 2.5)), "[1 + 0, 2.5 + 0]");
+    assert_eq!(format!("{}", TwoFloatInterval::EMPTY), "[empty]");
+}
+
+// Mathematical functions, checked against values computed with mpmath.
+#[cfg(feature = "math_funcs")]
+mod functions {
+    use super::*;
+    use twofloat::consts::{FRAC_PI_2, PI};
+
+    type Case = ((f64, f64), (f64, f64));
+
+    const EXP_CASES: [Case; 6] = [
+        (
+            (-502.77968768014125, -3.771517598234072e-15),
+            (4.4213615230925555e-219, 1.87764731464359e-235),
+        ),
+        (
+            (-0.3357216684744415, 3.354512280988987e-18),
+            (0.7148220356382347, 1.0921201111126661e-17),
+        ),
+        (
+            (0.918495070954692, 2.3327763851774063e-18),
+            (2.5055169260552503, -1.4045062717429331e-16),
+        ),
+        (
+            (18.366488800851453, -9.269837041032779e-17),
+            (94725027.08969755, -6.109157823562604e-09),
+        ),
+        (
+            (573.9433793029735, 2.6452414252589267e-15),
+            (1.821556121260756e+249, -1.1872938748220137e+233),
+        ),
+        (
+            (1e-10, -1.3522108846137315e-28),
+            (1.0000000001, -8.269037096400872e-18),
+        ),
+    ];
+
+    const LN_CASES: [Case; 6] = [
+        (
+            (6.040793503916153e-300, 0.0),
+            (-688.9769925200753, -1.474677170867387e-14),
+        ),
+        (
+            (0.5427796375354248, 3.3229100318869434e-18),
+            (-0.6110518654770623, 4.345166582855556e-17),
+        ),
+        (
+            (1.0152323967335373, 7.903870375418275e-18),
+            (0.015117548584935298, -6.3803890270214615e-19),
+        ),
+        (
+            (1.000000000001, 0.0),
+            (1.000088900581841e-12, -6.468659389470072e-29),
+        ),
+        (
+            (82.90677462898896, -6.243588375405476e-16),
+            (4.41771677929675, -8.830948281159741e-17),
+        ),
+        (
+            (9.817314276550644e+301, -5.129027148120627e+282),
+            (695.3622605809079, -3.266431449447841e-14),
+        ),
+    ];
+
+    const SIN_CASES: [Case; 6] = [
+        (
+            (0.5293124795488029, -1.857551039643041e-18),
+            (0.5049400242663131, 5.366863463716741e-17),
+        ),
+        (
+            (0.4839128694409425, 3.738134841579777e-18),
+            (0.4652463269712942, -2.61438248415992e-17),
+        ),
+        (
+            (266.0183209370714, -1.7637342961168967e-15),
+            (0.8505628154249776, 1.4884143700238482e-17),
+        ),
+        (
+            (core::f64::consts::PI, 0.0),
+            (1.2246467991473532e-16, -2.99476980971834e-33),
+        ),
+        (
+            (70576462.6918016, -1.6166563948796275e-10),
+            (-0.5706742344454275, -5.250285215091398e-17),
+        ),
+        ((1e-20, 0.0), (1e-20, -1.6666666666666664e-61)),
+    ];
+
+    const COS_CASES: [Case; 6] = [
+        (
+            (-0.7227587587003454, 4.2185744557957225e-18),
+            (0.7499837873469003, -8.630514656036783e-18),
+        ),
+        (
+            (7.602249416352585, -8.222769617022351e-19),
+            (0.2490819544088089, 9.696318504294563e-18),
+        ),
+        (
+            (475.8749001269539, 4.0864822277873024e-15),
+            (-0.07631262786129978, -4.305840056871604e-18),
+        ),
+        (
+            (core::f64::consts::FRAC_PI_2, 0.0),
+            (6.123233995736766e-17, -1.4973849048591698e-33),
+        ),
+        (
+            (37251307.79787744, 3.2749970993144493e-10),
+            (0.14195777041927266, 2.2524540565034865e-18),
+        ),
+        ((1e-20, 0.0), (1.0, -5e-41)),
+    ];
+
+    const TAN_CASES: [Case; 6] = [
+        (
+            (-0.5278151090930732, -5.139966325837295e-18),
+            (-0.5829857991556489, 1.0982789599892085e-17),
+        ),
+        (
+            (0.14462999438341662, -1.2018265073845192e-18),
+            (0.14564695306088013, 3.0545642648609057e-18),
+        ),
+        (
+            (684.7602863388448, -3.63155900501353e-15),
+            (-0.10732135741667789, -2.1945614732540496e-19),
+        ),
+        (
+            (core::f64::consts::FRAC_PI_2, 0.0),
+            (1.633123935319537e+16, -0.24403226295847108),
+        ),
+        (
+            (29666624.81816547, -1.9726106336013094e-10),
+            (-0.09665995364326833, -4.9316681503303045e-18),
+        ),
+        ((1e-20, 0.0), (1e-20, 3.333333333333333e-61)),
+    ];
+
+    fn two(parts: (f64, f64)) -> TwoFloat {
+        TwoFloat::new_add(parts.0, parts.1)
+    }
+
+    fn check_cases(cases: &[Case], f: fn(TwoFloatInterval) -> TwoFloatInterval, name: &str) {
+        for (x, expected) in cases.iter() {
+            let result = f(TwoFloatInterval::from(two(*x)));
+            assert!(
+                result.contains(two(*expected)),
+                "{} of {:?} gave {:?}",
+                name,
+                x,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn sqrt_test() {
+        repeated_test(|| {
+            let a = get_valid_twofloat(|x, _| x > 0.0);
+            let result = TwoFloatInterval::from(a).sqrt();
+
+            let exact = big(a) * to_big(&[1.0]);
+            assert!(big(result.lower()).pow(2) <= exact, "sqrt of {:?}", a);
+            assert!(big(result.upper()).pow(2) >= exact, "sqrt of {:?}", a);
+        });
+
+        let a = interval(-1.0, 4.0).sqrt();
+        assert_eq!(a.lower(), 0.0);
+        assert!(a.contains(TwoFloat::from(2.0)) && a.upper() - 2.0 < 1e-30);
+        assert!(interval(-2.0, -1.0).sqrt().is_empty());
+        assert_eq!(interval(1.0, f64::INFINITY).sqrt().upper(), f64::INFINITY);
+    }
+
+    #[test]
+    fn exp_ln_test() {
+        check_cases(&EXP_CASES, TwoFloatInterval::exp, "exp");
+        check_cases(&LN_CASES, TwoFloatInterval::ln, "ln");
+
+        let a = interval(f64::NEG_INFINITY, 0.0).exp();
+        assert_eq!(a.lower(), 0.0);
+        assert!(a.contains(TwoFloat::ONE) && a.upper() < 1.0 + 1e-9);
+        assert_eq!(interval(0.0, 1000.0).exp().upper(), f64::INFINITY);
+        assert!(interval(1000.0, 2000.0).exp().lower() > 1e308);
+
+        let b = interval(-1.0, 1.0).ln();
+        assert_eq!(b.lower(), f64::NEG_INFINITY);
+        assert!(b.contains(TwoFloat::ZERO));
+        assert!(interval(-2.0, -1.0).ln().is_empty());
+        let subnormal = interval(1e-320, 1e-310).ln();
+        assert!(subnormal.contains(TwoFloat::from(-736.0)));
+        assert!(subnormal.contains(TwoFloat::from(-714.0)));
+    }
+
+    #[test]
+    fn trigonometry_test() {
+        check_cases(&SIN_CASES, TwoFloatInterval::sin, "sin");
+        check_cases(&COS_CASES, TwoFloatInterval::cos, "cos");
+        check_cases(&TAN_CASES, TwoFloatInterval::tan, "tan");
+
+        // Extrema inside the interval
+        let a = interval(1.0, 2.0);
+        assert_eq!(a.sin().upper(), 1.0);
+        assert!(a.sin().lower() < TwoFloat::from(1.0).sin());
+        assert!((a.cos().upper() - TwoFloat::from(1.0).cos()).abs() < 1e-18);
+        let b = TwoFloatInterval::new(PI * 2.0 - 0.5, PI * 2.0 + 0.5).unwrap();
+        assert_eq!(b.cos().upper(), 1.0);
+        assert_eq!((b + TwoFloatInterval::from(PI)).cos().lower(), -1.0);
+        assert!(((-b).sin().lower() + TwoFloat::from(0.5).sin()).abs() < 1e-18);
+
+        // Monotonic intervals
+        let c = interval(0.1, 0.2).sin();
+        assert!(c.contains(TwoFloat::from(0.1).sin()) && c.contains(TwoFloat::from(0.2).sin()));
+        assert!(c.width() - (TwoFloat::from(0.2).sin() - TwoFloat::from(0.1).sin()) < 1e-18);
+
+        // Wide or large intervals
+        let full = interval(-1.0, 1.0).hull(TwoFloatInterval::from(0.0));
+        assert_eq!(interval(0.0, 7.0).sin(), full);
+        assert_eq!(TwoFloatInterval::ENTIRE.sin(), full);
+
+        // Critical points are located beyond the Cody-Waite range, with
+        // sin(1e22) = -0.852... and cos(1e22) = 0.523...
+        let big = TwoFloat::from(1e22);
+        let f = TwoFloatInterval::new(big, big + 1.0).unwrap().sin();
+        assert!(f.contains(big.sin()) && f.contains((big + 1.0).sin()));
+        assert!(f.width() - ((big + 1.0).sin() - big.sin()) < 1e-18);
+        let g = TwoFloatInterval::new(big, big + 3.0).unwrap();
+        assert_eq!(g.sin().upper(), 1.0);
+        assert_eq!(g.cos().upper(), 1.0);
+        assert!(g.sin().lower() > -0.9 && g.cos().lower() > -0.5);
+        assert_eq!(
+            TwoFloatInterval::new(big - 3.0, big).unwrap().cos().lower(),
+            -1.0
+        );
+        let h = TwoFloatInterval::from(big).tan();
+        assert!(h.contains(big.tan()) && h.width() < 1e-14);
+
+        // Poles of the tangent
+        let d = TwoFloatInterval::new(FRAC_PI_2 - 1e-10, FRAC_PI_2 + 1e-10).unwrap();
+        assert_eq!(d.tan(), TwoFloatInterval::ENTIRE);
+        let e = TwoFloatInterval::new(FRAC_PI_2 - 1e-10, FRAC_PI_2 - 1e-15).unwrap();
+        assert!(e.tan().lower() > 1e9 && e.tan().upper() < 1.1e15);
+        assert!((interval(-1.0, 1.0).tan().lower() - TwoFloat::from(-1.0).tan()).abs() < 1e-14);
+    }
+}
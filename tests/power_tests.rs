@@ -4,9 +4,72 @@
 pub mod common;
 
 use common::*;
+use num_bigint::BigInt;
 use rand::Rng;
 use twofloat::TwoFloat;
 
+// Returns twice the midpoints between a value and its neighbours as exact
+// integer multiples of 2^BIG_SCALE.
+fn big_midpoints(value: TwoFloat) -> (BigInt, BigInt) {
+    let below = value.next_down();
+    let above = value.next_up();
+    (
+        to_big(&[value.hi(), value.lo(), below.hi(), below.lo()]),
+        to_big(&[value.hi(), value.lo(), above.hi(), above.lo()]),
+    )
+}
+
+fn assert_on_grid(value: TwoFloat, source: TwoFloat) {
+    assert!(value.is_valid(), "Invalid result for {:?}", source);
+    assert_eq!(
+        (value.lo() / value.ulp()).fract(),
+        0.0,
+        "Result {:?} for {:?} not rounded to full precision",
+        value,
+        source
+    );
+}
+
+// Checks that `result` is the correctly rounded square root of `source`,
+// i.e. that 4 * source lies strictly between the squares of twice the
+// midpoints to the neighbouring values.
+fn assert_sqrt_correctly_rounded(source: TwoFloat, result: TwoFloat) {
+    assert_on_grid(result, source);
+    let x = to_big(&[source.hi(), source.lo()]) << ((2 - BIG_SCALE) as usize);
+    let (below, above) = big_midpoints(result);
+    assert!(
+        &below * &below < x && x < &above * &above,
+        "Square root of {:?} gave incorrectly rounded result {:?}",
+        source,
+        result
+    );
+}
+
+// Checks that `result` is the correctly rounded reciprocal square root of
+// `source`, i.e. that 4 lies strictly between `source` times the squares of
+// twice the midpoints to the neighbouring values.
+fn assert_rsqrt_correctly_rounded(source: TwoFloat, result: TwoFloat) {
+    assert_on_grid(result, source);
+    let x = to_big(&[source.hi(), source.lo()]);
+    let four = BigInt::from(4) << ((-3 * BIG_SCALE) as usize);
+    let (below, above) = big_midpoints(result);
+    assert!(
+        &x * &below * &below < four && four < &x * &above * &above,
+        "Reciprocal square root of {:?} gave incorrectly rounded result {:?}",
+        source,
+        result
+    );
+}
+
+// Generates a positive value with a low word close to the maximum allowed,
+// so that all bits of precision are used.
+fn get_full_precision_twofloat(hi_exponent: core::ops::Range<i32>) -> TwoFloat {
+    let mut rng = rand::thread_rng();
+    let hi = rng.gen_range(1.0..2.0) * 2f64.powi(rng.gen_range(hi_exponent));
+    let lo = hi * rng.gen_range(-1.0..1.0) * f64::EPSILON * 0.5;
+    TwoFloat::normalize(hi, lo)
+}
+
 #[test]
 fn recip_test() {
     repeated_test(|| {
@@ -50,6 +113,84 @@ fn sqrt_test() {
     });
 }
 
+#[test]
+fn sqrt_correctly_rounded_test() {
+    repeated_test(|| {
+        let source = get_valid_twofloat(|x, _| x > 0.0);
+        assert_sqrt_correctly_rounded(source, source.sqrt());
+
+        let source = get_full_precision_twofloat(-1022..1024);
+        assert_sqrt_correctly_rounded(source, source.sqrt());
+    });
+}
+
+#[test]
+fn sqrt_subnormal_lo_test() {
+    repeated_test(|| {
+        // Low words of these values are subnormal
+        let source = get_full_precision_twofloat(-1022..-969);
+        assert!(source.lo() == 0.0 || source.lo().abs() < f64::MIN_POSITIVE);
+        assert_sqrt_correctly_rounded(source, source.sqrt());
+    });
+}
+
+#[test]
+fn sqrt_midpoint_test() {
+    repeated_test(|| {
+        // Squares of values close to midpoints between representable values
+        // require the exact rounding test
+        let root = get_full_precision_twofloat(-500..500);
+        let half_ulp = root.ulp() * 0.5;
+        let source = root * root + 2.0 * root * half_ulp;
+        assert_sqrt_correctly_rounded(source, source.sqrt());
+    });
+}
+
+#[test]
+fn sqrt_exact_test() {
+    repeated_test(|| {
+        // A root with at most 53 significant bits has an exact square
+        let root = TwoFloat::from(get_valid_f64(|x| x.abs() > 1e-120 && x.abs() < 1e120).abs());
+        let source = root * root;
+        assert_eq!(source.sqrt(), root, "Incorrect square root of {:?}", source);
+    });
+
+    for i in -500..500 {
+        let value = TwoFloat::from(2f64.powi(i));
+        assert_eq!((value * value).sqrt(), value);
+        assert_eq!((value * value).rsqrt(), value.recip());
+    }
+}
+
+#[test]
+fn rsqrt_correctly_rounded_test() {
+    repeated_test(|| {
+        let source = get_valid_twofloat(|x, _| x > 0.0);
+        assert_rsqrt_correctly_rounded(source, source.rsqrt());
+
+        let source = get_full_precision_twofloat(-1022..1024);
+        assert_rsqrt_correctly_rounded(source, source.rsqrt());
+    });
+}
+
+#[test]
+fn rsqrt_subnormal_lo_test() {
+    repeated_test(|| {
+        let source = get_full_precision_twofloat(-1022..-969);
+        assert_rsqrt_correctly_rounded(source, source.rsqrt());
+    });
+}
+
+#[test]
+fn rsqrt_special_test() {
+    assert_eq!(TwoFloat::from(0.0).rsqrt(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-0.0).rsqrt(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.rsqrt(), 0.0);
+    assert!(TwoFloat::from(-1.0).rsqrt().is_nan());
+    assert!(TwoFloat::NEG_INFINITY.rsqrt().is_nan());
+    assert!(TwoFloat::NAN.rsqrt().is_nan());
+}
+
 #[test]
 fn sqrt_negative_test() {
     repeated_test(|| {
@@ -172,33 +313,240 @@ fn powi_reciprocal_test() {
 }
 
 #[test]
-fn zero_powf_test() {
-    repeated_test(|| {
-        let source = get_twofloat();
-        let result = TwoFloat::from(0.0).powf(source);
+fn powi_exact_test() {
+    assert_eq!(
+        TwoFloat::from(3.0).powi(40),
+        TwoFloat::new_add(12157665459056928768.0, 33.0)
+    );
+    assert_eq!(TwoFloat::from(-1.5).powi(21).hi(), -4987.885095119476);
+    assert_eq!(TwoFloat::from(10.0).powi(30).lo(), -19884624838656.0);
+    assert_eq!(TwoFloat::from(-2.0).powi(-1073), -f64::from_bits(2));
+    assert_eq!(
+        TwoFloat::from(0.5).powi(1024),
+        2f64.powi(-1000) * 2f64.powi(-24)
+    );
+    assert_eq!(TwoFloat::from(2.0).powi(i32::MIN), 0.0);
+    assert_eq!(TwoFloat::from(-1.0).powi(i32::MIN), 1.0);
+    assert_eq!(TwoFloat::from(0.5).powi(i32::MIN), TwoFloat::INFINITY);
+}
 
-        if source == 0.0 {
-            assert!(!result.is_valid(), "0^0 returned valid result");
-        } else {
-            assert!(result.is_valid(), "0^{} produced invalid value", source);
-            assert_eq!(result, 0.0, "0^{} did not return 0", source);
-        }
-    })
+#[test]
+fn powi64_test() {
+    let cases = [
+        (
+            [1.0, 1e-19],
+            12345678901234,
+            [1.0000012345686522, 5.141580182336558e-18],
+        ),
+        (
+            [-0.999999999, 0.0],
+            -5000000001,
+            [-148.41313863497007, -9.068133640655464e-15],
+        ),
+        (
+            [3.0, 0.0],
+            -40,
+            [8.225263339969959e-20, 4.695964864283024e-36],
+        ),
+        (
+            [1.25, 1e-17],
+            -3000,
+            [1.8619198236024023e-291, -9.186832745883323e-308],
+        ),
+    ];
+    for ([x_hi, x_lo], n, [hi, lo]) in cases.iter() {
+        let x = TwoFloat::new_add(*x_hi, *x_lo);
+        let result = x.powi64(*n);
+        let expected = TwoFloat::new_add(*hi, *lo);
+        assert!(
+            ((result - expected) / expected).abs() < 1e-30,
+            "Mismatch in {:?}.powi64({}): {:?} vs {:?}",
+            x,
+            n,
+            expected,
+            result
+        );
+    }
+
+    assert_eq!(TwoFloat::from(-2.0).powi64(63), -9223372036854775808.0);
+    assert_eq!(TwoFloat::from(-1.0).powi64(i64::MIN), 1.0);
+    assert_eq!(TwoFloat::from(-1.0).powi64(i64::MAX), -1.0);
+    assert_eq!(TwoFloat::from(2.0).powi64(i64::MIN), 0.0);
+    assert_eq!(
+        TwoFloat::from(-2.0).powi64(i64::MAX),
+        TwoFloat::NEG_INFINITY
+    );
+    assert_eq!(TwoFloat::from(-0.0).powi64(-3), TwoFloat::NEG_INFINITY);
 }
 
 #[test]
-fn powf_zero_test() {
-    repeated_test(|| {
-        let source = get_twofloat();
-        let result = source.powf(TwoFloat::from(0.0));
+fn pow_zero_exponent_test() {
+    // IEEE 754 `pown` and `pow` both return 1 for a zero exponent
+    let sources = [0.0, -0.0, 1.5, f64::INFINITY, f64::NAN];
+    for &x in sources.iter() {
+        let source = TwoFloat::from(x);
+        assert_eq!(source.powi(0), 1.0, "{:?}.powi(0) did not return 1", source);
+        assert_eq!(
+            source.powi64(0),
+            1.0,
+            "{:?}.powi64(0) did not return 1",
+            source
+        );
+        assert_eq!(
+            source.powf(TwoFloat::from(0.0)),
+            1.0,
+            "{:?}.powf(0) did not return 1",
+            source
+        );
+    }
+}
 
-        if source == 0.0 {
-            assert!(!result.is_valid(), "0^0 returned valid result");
-        } else {
-            assert!(result.is_valid(), "{}^0 returned invalid value", source);
-            assert_eq!(result, 1.0, "{}^0 did not return 1", source);
-        }
+// Reference values computed with mpmath
+const POWF_CASES: [([f64; 2], [f64; 2], [f64; 2]); 10] = [
+    (
+        [2.0, 0.0],
+        [1.5, 0.0],
+        [2.8284271247461903, -1.9334586626905827e-16],
+    ),
+    (
+        [10.0, 0.0],
+        [-0.3, 0.0],
+        [0.5011872336272722, 5.390615057675093e-17],
+    ),
+    (
+        [0.75, 1e-17],
+        [123.456, 0.0],
+        [3.7632522787960437e-16, 2.2936219122852942e-32],
+    ),
+    (
+        [1.0, 1e-20],
+        [2e+20, 0.0],
+        [7.3890560989306495, -1.0213797397458862e-16],
+    ),
+    (
+        [1.0000001, 0.0],
+        [-750000000.0, 0.0],
+        [2.6786468894168087e-33, -8.957614054698202e-50],
+    ),
+    (
+        [1e-300, 0.0],
+        [0.9, 0.0],
+        [9.999999999999847e-271, -1.6458000817108552e-287],
+    ),
+    (
+        [123.0, 0.0],
+        [147.25, 0.0],
+        [5.476809806891005e+307, -3.680752225609846e+290],
+    ),
+    (
+        [7.0, 0.0],
+        [-250.0, 0.0],
+        [5.314837571380598e-212, -5.119798551423277e-228],
+    ),
+    (
+        [-1.1, 5e-17],
+        [4095.0, 0.0],
+        [-3.184532774684217e+169, 2.3522756794336898e+153],
+    ),
+    ([0.5, -1e-17], [3e-18, 0.0], [1.0, -2.0794415416798358e-18]),
+];
+
+#[test]
+fn powf_accuracy_test() {
+    for ([x_hi, x_lo], [y_hi, y_lo], [hi, lo]) in POWF_CASES.iter() {
+        let x = TwoFloat::new_add(*x_hi, *x_lo);
+        let y = TwoFloat::new_add(*y_hi, *y_lo);
+        let result = x.powf(y);
+        let expected = TwoFloat::new_add(*hi, *lo);
+        assert!(result.is_valid(), "{:?}^{:?} produced invalid value", x, y);
+        assert!(
+            ((result - expected) / expected).abs() < 1e-30,
+            "Mismatch in {:?}^{:?}: {:?} vs {:?}",
+            x,
+            y,
+            expected,
+            result
+        );
+    }
+}
+
+#[test]
+fn powf_exact_test() {
+    let mut rng = rand::thread_rng();
+    repeated_test(|| {
+        // Powers of 26-bit integers with up to 106 bits are exact
+        let base = rng.gen_range(1..1 << 26);
+        let n = rng.gen_range(0..5);
+        let exact = BigInt::from(base).pow(n) << ((-BIG_SCALE) as usize);
+        let base = f64::from(base);
+        let result = TwoFloat::from(base).powf(TwoFloat::from(f64::from(n)));
+        assert_eq!(
+            to_big(&[result.hi(), result.lo()]),
+            exact,
+            "{}^{} was not exact",
+            base,
+            n
+        );
+        assert_eq!(TwoFloat::from(base).powi(n as i32), result);
+        assert_eq!(TwoFloat::from(base * base).powf(TwoFloat::from(0.5)), base);
     });
+
+    assert_eq!(TwoFloat::from(10.0).powf(TwoFloat::from(22.0)), 1e22);
+    assert_eq!(TwoFloat::from(-3.0).powf(TwoFloat::from(35.0)).lo(), -3.0);
+    assert_eq!(TwoFloat::from(-0.5).powf(TwoFloat::from(-7.0)), -128.0);
+    assert_eq!(TwoFloat::from(2.25).powf(TwoFloat::from(0.5)), 1.5);
+    assert_eq!(TwoFloat::from(16.0).powf(TwoFloat::from(-0.5)), 0.25);
+    assert_eq!(
+        TwoFloat::from(2.0).powf(TwoFloat::from(-1074.0)),
+        f64::from_bits(1)
+    );
+}
+
+#[test]
+fn powf_special_test() {
+    let zero = TwoFloat::from(0.0);
+    let neg_zero = TwoFloat::from(-0.0);
+    let inf = TwoFloat::INFINITY;
+    let neg_inf = TwoFloat::NEG_INFINITY;
+    let nan = TwoFloat::NAN;
+    let one = TwoFloat::from(1.0);
+    let two = TwoFloat::from(2.0);
+    let three = TwoFloat::from(3.0);
+    let half = TwoFloat::from(0.5);
+
+    for x in [zero, neg_zero, inf, neg_inf, nan, two].iter() {
+        assert_eq!(x.powf(zero), 1.0, "{:?}^0 did not return 1", x);
+        assert_eq!(x.powf(neg_zero), 1.0, "{:?}^-0 did not return 1", x);
+    }
+    for y in [inf, neg_inf, nan, half].iter() {
+        assert_eq!(one.powf(*y), 1.0, "1^{:?} did not return 1", y);
+    }
+    assert_eq!((-one).powf(inf), 1.0);
+    assert_eq!((-one).powf(neg_inf), 1.0);
+    assert!(nan.powf(one).is_nan());
+    assert!(two.powf(nan).is_nan());
+
+    assert_eq!(neg_zero.powf(-three), neg_inf);
+    assert_eq!(neg_zero.powf(-two), inf);
+    assert_eq!(zero.powf(-half), inf);
+    assert!(neg_zero.powf(three).is_sign_negative());
+    assert!(neg_zero.powf(two).is_sign_positive());
+    assert!(neg_zero.powf(half).is_sign_positive());
+    assert_eq!(neg_inf.powf(three), neg_inf);
+    assert_eq!(neg_inf.powf(half), inf);
+    assert!(neg_inf.powf(-three).is_sign_negative());
+    assert_eq!(inf.powf(-half), 0.0);
+
+    assert_eq!(half.powf(inf), 0.0);
+    assert_eq!(half.powf(neg_inf), inf);
+    assert_eq!((-two).powf(inf), inf);
+    assert_eq!((-two).powf(neg_inf), 0.0);
+
+    assert!((-two).powf(half).is_nan());
+    assert_eq!((-two).powf(TwoFloat::new_add(1e20, 1.0)), -inf);
+    assert_eq!((-half).powf(TwoFloat::from(1e20)), 0.0);
+    assert_eq!(two.powf(TwoFloat::from(1024.0)), inf);
+    assert_eq!(two.powf(TwoFloat::from(-1075.0)), 0.0);
 }
 
 #[test]
@@ -226,3 +574,47 @@ fn powf_test() {
         );
     });
 }
+
+#[test]
+fn hypot_test() {
+    repeated_test(|| {
+        let a = get_valid_twofloat(|x, _| x.abs() < 1e307);
+        let b = get_valid_twofloat(|x, _| x.abs() < 1e307);
+        let result = a.hypot(b);
+
+        let exact = to_big(&[a.hi(), a.lo()]).pow(2) + to_big(&[b.hi(), b.lo()]).pow(2);
+        let squared = to_big(&[result.hi(), result.lo()]).pow(2);
+        let error: BigInt = squared - &exact;
+        assert!(
+            (error.magnitude() << 100) <= *exact.magnitude(),
+            "Inaccurate hypot of {:?} and {:?}",
+            a,
+            b
+        );
+    });
+
+    let large = TwoFloat::from(1e300);
+    let difference = large.hypot(large) / 1e300 - TwoFloat::from(2.0).sqrt();
+    assert!(difference.abs() < 1e-30);
+    let small = TwoFloat::from(3e-280);
+    let difference = small.hypot(small * 4.0 / 3.0) / small - TwoFloat::from(5.0) / 3.0;
+    assert!(difference.abs() < 1e-30);
+    assert_eq!(TwoFloat::MAX.hypot(TwoFloat::MAX), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-3.0).hypot(TwoFloat::from(-0.0)), 3.0);
+}
+
+#[test]
+fn power_special_test() {
+    let negative_zero = TwoFloat::from(-0.0).sqrt();
+    assert!(negative_zero == 0.0 && negative_zero.is_sign_negative());
+    assert_eq!(TwoFloat::INFINITY.sqrt(), TwoFloat::INFINITY);
+    assert!(TwoFloat::NEG_INFINITY.sqrt().is_nan());
+    assert!(TwoFloat::NAN.sqrt().is_nan());
+    assert_eq!(TwoFloat::NEG_INFINITY.cbrt(), TwoFloat::NEG_INFINITY);
+    assert_eq!(TwoFloat::INFINITY.recip(), 0.0);
+    assert_eq!(TwoFloat::from(-0.0).recip(), TwoFloat::NEG_INFINITY);
+    assert_eq!(
+        TwoFloat::NAN.hypot(TwoFloat::NEG_INFINITY),
+        TwoFloat::INFINITY
+    );
+}
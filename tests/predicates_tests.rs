@@ -0,0 +1,209 @@
+pub mod common;
+
+use common::*;
+use num_bigint::{BigInt, Sign};
+use rand::Rng;
+use twofloat::predicates::{incircle, insphere, orient2d, orient3d};
+
+fn sign(x: f64) -> Sign {
+    if x > 0.0 {
+        Sign::Plus
+    } else if x < 0.0 {
+        Sign::Minus
+    } else {
+        Sign::NoSign
+    }
+}
+
+// Returns the exact differences of the coordinates of two points.
+fn differences<const N: usize>(p: [f64; N], q: [f64; N]) -> [BigInt; N] {
+    core::array::from_fn(|i| to_big(&[p[i]]) - to_big(&[q[i]]))
+}
+
+fn det3(r: [&[BigInt]; 3]) -> BigInt {
+    &r[0][0] * (&r[1][1] * &r[2][2] - &r[1][2] * &r[2][1])
+        + &r[0][1] * (&r[1][2] * &r[2][0] - &r[1][0] * &r[2][2])
+        + &r[0][2] * (&r[1][0] * &r[2][1] - &r[1][1] * &r[2][0])
+}
+
+fn lifted(d: &[BigInt]) -> Vec<BigInt> {
+    let lift = d.iter().map(|x| x * x).sum();
+    d.iter().cloned().chain(Some(lift)).collect()
+}
+
+fn exact_orient2d(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> Sign {
+    let ([acx, acy], [bcx, bcy]) = (differences(a, c), differences(b, c));
+    (acx * bcy - acy * bcx).sign()
+}
+
+fn exact_orient3d(a: [f64; 3], b: [f64; 3], c: [f64; 3], d: [f64; 3]) -> Sign {
+    let (ad, bd, cd) = (differences(a, d), differences(b, d), differences(c, d));
+    det3([&ad, &bd, &cd]).sign()
+}
+
+fn exact_incircle(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> Sign {
+    let ad = lifted(&differences(a, d));
+    let bd = lifted(&differences(b, d));
+    let cd = lifted(&differences(c, d));
+    det3([&ad, &bd, &cd]).sign()
+}
+
+fn exact_insphere(a: [f64; 3], b: [f64; 3], c: [f64; 3], d: [f64; 3], e: [f64; 3]) -> Sign {
+    // Expansion of the lifted 4x4 determinant along its last column
+    let rows = [a, b, c, d].map(|p| lifted(&differences(p, e)));
+    let minor = |i: usize| {
+        let others: Vec<&[BigInt]> = (0..4).filter(|j| *j != i).map(|j| &rows[j][..]).collect();
+        det3([others[0], others[1], others[2]])
+    };
+    let det = &rows[3][3] * minor(3) - &rows[2][3] * minor(2) + &rows[1][3] * minor(1)
+        - &rows[0][3] * minor(0);
+    det.sign()
+}
+
+// Returns a random coordinate in [-1, 1], scaled by a random power of two.
+fn get_coordinate(scale: i32) -> f64 {
+    rand::thread_rng().gen_range(-1.0..1.0) * 2f64.powi(scale)
+}
+
+// Perturbs a value by a few units in the last place.
+fn perturb(x: f64) -> f64 {
+    let ulps = rand::thread_rng().gen_range(-3..=3);
+    f64::from_bits((x.to_bits() as i64 + ulps) as u64)
+}
+
+fn get_scale() -> i32 {
+    rand::thread_rng().gen_range(-40..40)
+}
+
+#[test]
+fn orient2d_test() {
+    repeated_test(|| {
+        // Points near the line through two random points
+        let scale = get_scale();
+        let a = [get_coordinate(scale), get_coordinate(scale)];
+        let b = [get_coordinate(scale), get_coordinate(scale)];
+        let t = rand::thread_rng().gen_range(-2.0..2.0);
+        let c = [
+            perturb(a[0] + t * (b[0] - a[0])),
+            perturb(a[1] + t * (b[1] - a[1])),
+        ];
+
+        let description = format!("orient2d of {:?}, {:?}, {:?}", a, b, c);
+        let expected = exact_orient2d(a, b, c);
+        assert_eq!(sign(orient2d(a, b, c)), expected, "{}", description);
+        assert_eq!(sign(orient2d(b, c, a)), expected, "{}", description);
+        assert_eq!(sign(orient2d(b, a, c)), -expected, "{}", description);
+    });
+}
+
+#[test]
+fn orient2d_grid_test() {
+    // Points near the diagonal which defeat the f64 evaluation, from Kettner
+    // et al. (2008)
+    let (b, c) = ([12.0, 12.0], [24.0, 24.0]);
+    for i in 0..256 {
+        for j in 0..256 {
+            let a = [
+                0.5 + f64::from(i) * f64::EPSILON / 2.0,
+                0.5 + f64::from(j) * f64::EPSILON / 2.0,
+            ];
+            let expected = exact_orient2d(a, b, c);
+            assert_eq!(sign(orient2d(a, b, c)), expected, "orient2d of {:?}", a);
+            assert_eq!(expected == Sign::NoSign, i == j);
+        }
+    }
+}
+
+#[test]
+fn orient3d_test() {
+    for _ in 0..10000 {
+        // Points near the plane through three random points
+        let scale = get_scale();
+        let point = || [0, 1, 2].map(|_| get_coordinate(scale));
+        let (a, b, c) = (point(), point(), point());
+        let (s, t) = (get_coordinate(0), get_coordinate(0));
+        let d = [0, 1, 2].map(|i| perturb(a[i] + s * (b[i] - a[i]) + t * (c[i] - a[i])));
+
+        let description = format!("orient3d of {:?}, {:?}, {:?}, {:?}", a, b, c, d);
+        let expected = exact_orient3d(a, b, c, d);
+        assert_eq!(sign(orient3d(a, b, c, d)), expected, "{}", description);
+        assert_eq!(sign(orient3d(b, a, c, d)), -expected, "{}", description);
+    }
+}
+
+#[test]
+fn incircle_test() {
+    for _ in 0..10000 {
+        // Points near a random circle
+        let scale = get_scale();
+        let centre = [get_coordinate(scale), get_coordinate(scale)];
+        let radius = get_coordinate(scale).abs();
+        let point = || {
+            let theta = get_coordinate(2);
+            [
+                perturb(centre[0] + radius * theta.cos()),
+                perturb(centre[1] + radius * theta.sin()),
+            ]
+        };
+        let (a, b, c, d) = (point(), point(), point(), point());
+
+        let description = format!("incircle of {:?}, {:?}, {:?}, {:?}", a, b, c, d);
+        let expected = exact_incircle(a, b, c, d);
+        assert_eq!(sign(incircle(a, b, c, d)), expected, "{}", description);
+        assert_eq!(sign(incircle(b, a, c, d)), -expected, "{}", description);
+    }
+}
+
+#[test]
+fn insphere_test() {
+    for _ in 0..10000 {
+        // Points near a random sphere
+        let scale = get_scale();
+        let centre = [0, 1, 2].map(|_| get_coordinate(scale));
+        let radius = get_coordinate(scale).abs();
+        let point = || {
+            let (theta, phi) = (get_coordinate(2), get_coordinate(1));
+            let direction = [theta.cos() * phi.cos(), theta.sin() * phi.cos(), phi.sin()];
+            [0, 1, 2].map(|i| perturb(centre[i] + radius * direction[i]))
+        };
+        let (a, b, c, d, e) = (point(), point(), point(), point(), point());
+
+        let description = format!("insphere of {:?}, {:?}, {:?}, {:?}, {:?}", a, b, c, d, e);
+        let expected = exact_insphere(a, b, c, d, e);
+        assert_eq!(sign(insphere(a, b, c, d, e)), expected, "{}", description);
+        assert_eq!(sign(insphere(b, a, c, d, e)), -expected, "{}", description);
+    }
+}
+
+#[test]
+fn degenerate_test() {
+    // Exactly collinear, coplanar, cocircular and cospherical points with
+    // integer coordinates, offset so that the f64 permanents are large
+    let offset = 2f64.powi(40);
+    let shift2 = |p: [f64; 2]| [p[0] + offset, p[1] - offset];
+    let shift3 = |p: [f64; 3]| [p[0] + offset, p[1] - offset, p[2] + offset];
+
+    let [a, b, c] = [[1.0, 1.0], [2.0, 3.0], [4.0, 7.0]].map(shift2);
+    assert_eq!(exact_orient2d(a, b, c), Sign::NoSign);
+    assert_eq!(orient2d(a, b, c), 0.0);
+
+    let [a, b, c, d] = [[3.0, 4.0], [5.0, 0.0], [-4.0, 3.0], [0.0, -5.0]].map(shift2);
+    assert_eq!(exact_incircle(a, b, c, d), Sign::NoSign);
+    assert_eq!(incircle(a, b, c, d), 0.0);
+
+    let points = [[0.0; 3], [1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    let [a, b, c, d] = points.map(shift3);
+    assert_eq!(exact_orient3d(a, b, c, d), Sign::NoSign);
+    assert_eq!(orient3d(a, b, c, d), 0.0);
+
+    let points = [
+        [1.0, 2.0, 2.0],
+        [2.0, -2.0, 1.0],
+        [-2.0, 1.0, 2.0],
+        [0.0, 0.0, -3.0],
+        [2.0, 2.0, -1.0],
+    ];
+    let [a, b, c, d, e] = points.map(shift3);
+    assert_eq!(exact_insphere(a, b, c, d, e), Sign::NoSign);
+    assert_eq!(insphere(a, b, c, d, e), 0.0);
+}
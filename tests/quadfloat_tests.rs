@@ -0,0 +1,1408 @@
+#![allow(clippy::float_cmp)]
+
+use core::convert::TryFrom;
+
+pub mod common;
+
+use common::*;
+use rand::Rng;
+use twofloat::{QuadFloat, ThreeFloat, TwoFloat, TwoFloatError};
+
+// Returns a random value with each word at most half a unit in the last
+// place of the previous one, so that arithmetic results are normal.
+fn get_quadfloat() -> QuadFloat {
+    let mut rng = rand::thread_rng();
+    let sign = if rng.gen() { 1.0 } else { -1.0 };
+    let mut words = [0.0; 4];
+    words[0] = sign * rng.gen_range(1.0..2.0) * 2f64.powi(rng.gen_range(-250..250));
+    for i in 1..4 {
+        words[i] = words[i - 1] * rng.gen_range(-1.0..1.0) * f64::EPSILON * 0.25;
+    }
+    QuadFloat::try_from(words).unwrap()
+}
+
+#[test]
+fn add_sub_test() {
+    repeated_test(|| {
+        let a = get_quadfloat();
+        let b = get_quadfloat();
+        let x = get_valid_f64(|x| x.is_normal() && x.abs() < 1e300);
+
+        let cases = [
+            (a + b, big(a) + big(b), "add"),
+            (a - b, big(a) - big(b), "sub"),
+            (a + x, big(a) + to_big(&[x]), "add f64"),
+            (x + a, big(a) + to_big(&[x]), "f64 add"),
+            (a - x, big(a) - to_big(&[x]), "sub f64"),
+            (x - a, to_big(&[x]) - big(a), "f64 sub"),
+        ];
+
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(
+                &big(*result),
+                exact,
+                QuadFloat::TOLERANCE_BITS,
+                &description,
+            );
+        }
+    });
+}
+
+#[test]
+fn mul_test() {
+    repeated_test(|| {
+        let a = get_quadfloat();
+        let b = get_quadfloat();
+        let x = get_valid_f64(|x| x.abs() < 1e100 && x.abs() > 1e-100);
+
+        let scale = to_big(&[1.0]);
+        let cases = [
+            (a * b, big(a) * big(b), "mul"),
+            (a * x, big(a) * to_big(&[x]), "mul f64"),
+            (x * a, big(a) * to_big(&[x]), "f64 mul"),
+        ];
+
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(
+                &(big(*result) * &scale),
+                exact,
+                QuadFloat::TOLERANCE_BITS,
+                &description,
+            );
+        }
+    });
+}
+
+#[test]
+fn div_test() {
+    repeated_test(|| {
+        let a = get_quadfloat();
+        let b = get_quadfloat();
+        let x = get_valid_f64(|x| x.abs() < 1e100 && x.abs() > 1e-100);
+
+        let scale = to_big(&[1.0]);
+        let cases = [
+            (a / b, big(a), big(b), "div"),
+            (a / x, big(a), to_big(&[x]), "div f64"),
+            (x / a, to_big(&[x]), big(a), "f64 div"),
+        ];
+
+        for (result, dividend, divisor, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(
+                &(big(*result) * divisor),
+                &(dividend * &scale),
+                QuadFloat::TOLERANCE_BITS,
+                &description,
+            );
+        }
+    });
+}
+
+#[test]
+fn rem_test() {
+    let a = QuadFloat::from(7.5);
+    let b = QuadFloat::from(TwoFloat::new_add(2.0, 1e-20)) + 1e-40;
+
+    assert_eq!(a % 2.0, 1.5);
+    assert_eq!(-a % 2.0, -1.5);
+    assert_eq!((a % b) + b * 3.0, a);
+    assert!((a % b).is_valid());
+    assert_eq!(a % f64::INFINITY, a);
+    assert!((a % 0.0).is_nan());
+    assert!((QuadFloat::from(-4.0) % 2.0).is_sign_negative());
+}
+
+#[test]
+fn assign_ops_test() {
+    repeated_test(|| {
+        let a = get_quadfloat();
+        let b = get_quadfloat();
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a + b - b);
+        c *= b;
+        assert_eq!(c, (a + b - b) * b);
+        c /= b;
+        assert_eq!(c, (a + b - b) * b / b);
+    });
+}
+
+#[test]
+fn conversion_test() {
+    repeated_test(|| {
+        let source = get_valid_twofloat(|x, _| x.abs() < 1e300);
+        let result = QuadFloat::from(source);
+
+        assert!(result.is_valid());
+        assert_eq!(result, source);
+        assert_eq!(TwoFloat::from(result), source);
+        assert_eq!(
+            TwoFloat::from(QuadFloat::from(ThreeFloat::from(source))),
+            source
+        );
+    });
+}
+
+#[test]
+fn rounding_conversion_test() {
+    repeated_test(|| {
+        let source = get_quadfloat();
+        let two = TwoFloat::from(source);
+        let three = ThreeFloat::from(source);
+
+        assert!(two.is_valid(), "Invalid conversion of {:?}", source);
+        assert!(three.is_valid(), "Invalid conversion of {:?}", source);
+        assert!(
+            (QuadFloat::from(two) - source).abs() <= QuadFloat::from(two.ulp()) * 0.5,
+            "Conversion of {:?} not rounded to nearest",
+            source
+        );
+        assert!(
+            (QuadFloat::from(three) - source).abs() <= source.abs() * 1e-47,
+            "Inaccurate conversion of {:?}",
+            source
+        );
+    });
+}
+
+#[test]
+fn array_conversion_test() {
+    let value = QuadFloat::from(1.0) / 3.0;
+    let array: [f64; 4] = value.into();
+
+    assert_eq!(QuadFloat::try_from(array), Ok(value));
+    assert_eq!(
+        QuadFloat::try_from([1.0, 1e-20, 1e-40, 1e-50]),
+        Err(TwoFloatError::ConversionError)
+    );
+}
+
+#[test]
+fn comparison_test() {
+    repeated_test(|| {
+        let a = get_quadfloat();
+        let b = if rand::thread_rng().gen() {
+            get_quadfloat()
+        } else {
+            a + a.words()[3] * rand::thread_rng().gen_range(-4.0..4.0)
+        };
+
+        assert_eq!(
+            a.partial_cmp(&b),
+            big(a).partial_cmp(&big(b)),
+            "Incorrect comparison of {:?} and {:?}",
+            a,
+            b
+        );
+        assert_eq!(a == b, big(a) == big(b));
+    });
+
+    let one = QuadFloat::from(1.0);
+    let above = one + 1e-60;
+    assert_eq!(one, 1.0);
+    assert!(above > 1.0);
+    assert!(above > TwoFloat::from(1.0));
+    assert!(TwoFloat::from(1.0) < above);
+    assert_ne!(above, TwoFloat::from(1.0));
+}
+
+#[test]
+fn special_values_test() {
+    let one = QuadFloat::from(1.0);
+    let zero = QuadFloat::from(0.0);
+
+    assert_eq!(one / zero, QuadFloat::INFINITY);
+    assert_eq!(-one / zero, QuadFloat::NEG_INFINITY);
+    assert!((zero / zero).is_nan());
+    assert!((QuadFloat::INFINITY - QuadFloat::INFINITY).is_nan());
+    assert!((QuadFloat::INFINITY * 0.0).is_nan());
+    assert!((QuadFloat::NAN + 1.0).is_nan());
+    assert_eq!(QuadFloat::INFINITY + one, QuadFloat::INFINITY);
+    assert_eq!(one / QuadFloat::INFINITY, 0.0);
+    assert_eq!(QuadFloat::from(1e300) * 1e300, QuadFloat::INFINITY);
+    assert!((-zero * one).is_sign_negative());
+    assert!((one - one).is_sign_positive());
+
+    assert_eq!(QuadFloat::EPSILON, QuadFloat::from(2f64.powi(-211)));
+    assert_eq!(QuadFloat::MIN_POSITIVE, QuadFloat::from(2f64.powi(-863)));
+}
+
+#[test]
+fn rounding_functions_test() {
+    let a = QuadFloat::from(TwoFloat::new_add(3.0, 1e-20)) + 1e-60;
+    let b = QuadFloat::from(3.0) - 1e-60;
+
+    assert_eq!(a.floor(), 3.0);
+    assert_eq!(a.ceil(), 4.0);
+    assert_eq!(b.floor(), 2.0);
+    assert_eq!(b.ceil(), 3.0);
+    assert_eq!((-a).trunc(), -3.0);
+    assert_eq!((-b).trunc(), -2.0);
+    assert_eq!((QuadFloat::from(2.5) + 1e-60).round(), 3.0);
+    assert_eq!((QuadFloat::from(-2.5) + 1e-60).round(), -2.0);
+
+    let big = QuadFloat::from(TwoFloat::new_add(2f64.powi(60), 0.5)) - 1e-50;
+    assert_eq!(big.floor(), QuadFloat::from(2f64.powi(60)));
+    assert_eq!(big.ceil(), QuadFloat::from(2f64.powi(60)) + 1.0);
+}
+
+#[test]
+fn format_test() {
+    let third = QuadFloat::from(1.0) / 3.0;
+    assert_eq!(
+        format!("{}", third),
+        "3.333333333333333333333333333333333333333333333333333333333333333e-1"
+    );
+    assert_eq!(format!("{:.5}", third * 2.0), "6.66667e-1");
+    assert_eq!(format!("{:.3e}", QuadFloat::from(-9.9996)), "-1.000e1");
+    assert_eq!(format!("{:.3E}", QuadFloat::from(1.5e-300)), "1.500E-300");
+    assert_eq!(format!("{:+}", QuadFloat::from(250.0)), "+2.5e2");
+    assert_eq!(format!("{:>8}", QuadFloat::ZERO), "     0e0");
+    assert_eq!(format!("{:.2}", QuadFloat::ZERO), "0.00e0");
+    assert_eq!(format!("{}", QuadFloat::NEG_INFINITY), "-inf");
+    assert_eq!(format!("{}", QuadFloat::NAN), "NaN");
+    assert_eq!(format!("{}", QuadFloat::PI).len(), 67);
+}
+
+#[test]
+fn parse_test() {
+    let parse = |s: &str| s.parse::<QuadFloat>();
+
+    assert_eq!(parse("1.5"), Ok(QuadFloat::from(1.5)));
+    assert_eq!(parse("-0.25e2"), Ok(QuadFloat::from(-25.0)));
+    assert_eq!(parse("+.5"), Ok(QuadFloat::from(0.5)));
+    assert_eq!(parse("7."), Ok(QuadFloat::from(7.0)));
+    assert_eq!(parse("1E-3"), Ok(QuadFloat::from(1.0) / 1000.0));
+    assert_eq!(parse("-inf"), Ok(QuadFloat::NEG_INFINITY));
+    assert_eq!(parse("Infinity"), Ok(QuadFloat::INFINITY));
+    assert!(parse("nan").unwrap().is_nan());
+    assert_eq!(parse("1e400"), Ok(QuadFloat::INFINITY));
+    assert_eq!(parse("1e-400"), Ok(QuadFloat::ZERO));
+    assert_eq!(parse("0.000"), Ok(QuadFloat::ZERO));
+
+    for s in ["", ".", "1.2.3", "e5", "1e", "1e+", "--1", "0x10", "1 "].iter() {
+        assert_eq!(parse(s), Err(TwoFloatError::ConversionError), "{:?}", s);
+    }
+
+    let pi =
+        parse("3.14159265358979323846264338327950288419716939937510582097494459230781640628620899")
+            .unwrap();
+    assert_close(
+        &big(pi),
+        &big(QuadFloat::PI),
+        QuadFloat::TOLERANCE_BITS,
+        "parsing pi",
+    );
+}
+
+#[test]
+fn format_parse_roundtrip_test() {
+    for _ in 0..1000 {
+        let value = get_quadfloat();
+        let text = format!("{}", value);
+        let parsed = text.parse::<QuadFloat>().unwrap();
+
+        assert_close(
+            &big(parsed),
+            &big(value),
+            QuadFloat::TOLERANCE_BITS,
+            &format!("parsing {}", text),
+        );
+    }
+}
+
+// Mathematical functions, checked against values computed with mpmath.
+#[cfg(feature = "math_funcs")]
+mod functions {
+    use super::*;
+
+    const FUNCTION_TOLERANCE_BITS: usize = 205;
+
+    fn check_cases(cases: &[([f64; 4], [f64; 4])], f: impl Fn(QuadFloat) -> QuadFloat, name: &str) {
+        for (input, expected) in cases.iter() {
+            let x = QuadFloat::try_from(*input).unwrap();
+            let result = f(x);
+
+            let description = format!("{} of {:?}", name, x);
+            assert_valid(result, &description);
+            assert_close(
+                &big(result),
+                &to_big(expected),
+                FUNCTION_TOLERANCE_BITS,
+                &description,
+            );
+        }
+    }
+    const SQRT_CASES: [([f64; 4], [f64; 4]); 9] = [
+        (
+            [
+                5.707580947137362,
+                1.309248761745845e-18,
+                6.601969703200505e-35,
+                -4.772050047143432e-51,
+            ],
+            [
+                2.3890544043904405,
+                2.3603582393791096e-17,
+                -4.770019573004258e-35,
+                -1.1834595405567718e-51,
+            ],
+        ),
+        (
+            [
+                6.522081080405692,
+                4.4216379503436315e-18,
+                -2.0922252121349123e-34,
+                9.375909403079227e-52,
+            ],
+            [
+                2.5538365414422457,
+                1.4980853199231905e-16,
+                -9.810048375194766e-33,
+                -5.79097310791483e-49,
+            ],
+        ),
+        (
+            [
+                6.520753823317214,
+                1.3247960668801082e-18,
+                -4.5184601374222715e-35,
+                -1.1237199619230148e-51,
+            ],
+            [
+                2.553576672692092,
+                -9.678956042680801e-17,
+                -3.2997871396647328e-34,
+                -4.7146524772847266e-51,
+            ],
+        ),
+        (
+            [
+                5.282662249155088e-191,
+                -5.01689167756169e-210,
+                -2.278875321237881e-226,
+                8.076399314166675e-243,
+            ],
+            [
+                7.26819251888328e-96,
+                -3.332713126187013e-112,
+                -4.902933909563457e-130,
+                -3.344949914295373e-146,
+            ],
+        ),
+        (
+            [
+                3.0129445659478807e-191,
+                -9.969296194705586e-210,
+                -6.224326856381554e-226,
+                -9.854261336812152e-243,
+            ],
+            [
+                5.489029573565696e-96,
+                2.308882389488178e-112,
+                -3.065179801042011e-129,
+                2.3396466854699946e-145,
+            ],
+        ),
+        (
+            [
+                6.075816963993666e-191,
+                2.4858751007796734e-209,
+                -2.3665034038822095e-225,
+                1.2055270560715936e-241,
+            ],
+            [
+                7.794752699087808e-96,
+                -4.574841861942854e-112,
+                -1.855583139292098e-128,
+                6.499683139426303e-145,
+            ],
+        ),
+        (
+            [
+                9.279845455320792e+259,
+                8.951752420065854e+240,
+                -3.702298561571883e+224,
+                1.2682162590223296e+208,
+            ],
+            [
+                9.633195448718349e+129,
+                1.1031070726726626e+113,
+                -4.747462876725695e+96,
+                -2.5532817435433093e+80,
+            ],
+        ),
+        (
+            [
+                5.6019554525483124e+258,
+                4.664782839533289e+240,
+                -6.842348805507659e+223,
+                -2.4352300599994158e+207,
+            ],
+            [
+                2.3668450419383845e+129,
+                -8.962298082960485e+112,
+                -9.540962599986965e+95,
+                3.044889626774572e+79,
+            ],
+        ),
+        (
+            [
+                8.966613342435287e+259,
+                3.8858354604524705e+241,
+                -6.619649700186013e+224,
+                -2.5138126609177053e+208,
+            ],
+            [
+                9.469220317658306e+129,
+                1.567534139397131e+112,
+                4.3337296235728244e+95,
+                -2.325696001047439e+79,
+            ],
+        ),
+    ];
+
+    const EXP_CASES: [([f64; 4], [f64; 4]); 9] = [
+        (
+            [
+                -0.5146435422779092,
+                2.0292001653170413e-19,
+                -7.767570859798421e-36,
+                2.2527770383404435e-52,
+            ],
+            [
+                0.5977136162834962,
+                2.23187453581468e-17,
+                -8.626870508404183e-34,
+                -1.6611709408550385e-50,
+            ],
+        ),
+        (
+            [
+                -0.39131479628313914,
+                7.181307665375333e-20,
+                2.5735862439648477e-37,
+                -9.138035260937233e-54,
+            ],
+            [
+                0.6761672675901832,
+                3.454933387776758e-17,
+                -1.4246691622347213e-33,
+                3.497265659192569e-50,
+            ],
+        ),
+        (
+            [
+                -0.9341707324299451,
+                -6.040605828454268e-19,
+                -3.0815164829502626e-35,
+                1.0635416622876078e-51,
+            ],
+            [
+                0.39291155928323407,
+                1.561076019838665e-17,
+                1.1743038710102135e-33,
+                -6.858773496233959e-50,
+            ],
+        ),
+        (
+            [
+                -526.6213648866662,
+                3.20699425662338e-16,
+                1.0697588990760563e-32,
+                -3.2132843961681565e-49,
+            ],
+            [
+                1.9554520786285873e-229,
+                -1.1144977672188667e-245,
+                1.0969709568143495e-262,
+                4.651761042035418e-279,
+            ],
+        ),
+        (
+            [
+                -619.3640755564955,
+                2.413113419146608e-16,
+                3.364097329442134e-33,
+                6.770356668796427e-50,
+            ],
+            [
+                1.031809914690526e-269,
+                2.4223940278606686e-286,
+                -1.5929162754040683e-302,
+                4.14684e-319,
+            ],
+        ),
+        (
+            [
+                215.22040474390826,
+                -1.5755473615059698e-17,
+                3.9418352985032375e-34,
+                -2.222093329947404e-50,
+            ],
+            [
+                2.944653330171344e+93,
+                7.611197664818413e+76,
+                5.442058974421708e+60,
+                -1.4866578366605992e+44,
+            ],
+        ),
+        (
+            [
+                7.802792570403922e-12,
+                3.859853025661078e-30,
+                2.795489236358164e-46,
+                1.95886275829689e-64,
+            ],
+            [
+                1.0000000000078029,
+                -7.689123616183989e-17,
+                -1.9543036021520568e-33,
+                -1.3890222435917653e-49,
+            ],
+        ),
+        (
+            [
+                -3.765780657873502e-12,
+                7.893394438359682e-31,
+                5.149515785304128e-47,
+                -4.4953968664014605e-63,
+            ],
+            [
+                0.9999999999962342,
+                -1.5180640342782037e-17,
+                -4.208346900566546e-34,
+                4.095869002396433e-50,
+            ],
+        ),
+        (
+            [
+                7.454559541313313e-11,
+                -6.274063792393658e-29,
+                3.974719295187202e-46,
+                3.427014736912311e-62,
+            ],
+            [
+                1.0000000000745457,
+                -1.0752793955365881e-16,
+                -5.184552093567545e-33,
+                -2.8918832974682223e-50,
+            ],
+        ),
+    ];
+
+    const LN_CASES: [([f64; 4], [f64; 4]); 9] = [
+        (
+            [
+                0.9729966730545876,
+                6.564179745107954e-19,
+                -1.5625527695531944e-36,
+                -5.315691833657469e-53,
+            ],
+            [
+                -0.027374616067560847,
+                1.397035146650166e-18,
+                -9.082493179132535e-35,
+                1.030895564973031e-51,
+            ],
+        ),
+        (
+            [
+                1.2424094572219955,
+                2.419527234284802e-19,
+                1.960593413956365e-35,
+                3.072981604024787e-52,
+            ],
+            [
+                0.21705260488228326,
+                1.0423650065069063e-17,
+                2.893471678765095e-34,
+                -1.4189106210460765e-50,
+            ],
+        ),
+        (
+            [
+                1.4026060580862847,
+                5.584198860342675e-19,
+                -9.297098581702016e-37,
+                -1.0286384265832845e-53,
+            ],
+            [
+                0.3383319762944943,
+                1.0253812322924315e-17,
+                7.1708064448598796e-34,
+                -2.453649474805335e-50,
+            ],
+        ),
+        (
+            [
+                5.06945404457955e+299,
+                -2.105428746566081e+281,
+                3.8352097070978035e+264,
+                1.401709602283752e+247,
+            ],
+            [
+                690.0961759335096,
+                -1.0869313179425345e-15,
+                -7.935935249077277e-32,
+                2.6068914762555448e-48,
+            ],
+        ),
+        (
+            [
+                4.819059251831486e+299,
+                -4.0082218892488624e+281,
+                7.706309756178248e+264,
+                4.44729222610807e+248,
+            ],
+            [
+                690.0455215382643,
+                -3.549684072121883e-14,
+                -6.198374400261015e-31,
+                -4.3745601789499127e-47,
+            ],
+        ),
+        (
+            [
+                3.2495442902783192e+299,
+                -2.2234524464135728e+281,
+                -6.832060109191792e+264,
+                6.615726808651585e+247,
+            ],
+            [
+                689.6514575733539,
+                -3.484876828432313e-14,
+                1.1329828626986331e-30,
+                -8.132049420299203e-47,
+            ],
+        ),
+        (
+            [
+                1.000000000000244,
+                -3.7094370559538895e-19,
+                -1.3940450387324135e-36,
+                -5.317259873286989e-53,
+            ],
+            [
+                2.4402664986887406e-13,
+                -1.936252916691289e-29,
+                -2.457201428400306e-46,
+                -1.7925313709080035e-62,
+            ],
+        ),
+        (
+            [
+                1.0000000000009324,
+                5.74711860245357e-19,
+                4.7583857434095114e-35,
+                1.762512615043986e-51,
+            ],
+            [
+                9.32365870791632e-13,
+                2.0588191111170637e-29,
+                -7.1924011480613e-46,
+                5.796990564591013e-62,
+            ],
+        ),
+        (
+            [
+                1.0000000000008509,
+                6.400201728272311e-19,
+                2.0802356149544147e-35,
+                -3.734664401945196e-52,
+            ],
+            [
+                8.508755660925308e-13,
+                1.2562071032681076e-29,
+                5.10412684346711e-46,
+                1.1929401730072525e-62,
+            ],
+        ),
+    ];
+
+    const SIN_CASES: [([f64; 4], [f64; 4]); 9] = [
+        (
+            [
+                0.022894174379672005,
+                1.3910753275306866e-20,
+                4.4332579140094756e-37,
+                3.924153076672949e-53,
+            ],
+            [
+                0.02289217446103647,
+                -3.5034909787179894e-20,
+                -2.7508026841921643e-36,
+                -1.1928947486352591e-52,
+            ],
+        ),
+        (
+            [
+                -0.5441673624422139,
+                3.8496946795576277e-19,
+                1.8910814832427062e-35,
+                1.1127530421894686e-51,
+            ],
+            [
+                -0.5177058997819853,
+                -6.083098459830352e-18,
+                -3.7422202469684556e-34,
+                -1.1301136822163744e-50,
+            ],
+        ),
+        (
+            [
+                0.14598246093705503,
+                -7.631898785443875e-20,
+                -2.4356599268731336e-36,
+                -1.6188304265477857e-52,
+            ],
+            [
+                0.14546451071946812,
+                1.0947556507591852e-17,
+                6.515865182064827e-34,
+                3.634029649890288e-50,
+            ],
+        ),
+        (
+            [
+                -856995.3432007125,
+                -6.339089575767113e-13,
+                -2.856349017475343e-29,
+                -4.608686573609451e-46,
+            ],
+            [
+                -0.2794564449715548,
+                -7.81061900739838e-18,
+                -4.388746900791902e-34,
+                -3.0191127518075333e-50,
+            ],
+        ),
+        (
+            [
+                991151.8622655715,
+                3.090839225248989e-13,
+                -2.068762125643889e-29,
+                2.3414399401045167e-46,
+            ],
+            [
+                -0.9801479974847538,
+                -4.8887314217942515e-17,
+                6.0522486637456916e-34,
+                -3.663640215687733e-50,
+            ],
+        ),
+        (
+            [
+                -173169.7764518871,
+                -1.2515032999052858e-13,
+                -1.0915008972292107e-29,
+                4.983259214397622e-46,
+            ],
+            [
+                0.888377626968876,
+                5.424545925041148e-17,
+                3.0422611175121584e-33,
+                -1.1985865784997138e-50,
+            ],
+        ),
+        (
+            [
+                -9.206291171844556e-21,
+                7.652618854465887e-39,
+                -9.236729968660434e-57,
+                3.4482203015662315e-73,
+            ],
+            [
+                -9.206291171844556e-21,
+                7.652618854465887e-39,
+                -9.236599920902604e-57,
+                -2.7879923059498754e-73,
+            ],
+        ),
+        (
+            [
+                -5.43959443631981e-21,
+                3.654278713747906e-39,
+                9.833879927382094e-56,
+                6.823423172033091e-72,
+            ],
+            [
+                -5.43959443631981e-21,
+                3.654278713747906e-39,
+                9.833882609935103e-56,
+                -6.638903313894402e-72,
+            ],
+        ),
+        (
+            [
+                4.815373197075735e-21,
+                3.0663110741338526e-39,
+                1.7785139831470396e-55,
+                -3.7767751502938364e-73,
+            ],
+            [
+                4.815373197075735e-21,
+                3.0663110741338526e-39,
+                1.7785137970503692e-55,
+                -1.8839041444298447e-72,
+            ],
+        ),
+    ];
+
+    const COS_CASES: [([f64; 4], [f64; 4]); 6] = [
+        (
+            [
+                0.6712082012999028,
+                -5.631149000384328e-19,
+                3.734296890499391e-35,
+                -9.557638307137078e-52,
+            ],
+            [
+                0.7830708178947081,
+                -8.460506610796674e-19,
+                2.8883495902152827e-35,
+                3.323991195832822e-52,
+            ],
+        ),
+        (
+            [
+                0.237420414819574,
+                -7.132704946822285e-20,
+                -2.0357106175186435e-36,
+                -2.724162871159523e-53,
+            ],
+            [
+                0.971947916578693,
+                -1.6321777441387955e-17,
+                1.0040126256037377e-33,
+                -7.037963265993975e-50,
+            ],
+        ),
+        (
+            [
+                -0.3217284621778318,
+                2.388936536178093e-21,
+                1.4079270379707256e-37,
+                5.001464138224899e-55,
+            ],
+            [
+                0.948690283992004,
+                -4.246340285924789e-17,
+                -1.671957252624987e-33,
+                4.86576622121013e-50,
+            ],
+        ),
+        (
+            [
+                -835892.4906273761,
+                4.3052050240950533e-13,
+                2.4331512312392462e-29,
+                1.1604678864419745e-45,
+            ],
+            [
+                -0.8816300706578489,
+                1.161136339538088e-17,
+                -5.443377374106083e-34,
+                2.5544065445150694e-50,
+            ],
+        ),
+        (
+            [
+                -170972.84600692242,
+                1.0969965018528404e-13,
+                -3.658580835018153e-30,
+                2.1457591110156404e-47,
+            ],
+            [
+                0.46194154827670864,
+                -2.1047513780562988e-17,
+                -3.1942171254639004e-34,
+                -1.14084987577035e-50,
+            ],
+        ),
+        (
+            [
+                -79820.56850476877,
+                -1.9271344224094974e-14,
+                5.564370231475501e-31,
+                -4.812936146544424e-48,
+            ],
+            [
+                0.525377474310673,
+                4.197942352556863e-18,
+                -3.0826703160654207e-34,
+                -6.428699128743358e-51,
+            ],
+        ),
+    ];
+
+    const TAN_CASES: [([f64; 4], [f64; 4]); 6] = [
+        (
+            [
+                -1.2640516128578017,
+                3.5703457297500276e-19,
+                -2.0442405363902438e-35,
+                -7.083683729664724e-52,
+            ],
+            [
+                -3.1571444535851056,
+                2.026008793472597e-16,
+                -4.814420498665206e-33,
+                1.1010018122439134e-49,
+            ],
+        ),
+        (
+            [
+                -0.5806717064764922,
+                -3.361509617210388e-19,
+                -9.122873571138509e-36,
+                -4.4198791552247306e-52,
+            ],
+            [
+                -0.6561289051751426,
+                -5.356167632347536e-17,
+                -3.02878130132101e-33,
+                7.167096863225981e-51,
+            ],
+        ),
+        (
+            [
+                -0.35107593448659014,
+                1.3273875020248234e-19,
+                -4.180417104645374e-36,
+                2.1932774805184048e-52,
+            ],
+            [
+                -0.3662482726050588,
+                -1.4538550517550923e-19,
+                2.548853349225552e-36,
+                7.327942099377553e-53,
+            ],
+        ),
+        (
+            [
+                -915.4261308023187,
+                5.407621254638093e-16,
+                -8.963337554632746e-33,
+                -4.354555553628592e-49,
+            ],
+            [
+                -2.755517885420164,
+                9.428545033307586e-17,
+                -3.5825565673698794e-33,
+                1.2310349137270883e-49,
+            ],
+        ),
+        (
+            [
+                -765.8608880455333,
+                -6.255723688886312e-16,
+                2.2124854497136064e-32,
+                -3.552561787991071e-51,
+            ],
+            [
+                0.8215092543473256,
+                -7.7430346147111e-18,
+                4.452525686043142e-34,
+                -2.5024392566243416e-51,
+            ],
+        ),
+        (
+            [
+                -337.25100397377946,
+                -1.8134393137525505e-16,
+                1.0002243861902903e-32,
+                -9.673419133726326e-50,
+            ],
+            [
+                -1.9676307443096586,
+                -9.63789816070229e-17,
+                1.6191914538795072e-33,
+                -7.35168121081334e-50,
+            ],
+        ),
+    ];
+
+    const ASIN_CASES: [([f64; 4], [f64; 4]); 3] = [
+        (
+            [
+                0.5893099178241965,
+                1.6338009557632563e-20,
+                -1.3488500530012716e-36,
+                3.672013244263616e-53,
+            ],
+            [
+                0.6302044140702117,
+                5.308883025897588e-17,
+                2.5305430151796467e-33,
+                -1.2431510524105118e-49,
+            ],
+        ),
+        (
+            [
+                0.7527323477253627,
+                -5.515768561435612e-19,
+                2.0240402017218892e-35,
+                -3.5029425646612744e-52,
+            ],
+            [
+                0.8522027325801528,
+                3.819273543460625e-17,
+                1.6098348164659356e-33,
+                -9.901744905803539e-50,
+            ],
+        ),
+        (
+            [
+                -0.6011572648099215,
+                -3.9953891150352353e-19,
+                -6.08513796529588e-37,
+                3.2025617114707766e-53,
+            ],
+            [
+                -0.6449484758877052,
+                5.237677538451906e-17,
+                3.428662929827683e-34,
+                2.072057926768467e-50,
+            ],
+        ),
+    ];
+
+    const ACOS_CASES: [([f64; 4], [f64; 4]); 3] = [
+        (
+            [
+                -0.4320726352657831,
+                -1.7256802995909678e-19,
+                6.081809594397946e-36,
+                1.3554195845851289e-52,
+            ],
+            [
+                2.0175860743678142,
+                5.187189356473581e-17,
+                2.8714882332332076e-33,
+                1.3072473187881085e-49,
+            ],
+        ),
+        (
+            [
+                -0.5308304043477676,
+                -1.5494139610027514e-19,
+                5.7168697031935235e-36,
+                1.90447784345735e-52,
+            ],
+            [
+                2.1303764452801643,
+                -1.1410354503059055e-17,
+                -3.076089725312771e-34,
+                9.495887859700692e-51,
+            ],
+        ),
+        (
+            [
+                -0.11209860982741526,
+                1.9556844205452157e-20,
+                8.372687384493527e-37,
+                -7.093555732839244e-53,
+            ],
+            [
+                1.6831310479155133,
+                -7.192844486680037e-17,
+                4.689056750893115e-33,
+                1.6300397642403763e-49,
+            ],
+        ),
+    ];
+
+    const ATAN_CASES: [([f64; 4], [f64; 4]); 6] = [
+        (
+            [
+                -6.8348332822600355,
+                3.3381147727665566e-18,
+                6.989404535792387e-35,
+                1.5956468761992704e-52,
+            ],
+            [
+                -1.425517758535142,
+                -5.505085700508614e-18,
+                -1.5523797262454737e-35,
+                1.204305919807879e-51,
+            ],
+        ),
+        (
+            [
+                -2.3251945779479843,
+                9.423287036762726e-20,
+                3.6913787022142026e-37,
+                1.6259606702996908e-53,
+            ],
+            [
+                -1.1646378983373658,
+                1.04856593623265e-16,
+                4.136961395929735e-33,
+                -1.506382115762107e-49,
+            ],
+        ),
+        (
+            [
+                5.161447709282694,
+                -3.608980066530078e-18,
+                -2.009541419344552e-34,
+                -1.9856104894382982e-50,
+            ],
+            [
+                1.3794232357304013,
+                9.958398438730458e-17,
+                8.688209671500165e-34,
+                -5.636044792380074e-50,
+            ],
+        ),
+        (
+            [
+                8.078274897776513e-21,
+                5.853359016071846e-39,
+                1.8820435346401344e-55,
+                -1.0270802135603932e-71,
+            ],
+            [
+                8.078274897776513e-21,
+                5.853359016071846e-39,
+                1.8820417773857776e-55,
+                7.080240740092766e-72,
+            ],
+        ),
+        (
+            [
+                7.474930643742166e-21,
+                6.388933997598841e-39,
+                -3.369512238149394e-55,
+                2.330269625611133e-72,
+            ],
+            [
+                7.474930643742166e-21,
+                6.388933997598841e-39,
+                -3.369513630344964e-55,
+                9.60307977261631e-72,
+            ],
+        ),
+        (
+            [
+                -2.3471267516144504e-21,
+                -3.5396304172434164e-40,
+                1.582635470308811e-56,
+                9.54086226132014e-73,
+            ],
+            [
+                -2.3471267516144504e-21,
+                -3.5396304172434164e-40,
+                1.5826359013198323e-56,
+                -1.0011577935272078e-72,
+            ],
+        ),
+    ];
+
+    const ATAN2_CASES: [([f64; 4], [f64; 4], [f64; 4]); 6] = [
+        (
+            [
+                -0.12832116917091696,
+                -1.4824841392499457e-21,
+                8.74763905342459e-38,
+                -4.4786888770519436e-54,
+            ],
+            [
+                0.8733779836831914,
+                -3.286176398693223e-19,
+                -2.0772841429759986e-35,
+                -1.0111282946617961e-51,
+            ],
+            [
+                -0.14588138657678465,
+                4.8223204368760184e-18,
+                1.1123045402560986e-34,
+                -9.246541610820421e-51,
+            ],
+        ),
+        (
+            [
+                -5.28091022158465,
+                2.2551397208265812e-18,
+                1.9252507168283894e-34,
+                1.0449508639781063e-50,
+            ],
+            [
+                -3.9348223853329074,
+                -2.231838154196942e-18,
+                5.667893718105641e-37,
+                -2.8789152060823556e-53,
+            ],
+            [
+                -2.211156022694966,
+                5.5444154919129294e-17,
+                1.1985870965313585e-33,
+                3.238596457286987e-50,
+            ],
+        ),
+        (
+            [
+                6.08700211813246,
+                -3.2235835976722567e-18,
+                3.0860552373107037e-35,
+                1.3059036082254257e-51,
+            ],
+            [
+                -5.083411149386956,
+                -8.97213091442503e-19,
+                -4.864051793596035e-35,
+                2.6504756340464764e-51,
+            ],
+            [
+                2.2665913880373605,
+                -1.9656747446195406e-16,
+                5.990042071691173e-33,
+                -5.806727633278807e-50,
+            ],
+        ),
+        (
+            [
+                -2.987468437685581,
+                1.9731770533541937e-18,
+                2.3819558124119672e-35,
+                8.37954843693042e-52,
+            ],
+            [
+                0.9428767452565161,
+                -3.5909737321296115e-19,
+                6.02093036849771e-36,
+                -4.611527592724274e-52,
+            ],
+            [
+                -1.265080105224199,
+                5.791979785528519e-17,
+                4.669380093793886e-33,
+                -3.244728245818062e-49,
+            ],
+        ),
+        (
+            [
+                2.4234682277138155,
+                1.3741501628356218e-18,
+                -9.301787921567042e-35,
+                -5.883498988273682e-52,
+            ],
+            [
+                -2.9837618725284916,
+                1.0908871865272011e-18,
+                1.9344277508819136e-35,
+                1.181190439527298e-51,
+            ],
+            [
+                2.459445354219505,
+                -1.5462943375554847e-16,
+                1.163724601423033e-32,
+                3.452384764952894e-49,
+            ],
+        ),
+        (
+            [
+                8.0075766453099,
+                -6.512831332128002e-18,
+                -1.8406560627854317e-35,
+                -6.450238357812025e-52,
+            ],
+            [
+                -1.6479327502885344,
+                5.243693289318439e-19,
+                9.994991114663239e-36,
+                -2.3692623318220125e-52,
+            ],
+            [
+                1.7737593609212372,
+                -6.998570729338148e-17,
+                -2.111824749906881e-33,
+                -2.847101876297949e-50,
+            ],
+        ),
+    ];
+
+    #[test]
+    fn sqrt_test() {
+        check_cases(&SQRT_CASES, QuadFloat::sqrt, "sqrt");
+
+        assert_eq!(QuadFloat::from(4.0).sqrt(), 2.0);
+        assert!(QuadFloat::from(-0.0).sqrt().is_sign_negative());
+        assert!(QuadFloat::from(-1.0).sqrt().is_nan());
+        assert_eq!(QuadFloat::INFINITY.sqrt(), QuadFloat::INFINITY);
+    }
+
+    #[test]
+    fn exp_test() {
+        check_cases(&EXP_CASES, QuadFloat::exp, "exp");
+
+        assert_eq!(QuadFloat::ZERO.exp(), 1.0);
+        assert_eq!(QuadFloat::from(-800.0).exp(), 0.0);
+        assert_eq!(QuadFloat::from(710.0).exp(), QuadFloat::INFINITY);
+        assert!(QuadFloat::NAN.exp().is_nan());
+    }
+
+    #[test]
+    fn ln_test() {
+        check_cases(&LN_CASES, QuadFloat::ln, "ln");
+
+        assert_eq!(QuadFloat::ONE.ln(), 0.0);
+        assert_eq!(QuadFloat::ZERO.ln(), QuadFloat::NEG_INFINITY);
+        assert_eq!(QuadFloat::INFINITY.ln(), QuadFloat::INFINITY);
+        assert!(QuadFloat::from(-1.0).ln().is_nan());
+    }
+
+    #[test]
+    fn trigonometry_test() {
+        check_cases(&SIN_CASES, QuadFloat::sin, "sin");
+        check_cases(&COS_CASES, QuadFloat::cos, "cos");
+        check_cases(&TAN_CASES, QuadFloat::tan, "tan");
+
+        assert_eq!(QuadFloat::ZERO.sin(), 0.0);
+        assert_eq!(QuadFloat::ZERO.cos(), 1.0);
+        assert!(QuadFloat::INFINITY.sin().is_nan());
+        assert!(QuadFloat::NAN.cos().is_nan());
+    }
+
+    #[test]
+    fn inverse_trigonometry_test() {
+        check_cases(&ASIN_CASES, QuadFloat::asin, "asin");
+        check_cases(&ACOS_CASES, QuadFloat::acos, "acos");
+        check_cases(&ATAN_CASES, QuadFloat::atan, "atan");
+
+        for (y, x, expected) in ATAN2_CASES.iter() {
+            let y = QuadFloat::try_from(*y).unwrap();
+            let x = QuadFloat::try_from(*x).unwrap();
+            let result = y.atan2(x);
+
+            let description = format!("atan2 of {:?} and {:?}", y, x);
+            assert_valid(result, &description);
+            assert_close(
+                &big(result),
+                &to_big(expected),
+                FUNCTION_TOLERANCE_BITS,
+                &description,
+            );
+        }
+
+        assert_eq!(QuadFloat::ONE.asin(), QuadFloat::FRAC_PI_2);
+        assert_eq!(QuadFloat::from(-1.0).acos(), QuadFloat::PI);
+        assert!(QuadFloat::from(1.5).asin().is_nan());
+        assert_eq!(QuadFloat::NEG_INFINITY.atan(), -QuadFloat::FRAC_PI_2);
+        assert_eq!(QuadFloat::ZERO.atan2(QuadFloat::from(-1.0)), QuadFloat::PI);
+        assert_eq!(
+            QuadFloat::from(-1.0).atan2(QuadFloat::ZERO),
+            -QuadFloat::FRAC_PI_2
+        );
+    }
+}
@@ -4,7 +4,7 @@ pub mod common;
 
 use common::*;
 use serde_test::{assert_de_tokens_error, assert_tokens, Token};
-use twofloat::{no_overlap, TwoFloat};
+use twofloat::{no_overlap, QuadFloat, ThreeFloat, TwoComplex, TwoF32, TwoFloat};
 
 #[test]
 fn serialize_test() {
@@ -37,3 +37,104 @@ fn deserialize_invalid_test() {
         );
     });
 }
+
+#[test]
+fn threefloat_serialize_test() {
+    let source = ThreeFloat::from(1.0) / 3.0;
+    assert_tokens(
+        &source,
+        &[
+            Token::Tuple { len: 3 },
+            Token::F64(source.hi()),
+            Token::F64(source.mid()),
+            Token::F64(source.lo()),
+            Token::TupleEnd,
+        ],
+    );
+    assert_de_tokens_error::<ThreeFloat>(
+        &[
+            Token::Tuple { len: 3 },
+            Token::F64(1.0),
+            Token::F64(0.25),
+            Token::F64(0.0),
+            Token::TupleEnd,
+        ],
+        "invalid TwoFloat conversion",
+    );
+}
+
+#[test]
+fn quadfloat_serialize_test() {
+    let source = QuadFloat::from(1.0) / 3.0;
+    let words = source.words();
+    assert_tokens(
+        &source,
+        &[
+            Token::Tuple { len: 4 },
+            Token::F64(words[0]),
+            Token::F64(words[1]),
+            Token::F64(words[2]),
+            Token::F64(words[3]),
+            Token::TupleEnd,
+        ],
+    );
+    assert_de_tokens_error::<QuadFloat>(
+        &[
+            Token::Tuple { len: 4 },
+            Token::F64(1.0),
+            Token::F64(1e-20),
+            Token::F64(0.25),
+            Token::F64(0.0),
+            Token::TupleEnd,
+        ],
+        "invalid TwoFloat conversion",
+    );
+}
+
+#[test]
+fn twof32_serialize_test() {
+    let source = TwoF32::from(1.0f32) / 3.0;
+    assert_tokens(
+        &source,
+        &[
+            Token::Tuple { len: 2 },
+            Token::F32(source.hi()),
+            Token::F32(source.lo()),
+            Token::TupleEnd,
+        ],
+    );
+    assert_de_tokens_error::<TwoF32>(
+        &[
+            Token::Tuple { len: 2 },
+            Token::F32(1.0),
+            Token::F32(0.5),
+            Token::TupleEnd,
+        ],
+        "invalid TwoFloat conversion",
+    );
+}
+
+#[test]
+fn twocomplex_serialize_test() {
+    let source = TwoComplex::new(TwoFloat::from(1.0) / 3.0, TwoFloat::from(-0.25));
+    assert_tokens(
+        &source,
+        &[
+            Token::Struct {
+                name: "TwoComplex",
+                len: 2,
+            },
+            Token::Str("re"),
+            Token::Tuple { len: 2 },
+            Token::F64(source.re.hi()),
+            Token::F64(source.re.lo()),
+            Token::TupleEnd,
+            Token::Str("im"),
+            Token::Tuple { len: 2 },
+            Token::F64(-0.25),
+            Token::F64(0.0),
+            Token::TupleEnd,
+            Token::StructEnd,
+        ],
+    );
+}
@@ -0,0 +1,363 @@
+#![cfg(feature = "math_funcs")]
+#![allow(clippy::float_cmp)]
+
+pub mod common;
+
+use common::*;
+use rand::Rng;
+use twofloat::{QuadFloat, TwoFloat};
+
+// Reference values computed with mpmath at 100 digits
+const ERF_CASES: [(f64, [f64; 2]); 22] = [
+    (-26.5, [-1.0, 0.0]),
+    (-5.3, [-0.9999999999999338, -3.8473764251220525e-17]),
+    (-2.7, [-0.9998656672600594, -4.385430306441303e-17]),
+    (-1.5, [-0.9661051464753108, 3.3867031441680696e-17]),
+    (-1.0, [-0.8427007929497149, 2.4801011789118602e-17]),
+    (-0.75, [-0.7111556336535151, -4.69744077164289e-17]),
+    (-0.1, [-0.1124629160182849, -9.255497413884101e-19]),
+    (1e-10, [1.1283791670955126e-10, 3.250270181699747e-27]),
+    (0.3, [0.3286267594591274, 2.2908254446982777e-17]),
+    (0.9999, [0.8426592780487595, -3.002649280420523e-17]),
+    (1.0, [0.8427007929497149, -2.4801011789118602e-17]),
+    (1.25, [0.9229001282564583, -5.51775442986392e-17]),
+    (1.9, [0.9927904292352575, -4.272839049231346e-17]),
+    (2.0, [0.9953222650189527, 2.20719858329765e-17]),
+    (2.6, [0.9997639655834707, -4.196501214677855e-17]),
+    (3.0, [0.9999779095030014, 5.363397058636269e-17]),
+    (3.7, [0.9999998328489421, -2.536060513418204e-17]),
+    (5.5, [0.9999999999999927, -3.03759554483649e-17]),
+    (9.25, [1.0, -4.202037214919711e-39]),
+    (15.0, [1.0, -7.215107907469361e-100]),
+    (26.5, [1.0, 0.0]),
+    (100000.0, [1.0, 0.0]),
+];
+
+const ERFC_CASES: [(f64, [f64; 2]); 21] = [
+    (-26.5, [2.0, 0.0]),
+    (-5.3, [1.9999999999999338, 3.8473764251220525e-17]),
+    (-2.7, [1.9998656672600594, 4.385430306441303e-17]),
+    (-1.5, [1.9661051464753108, -3.3867031441680696e-17]),
+    (-1.0, [1.8427007929497148, 8.622129067339705e-17]),
+    (-0.75, [1.7111556336535152, -6.404789474608675e-17]),
+    (-0.1, [1.1124629160182848, 5.643670097264623e-17]),
+    (1e-10, [0.999999999887162, 4.44874363735484e-17]),
+    (0.3, [0.6713732405408726, 3.2602896784275053e-17]),
+    (0.9999, [0.15734072195124052, 2.2709171885763148e-18]),
+    (1.0, [0.15729920705028513, -2.954563826510312e-18]),
+    (1.25, [0.07709987174354177, -3.3360693261863044e-19]),
+    (1.9, [0.0072095707647425325, 2.2766533088168416e-19]),
+    (2.0, [0.004677734981047266, -3.8794238326641256e-19]),
+    (2.6, [0.00023603441652934908, 6.388071589529278e-21]),
+    (3.0, [2.209049699858544e-05, 1.5563377960343457e-22]),
+    (3.7, [1.6715105790914599e-07, -1.1292864940324383e-23]),
+    (5.5, [7.357847917974398e-15, -2.2410709375634793e-31]),
+    (9.25, [4.202037214919711e-39, -1.3986661134819188e-56]),
+    (15.0, [7.212994172451207e-100, -1.5626120284327402e-116]),
+    (26.5, [2.2109076642637343e-307, 5e-324]),
+];
+
+const ERFCX_CASES: [(f64, [f64; 2]); 24] = [
+    (-26.5, [1.924553162418569e+305, -9.805373154492887e+288]),
+    (-5.3, [3164914574749.34, -2.617320810271372e-05]),
+    (-2.7, [2930.94452028064, -1.9370124869440422e-13]),
+    (-1.5, [18.653886256262734, 2.7154698835576695e-16]),
+    (-1.0, [5.008980080762283, 1.7287133860002015e-16]),
+    (-0.75, [3.003171663627452, 1.7166132816177178e-16]),
+    (-0.1, [1.1236433541992095, 1.5314264041001623e-17]),
+    (1e-10, [0.999999999887162, 4.4497436373547273e-17]),
+    (0.3, [0.7345993345676551, 1.2102173300750303e-17]),
+    (0.9999, [0.4276108989010802, 8.217849248578815e-18]),
+    (1.0, [0.427583576155807, 5.235737283314228e-18]),
+    (1.25, [0.3678229164523611, 1.387401093925035e-19]),
+    (1.9, [0.26650937366167265, 4.987639529064167e-18]),
+    (2.0, [0.25539567631050575, -4.276022290165946e-18]),
+    (2.6, [0.20361324735670921, -3.436082104339015e-18]),
+    (3.0, [0.17900115118138996, -5.4272175920200274e-18]),
+    (3.7, [0.1474349975371851, -1.3798923832845374e-17]),
+    (5.5, [0.10096221839949909, -4.702857612943069e-18]),
+    (9.25, [0.06064311514114366, 2.380306301475733e-18]),
+    (15.0, [0.03752960638850576, 3.3861416628351198e-18]),
+    (26.5, [0.021275046685371106, 1.84766862417374e-20]),
+    (100000.0, [5.6418958351954685e-06, -4.0701773892268826e-22]),
+    (10000000000.0, [5.641895835477563e-11, -4.3042556386342e-28]),
+    (1e+200, [5.641895835477563e-201, 3.5981468851464104e-217]),
+];
+
+const GAMMA_CASES: [(f64, [f64; 2]); 18] = [
+    (-150.3, [-1.5097598047749159e-263, 6.339343818319325e-280]),
+    (-20.7, [-1.9040507251044001e-19, 7.293294084533088e-36]),
+    (-3.5, [0.2700882058522691, -5.338285954257465e-18]),
+    (-1.5, [2.363271801207355, -1.0222115333101065e-16]),
+    (-0.5, [-3.544907701811032, 1.5333172999651597e-16]),
+    (-1e-05, [-100000.57722555555, 5.320988584083994e-12]),
+    (1e-300, [9.999999999999999e299, 7.113783875736513e283]),
+    (0.001, [999.4237724845955, -2.9154041355101375e-14]),
+    (0.3, [2.991568987687591, -8.576219985605117e-17]),
+    (0.75, [1.2254167024651776, 2.151319998296141e-18]),
+    (1.25, [0.906402477055477, 2.638976911771602e-17]),
+    (1.5, [0.886226925452758, -3.8332932499128993e-17]),
+    (2.5, [1.329340388179137, -1.9882475174356644e-18]),
+    (3.7, [4.170651783796604, 7.928234708325672e-17]),
+    (10.1, [454760.7514415856, -1.3137359133182565e-11]),
+    (37.5, [2.2551157841065116e42, -8.473116002913847e25]),
+    (100.25, [2.94846628183877e156, 4.633368316548521e139]),
+    (171.5, [9.4833675668248e307, 3.2177969643752153e291]),
+];
+
+const LN_GAMMA_CASES: [(f64, [f64; 2]); 13] = [
+    (-100000.5, [-1051303.8336357186, 7.336556626993987e-11]),
+    (-3.5, [-1.309006684993042, -8.845116096720255e-17]),
+    (-0.5, [1.2655121234846454, 2.832344371981691e-17]),
+    (0.25, [1.2880225246980774, 2.618836891454408e-17]),
+    (0.999, [0.0005780385328913802, 7.39181340148594e-21]),
+    (1.001, [-0.0005763935982833062, 1.4393080695562806e-20]),
+    (1.9999, [-4.227520877215346e-05, 1.2621097248551783e-21]),
+    (2.5, [0.2846828704729192, -2.0938630583071727e-17]),
+    (15.9, [27.625493215168692, -4.926557407645097e-16]),
+    (16.0, [27.89927138384089, 1.2290202987493117e-15]),
+    (100.5, [361.4355404677776, -6.226945756445924e-15]),
+    (10000000000.0, [220258509288.81058, 4.07746379812346e-06]),
+    (1e300, [6.897755278982137e302, -1.4755313624857933e285]),
+];
+
+const BETA_CASES: [(f64, f64, [f64; 2]); 6] = [
+    (0.5, 2.5, [1.1780972450961724, 4.592425496802574e-17]),
+    (2.5, 1.5, [0.19634954084936207, 7.654042494670958e-18]),
+    (0.001, 3.0, [998.5017481269355, 4.486247265636312e-14]),
+    (-0.5, 2.25, [-4.3700959238202, 2.7271378655511716e-16]),
+    (
+        100.0,
+        200.0,
+        [3.607285449794666e-84, 2.2600122373793102e-100],
+    ),
+    (
+        300.0,
+        400.5,
+        [3.5675945606296e-209, -9.258891618751565e-226],
+    ),
+];
+
+// Checks the relative error of the results, allowing for the reduced precision
+// of the low word near the subnormal range.
+fn check_cases(
+    cases: &[(f64, [f64; 2])],
+    f: impl Fn(TwoFloat) -> TwoFloat,
+    tolerance: f64,
+    name: &str,
+) {
+    for (x, [hi, lo]) in cases.iter() {
+        let result = f(TwoFloat::from(*x));
+        let expected = TwoFloat::new_add(*hi, *lo);
+
+        assert!(result.is_valid(), "{}({}) produced invalid value", name, x);
+        let difference = (result - expected).abs();
+        assert!(
+            difference <= expected.abs() * tolerance || difference.hi() < 1e-322,
+            "Mismatch in {}({}): {:?} vs {:?}",
+            name,
+            x,
+            expected,
+            result
+        );
+    }
+}
+
+#[test]
+fn erf_test() {
+    check_cases(&ERF_CASES, TwoFloat::erf, 1e-30, "erf");
+
+    assert_eq!(TwoFloat::from(0.0).erf(), 0.0);
+    assert!(TwoFloat::from(-0.0).erf().is_sign_negative());
+    assert_eq!(TwoFloat::INFINITY.erf(), 1.0);
+    assert_eq!(TwoFloat::NEG_INFINITY.erf(), -1.0);
+    assert!(TwoFloat::NAN.erf().is_nan());
+}
+
+#[test]
+fn erfc_test() {
+    check_cases(&ERFC_CASES, TwoFloat::erfc, 1e-30, "erfc");
+
+    assert_eq!(TwoFloat::from(0.0).erfc(), 1.0);
+    assert_eq!(TwoFloat::from(30.0).erfc(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.erfc(), 0.0);
+    assert_eq!(TwoFloat::NEG_INFINITY.erfc(), 2.0);
+    assert!(TwoFloat::NAN.erfc().is_nan());
+
+    // Gradual underflow
+    let tail = TwoFloat::from(27.2).erfc().hi();
+    assert!(tail > 0.0 && tail < f64::MIN_POSITIVE);
+}
+
+#[test]
+fn erfcx_test() {
+    check_cases(&ERFCX_CASES, TwoFloat::erfcx, 1e-30, "erfcx");
+
+    assert_eq!(TwoFloat::from(0.0).erfcx(), 1.0);
+    assert_eq!(TwoFloat::INFINITY.erfcx(), 0.0);
+    assert_eq!(TwoFloat::from(-30.0).erfcx(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::NEG_INFINITY.erfcx(), TwoFloat::INFINITY);
+    assert!(TwoFloat::NAN.erfcx().is_nan());
+}
+
+#[test]
+fn erf_identities_test() {
+    let mut rng = rand::thread_rng();
+    let src_dist = rand::distributions::Uniform::new(-6.0, 6.0);
+
+    repeated_test(|| {
+        let x = TwoFloat::new_add(rng.sample(src_dist), rng.gen_range(-1e-17..1e-17));
+        let (erf, erfc) = (x.erf(), x.erfc());
+
+        assert!(erf.is_valid(), "erf({:?}) produced invalid value", x);
+        assert!(erfc.is_valid(), "erfc({:?}) produced invalid value", x);
+        assert_eq!((-x).erf(), -erf, "Asymmetric erf({:?})", x);
+
+        let difference = (erf + erfc - 1.0).abs();
+        assert!(
+            difference < 1e-30,
+            "Mismatch in erf({:?}) + erfc({:?}): {:?}",
+            x,
+            x,
+            erf + erfc
+        );
+
+        let difference = ((-x).erfc() + erfc - 2.0).abs();
+        assert!(
+            difference < 1e-30,
+            "Mismatch in erfc({:?}) + erfc({:?}): {:?}",
+            x,
+            -x,
+            (-x).erfc() + erfc
+        );
+    });
+}
+
+#[test]
+fn erfcx_erfc_test() {
+    let mut rng = rand::thread_rng();
+    let src_dist = rand::distributions::Uniform::new(-26.0, 20.0);
+
+    for _ in 0..1000 {
+        let x = TwoFloat::new_add(rng.sample(src_dist), rng.gen_range(-1e-16..1e-16));
+        let erfcx = x.erfcx();
+        assert!(erfcx.is_valid(), "erfcx({:?}) produced invalid value", x);
+
+        // erfc is compared where its low word is normal, and exp(x^2) is
+        // evaluated in quadruple-double precision
+        let square = QuadFloat::from(x) * QuadFloat::from(x);
+        let expected = TwoFloat::from(square.exp() * QuadFloat::from(x.erfc()));
+
+        let difference = ((erfcx - expected) / expected).abs();
+        assert!(
+            difference < 1e-30,
+            "Mismatch in erfcx({:?}): {:?} vs {:?}",
+            x,
+            expected,
+            erfcx
+        );
+    }
+}
+
+#[test]
+fn gamma_test() {
+    check_cases(&GAMMA_CASES, TwoFloat::gamma, 1e-30, "gamma");
+
+    // Exact factorials
+    let mut factorial = 1u128;
+    for n in 1..=34 {
+        let expected = TwoFloat::from(factorial);
+        assert_eq!(TwoFloat::from(n).gamma(), expected, "Inexact gamma({})", n);
+        factorial *= n as u128;
+    }
+
+    assert_eq!(TwoFloat::from(0.0).gamma(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-0.0).gamma(), TwoFloat::NEG_INFINITY);
+    assert!(TwoFloat::from(-3.0).gamma().is_nan());
+    assert_eq!(TwoFloat::from(172.0).gamma(), TwoFloat::INFINITY);
+    assert_eq!(TwoFloat::from(-500.5).gamma(), 0.0);
+    assert_eq!(TwoFloat::INFINITY.gamma(), TwoFloat::INFINITY);
+    assert!(TwoFloat::NEG_INFINITY.gamma().is_nan());
+    assert!(TwoFloat::NAN.gamma().is_nan());
+}
+
+#[test]
+fn gamma_recurrence_test() {
+    let mut rng = rand::thread_rng();
+    let src_dist = rand::distributions::Uniform::new(-150.0, 170.0);
+
+    for _ in 0..10000 {
+        let x = TwoFloat::new_add(rng.sample(src_dist), rng.gen_range(-1e-16..1e-16));
+        let (gamma, next) = (x.gamma(), (x + 1.0).gamma());
+        assert!(gamma.is_valid(), "gamma({:?}) produced invalid value", x);
+
+        let difference = ((next - x * gamma) / next).abs();
+        assert!(
+            difference < 1e-28,
+            "Mismatch in gamma({:?}) recurrence: {:?} vs {:?}",
+            x,
+            next,
+            x * gamma
+        );
+    }
+}
+
+#[test]
+fn ln_gamma_test() {
+    check_cases(&LN_GAMMA_CASES, |x| x.ln_gamma().0, 1e-30, "ln_gamma");
+
+    assert_eq!(TwoFloat::from(-0.5).ln_gamma().1, -1);
+    assert_eq!(TwoFloat::from(-1.5).ln_gamma().1, 1);
+    assert_eq!(TwoFloat::from(1.0).ln_gamma(), (TwoFloat::ZERO, 1));
+    assert_eq!(TwoFloat::from(2.0).ln_gamma(), (TwoFloat::ZERO, 1));
+    assert!(TwoFloat::from(1.0).ln_gamma().0.is_sign_positive());
+    assert!(TwoFloat::from(2.0).ln_gamma().0.is_sign_positive());
+    assert_eq!(TwoFloat::from(0.0).ln_gamma(), (TwoFloat::INFINITY, 1));
+    assert_eq!(TwoFloat::from(-0.0).ln_gamma(), (TwoFloat::INFINITY, -1));
+    assert_eq!(TwoFloat::from(-4.0).ln_gamma(), (TwoFloat::INFINITY, 1));
+    assert_eq!(TwoFloat::NEG_INFINITY.ln_gamma().0, TwoFloat::INFINITY);
+    assert!(TwoFloat::NAN.ln_gamma().0.is_nan());
+}
+
+#[test]
+fn beta_test() {
+    for (a, b, [hi, lo]) in BETA_CASES.iter() {
+        let (a, b) = (TwoFloat::from(*a), TwoFloat::from(*b));
+        let expected = TwoFloat::new_add(*hi, *lo);
+        let result = a.beta(b);
+        assert!(
+            result.is_valid(),
+            "beta({}, {}) produced invalid value",
+            a,
+            b
+        );
+
+        // Results which overflow the gamma function are computed as the
+        // exponential of `ln_beta`, which scales its absolute error
+        let tolerance = if (a + b).hi() < 171.0 { 1e-30 } else { 1e-28 };
+        assert!(
+            ((result - expected) / expected).abs() < tolerance,
+            "Mismatch in beta({}, {}): {:?} vs {:?}",
+            a,
+            b,
+            expected,
+            result
+        );
+        assert!(((b.beta(a) - result) / result).abs() < 1e-30);
+
+        let (ln, sign) = a.ln_beta(b);
+        assert!(
+            (ln - expected.abs().ln()).abs() < 1e-9 * ln.abs().max(TwoFloat::ONE),
+            "Mismatch in ln_beta({}, {}): {:?}",
+            a,
+            b,
+            ln
+        );
+        assert_eq!(sign, if expected.hi() < 0.0 { -1 } else { 1 });
+    }
+
+    let (a, b) = (TwoFloat::from(1.5), TwoFloat::from(-2.5));
+    assert_eq!(a.beta(b), 0.0);
+    assert_eq!(a.ln_beta(b), (TwoFloat::NEG_INFINITY, 1));
+    assert!(TwoFloat::NAN.beta(a).is_nan());
+}
@@ -0,0 +1,307 @@
+#![cfg(feature = "fp_status")]
+
+use twofloat::{FpEnv, FpFlags, QuadFloat, ThreeFloat, TwoFloat, TwoFloatError, TwoFloatInterval};
+
+pub mod common;
+use common::*;
+
+type BinaryOp = fn(TwoFloat, TwoFloat) -> TwoFloat;
+type PairFunction = fn() -> (TwoFloat, TwoFloat);
+
+fn expected_flags(result: Result<TwoFloat, TwoFloatError>) -> FpFlags {
+    match result {
+        Ok(_) => FpFlags::empty(),
+        Err(TwoFloatError::Overflow) => FpFlags::OVERFLOW,
+        Err(TwoFloatError::Underflow) => FpFlags::UNDERFLOW,
+        Err(TwoFloatError::DivisionByZero) => FpFlags::DIVISION_BY_ZERO,
+        Err(_) => FpFlags::INVALID,
+    }
+}
+
+#[test]
+fn arithmetic_flags_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let b = get_twofloat();
+
+        let env = FpEnv::new();
+        let ops: [(BinaryOp, Result<TwoFloat, TwoFloatError>); 4] = [
+            (|x, y| x + y, a.checked_add(b)),
+            (|x, y| x - y, a.checked_sub(b)),
+            (|x, y| x * y, a.checked_mul(b)),
+            (|x, y| x / y, a.checked_div(b)),
+        ];
+
+        for (op, checked) in ops.iter() {
+            env.clear();
+            op(a, b);
+            assert_eq!(
+                env.flags(),
+                expected_flags(*checked),
+                "Incorrect flags for {:?}, {:?}",
+                a,
+                b
+            );
+        }
+    });
+}
+
+#[test]
+fn arithmetic_special_flags_test() {
+    let cases: [(fn() -> TwoFloat, FpFlags); 18] = [
+        (|| TwoFloat::MAX * 2.0, FpFlags::OVERFLOW),
+        (|| TwoFloat::MAX + TwoFloat::MAX, FpFlags::OVERFLOW),
+        (
+            || TwoFloat::from(1.0) / TwoFloat::from(0.0),
+            FpFlags::DIVISION_BY_ZERO,
+        ),
+        (|| 1.0 / TwoFloat::from(0.0), FpFlags::DIVISION_BY_ZERO),
+        (|| TwoFloat::from(0.0) / 0.0, FpFlags::INVALID),
+        (|| TwoFloat::INFINITY - TwoFloat::INFINITY, FpFlags::INVALID),
+        (|| TwoFloat::INFINITY * 0.0, FpFlags::INVALID),
+        (|| TwoFloat::from(1.0) % 0.0, FpFlags::INVALID),
+        (|| TwoFloat::from(1e-200) * 1e-200, FpFlags::UNDERFLOW),
+        (|| TwoFloat::MIN_POSITIVE / 3.0, FpFlags::UNDERFLOW),
+        (|| TwoFloat::from(1e-300) * 1.0, FpFlags::empty()),
+        (|| TwoFloat::MIN_POSITIVE / 2.0, FpFlags::empty()),
+        (|| TwoFloat::from(1e-300) - 1e-300, FpFlags::empty()),
+        (|| TwoFloat::NAN + 1.0, FpFlags::empty()),
+        (|| TwoFloat::INFINITY * 2.0, FpFlags::empty()),
+        (|| 1.0 / TwoFloat::INFINITY, FpFlags::empty()),
+        (|| TwoFloat::from(1.0) - 1.0, FpFlags::empty()),
+        (|| TwoFloat::MAX / TwoFloat::MAX, FpFlags::empty()),
+    ];
+
+    for (i, (f, expected)) in cases.iter().enumerate() {
+        let env = FpEnv::new();
+        f();
+        assert_eq!(env.flags(), *expected, "Incorrect flags for case {}", i);
+    }
+}
+
+#[test]
+fn threefloat_flags_test() {
+    let cases: [(fn() -> ThreeFloat, FpFlags); 7] = [
+        (|| ThreeFloat::from(1e-200) * 1e-200, FpFlags::UNDERFLOW),
+        (|| ThreeFloat::MIN_POSITIVE / 3.0, FpFlags::UNDERFLOW),
+        (|| ThreeFloat::from(1e-300) * 1.0, FpFlags::empty()),
+        (|| ThreeFloat::MIN_POSITIVE / 2.0, FpFlags::empty()),
+        (
+            || ThreeFloat::from(1e-300) * ThreeFloat::from(0.5),
+            FpFlags::empty(),
+        ),
+        (
+            || ThreeFloat::from(1e-300) - ThreeFloat::from(3e-301),
+            FpFlags::empty(),
+        ),
+        (|| ThreeFloat::from(1.0) / 0.0, FpFlags::DIVISION_BY_ZERO),
+    ];
+
+    for (i, (f, expected)) in cases.iter().enumerate() {
+        let env = FpEnv::new();
+        f();
+        assert_eq!(env.flags(), *expected, "Incorrect flags for case {}", i);
+    }
+}
+
+#[test]
+fn quadfloat_flags_test() {
+    let cases: [(fn() -> QuadFloat, FpFlags); 7] = [
+        (|| QuadFloat::from(1e-200) * 1e-200, FpFlags::UNDERFLOW),
+        (|| QuadFloat::MIN_POSITIVE / 3.0, FpFlags::UNDERFLOW),
+        (|| QuadFloat::from(1e-300) * 1.0, FpFlags::empty()),
+        (|| QuadFloat::MIN_POSITIVE / 2.0, FpFlags::empty()),
+        (
+            || QuadFloat::from(1e-300) * QuadFloat::from(0.5),
+            FpFlags::empty(),
+        ),
+        (
+            || QuadFloat::from(1e-300) - QuadFloat::from(3e-301),
+            FpFlags::empty(),
+        ),
+        (|| QuadFloat::from(1.0) / 0.0, FpFlags::DIVISION_BY_ZERO),
+    ];
+
+    for (i, (f, expected)) in cases.iter().enumerate() {
+        let env = FpEnv::new();
+        f();
+        assert_eq!(env.flags(), *expected, "Incorrect flags for case {}", i);
+    }
+}
+
+#[test]
+fn function_flags_test() {
+    let cases: [(fn() -> TwoFloat, FpFlags); 14] = [
+        (|| TwoFloat::from(0.0).ln(), FpFlags::DIVISION_BY_ZERO),
+        (|| TwoFloat::from(0.0).log2(), FpFlags::DIVISION_BY_ZERO),
+        (|| TwoFloat::from(0.0).log10(), FpFlags::DIVISION_BY_ZERO),
+        (|| TwoFloat::from(-1.0).ln_1p(), FpFlags::DIVISION_BY_ZERO),
+        (|| TwoFloat::from(1.0).atanh(), FpFlags::DIVISION_BY_ZERO),
+        (|| TwoFloat::from(0.0).powi(-2), FpFlags::DIVISION_BY_ZERO),
+        (|| TwoFloat::from(-1.0).sqrt(), FpFlags::INVALID),
+        (|| TwoFloat::from(-1.0).ln(), FpFlags::INVALID),
+        (|| TwoFloat::from(2.0).asin(), FpFlags::INVALID),
+        (|| TwoFloat::INFINITY.sin(), FpFlags::INVALID),
+        (|| TwoFloat::from(1000.0).exp(), FpFlags::OVERFLOW),
+        (|| TwoFloat::from(1000.0).cosh(), FpFlags::OVERFLOW),
+        (|| TwoFloat::from(-1000.0).exp(), FpFlags::UNDERFLOW),
+        (|| TwoFloat::from(-2000.0).exp2(), FpFlags::UNDERFLOW),
+    ];
+
+    for (i, (f, expected)) in cases.iter().enumerate() {
+        let env = FpEnv::new();
+        f();
+        assert_eq!(env.flags(), *expected, "Incorrect flags for case {}", i);
+    }
+}
+
+#[test]
+fn function_no_flags_test() {
+    // Intermediate results of these functions underflow or overflow, but
+    // the results do not
+    let cases: [fn() -> TwoFloat; 10] = [
+        || TwoFloat::from(1e-200).sin(),
+        || TwoFloat::from(1e-200).cos(),
+        || TwoFloat::from(1e-200).exp(),
+        || TwoFloat::from(1e-200).atan(),
+        || TwoFloat::from(1e200).tanh(),
+        || TwoFloat::NAN.exp(),
+        || TwoFloat::NEG_INFINITY.exp(),
+        || TwoFloat::INFINITY.ln(),
+        || TwoFloat::NAN.sin_pi(),
+        || TwoFloat::NAN.cos_pi(),
+    ];
+
+    for (i, f) in cases.iter().enumerate() {
+        let env = FpEnv::new();
+        f();
+        assert_eq!(
+            env.flags(),
+            FpFlags::empty(),
+            "Unexpected flags for case {}",
+            i
+        );
+    }
+}
+
+#[test]
+fn pair_function_flags_test() {
+    let cases: [(PairFunction, FpFlags); 6] = [
+        (|| TwoFloat::NAN.sin_cos(), FpFlags::empty()),
+        (|| TwoFloat::INFINITY.sin_cos(), FpFlags::INVALID),
+        (|| TwoFloat::from(1e-200).sin_cos(), FpFlags::empty()),
+        (|| TwoFloat::NAN.sin_cos_pi(), FpFlags::empty()),
+        (|| TwoFloat::INFINITY.sin_cos_pi(), FpFlags::INVALID),
+        (|| TwoFloat::from(1e-200).sin_cos_pi(), FpFlags::empty()),
+    ];
+
+    for (i, (f, expected)) in cases.iter().enumerate() {
+        let env = FpEnv::new();
+        f();
+        assert_eq!(env.flags(), *expected, "Incorrect flags for case {}", i);
+    }
+}
+
+#[test]
+fn sticky_flags_test() {
+    let env = FpEnv::new();
+    let a = TwoFloat::from(1.0) / 0.0;
+    let b = a - a;
+    let c = TwoFloat::from(1.5) * 2.0;
+
+    assert!(b.is_nan());
+    assert_eq!(c, 3.0);
+    assert_eq!(env.flags(), FpFlags::DIVISION_BY_ZERO | FpFlags::INVALID);
+    assert!(env.flags().contains(FpFlags::INVALID));
+    assert!(!env
+        .flags()
+        .intersects(FpFlags::OVERFLOW | FpFlags::UNDERFLOW));
+
+    env.clear();
+    assert!(env.flags().is_empty());
+}
+
+#[test]
+fn nested_env_test() {
+    let outer = FpEnv::new();
+    let _ = TwoFloat::MAX * 2.0;
+    {
+        let inner = FpEnv::new();
+        assert!(inner.flags().is_empty());
+        let _ = TwoFloat::from(0.0).ln();
+        assert_eq!(inner.flags(), FpFlags::DIVISION_BY_ZERO);
+    }
+    assert_eq!(outer.flags(), FpFlags::OVERFLOW | FpFlags::DIVISION_BY_ZERO);
+}
+
+#[test]
+fn thread_local_env_test() {
+    let env = FpEnv::new();
+    std::thread::spawn(|| {
+        let _ = TwoFloat::from(-1.0).sqrt();
+    })
+    .join()
+    .unwrap();
+
+    assert!(env.flags().is_empty());
+}
+
+#[test]
+fn trap_test() {
+    let env = FpEnv::with_traps(FpFlags::INVALID);
+    assert_eq!(env.traps(), FpFlags::INVALID);
+
+    // Untrapped flags are still recorded
+    let _ = TwoFloat::MAX * 2.0;
+    assert_eq!(env.flags(), FpFlags::OVERFLOW);
+
+    env.set_traps(FpFlags::empty());
+    let _ = TwoFloat::from(-1.0).sqrt();
+    assert_eq!(env.flags(), FpFlags::OVERFLOW | FpFlags::INVALID);
+}
+
+#[test]
+#[should_panic(expected = "invalid operation in TwoFloat sqrt")]
+fn trap_invalid_function_test() {
+    let _env = FpEnv::with_traps(FpFlags::INVALID);
+    let _ = TwoFloat::from(-1.0).sqrt();
+}
+
+#[test]
+#[should_panic(expected = "invalid operation in TwoFloat sub")]
+fn trap_invalid_operator_test() {
+    let _env = FpEnv::with_traps(FpFlags::INVALID);
+    let _ = TwoFloat::INFINITY - TwoFloat::INFINITY;
+}
+
+#[test]
+#[should_panic(expected = "division by zero in TwoFloat div")]
+fn trap_division_by_zero_test() {
+    let _env = FpEnv::with_traps(FpFlags::all());
+    let _ = TwoFloat::from(1.0) / TwoFloat::from(0.0);
+}
+
+#[test]
+fn interval_flags_test() {
+    let env = FpEnv::with_traps(FpFlags::all());
+    let unbounded = TwoFloatInterval::new(TwoFloat::from(1.0), TwoFloat::INFINITY).unwrap();
+    let _ = TwoFloatInterval::from(0.0) * unbounded;
+    let _ = TwoFloatInterval::from(1.0) / TwoFloatInterval::from(0.0);
+    let _ = TwoFloatInterval::from(1e300) * TwoFloatInterval::from(1e300);
+    let _ = TwoFloatInterval::from(1e-300) * TwoFloatInterval::from(1e-300);
+    assert_eq!(env.flags(), FpFlags::empty());
+
+    env.set_traps(FpFlags::empty());
+    let _ = TwoFloatInterval::from(-1.0).sqrt();
+    assert_eq!(env.flags(), FpFlags::INVALID);
+}
+
+#[test]
+fn flags_display_test() {
+    assert_eq!(FpFlags::empty().to_string(), "none");
+    assert_eq!(FpFlags::OVERFLOW.to_string(), "overflow");
+    assert_eq!(
+        (FpFlags::UNDERFLOW | FpFlags::INVALID).to_string(),
+        "invalid operation, underflow"
+    );
+}
@@ -0,0 +1,227 @@
+#![allow(clippy::float_cmp)]
+
+pub mod common;
+
+use common::*;
+use twofloat::{consts::E, TaylorJet, TwoFloat};
+
+type Jet = TaylorJet<8>;
+
+// Checks that the coefficients of `result` are within `tolerance` of those of
+// `expected`, relative to the largest coefficient of `expected`.
+fn assert_close_jet(result: &Jet, expected: &Jet, tolerance: f64, description: &str) {
+    let scale = expected
+        .coefficients
+        .iter()
+        .fold(TwoFloat::ZERO, |m, c| m.max(c.abs()));
+    for (k, (r, e)) in result
+        .coefficients
+        .iter()
+        .zip(expected.coefficients.iter())
+        .enumerate()
+    {
+        assert!(
+            (r - e).abs() <= scale * tolerance,
+            "Coefficient {} of {} is {:?}, expected {:?}",
+            k,
+            description,
+            r,
+            e
+        );
+    }
+}
+
+// Returns a series with random coefficients in [-1, 1].
+fn get_jet() -> Jet {
+    let mut result = Jet::default();
+    for c in result.coefficients.iter_mut() {
+        *c = get_twofloat_decades(0..1);
+    }
+    result
+}
+
+#[test]
+fn constructor_test() {
+    let x = Jet::variable(TwoFloat::from(2.0));
+    assert_eq!(x.value(), 2.0);
+    assert_eq!(x.coefficients[1], 1.0);
+    assert!(x.coefficients[2..].iter().all(|c| *c == 0.0));
+
+    assert_eq!(Jet::from(3.0), Jet::constant(TwoFloat::from(3.0)));
+    assert_eq!(
+        TaylorJet::<1>::variable(TwoFloat::ONE).coefficients,
+        [TwoFloat::ONE]
+    );
+}
+
+#[test]
+fn add_sub_test() {
+    repeated_test(|| {
+        let (a, b) = (get_jet(), get_jet());
+        let c = random_float();
+
+        let sum = a + b;
+        let difference = a - b;
+        for k in 0..8 {
+            assert_eq!(sum.coefficients[k], a.coefficients[k] + b.coefficients[k]);
+            assert_eq!(
+                difference.coefficients[k],
+                a.coefficients[k] - b.coefficients[k]
+            );
+        }
+
+        assert_eq!((a + c).value(), a.value() + c);
+        assert_eq!((c - a).coefficients[3], -a.coefficients[3]);
+        assert_eq!((a - c).coefficients[1..], a.coefficients[1..]);
+    });
+}
+
+#[test]
+fn mul_div_test() {
+    repeated_test(|| {
+        let (a, b) = (get_jet(), get_jet());
+        let b = b + b.value().signum() * 2.0;
+
+        let product = a * b;
+        for k in 0..8 {
+            let expected = (0..=k).fold(TwoFloat::ZERO, |sum, j| {
+                sum + a.coefficients[j] * b.coefficients[k - j]
+            });
+            assert!((product.coefficients[k] - expected).abs() < 1e-30);
+        }
+
+        let description = format!("({:?} * {:?}) / {:?}", a, b, b);
+        assert_close_jet(&(product / b), &a, 1e-25, &description);
+
+        let reciprocal = 1.0 / b;
+        let description = format!("{:?} * recip({:?})", b, b);
+        assert_close_jet(&(b * reciprocal), &Jet::from(1.0), 1e-25, &description);
+    });
+}
+
+#[test]
+fn assign_test() {
+    let mut x = Jet::variable(TwoFloat::ONE);
+    x *= x;
+    x += 1.0;
+    x -= TwoFloat::from(2.0);
+    x /= 2.0;
+
+    assert_eq!(
+        x.coefficients[..3],
+        [TwoFloat::ZERO, TwoFloat::ONE, TwoFloat::from(0.5)]
+    );
+}
+
+#[test]
+fn calculus_test() {
+    let x = Jet::variable(TwoFloat::from(3.0));
+    let cube = x * x * x;
+
+    assert_eq!(cube.evaluate(TwoFloat::from(-1.0)), 8.0);
+    assert_eq!(cube.derivative(), x * x * 3.0);
+    assert_eq!(cube.derivative().integral(TwoFloat::from(27.0)), cube);
+}
+
+#[test]
+fn picard_test() {
+    // Solves x' = x with x(0) = 1 by Picard iteration, giving the series of
+    // e^h, and evaluates it at h = 1
+    let mut x = TaylorJet::<30>::from(1.0);
+    for _ in 0..30 {
+        x = x.integral(TwoFloat::ONE);
+    }
+
+    assert!((x.evaluate(TwoFloat::ONE) - E).abs() < 1e-30);
+}
+
+// Mathematical functions, checked against known series and identities.
+#[cfg(feature = "math_funcs")]
+mod functions {
+    use super::*;
+    use rand::Rng;
+
+    fn factorial(k: usize) -> TwoFloat {
+        (1..=k).fold(TwoFloat::ONE, |f, i| f * i as f64)
+    }
+
+    #[test]
+    fn exp_ln_test() {
+        let e = Jet::variable(TwoFloat::ZERO).exp();
+        let expected = Jet::new(core::array::from_fn(|k| 1.0 / factorial(k)));
+        assert_close_jet(&e, &expected, 1e-30, "exp(h)");
+
+        let l = Jet::variable(TwoFloat::ONE).ln();
+        let expected = Jet::new(core::array::from_fn(|k| match k {
+            0 => TwoFloat::ZERO,
+            _ if k % 2 == 0 => TwoFloat::from(-1.0) / k as f64,
+            _ => TwoFloat::ONE / k as f64,
+        }));
+        assert_close_jet(&l, &expected, 1e-30, "ln(1 + h)");
+
+        repeated_test(|| {
+            let a = get_jet();
+            let a = a + a.value().signum() * 2.0;
+            let description = format!("ln(exp({:?}))", a);
+            assert_close_jet(&a.exp().ln(), &a, 1e-9, &description);
+
+            let description = format!("exp({:?}) * exp(-{:?})", a, a);
+            assert_close_jet(&(a.exp() * (-a).exp()), &Jet::from(1.0), 1e-9, &description);
+        });
+    }
+
+    #[test]
+    fn sqrt_powf_test() {
+        repeated_test(|| {
+            let a = get_jet();
+            let a = a + a.value().abs() + 1.0;
+
+            let r = a.sqrt();
+            let description = format!("sqrt({:?})^2", a);
+            assert_close_jet(&(r * r), &a, 1e-29, &description);
+
+            let p = a.powf(TwoFloat::from(1.5));
+            let description = format!("{:?}^1.5", a);
+            assert_close_jet(&p, &(a * r), 1e-9, &description);
+        });
+
+        // Binomial series of (1 + h)^0.5
+        let p = Jet::variable(TwoFloat::ONE).powf(TwoFloat::from(0.5));
+        let expected = Jet::new(core::array::from_fn(|k| {
+            (0..k).fold(TwoFloat::ONE, |c, j| c * (0.5 - j as f64) / (j + 1) as f64)
+        }));
+        assert_close_jet(&p, &expected, 1e-9, "(1 + h)^0.5");
+        assert_close_jet(
+            &Jet::variable(TwoFloat::ONE).sqrt(),
+            &expected,
+            1e-30,
+            "sqrt(1 + h)",
+        );
+    }
+
+    #[test]
+    fn trigonometry_test() {
+        repeated_test(|| {
+            let a = get_jet();
+            let (s, c) = a.sin_cos();
+
+            let description = format!("sin^2 + cos^2 of {:?}", a);
+            assert_close_jet(&(s * s + c * c), &Jet::from(1.0), 1e-18, &description);
+
+            let description = format!("tan of {:?}", a);
+            assert_close_jet(&(a.tan() * c), &s, 1e-18, &description);
+            assert_eq!(a.sin(), s);
+            assert_eq!(a.cos(), c);
+        });
+
+        // sin(x + h) has coefficients sin(x + k pi / 2) / k!
+        let x = rand::thread_rng().gen_range(-3.0..3.0);
+        let s = Jet::variable(TwoFloat::from(x)).sin();
+        let (sx, cx) = TwoFloat::from(x).sin_cos();
+        let expected = Jet::new(core::array::from_fn(|k| {
+            let value = [sx, cx, -sx, -cx][k % 4];
+            value / factorial(k)
+        }));
+        assert_close_jet(&s, &expected, 1e-30, "sin(x + h)");
+    }
+}
@@ -0,0 +1,285 @@
+#![allow(clippy::float_cmp)]
+
+use core::convert::TryFrom;
+
+pub mod common;
+
+use common::*;
+use rand::Rng;
+use twofloat::{ThreeFloat, TwoFloat, TwoFloatError};
+
+// Returns a random value with each word at most half a unit in the last
+// place of the previous one, so that arithmetic results are normal.
+fn get_threefloat() -> ThreeFloat {
+    let mut rng = rand::thread_rng();
+    let sign = if rng.gen() { 1.0 } else { -1.0 };
+    let hi = sign * rng.gen_range(1.0..2.0) * 2f64.powi(rng.gen_range(-300..300));
+    let mid = hi * rng.gen_range(-1.0..1.0) * f64::EPSILON * 0.25;
+    let lo = mid * rng.gen_range(-1.0..1.0) * f64::EPSILON * 0.25;
+    ThreeFloat::try_from((hi, mid, lo)).unwrap()
+}
+
+#[test]
+fn add_sub_test() {
+    repeated_test(|| {
+        let a = get_threefloat();
+        let b = get_threefloat();
+        let x = get_valid_f64(|x| x.is_normal() && x.abs() < 1e300);
+
+        let cases = [
+            (a + b, big(a) + big(b), "add"),
+            (a - b, big(a) - big(b), "sub"),
+            (a + x, big(a) + to_big(&[x]), "add f64"),
+            (x + a, big(a) + to_big(&[x]), "f64 add"),
+            (a - x, big(a) - to_big(&[x]), "sub f64"),
+            (x - a, to_big(&[x]) - big(a), "f64 sub"),
+        ];
+
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(
+                &big(*result),
+                exact,
+                ThreeFloat::TOLERANCE_BITS,
+                &description,
+            );
+        }
+    });
+}
+
+#[test]
+fn add_cancellation_test() {
+    repeated_test(|| {
+        let a = get_threefloat();
+        let b = -a + a.hi() * rand::thread_rng().gen_range(-1e-30..1e-30);
+        let result = a + b;
+
+        let description = format!("add of {:?} and {:?}", a, b);
+        assert_valid(result, &description);
+        assert_close(
+            &big(result),
+            &(big(a) + big(b)),
+            ThreeFloat::TOLERANCE_BITS,
+            &description,
+        );
+    });
+}
+
+#[test]
+fn mul_test() {
+    repeated_test(|| {
+        let a = get_threefloat();
+        let b = get_threefloat();
+        let x = get_valid_f64(|x| x.abs() < 1e100 && x.abs() > 1e-100);
+
+        let scale = to_big(&[1.0]);
+        let cases = [
+            (a * b, big(a) * big(b), "mul"),
+            (a * x, big(a) * to_big(&[x]), "mul f64"),
+            (x * a, big(a) * to_big(&[x]), "f64 mul"),
+        ];
+
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(
+                &(big(*result) * &scale),
+                exact,
+                ThreeFloat::TOLERANCE_BITS,
+                &description,
+            );
+        }
+    });
+}
+
+#[test]
+fn div_test() {
+    repeated_test(|| {
+        let a = get_threefloat();
+        let b = get_threefloat();
+        let x = get_valid_f64(|x| x.abs() < 1e100 && x.abs() > 1e-100);
+
+        let scale = to_big(&[1.0]);
+        let cases = [
+            (a / b, big(a), big(b), "div"),
+            (a / x, big(a), to_big(&[x]), "div f64"),
+            (x / a, to_big(&[x]), big(a), "f64 div"),
+        ];
+
+        for (result, dividend, divisor, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(
+                &(big(*result) * divisor),
+                &(dividend * &scale),
+                ThreeFloat::TOLERANCE_BITS,
+                &description,
+            );
+        }
+    });
+}
+
+#[test]
+fn rem_test() {
+    let a = ThreeFloat::from(7.5);
+    let b = ThreeFloat::from(TwoFloat::new_add(2.0, 1e-20));
+
+    assert_eq!(a % 2.0, 1.5);
+    assert_eq!(-a % 2.0, -1.5);
+    assert_eq!((a % b) + b * 3.0, a);
+    assert!((a % b).is_valid());
+    assert_eq!(a % f64::INFINITY, a);
+    assert!((a % 0.0).is_nan());
+    assert!((ThreeFloat::from(-4.0) % 2.0).is_sign_negative());
+}
+
+#[test]
+fn assign_ops_test() {
+    repeated_test(|| {
+        let a = get_threefloat();
+        let b = get_threefloat();
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a + b - b);
+        c *= b;
+        assert_eq!(c, (a + b - b) * b);
+        c /= b;
+        assert_eq!(c, (a + b - b) * b / b);
+    });
+}
+
+#[test]
+fn twofloat_conversion_test() {
+    repeated_test(|| {
+        let source = get_valid_twofloat(|x, _| x.abs() < 1e300);
+        let result = ThreeFloat::from(source);
+        let back = TwoFloat::from(result);
+
+        assert!(result.is_valid() && back.is_valid());
+        assert_eq!(result, source);
+        assert_eq!(
+            to_big(&[back.hi(), back.lo()]),
+            to_big(&[source.hi(), source.lo()])
+        );
+    });
+}
+
+#[test]
+fn twofloat_rounding_test() {
+    repeated_test(|| {
+        let source = get_threefloat();
+        let result = TwoFloat::from(source);
+
+        assert!(result.is_valid(), "Invalid conversion of {:?}", source);
+        let error = (ThreeFloat::from(result) - source).abs();
+        assert!(
+            error <= ThreeFloat::from(result.ulp()) * 0.5,
+            "Conversion of {:?} not rounded to nearest",
+            source
+        );
+    });
+}
+
+#[test]
+fn tuple_conversion_test() {
+    let value = ThreeFloat::from(1.0) / 3.0;
+    let tuple: (f64, f64, f64) = value.into();
+    let array: [f64; 3] = value.into();
+
+    assert_eq!(ThreeFloat::try_from(tuple), Ok(value));
+    assert_eq!(ThreeFloat::try_from(array), Ok(value));
+    assert_eq!(
+        ThreeFloat::try_from((1.0, 0.25, 0.0)),
+        Err(TwoFloatError::ConversionError)
+    );
+    assert_eq!(
+        ThreeFloat::try_from((1.0, 0.0, 1e-40)),
+        Err(TwoFloatError::ConversionError)
+    );
+}
+
+#[test]
+fn comparison_test() {
+    repeated_test(|| {
+        let a = get_threefloat();
+        let b = if rand::thread_rng().gen() {
+            get_threefloat()
+        } else {
+            a + a.lo() * rand::thread_rng().gen_range(-4.0..4.0)
+        };
+
+        assert_eq!(
+            a.partial_cmp(&b),
+            big(a).partial_cmp(&big(b)),
+            "Incorrect comparison of {:?} and {:?}",
+            a,
+            b
+        );
+        assert_eq!(a == b, big(a) == big(b));
+    });
+
+    let one = ThreeFloat::from(1.0);
+    let above = one + 1e-40;
+    assert_eq!(one, 1.0);
+    assert!(above > 1.0);
+    assert!(ThreeFloat::from(0.5) < 1.0);
+    assert!(above > TwoFloat::from(1.0) && TwoFloat::from(1.0) < above);
+    assert_ne!(above, TwoFloat::from(1.0));
+    assert_eq!(
+        ThreeFloat::from(TwoFloat::new_add(1.0, 1e-20)),
+        TwoFloat::new_add(1.0, 1e-20)
+    );
+}
+
+#[test]
+fn special_values_test() {
+    let one = ThreeFloat::from(1.0);
+    let zero = ThreeFloat::from(0.0);
+
+    assert_eq!(one / zero, ThreeFloat::INFINITY);
+    assert_eq!(-one / zero, ThreeFloat::NEG_INFINITY);
+    assert!((zero / zero).is_nan());
+    assert!((ThreeFloat::INFINITY - ThreeFloat::INFINITY).is_nan());
+    assert!((ThreeFloat::INFINITY * 0.0).is_nan());
+    assert!((ThreeFloat::NAN + 1.0).is_nan());
+    assert_eq!(ThreeFloat::INFINITY + one, ThreeFloat::INFINITY);
+    assert_eq!(one / ThreeFloat::INFINITY, 0.0);
+    assert_eq!(ThreeFloat::from(1e300) * 1e300, ThreeFloat::INFINITY);
+    assert!((-zero * one).is_sign_negative());
+    assert!((one - one).is_sign_positive());
+    assert!((ThreeFloat::from(1e-200) * 1e-200).hi() == 0.0);
+}
+
+#[test]
+fn rounding_functions_test() {
+    let a = ThreeFloat::from(TwoFloat::new_add(3.0, 1e-20)) + 1e-40;
+    let b = ThreeFloat::from(TwoFloat::new_add(3.0, -1e-20));
+
+    assert_eq!(a.floor(), 3.0);
+    assert_eq!(a.ceil(), 4.0);
+    assert_eq!(b.floor(), 2.0);
+    assert_eq!(b.ceil(), 3.0);
+    assert_eq!((-a).trunc(), -3.0);
+    assert_eq!((-b).trunc(), -2.0);
+    assert_eq!(ThreeFloat::from(2.5).floor(), 2.0);
+
+    let big = ThreeFloat::from(TwoFloat::new_add(2f64.powi(60), 0.5)) - 1e-30;
+    assert_eq!(big.floor(), ThreeFloat::from(2f64.powi(60)));
+    assert_eq!(big.ceil(), ThreeFloat::from(2f64.powi(60)) + 1.0);
+}
+
+#[test]
+fn display_test() {
+    let value = ThreeFloat::try_from((1.0, 0.3e-16, -0.3e-33)).unwrap();
+    assert_eq!(
+        format!("{}", value),
+        "1 + 0.00000000000000003 - 0.0000000000000000000000000000000003"
+    );
+    assert_eq!(format!("{:.2}", value), "1.00 + 0.00 - 0.00");
+    assert_eq!(format!("{:+.1}", -value), "-1.0 - 0.0 + 0.0");
+    assert_eq!(format!("{}", ThreeFloat::NEG_INFINITY), "-inf");
+}
@@ -0,0 +1,296 @@
+#![allow(clippy::float_cmp)]
+
+pub mod common;
+
+use common::*;
+use num_bigint::BigInt;
+use rand::Rng;
+use twofloat::{QuadFloat, TrackedTwoFloat, TwoFloat};
+
+// Checks that `exact` lies within the error bound of `result`, where the
+// value and error of `result` are scaled by `scale` to match the scale of
+// `exact`.
+fn assert_within_bound(result: TrackedTwoFloat, exact: &BigInt, scale: &BigInt, description: &str) {
+    if !result.value().is_finite() {
+        return;
+    }
+    let difference = big(result.value()) * scale - exact;
+    assert!(
+        difference.magnitude() <= (to_big(&[result.error()]) * scale).magnitude(),
+        "Error bound of {} too small",
+        description
+    );
+}
+
+// Checks that `reference`, computed at quad-word precision, lies within the
+// error bound of `result`.
+fn assert_within_reference(result: TrackedTwoFloat, reference: QuadFloat, description: &str) {
+    assert!(
+        (QuadFloat::from(result.value()) - reference).abs() <= result.error(),
+        "Error bound {:e} of {} too small",
+        result.error(),
+        description
+    );
+}
+
+// Returns a tracked value with a random error up to 1e-20 relative to its
+// magnitude, together with an exact value within that error.
+fn get_perturbed(value: TwoFloat) -> (TrackedTwoFloat, QuadFloat) {
+    let mut rng = rand::thread_rng();
+    let error = value.hi().abs() * rng.gen_range(0.0..1e-20);
+    let offset = error * rng.gen_range(-1.0..1.0);
+    (
+        TrackedTwoFloat::with_error(value, error),
+        QuadFloat::from(value) + QuadFloat::from(offset),
+    )
+}
+
+#[test]
+fn add_sub_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let b = get_twofloat();
+        let c = random_float();
+        let (x, y) = (TrackedTwoFloat::from(a), TrackedTwoFloat::from(b));
+
+        let one = to_big(&[1.0]).pow(0);
+        let description = format!("{:?} and {:?}", a, b);
+        assert_within_bound(x + y, &(big(a) + big(b)), &one, &description);
+        assert_within_bound(x - y, &(big(a) - big(b)), &one, &description);
+
+        let description = format!("{:?} and {:?}", a, c);
+        let big_c = to_big(&[c]);
+        assert_within_bound(x + c, &(big(a) + &big_c), &one, &description);
+        assert_within_bound(c + x, &(big(a) + &big_c), &one, &description);
+        assert_within_bound(x - c, &(big(a) - &big_c), &one, &description);
+        assert_within_bound(c - x, &(&big_c - big(a)), &one, &description);
+    });
+}
+
+#[test]
+fn mul_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let b = get_twofloat();
+        let c = random_float();
+        let x = TrackedTwoFloat::from(a);
+
+        // Products carry twice the scale of their arguments
+        let scale = to_big(&[1.0]);
+        let description = format!("product of {:?} and {:?}", a, b);
+        let result = x * TrackedTwoFloat::from(b);
+        assert_within_bound(result, &(big(a) * big(b)), &scale, &description);
+
+        let description = format!("product of {:?} and {:?}", a, c);
+        let exact = big(a) * to_big(&[c]);
+        assert_within_bound(x * c, &exact, &scale, &description);
+        assert_within_bound(c * x, &exact, &scale, &description);
+    });
+}
+
+#[test]
+fn div_test() {
+    repeated_test(|| {
+        let a = get_twofloat();
+        let b = get_twofloat();
+        let c = random_float();
+        let x = TrackedTwoFloat::from(a);
+
+        // The quotient times the divisor must be within the error bound
+        // times the divisor of the dividend
+        let check = |result: TrackedTwoFloat, dividend: &BigInt, divisor: &BigInt, description| {
+            if !result.value().is_finite() {
+                return;
+            }
+            let difference = big(result.value()) * divisor - dividend * to_big(&[1.0]);
+            let bound = to_big(&[result.error()]) * divisor;
+            assert!(
+                difference.magnitude() <= bound.magnitude(),
+                "Error bound of {} too small",
+                description
+            );
+        };
+
+        check(
+            x / TrackedTwoFloat::from(b),
+            &big(a),
+            &big(b),
+            format!("quotient of {:?} and {:?}", a, b),
+        );
+        check(
+            x / c,
+            &big(a),
+            &to_big(&[c]),
+            format!("quotient of {:?} and {:?}", a, c),
+        );
+        check(
+            c / x,
+            &to_big(&[c]),
+            &big(a),
+            format!("quotient of {:?} and {:?}", c, a),
+        );
+    });
+}
+
+#[test]
+fn perturbed_ops_test() {
+    repeated_test(|| {
+        let (a, b) = (get_twofloat_decades(-50..50), get_twofloat_decades(-50..50));
+        let ((x, exact_x), (y, exact_y)) = (get_perturbed(a), get_perturbed(b));
+
+        let description = format!("{:?} and {:?}", x, y);
+        assert_within_reference(x + y, exact_x + exact_y, &description);
+        assert_within_reference(x - y, exact_x - exact_y, &description);
+        assert_within_reference(x * y, exact_x * exact_y, &description);
+        assert_within_reference(x / y, exact_x / exact_y, &description);
+    });
+}
+
+#[test]
+fn chain_test() {
+    repeated_test(|| {
+        let values: Vec<_> = (0..8)
+            .map(|_| get_perturbed(get_twofloat_decades(-50..50)))
+            .collect();
+
+        // Evaluates a polynomial with the values as coefficients by Horner's
+        // method, at a point in [-2, 2]
+        let point = rand::thread_rng().gen_range(-2.0..2.0);
+        let mut result = TrackedTwoFloat::default();
+        let mut exact = QuadFloat::from(0.0);
+        for (coefficient, exact_coefficient) in values.iter() {
+            result = result * point + coefficient;
+            exact = exact * QuadFloat::from(point) + exact_coefficient;
+        }
+
+        let description = format!("polynomial {:?} at {}", values, point);
+        assert_within_reference(result, exact, &description);
+    });
+}
+
+#[test]
+fn cancellation_test() {
+    let a = TrackedTwoFloat::with_error(TwoFloat::from(1.0), 1e-25);
+    let b = TrackedTwoFloat::from(TwoFloat::new_add(1.0, 1e-20));
+
+    let result = (b - a) * 1e20;
+    assert!(result.error() >= 1e-5);
+    assert!(result.correct_bits() < 20);
+
+    let exact = TrackedTwoFloat::from(1.0) / 3.0 * 3.0;
+    assert!((exact.value() - 1.0).abs() <= exact.error());
+    assert!(exact.correct_bits() >= 100);
+}
+
+#[test]
+fn assign_test() {
+    let mut value = TrackedTwoFloat::from(1.0);
+    value += TrackedTwoFloat::with_error(TwoFloat::from(2.0), 1e-20);
+    value *= 3.0;
+    value -= 1.0;
+    value /= TrackedTwoFloat::from(4.0);
+
+    assert_eq!(value.value(), 2.0);
+    assert!(value.error() >= 7.5e-21 && value.error() < 1e-20);
+}
+
+#[test]
+fn special_values_test() {
+    let zero_error = TrackedTwoFloat::with_error(TwoFloat::ZERO, 1e-20);
+    assert_eq!(zero_error.relative_error(), f64::INFINITY);
+    assert_eq!(zero_error.correct_bits(), 0);
+    assert_eq!(
+        (TrackedTwoFloat::from(1.0) / zero_error).error(),
+        f64::INFINITY
+    );
+
+    let overflow = TrackedTwoFloat::from(f64::MAX) * 2.0;
+    assert!(overflow.value().is_infinite());
+    assert_eq!(overflow.error(), f64::INFINITY);
+
+    let nan = TrackedTwoFloat::from(f64::NAN) + 1.0;
+    assert!(nan.value().is_nan() && nan.error().is_nan());
+    assert!(nan.to_interval().is_empty());
+
+    assert_eq!(
+        TrackedTwoFloat::with_error(TwoFloat::ONE, -0.5).error(),
+        0.5
+    );
+    assert_eq!((-TrackedTwoFloat::from(2.0)).value(), -2.0);
+}
+
+#[test]
+fn to_interval_test() {
+    repeated_test(|| {
+        let (x, exact) = get_perturbed(get_twofloat_decades(-50..50));
+        let interval = x.to_interval();
+
+        assert!(interval.contains(x.value()));
+        assert!(QuadFloat::from(interval.lower()) <= exact);
+        assert!(QuadFloat::from(interval.upper()) >= exact);
+    });
+}
+
+#[test]
+fn display_test() {
+    let value = TrackedTwoFloat::with_error(TwoFloat::from(1.5), 0.25);
+    assert_eq!(format!("{}", value), "1.5 + 0 ± 2.5e-1");
+}
+
+// Mathematical functions, checked against quad-word references for perturbed
+// arguments.
+#[cfg(feature = "math_funcs")]
+mod functions {
+    use super::*;
+
+    type Function = (
+        &'static str,
+        fn(TrackedTwoFloat) -> TrackedTwoFloat,
+        fn(QuadFloat) -> QuadFloat,
+        fn(TwoFloat) -> bool,
+    );
+
+    const FUNCTIONS: [Function; 6] = [
+        ("sqrt", TrackedTwoFloat::sqrt, QuadFloat::sqrt, |x| x > 0.0),
+        ("exp", TrackedTwoFloat::exp, QuadFloat::exp, |x| {
+            x.abs() < 700.0
+        }),
+        ("ln", TrackedTwoFloat::ln, QuadFloat::ln, |x| x > 0.0),
+        ("sin", TrackedTwoFloat::sin, QuadFloat::sin, |x| {
+            x.abs() < 1e9
+        }),
+        ("cos", TrackedTwoFloat::cos, QuadFloat::cos, |x| {
+            x.abs() < 1e9
+        }),
+        ("tan", TrackedTwoFloat::tan, QuadFloat::tan, |x| {
+            x.abs() < 1e9
+        }),
+    ];
+
+    #[test]
+    fn functions_test() {
+        for (name, f, reference, valid) in FUNCTIONS.iter() {
+            repeated_test(|| {
+                let a = get_valid_twofloat(|x, _| valid(TwoFloat::from(x)) && x.abs() < 1e50);
+                let (x, exact) = get_perturbed(a);
+                let description = format!("{} of {:?}", name, x);
+                assert_within_reference(f(x), reference(exact), &description);
+            });
+        }
+    }
+
+    #[test]
+    fn function_errors_test() {
+        let x = TrackedTwoFloat::with_error(TwoFloat::from(2.0), 1e-20);
+        assert!(x.sqrt().error() < 1e-20);
+        assert!(x.exp().error() < 1e-19);
+        assert!(x.sin().error() < 1e-19);
+
+        let large = TrackedTwoFloat::from(1e40);
+        assert!(large.sin().error() < 1e-19);
+
+        let uncertain = TrackedTwoFloat::with_error(TwoFloat::from(1.0), 2.0);
+        assert_eq!(uncertain.ln().error(), f64::INFINITY);
+        assert!(uncertain.cos().error() <= 2.1);
+    }
+}
@@ -5,7 +5,61 @@ pub mod common;
 use common::*;
 use core::convert::TryFrom;
 use rand::Rng;
-use twofloat::TwoFloat;
+use twofloat::{QuadFloat, TwoFloat};
+
+// Reference values computed with mpmath
+const LARGE_CASES: [([f64; 2], [f64; 2], [f64; 2]); 10] = [
+    (
+        [1000000.0, 0.0],
+        [-0.34999350217129294, -1.5952848809323968e-17],
+        [0.9367521275331447, 4.637088260214747e-17],
+    ),
+    (
+        [123456789.0, 1e-09],
+        [0.9901147519422951, -2.069087840820867e-17],
+        [0.14025968054379487, 1.0143342283645665e-17],
+    ),
+    (
+        [-3000000000.0, 0.0],
+        [-0.9870048864743554, 2.351775507154133e-17],
+        [-0.16069024262768705, -1.282323261506693e-17],
+    ),
+    (
+        [1000000000000000.0, -0.0625],
+        [0.8886507555083304, 3.841435114432874e-17],
+        [-0.45858459932107787, -9.795092236032492e-19],
+    ),
+    (
+        [1.152921504606847e+18, 1.0],
+        [-0.9173294353474792, 5.537649894354936e-17],
+        [0.39812900804271345, 2.3040273936325782e-17],
+    ),
+    (
+        [-1e+22, 0.0],
+        [0.8522008497671888, 6.7806825896773284e-18],
+        [0.523214785395139, -4.7143201076575164e-17],
+    ),
+    (
+        [1e+100, 0.0],
+        [-0.3806377310050287, 1.2782658365978341e-17],
+        [0.9247242387519338, 1.669232482156043e-17],
+    ),
+    (
+        [5.319372648326541e+255, 0.0],
+        [1.0, -1.098476220074687e-37],
+        [-4.687165924254628e-19, 4.3720557429382733e-36],
+    ),
+    (
+        [-1e+300, 1e+283],
+        [0.6950021828401988, 3.714520811876803e-17],
+        [-0.7190076257226754, 4.2701767571535324e-18],
+    ),
+    (
+        [1.7976931348623157e+308, 0.0],
+        [0.004961954789184062, -2.5049377676494104e-19],
+        [-0.9999876894265599, -2.6032890267216748e-17],
+    ),
+];
 
 #[test]
 fn sin_cos_test() {
@@ -147,3 +201,172 @@ fn sin_cos_atan2_test() {
         );
     });
 }
+
+#[test]
+fn trigonometry_special_test() {
+    use twofloat::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    assert!(TwoFloat::INFINITY.sin().is_nan());
+    assert!(TwoFloat::NEG_INFINITY.cos().is_nan());
+    assert!(TwoFloat::INFINITY.tan().is_nan());
+    assert_eq!(TwoFloat::INFINITY.atan(), FRAC_PI_2);
+    assert_eq!(TwoFloat::NEG_INFINITY.atan(), -FRAC_PI_2);
+    assert!(TwoFloat::NAN.atan().is_nan());
+
+    let inf = TwoFloat::INFINITY;
+    let one = TwoFloat::ONE;
+    assert_eq!(inf.atan2(inf), FRAC_PI_4);
+    assert_eq!((-inf).atan2(-inf), -3.0 * FRAC_PI_4);
+    assert_eq!(inf.atan2(one), FRAC_PI_2);
+    assert_eq!(one.atan2(-inf), PI);
+    assert_eq!((-one).atan2(-inf), -PI);
+    let zero = one.atan2(inf);
+    assert!(zero == 0.0 && zero.is_sign_positive());
+    let zero = (-one).atan2(inf);
+    assert!(zero == 0.0 && zero.is_sign_negative());
+    assert!(TwoFloat::NAN.atan2(one).is_nan());
+}
+
+#[test]
+fn sin_cos_pi_test() {
+    let mut rng = rand::thread_rng();
+    let dist = rand::distributions::Uniform::new_inclusive(-20.0, 20.0);
+
+    for _ in 0..10000 {
+        let source = TwoFloat::new_add(rng.sample(dist), rng.gen_range(-1e-16..1e-16));
+        let (sin, cos) = source.sin_cos_pi();
+        assert_eq!(
+            sin,
+            source.sin_pi(),
+            "sin_cos_pi({:?}).0 not equal to sin_pi",
+            source
+        );
+        assert_eq!(
+            cos,
+            source.cos_pi(),
+            "sin_cos_pi({:?}).1 not equal to cos_pi",
+            source
+        );
+
+        // Compare with the quadruple-double functions, where the product with
+        // π is accurate to about 2^-200
+        let x = QuadFloat::PI * QuadFloat::from(source);
+        let (expected_sin, expected_cos) = (TwoFloat::from(x.sin()), TwoFloat::from(x.cos()));
+        assert!(
+            (sin - expected_sin).abs() < 1e-31,
+            "Mismatch in sin_pi({:?}): {:?} vs {:?}",
+            source,
+            expected_sin,
+            sin
+        );
+        assert!(
+            (cos - expected_cos).abs() < 1e-31,
+            "Mismatch in cos_pi({:?}): {:?} vs {:?}",
+            source,
+            expected_cos,
+            cos
+        );
+
+        let tan = source.tan_pi();
+        let expected_tan = sin / cos;
+        assert!(
+            ((tan - expected_tan) / expected_tan).abs() < 1e-30,
+            "Mismatch in tan_pi({:?}): {:?} vs {:?}",
+            source,
+            expected_tan,
+            tan
+        );
+    }
+}
+
+#[test]
+fn sin_cos_pi_exact_test() {
+    for n in -20..=20 {
+        let x = TwoFloat::from(f64::from(n));
+        let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+        assert_eq!(x.sin_pi(), 0.0);
+        assert_eq!(x.sin_pi().is_sign_negative(), n < 0);
+        assert_eq!(x.cos_pi(), sign);
+        assert_eq!(x.tan_pi(), 0.0);
+
+        let x = x + 0.5;
+        assert_eq!(x.sin_pi(), sign);
+        assert_eq!(x.cos_pi(), 0.0);
+        assert!(x.cos_pi().is_sign_positive());
+        assert_eq!(x.tan_pi(), sign * f64::INFINITY);
+    }
+
+    assert_eq!(TwoFloat::from(1.0 / 6.0).sin_pi().hi(), 0.5);
+    assert!(TwoFloat::from(-0.0).sin_pi().is_sign_negative());
+}
+
+#[test]
+fn sin_cos_pi_large_test() {
+    // Multiples of 1/4 remain exact at any magnitude
+    let x = TwoFloat::new_add(1e300, 0.25);
+    assert_eq!(x.sin_pi(), TwoFloat::from(0.25).sin_pi());
+    assert_eq!(x.cos_pi(), TwoFloat::from(0.25).cos_pi());
+
+    let x = TwoFloat::new_add(4503599627370497.0, 0.5);
+    assert_eq!(x.sin_pi(), -1.0);
+    assert_eq!(x.cos_pi(), 0.0);
+
+    let x = TwoFloat::new_add(1e20, 1.0 / 3.0);
+    let expected = TwoFloat::from(1.0 / 3.0).sin_pi();
+    assert!((x.sin_pi() - expected).abs() < 1e-31);
+
+    assert_eq!(TwoFloat::from(f64::MAX).sin_pi(), 0.0);
+    assert_eq!(TwoFloat::from(f64::MAX).cos_pi(), 1.0);
+    assert!(TwoFloat::INFINITY.sin_pi().is_nan());
+    assert!(TwoFloat::NEG_INFINITY.cos_pi().is_nan());
+    assert!(TwoFloat::NAN.tan_pi().is_nan());
+}
+
+#[test]
+fn trigonometry_large_test() {
+    for ([x_hi, x_lo], [sin_hi, sin_lo], [cos_hi, cos_lo]) in LARGE_CASES.iter() {
+        let x = TwoFloat::new_add(*x_hi, *x_lo);
+        let (expected_sin, expected_cos) = (
+            TwoFloat::new_add(*sin_hi, *sin_lo),
+            TwoFloat::new_add(*cos_hi, *cos_lo),
+        );
+
+        let (sin, cos) = x.sin_cos();
+        assert!(
+            (sin - expected_sin).abs() < 1e-19,
+            "Mismatch in sin({:?}): {:?} vs {:?}",
+            x,
+            expected_sin,
+            sin
+        );
+        assert!(
+            (cos - expected_cos).abs() < 1e-19,
+            "Mismatch in cos({:?}): {:?} vs {:?}",
+            x,
+            expected_cos,
+            cos
+        );
+
+        let tan = x.tan();
+        let expected_tan = expected_sin / expected_cos;
+        assert!(
+            ((tan - expected_tan) / expected_tan).abs() < 1e-15,
+            "Mismatch in tan({:?}): {:?} vs {:?}",
+            x,
+            expected_tan,
+            tan
+        );
+    }
+
+    // Close to a multiple of π/2 the reduced argument keeps its relative
+    // accuracy
+    let (x, [hi, lo]) = (LARGE_CASES[7].0[0], LARGE_CASES[7].2);
+    let cos = TwoFloat::from(x).cos();
+    let expected = TwoFloat::new_add(hi, lo);
+    assert!(((cos - expected) / expected).abs() < 1e-19);
+
+    let x = 1000.0 * twofloat::consts::FRAC_PI_2;
+    let expected = QuadFloat::from(x) - QuadFloat::PI * QuadFloat::from(500.0);
+    let sin = x.sin();
+    assert!(((QuadFloat::from(sin) - expected) / expected).abs() < QuadFloat::from(1e-19));
+}
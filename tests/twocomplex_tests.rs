@@ -0,0 +1,634 @@
+#![allow(clippy::float_cmp)]
+
+pub mod common;
+
+use common::*;
+use num_bigint::BigInt;
+use twofloat::{TwoComplex, TwoFloat};
+
+// Returns a random value with parts small enough that their products are
+// normal.
+fn get_twocomplex() -> TwoComplex {
+    let get_part = || get_valid_twofloat(|x, _| x.abs() > 1e-100 && x.abs() < 1e100);
+    TwoComplex::new(get_part(), get_part())
+}
+
+// Checks that the error in each part is small relative to the sum of the
+// magnitudes of the exact parts, where all values are multiples of the same
+// power of two.
+fn assert_close_parts(
+    result: (&BigInt, &BigInt),
+    exact: (&BigInt, &BigInt),
+    bits: usize,
+    description: &str,
+) {
+    let re_error: BigInt = result.0 - exact.0;
+    let im_error: BigInt = result.1 - exact.1;
+    let magnitude = exact.0.magnitude() + exact.1.magnitude();
+    assert!(
+        (re_error.magnitude() << bits) <= magnitude && (im_error.magnitude() << bits) <= magnitude,
+        "Inaccurate result of {}",
+        description
+    );
+}
+
+#[test]
+fn add_sub_test() {
+    repeated_test(|| {
+        let a = get_twocomplex();
+        let b = get_twocomplex();
+        let x = get_twofloat();
+
+        assert_eq!(a + b, TwoComplex::new(a.re + b.re, a.im + b.im));
+        assert_eq!(a - b, TwoComplex::new(a.re - b.re, a.im - b.im));
+        assert_eq!(a + x, TwoComplex::new(a.re + x, a.im));
+        assert_eq!(x - a, TwoComplex::new(x - a.re, -a.im));
+        assert_eq!(a - 2.0, TwoComplex::new(a.re - 2.0, a.im));
+    });
+}
+
+#[test]
+fn mul_test() {
+    repeated_test(|| {
+        let a = get_twocomplex();
+        let b = get_twocomplex();
+        let result = a * b;
+
+        let scale = to_big(&[1.0]);
+        let exact_re = big(a.re) * big(b.re) - big(a.im) * big(b.im);
+        let exact_im = big(a.re) * big(b.im) + big(a.im) * big(b.re);
+        assert_close_parts(
+            (&(big(result.re) * &scale), &(big(result.im) * &scale)),
+            (&exact_re, &exact_im),
+            TwoFloat::TOLERANCE_BITS,
+            &format!("mul of {:?} and {:?}", a, b),
+        );
+    });
+}
+
+#[test]
+fn div_test() {
+    repeated_test(|| {
+        let a = get_twocomplex();
+        let b = get_twocomplex();
+        let result = a / b;
+
+        // Checks that the quotient times the divisor is close to the dividend
+        let scale = to_big(&[1.0]);
+        let product_re = big(result.re) * big(b.re) - big(result.im) * big(b.im);
+        let product_im = big(result.re) * big(b.im) + big(result.im) * big(b.re);
+        assert_close_parts(
+            (&product_re, &product_im),
+            (&(big(a.re) * &scale), &(big(a.im) * &scale)),
+            TwoFloat::TOLERANCE_BITS,
+            &format!("div of {:?} and {:?}", a, b),
+        );
+    });
+}
+
+#[test]
+fn div_overflow_test() {
+    let large = TwoComplex::new(TwoFloat::from(1e308), TwoFloat::from(1e308));
+    assert!((large / large - 1.0).norm_sqr() < 1e-60);
+    assert!((large / large.conj() - TwoComplex::I).norm_sqr() < 1e-60);
+
+    let small = TwoComplex::new(TwoFloat::from(1e-300), TwoFloat::from(-1e-300));
+    assert!((small / small - 1.0).norm_sqr() < 1e-60);
+    assert!((small.recip() * small - 1.0).norm_sqr() < 1e-60);
+
+    let quotient = TwoComplex::from(1.0) / TwoComplex::ZERO;
+    assert!(quotient.re.is_infinite());
+}
+
+#[test]
+fn assign_ops_test() {
+    let mut value = TwoComplex::ONE;
+    value += TwoComplex::I;
+    value *= TwoComplex::I;
+    value -= 1.0;
+    value /= TwoFloat::from(2.0);
+
+    assert_eq!(
+        value,
+        TwoComplex::new(TwoFloat::from(-1.0), TwoFloat::from(0.5))
+    );
+}
+
+#[test]
+fn conj_recip_test() {
+    repeated_test(|| {
+        let a = get_twocomplex();
+        assert_eq!(a.conj().conj(), a);
+        assert_eq!(a.recip(), TwoComplex::ONE / a);
+    });
+}
+
+// Elementary functions, checked against values computed with mpmath.
+#[cfg(feature = "math_funcs")]
+mod functions {
+    use super::*;
+
+    // The elementary functions are limited by the accuracy of the underlying
+    // `TwoFloat` trigonometric functions.
+    const FUNCTION_TOLERANCE_BITS: usize = 64;
+
+    type Parts = (f64, f64);
+    type Value = (Parts, Parts);
+    type Case = (Parts, Value);
+
+    fn two(parts: (f64, f64)) -> TwoFloat {
+        TwoFloat::new_add(parts.0, parts.1)
+    }
+
+    fn check_result(result: TwoComplex, expected: ((f64, f64), (f64, f64)), description: &str) {
+        assert!(
+            result.re.is_valid() && result.im.is_valid(),
+            "Invalid result of {}",
+            description
+        );
+        assert_close_parts(
+            (&big(result.re), &big(result.im)),
+            (&big(two(expected.0)), &big(two(expected.1))),
+            FUNCTION_TOLERANCE_BITS,
+            description,
+        );
+    }
+
+    // Checks each part of the result separately, for values whose parts have
+    // very different magnitudes.
+    fn check_parts(result: TwoComplex, expected: ((f64, f64), (f64, f64)), description: &str) {
+        assert_close(
+            &big(result.re),
+            &big(two(expected.0)),
+            FUNCTION_TOLERANCE_BITS,
+            description,
+        );
+        assert_close(
+            &big(result.im),
+            &big(two(expected.1)),
+            FUNCTION_TOLERANCE_BITS,
+            description,
+        );
+    }
+
+    fn check_cases(cases: &[Case], f: impl Fn(TwoComplex) -> TwoComplex, name: &str) {
+        for (input, expected) in cases.iter() {
+            let z = TwoComplex::new(TwoFloat::from(input.0), TwoFloat::from(input.1));
+            check_result(f(z), *expected, &format!("{} of {:?}", name, z));
+        }
+    }
+
+    const SQRT_CASES: [Case; 6] = [
+        (
+            (2.376352375964177, 2.6626128098750246),
+            (
+                (1.7241206135227318, -6.835120927587244e-18),
+                (0.7721654706148315, -7.727616069588374e-18),
+            ),
+        ),
+        (
+            (1.5793136426354286, 1.9168500725517283),
+            (
+                (1.4253016123071445, 1.391481199316236e-17),
+                (0.6724366463912544, 1.5620572081735397e-17),
+            ),
+        ),
+        (
+            (2.1322442584973165, -1.4107266181823022),
+            (
+                (1.5311637333338812, -1.0593563432292077e-16),
+                (-0.4606713793797398, -4.475273577277439e-18),
+            ),
+        ),
+        (
+            (-0.7416649781480784, 1.4363681400387676),
+            (
+                (0.6613927869756927, -1.400861566028868e-17),
+                (1.0858661965507317, 7.35329398272779e-17),
+            ),
+        ),
+        (
+            (-1.3523239321091007, 0.6289441254617696),
+            (
+                (0.26372505126937096, 2.37228241479565e-17),
+                (1.1924239324905104, -6.497616003500707e-17),
+            ),
+        ),
+        (
+            (-2.949459551367647, -0.8926097292597786),
+            (
+                (0.25701054819979824, 1.0947679848386539e-17),
+                (-1.736523530866659, 1.571537398179109e-17),
+            ),
+        ),
+    ];
+
+    const EXP_CASES: [Case; 6] = [
+        (
+            (2.376352375964177, 2.6626128098750246),
+            (
+                (-9.554065765510552, -6.63307893317662e-16),
+                (4.961568488127442, -2.9011607594319043e-16),
+            ),
+        ),
+        (
+            (1.5793136426354286, 1.9168500725517283),
+            (
+                (-1.6456135820050388, 7.242433540894348e-17),
+                (4.564013404997839, -3.659021719538993e-16),
+            ),
+        ),
+        (
+            (2.1322442584973165, -1.4107266181823022),
+            (
+                (1.3442340082754292, -2.5369598361903023e-17),
+                (-8.325957276167083, -4.2409259623136975e-16),
+            ),
+        ),
+        (
+            (-0.7416649781480784, 1.4363681400387676),
+            (
+                (0.06383818432043499, -3.654553780905941e-18),
+                (0.47202289324324115, 1.798496073700314e-17),
+            ),
+        ),
+        (
+            (-1.3523239321091007, 0.6289441254617696),
+            (
+                (0.20914779833722863, 2.2493238240519355e-18),
+                (0.15215476948648138, -7.260581746659816e-18),
+            ),
+        ),
+        (
+            (-2.949459551367647, -0.8926097292597786),
+            (
+                (0.032854737686311204, -3.206945158852729e-19),
+                (-0.0407795744168595, -1.4494555230951112e-18),
+            ),
+        ),
+    ];
+
+    const LN_CASES: [Case; 6] = [
+        (
+            (2.376352375964177, 2.6626128098750246),
+            (
+                (1.2722382043851725, 4.3370032398048e-17),
+                (0.8421465370111478, 5.113690253362118e-17),
+            ),
+        ),
+        (
+            (1.5793136426354286, 1.9168500725517283),
+            (
+                (0.9097315592386763, -1.6547406425578396e-17),
+                (0.8816446693564897, 5.380052476777211e-17),
+            ),
+        ),
+        (
+            (2.1322442584973165, -1.4107266181823022),
+            (
+                (0.9387097366272875, -2.5908026882184376e-18),
+                (-0.5844977350070819, -4.321980874123362e-17),
+            ),
+        ),
+        (
+            (-0.7416649781480784, 1.4363681400387676),
+            (
+                (0.48029166017269725, -1.5104416523613263e-17),
+                (2.0474361772525524, -1.5327981032108447e-16),
+            ),
+        ),
+        (
+            (-1.3523239321091007, 0.6289441254617696),
+            (
+                (0.3997325332155884, 2.378416632333998e-18),
+                (2.7062659764713497, 1.9176383152324548e-16),
+            ),
+        ),
+        (
+            (-2.949459551367647, -0.8926097292597786),
+            (
+                (1.1254386865785417, 6.86797912083284e-17),
+                (-2.8477201727581862, -7.072207532540349e-17),
+            ),
+        ),
+    ];
+
+    const SIN_CASES: [Case; 6] = [
+        (
+            (2.376352375964177, 2.6626128098750246),
+            (
+                (4.988711629188172, 2.877461433880964e-16),
+                (-5.143685268119081, 3.7844366175255274e-17),
+            ),
+        ),
+        (
+            (1.5793136426354286, 1.9168500725517283),
+            (
+                (3.4731621364494623, 2.1111562103224485e-16),
+                (-0.02833011207714857, -1.690544007021537e-18),
+            ),
+        ),
+        (
+            (2.1322442584973165, -1.4107266181823022),
+            (
+                (1.8380994944472713, 7.426543545047339e-17),
+                (1.026216060847902, -1.0791247370551272e-16),
+            ),
+        ),
+        (
+            (-0.7416649781480784, 1.4363681400387676),
+            (
+                (-1.5007222292286635, 7.205403533553605e-17),
+                (1.462746516722227, 3.761860624149693e-17),
+            ),
+        ),
+        (
+            (-1.3523239321091007, 0.6289441254617696),
+            (
+                (-1.1757629974197268, 6.586964893554217e-17),
+                (0.14548302728628126, -1.3852273734229931e-17),
+            ),
+        ),
+        (
+            (-2.949459551367647, -0.8926097292597786),
+            (
+                (-0.27221124506114536, -4.100044480452738e-18),
+                (0.9972593482873037, -1.1220636867661607e-17),
+            ),
+        ),
+    ];
+
+    const COS_CASES: [Case; 6] = [
+        (
+            (2.376352375964177, 2.6626128098750246),
+            (
+                (-5.194001401909627, 2.5317226036235967e-16),
+                (-4.9403842102382285, -4.175139052677382e-16),
+            ),
+        ),
+        (
+            (1.5793136426354286, 1.9168500725517283),
+            (
+                (-0.02958273424153248, -1.2191302490557776e-18),
+                (-3.3260979794619847, 2.0810199091299478e-16),
+            ),
+        ),
+        (
+            (2.1322442584973165, -1.4107266181823022),
+            (
+                (-1.1561065590844881, -9.085020168030063e-18),
+                (1.6315859535750192, -2.2905047305356775e-17),
+            ),
+        ),
+        (
+            (-0.7416649781480784, 1.4363681400387676),
+            (
+                (1.638079612314364, -9.562956983564822e-17),
+                (1.3400912854720073, -3.849033947445196e-17),
+            ),
+        ),
+        (
+            (-1.3523239321091007, 0.6289441254617696),
+            (
+                (0.2610381668400635, 2.1618653756723057e-18),
+                (0.6552818015329437, 4.946664637793681e-17),
+            ),
+        ),
+        (
+            (-2.949459551367647, -0.8926097292597786),
+            (
+                (-1.3993080758443974, -5.2571473298210297e-17),
+                (-0.19399960132606292, -2.4137623915249326e-18),
+            ),
+        ),
+    ];
+
+    // Values near the unit circle, where the real part of the logarithm is
+    // much smaller than the modulus.
+    const UNIT_CIRCLE_LN_CASES: [Case; 4] = [
+        (
+            (1.0, 1e-20),
+            (
+                (5e-41, -1.94931682457305e-57),
+                (1e-20, -3.333333333333333e-61),
+            ),
+        ),
+        (
+            (0.6, 0.8),
+            (
+                (2.2204460492503132e-17, -4.930380657631324e-34),
+                (0.9272952180016123, -2.121582657158602e-17),
+            ),
+        ),
+        (
+            (-1.0, 1e-30),
+            (
+                (5.0000000000000005e-61, 2.9030340210263304e-77),
+                (core::f64::consts::PI, 1.224646799147343e-16),
+            ),
+        ),
+        (
+            (0.9999999999, -1.5e-5),
+            (
+                (1.2499991730806657e-11, -5.4273165726248275e-28),
+                (-1.5000000000375e-05, -3.600613093317216e-24),
+            ),
+        ),
+    ];
+
+    const TAN_CASES: [Case; 6] = [
+        (
+            (2.376352375964177, 2.6626128098750246),
+            (
+                (-0.009722550560604764, -2.4321820834510594e-19),
+                (0.9995604547744537, -2.0157140044991918e-17),
+            ),
+        ),
+        (
+            (1.5793136426354286, 1.9168500725517283),
+            (
+                (-0.0007697989555743967, 1.6912694925934205e-20),
+                (1.0442083742383743, -7.786912460233299e-17),
+            ),
+        ),
+        (
+            (2.1322442584973165, -1.4107266181823022),
+            (
+                (-0.11270768803827574, -2.5931602069725934e-18),
+                (-1.0467100389685042, 2.1870097264230395e-17),
+            ),
+        ),
+        (
+            (-0.7416649781480784, 1.4363681400387676),
+            (
+                (-0.11120160890015, -2.6130849243386896e-18),
+                (0.9839368072364912, 2.923292985315539e-17),
+            ),
+        ),
+        (
+            (-1.3523239321091007, 0.6289441254617696),
+            (
+                (-0.42526971477745096, 2.0893869647708696e-17),
+                (1.6248755393035204, -1.10722219058343e-16),
+            ),
+        ),
+        (
+            (-2.949459551367647, -0.8926097292597786),
+            (
+                (0.09392172042634847, -6.175190607855531e-18),
+                (-0.7257016107715183, -4.872768327563775e-17),
+            ),
+        ),
+    ];
+
+    // Values near the real poles of tan, where cos(2x) + cosh(2y) cancels.
+    const TAN_POLE_CASES: [Case; 2] = [
+        (
+            (core::f64::consts::FRAC_PI_2, 1e-10),
+            (
+                (6123.233995734469, 2.7738246226298376e-13),
+                (9999999999.99625, 8.366681631557875e-8),
+            ),
+        ),
+        (
+            (-core::f64::consts::FRAC_PI_2, -1e-3),
+            (
+                (-6.123231954659175e-11, -6.562137746507044e-28),
+                (-1000.0003333333111, 2.832552489253284e-14),
+            ),
+        ),
+    ];
+
+    const POWC_CASES: [(Parts, Parts, Value); 4] = [
+        (
+            (-1.9129003896119985, 2.615214473777051),
+            (-0.6174504268989881, -1.09274982017857),
+            (
+                (-4.7194216043737525, -2.3614447726774023e-16),
+                (-2.5605693318134195, 1.7934581937601845e-16),
+            ),
+        ),
+        (
+            (-1.8646528074731321, 2.931156482830707),
+            (0.6537081580136969, 0.29454788998369863),
+            (
+                (-0.23093200601676184, -7.963649513060045e-18),
+                (1.1802445378001256, -2.1052285681338664e-17),
+            ),
+        ),
+        (
+            (1.758482719860504, -2.722423252206174),
+            (-1.088372915393775, 0.3723263523858986),
+            (
+                (0.019167928296973464, -1.298078469745241e-18),
+                (0.40268524475363254, -1.8183445979307785e-17),
+            ),
+        ),
+        (
+            (1.5154326977796724, -1.6054507342557052),
+            (-0.5746724690326779, -0.5582001003053475),
+            (
+                (0.4025418650060973, 9.81695798684292e-18),
+                (0.010406658638650307, -3.0923532313879918e-19),
+            ),
+        ),
+    ];
+
+    #[test]
+    fn norm_arg_test() {
+        let a = TwoComplex::new(TwoFloat::from(3.0), TwoFloat::from(-4.0));
+        assert_eq!(a.norm(), 5.0);
+        assert_eq!(a.arg(), TwoFloat::from(-4.0).atan2(TwoFloat::from(3.0)));
+
+        let large = TwoComplex::new(TwoFloat::from(0.75), TwoFloat::ONE) * 2f64.powi(1023);
+        assert_eq!(large.norm(), TwoFloat::from(1.25) * 2f64.powi(1023));
+        let small = TwoComplex::new(TwoFloat::from(3.0), TwoFloat::from(4.0)) * 2f64.powi(-960);
+        assert_eq!(small.norm(), TwoFloat::from(5.0) * 2f64.powi(-960));
+    }
+
+    #[test]
+    fn sqrt_test() {
+        check_cases(&SQRT_CASES, TwoComplex::sqrt, "sqrt");
+
+        repeated_test(|| {
+            let a = get_twocomplex();
+            let root = a.sqrt();
+            assert!(root.re >= 0.0);
+            assert!((root * root - a).norm() <= a.norm() * 1e-29);
+        });
+
+        let negative = TwoComplex::new(TwoFloat::from(-4.0), TwoFloat::from(-0.0));
+        assert_eq!(negative.sqrt(), TwoComplex::new(0.0.into(), (-2.0).into()));
+        let large = TwoComplex::new(TwoFloat::MAX, TwoFloat::MAX);
+        assert!(large.sqrt().is_finite());
+        let infinite = TwoComplex::new(TwoFloat::NEG_INFINITY, TwoFloat::ONE);
+        assert_eq!(
+            infinite.sqrt(),
+            TwoComplex::new(0.0.into(), TwoFloat::INFINITY)
+        );
+    }
+
+    #[test]
+    fn exp_ln_test() {
+        check_cases(&EXP_CASES, TwoComplex::exp, "exp");
+        check_cases(&LN_CASES, TwoComplex::ln, "ln");
+
+        for (input, expected) in UNIT_CIRCLE_LN_CASES.iter() {
+            let z = TwoComplex::new(TwoFloat::from(input.0), TwoFloat::from(input.1));
+            check_parts(z.ln(), *expected, &format!("ln of {:?}", z));
+        }
+
+        assert_eq!(TwoComplex::ZERO.exp(), TwoComplex::ONE);
+        assert_eq!(TwoComplex::ONE.ln(), TwoComplex::ZERO);
+        assert_eq!(TwoComplex::from(-2.0).ln().im, twofloat::consts::PI);
+    }
+
+    #[test]
+    fn powc_test() {
+        for (base, exponent, expected) in POWC_CASES.iter() {
+            let z = TwoComplex::new(TwoFloat::from(base.0), TwoFloat::from(base.1));
+            let w = TwoComplex::new(TwoFloat::from(exponent.0), TwoFloat::from(exponent.1));
+            check_result(
+                z.powc(w),
+                *expected,
+                &format!("powc of {:?} and {:?}", z, w),
+            );
+        }
+
+        let near_unit = TwoComplex::new(TwoFloat::from(-1.0), TwoFloat::from(1e-30));
+        check_parts(
+            near_unit.powc(TwoComplex::I),
+            (
+                (0.04321391826377225, -1.2638616556156734e-18),
+                (2.1606959131886127e-62, 1.4133769946156239e-78),
+            ),
+            "powc of -1 + 1e-30 i and i",
+        );
+
+        let a = TwoComplex::new(TwoFloat::from(1.5), TwoFloat::from(-0.5));
+        let squared = a.powc(TwoComplex::from(2.0));
+        assert!((squared - a * a).norm() < 1e-12);
+        assert_eq!(TwoComplex::ZERO.powc(TwoComplex::I + 1.0), TwoComplex::ZERO);
+        assert!(TwoComplex::ZERO.powc(TwoComplex::I).is_nan());
+    }
+
+    #[test]
+    fn trigonometry_test() {
+        check_cases(&SIN_CASES, TwoComplex::sin, "sin");
+        check_cases(&COS_CASES, TwoComplex::cos, "cos");
+        check_cases(&TAN_CASES, TwoComplex::tan, "tan");
+        for (input, expected) in TAN_POLE_CASES.iter() {
+            let z = TwoComplex::new(TwoFloat::from(input.0), TwoFloat::from(input.1));
+            check_parts(z.tan(), *expected, &format!("tan of {:?}", z));
+        }
+
+        let large = TwoComplex::new(TwoFloat::from(1.0), TwoFloat::from(-1000.0));
+        let tan = large.tan();
+        assert_eq!(tan.im, -1.0);
+        assert!(tan.re.abs() < 1e-300);
+        assert_eq!(
+            TwoComplex::from(0.5).tan(),
+            TwoComplex::from(TwoFloat::from(0.5).tan())
+        );
+    }
+}
@@ -0,0 +1,264 @@
+#![allow(clippy::float_cmp)]
+
+use core::convert::TryFrom;
+
+pub mod common;
+
+use common::*;
+use rand::Rng;
+use twofloat::{TwoF32, TwoFloat, TwoFloatError};
+
+// Returns a random value with the low word at most half a unit in the last
+// place of the high word.
+fn get_twof32() -> TwoF32 {
+    let mut rng = rand::thread_rng();
+    let sign = if rng.gen() { 1.0 } else { -1.0 };
+    let hi = sign * rng.gen_range(1.0f32..2.0) * 2f32.powi(rng.gen_range(-40..40));
+    let lo = hi * rng.gen_range(-1.0..1.0) * f32::EPSILON * 0.5;
+    TwoF32::new_add(hi, lo)
+}
+
+#[test]
+fn new_test() {
+    repeated_test(|| {
+        let mut rng = rand::thread_rng();
+        let a = rng.gen_range(-1e10f32..1e10);
+        let b = rng.gen_range(-1e10f32..1e10);
+        let (x, y) = (to_big(&[f64::from(a)]), to_big(&[f64::from(b)]));
+
+        let cases = [
+            (TwoF32::new_add(a, b), &x + &y, "new_add"),
+            (TwoF32::new_sub(a, b), &x - &y, "new_sub"),
+        ];
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {} and {}", op, a, b);
+            assert_valid(*result, &description);
+            assert_eq!(big(*result), *exact, "Inexact result of {}", description);
+        }
+
+        let product = TwoF32::new_mul(a, b);
+        assert_eq!(
+            TwoFloat::from(product),
+            TwoFloat::new_mul(f64::from(a), f64::from(b))
+        );
+
+        let quotient = TwoF32::new_div(a, b);
+        let description = format!("new_div of {} and {}", a, b);
+        assert_valid(quotient, &description);
+        assert_close(
+            &(big(quotient) * &y),
+            &(x * to_big(&[1.0])),
+            TwoF32::TOLERANCE_BITS,
+            &description,
+        );
+    });
+}
+
+#[test]
+fn add_sub_test() {
+    repeated_test(|| {
+        let a = get_twof32();
+        let b = get_twof32();
+        let x = rand::thread_rng().gen_range(-1e10f32..1e10);
+
+        let cases = [
+            (a + b, big(a) + big(b), "add"),
+            (a - b, big(a) - big(b), "sub"),
+            (a + x, big(a) + to_big(&[f64::from(x)]), "add f32"),
+            (x + a, big(a) + to_big(&[f64::from(x)]), "f32 add"),
+            (a - x, big(a) - to_big(&[f64::from(x)]), "sub f32"),
+            (x - a, to_big(&[f64::from(x)]) - big(a), "f32 sub"),
+        ];
+
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(&big(*result), exact, TwoF32::TOLERANCE_BITS, &description);
+        }
+    });
+}
+
+#[test]
+fn mul_test() {
+    repeated_test(|| {
+        let a = get_twof32();
+        let b = get_twof32();
+        let x = rand::thread_rng().gen_range(-1e10f32..1e10);
+
+        let cases = [
+            (a * b, big(a) * big(b), "mul"),
+            (a * x, big(a) * to_big(&[f64::from(x)]), "mul f32"),
+            (x * a, big(a) * to_big(&[f64::from(x)]), "f32 mul"),
+        ];
+
+        // Products carry twice the scale of their arguments
+        let scale = to_big(&[1.0]);
+        for (result, exact, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(
+                &(big(*result) * &scale),
+                exact,
+                TwoF32::TOLERANCE_BITS,
+                &description,
+            );
+        }
+    });
+}
+
+#[test]
+fn div_test() {
+    repeated_test(|| {
+        let a = get_twof32();
+        let b = get_twof32();
+        let x = rand::thread_rng().gen_range(1.0f32..1e10);
+
+        let cases = [
+            (a / b, big(a), big(b), "div"),
+            (a / x, big(a), to_big(&[f64::from(x)]), "div f32"),
+            (x / b, to_big(&[f64::from(x)]), big(b), "f32 div"),
+        ];
+
+        // Checks that the quotient times the divisor is close to the dividend
+        let scale = to_big(&[1.0]);
+        for (result, dividend, divisor, op) in cases.iter() {
+            let description = format!("{} of {:?} and {:?} / {:?}", op, a, b, x);
+            assert_valid(*result, &description);
+            assert_close(
+                &(big(*result) * divisor),
+                &(dividend * &scale),
+                TwoF32::TOLERANCE_BITS,
+                &description,
+            );
+        }
+    });
+}
+
+#[test]
+fn assign_ops_test() {
+    let mut value = TwoF32::from(1.0f32);
+    value += 2.0;
+    value *= TwoF32::new_add(1.0, 1e-10);
+    value -= 3.0;
+    value /= 3.0;
+
+    let expected = (TwoF32::new_add(3.0, 3e-10) - 3.0) / 3.0;
+    assert_eq!(value, expected);
+}
+
+#[cfg(feature = "math_funcs")]
+#[test]
+fn sqrt_test() {
+    repeated_test(|| {
+        let a = get_twof32().abs();
+        let result = a.sqrt();
+
+        let description = format!("sqrt of {:?}", a);
+        assert_valid(result, &description);
+        let scale = to_big(&[1.0]);
+        assert_close(
+            &(big(result) * big(result)),
+            &(big(a) * scale),
+            TwoF32::TOLERANCE_BITS,
+            &description,
+        );
+    });
+
+    assert_eq!(TwoF32::from(4.0f32).sqrt(), 2.0);
+    assert!(TwoF32::from(-0.0f32).sqrt().is_sign_negative());
+    assert!(TwoF32::from(-1.0f32).sqrt().is_nan());
+    assert_eq!(TwoF32::INFINITY.sqrt(), TwoF32::INFINITY);
+}
+
+#[test]
+fn special_values_test() {
+    assert_eq!(TwoF32::MAX * 2.0, TwoF32::INFINITY);
+    assert_eq!(TwoF32::from(1.0f32) / 0.0, TwoF32::INFINITY);
+    assert_eq!(TwoF32::from(-1.0f32) / 0.0, TwoF32::NEG_INFINITY);
+    assert!((TwoF32::INFINITY - TwoF32::INFINITY).is_nan());
+    assert!((TwoF32::NAN + 1.0).is_nan());
+    assert!((TwoF32::from(1.0f32) - 1.0).is_sign_positive());
+    assert!((TwoF32::from(-0.0f32) * 1.0).is_sign_negative());
+
+    assert!(TwoF32::MAX.is_valid());
+    assert!(!TwoF32::NAN.is_valid());
+    assert!(TwoF32::MIN_POSITIVE.is_valid());
+    assert_eq!(TwoF32::MIN, -TwoF32::MAX);
+    assert_eq!(
+        TwoF32::from(1.0f32) + TwoF32::EPSILON,
+        TwoF32::new_add(1.0, TwoF32::EPSILON.hi())
+    );
+}
+
+#[test]
+fn conversion_test() {
+    repeated_test(|| {
+        let a = get_twof32();
+        let wide = TwoFloat::from(a);
+
+        assert_eq!(to_big(&[wide.hi(), wide.lo()]), big(a));
+        assert_eq!(TwoF32::from(wide), a);
+    });
+
+    assert_eq!(f32::from(TwoF32::new_add(1.0, 1e-10)), 1.0);
+    assert_eq!(
+        f64::from(TwoF32::new_add(1.0, 0.5f32.powi(30))),
+        1.0 + 0.5f64.powi(30)
+    );
+    assert!(TwoFloat::from(TwoF32::NAN).is_nan());
+    assert_eq!(TwoFloat::from(TwoF32::NEG_INFINITY), TwoFloat::NEG_INFINITY);
+    assert!(TwoFloat::from(TwoF32::from(-0.0f32)).is_sign_negative());
+}
+
+#[test]
+fn rounding_conversion_test() {
+    repeated_test(|| {
+        let a = get_valid_twofloat(|x, _| x.abs() > 1e-20 && x.abs() < 1e20);
+        let result = TwoF32::from(a);
+
+        let description = format!("conversion of {:?}", a);
+        assert_valid(result, &description);
+        assert_close(
+            &big(result),
+            &to_big(&[a.hi(), a.lo()]),
+            TwoF32::TOLERANCE_BITS,
+            &description,
+        );
+    });
+
+    // The remainder 2^-30 + 2^-54 + 2^-90 rounds to a midpoint between two
+    // f32 values in f64, so it must not be rounded twice
+    let value = TwoFloat::new_add(1.0 + 2f64.powi(-30), 2f64.powi(-54) + 2f64.powi(-90));
+    let result = TwoF32::from(value);
+    assert_eq!(result.hi(), 1.0);
+    assert_eq!(result.lo(), 2f32.powi(-30) + 2f32.powi(-53));
+    assert_eq!(
+        TwoF32::from(-value).lo(),
+        -(2f32.powi(-30) + 2f32.powi(-53))
+    );
+
+    assert_eq!(TwoF32::from(1e300), TwoF32::INFINITY);
+    assert_eq!(TwoF32::from(-1e-300), -0.0f32);
+    assert!(TwoF32::from(TwoFloat::NAN).is_nan());
+}
+
+#[test]
+fn tuple_conversion_test() {
+    let value = TwoF32::new_add(1.0, 1e-10);
+    let (hi, lo) = value.into();
+    assert_eq!(TwoF32::try_from((hi, lo)), Ok(value));
+    assert_eq!(TwoF32::try_from([hi, lo]), Ok(value));
+    assert_eq!(
+        TwoF32::try_from((1.0, 0.5)),
+        Err(TwoFloatError::ConversionError)
+    );
+}
+
+#[test]
+fn comparison_test() {
+    let a = TwoF32::new_add(1.0, 1e-10);
+    assert!(a > 1.0);
+    assert!(1.0 < a);
+    assert!(a == TwoF32::new_add(1e-10, 1.0));
+    assert!(TwoF32::from(2.0f32) == 2.0);
+}